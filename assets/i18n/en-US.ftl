@@ -86,6 +86,9 @@ not-found-btn = Go home
 nav-dashboard = Dashboard
 nav-user-list = User List
 nav-field-service-reports = Field Service Reports
+nav-monthly-totals = Monthly Totals
+nav-missing-reports = Missing Reports
+nav-pioneers = Pioneers
 nav-absences = Absences
 nav-public-preaching = Public Preaching
 nav-field-service-groups = Field Service Groups
@@ -247,21 +250,161 @@ terr-map-click-hint = Click on the map to add an address point
 
 # Attendants page
 page-attendants = Attendants
-btn-assign-attendant = ＋ Assign Attendant
-empty-attendants-title = No assignments yet
-empty-attendants-desc = Attendant assignments will appear here.
 
 # A/V platform page
 page-av-platform = Audio/Video & Platform
-btn-add-assignment = ＋ Add Assignment
-empty-av-title = No assignments yet
-empty-av-desc = A/V and platform assignments will appear here.
+
+# Duty rotas (attendants, AV & platform)
+duty-settings = Settings
+duty-print = Print chart
+duty-export = Export CSV
+duty-generate = Generate rota
+duty-open-places = open places
+duty-all-filled = Every place is filled
+duty-loading = Loading rota…
+duty-no-meetings = No meetings this month.
+duty-places-per-meeting = Places per meeting
+duty-days-hint = Usual meeting days, used for weeks without a meeting program.
+duty-midweek = Midweek meeting
+duty-weekend = Weekend meeting
+duty-date = Date
+duty-meeting = Meeting
+
+# Cleaning
+cleaning-rota = Rota
+cleaning-areas = Areas
+cleaning-print = Print
+cleaning-generate = Generate rota
+cleaning-generate-title = Rotate the cleaning among the groups
+cleaning-generate-confirm = Weeks already planned in this range will be replaced and their ticks cleared.
+cleaning-from = From
+cleaning-to = To
+cleaning-deep-every = Deep clean every … weeks (0 = never)
+cleaning-groups-hint = Groups take turns in this order, carrying on after the group that cleaned last.
+cleaning-loading = Loading cleaning rota…
+cleaning-coordinators = Coordinators:
+cleaning-no-groups = Create field service groups to rotate the cleaning among them.
+cleaning-no-areas = No cleaning areas yet. Add the areas of the hall and their checklists.
+cleaning-no-group = No group
+cleaning-weekly = Weekly
+cleaning-deep = Deep clean
+cleaning-checklist = Checklist
+cleaning-week = Week
+cleaning-kind = Cleaning
+cleaning-group = Group
+cleaning-completed = Completed
+cleaning-add-area = ＋ Add area
+cleaning-area-name = Area
+cleaning-area-name-required = The area needs a name.
+cleaning-area-delete-confirm = Delete this area and its checklists?
+cleaning-weekly-tasks = Weekly tasks
+cleaning-deep-tasks = Deep-clean tasks
+cleaning-tasks-placeholder = One task per line
+
+# Maintenance
+maint-work-orders = Work orders
+maint-preventive = Preventive
+maint-assets = Assets
+maint-loading = Loading maintenance…
+maint-show-closed = Show done and cancelled
+maint-add-order = ＋ Add work order
+maint-add-task = ＋ Add preventive task
+maint-add-asset = ＋ Add asset
+maint-no-orders = No open work orders.
+maint-no-tasks = No preventive tasks yet.
+maint-no-assets = No assets registered yet.
+maint-title = Title
+maint-title-required = A title is required.
+maint-task-placeholder = e.g. Inspect fire extinguishers
+maint-description = Description
+maint-asset = Asset
+maint-status = Status
+maint-status-open = Open
+maint-status-in-progress = In progress
+maint-status-done = Done
+maint-status-cancelled = Cancelled
+maint-assignee = Assigned to
+maint-reported = Reported
+maint-due = Due
+maint-completed = Completed
+maint-cost-notes = Cost notes
+maint-cost-notes-placeholder = Quotes, parts bought, who paid…
+maint-delete-confirm = Delete permanently?
+maint-every = Every
+maint-unit-day = day
+maint-unit-days = days
+maint-unit-week = week
+maint-unit-weeks = weeks
+maint-unit-month = month
+maint-unit-months = months
+maint-unit-year = year
+maint-unit-years = years
+maint-last-done = Last done
+maint-next-due = Next due
+maint-never = Never
+maint-due-now = Due now
+maint-overdue = Overdue
+maint-mark-done = Done today
+maint-notes = Notes
+maint-asset-name = Name
+maint-name-required = A name is required.
+maint-location = Location
+maint-installed = Installed
+maint-asset-notes-placeholder = Make, model, serial number…
+maint-tasks-count = preventive tasks
+maint-open-orders-count = open work orders
+maint-asset-delete-confirm = Delete this asset? Its tasks and work orders are kept.
+maint-alerts = Overdue maintenance
+maint-alerts-more = more
+
+# Public witnessing
+pw-schedule = Schedule
+pw-shifts = Shifts
+pw-locations = Locations
+pw-loading = Loading public witnessing…
+pw-pair = Pair automatically
+pw-week-totals = Placements · videos · return visits:
+pw-no-shifts = No shifts this week.
+pw-no-shifts-desc = Add locations and weekly shifts to start planning.
+pw-add-publisher = Add publisher
+pw-sign-me-up = Sign me up
+pw-remove = Remove
+pw-reported = Reported
+pw-report = Report
+pw-placements = Placements
+pw-videos = Videos
+pw-return-visits = Return visits
+pw-notes = Notes
+pw-issue-unstaffed = Nobody signed up
+pw-issue-needs-partner = Needs a partner
+pw-issue-mixed-pair = Brother and sister of different households
+pw-err-not-qualified = This publisher is not approved for public witnessing.
+pw-err-already = Already signed up for this shift.
+pw-err-full = This shift is full.
+pw-err-absent = This publisher is away on that date.
+pw-err-overlapping = Already signed up for another shift at the same time.
+pw-add-location = ＋ Add location
+pw-location-name = Name
+pw-location-name-required = A name is required.
+pw-description = Description
+pw-pick-point-hint = Click on the map to place the cart.
+pw-no-point = Not on the map
+pw-no-locations = No locations yet.
+pw-location-delete-confirm = Delete this location?
+pw-active = Active
+pw-inactive = Inactive
+pw-add-shift = ＋ Add shift
+pw-location = Location
+pw-location-required = Choose a location.
+pw-weekday = Day
+pw-start = From
+pw-end = To
+pw-time-invalid = The shift must end after it starts.
+pw-capacity = Capacity
+pw-shift-delete-confirm = Delete this shift? Past sign-ups are kept.
 
 # Public preaching page
 page-public-preaching = Public Preaching
-btn-add-arrangement = ＋ Add Arrangement
-empty-public-preaching-title = No arrangements yet
-empty-public-preaching-desc = Public preaching arrangements will appear here.
 
 # Field service groups page
 page-field-service-groups = Field Service Groups
@@ -271,6 +414,11 @@ empty-groups-desc = Create field service groups to organize your publishers.
 
 # Field service reports page
 page-field-service-reports = Field Service Reports
+page-monthly-totals = Monthly Totals (S-1)
+page-missing-reports = Missing Reports
+page-pioneers = Pioneer Hours
+page-bulk-reports = Report Entry Grid
+page-reports-csv = CSV Import & Export
 btn-add-report = ＋ Add Report
 empty-reports-title = No reports yet
 empty-reports-desc = Field service reports will appear here.
@@ -283,18 +431,87 @@ empty-fs-meetings-desc = Scheduled field service meetings will appear here.
 
 # Cleaning page
 page-cleaning = Cleaning
-empty-cleaning-title = No cleaning assignments yet
-empty-cleaning-desc = Cleaning schedules and assignments will appear here.
 
 # Weekday meeting page
 page-weekday-meeting = Weekday Meeting
-empty-weekday-title = No assignments yet
-empty-weekday-desc = Weekday meeting assignments will appear here.
+midweek-loading = Loading program…
+midweek-week-of = Week of
+midweek-create-btn = ＋ Create program
+midweek-open-slots = open
+midweek-complete = Complete
+midweek-date = Meeting date
+midweek-date-required = The meeting date is required.
+midweek-bible-reading = Weekly Bible reading
+midweek-bible-reading-placeholder = e.g. ISAIAH 1-2
+midweek-songs = Songs
+midweek-classrooms = Classrooms
+midweek-chairman = Chairman
+midweek-aux-counselor = Auxiliary classroom counselor
+midweek-opening-prayer = Opening prayer
+midweek-closing-prayer = Closing prayer
+midweek-part-kind = Part
+midweek-part-title = Theme
+midweek-minutes = Min.
+midweek-lesson = Lesson
+midweek-classroom = Classroom
+midweek-classroom-main = Main hall
+midweek-classroom-aux = Auxiliary
+midweek-student = Student
+midweek-assigned = Assigned to
+midweek-householder = Householder
+midweek-reader = Reader
+midweek-not-eligible = not eligible
+midweek-move-up = Move up
+midweek-move-down = Move down
+midweek-remove-part = Remove part
+midweek-add-part = ＋ Add part
+midweek-repeated-warning = Assigned more than once this week:
+midweek-delete-confirm = Delete this week's program?
 
 # Weekend meeting page
 page-weekend-meeting = Weekend Meeting
-empty-weekend-title = No assignments yet
-empty-weekend-desc = Weekend meeting assignments will appear here.
+weekend-loading = Loading program…
+weekend-week-of = Week of
+weekend-weeks-open = weeks with open slots
+weekend-all-filled = All weeks filled
+weekend-create-btn = ＋ Create program
+weekend-no-meeting = no meeting this week
+weekend-cancelled-but-planned = An assembly or convention falls in this week.
+weekend-open-slots = open
+weekend-complete = Complete
+weekend-date = Meeting date
+weekend-date-required = The meeting date is required.
+weekend-songs = Songs
+weekend-chairman = Chairman
+weekend-opening-prayer = Opening prayer
+weekend-closing-prayer = Closing prayer
+weekend-public-talk = Public talk
+weekend-visiting-speaker = Visiting speaker
+weekend-guest-from-catalog = Speaker from the catalog
+weekend-speaker = Speaker
+weekend-speaker-name = Speaker name
+weekend-speaker-congregation = Congregation
+weekend-talk-number = Outline no.
+weekend-talk-title = Talk title
+weekend-wt-conductor = Watchtower conductor
+weekend-wt-reader = Watchtower reader
+weekend-repeated-warning = Assigned more than once this week:
+weekend-delete-confirm = Delete this week's program?
+
+# Fair rotation
+rotation-propose = Propose assignments
+rotation-proposal-title = Proposed assignments
+rotation-proposal-hint = Open places go to whoever waited longest since their last assignment, skipping absences and anyone already in this program. Accept to fill them in, then adjust before saving.
+rotation-nothing-open = Every place is already filled.
+rotation-accept = Accept
+rotation-discard = Discard
+rotation-kept = Already assigned
+rotation-last-assigned = Last assigned
+rotation-never-assigned = Never assigned
+rotation-candidates = eligible
+rotation-absent = absent
+rotation-busy = already in this program
+rotation-no-candidate = Nobody available
 
 # Congregation settings page
 page-congregation-settings = Congregation Settings
@@ -378,6 +595,7 @@ user-delete-title = Delete User
 user-delete-confirm = This will permanently delete this user and all their data. This action cannot be undone.
 btn-delete = Delete
 btn-confirm = Confirm
+btn-close = Close
 user-detail-personal = Personal Information
 user-detail-contact = Contact Information
 
@@ -416,6 +634,15 @@ report-preached = Preached
 report-detail-title = Report Details
 report-btn-view = View
 report-filter-user-placeholder = Search by name…
+report-from-portal = Portal
+report-pending = Pending review
+report-pending-count = pending review
+report-locked = Locked
+report-approve-btn = Approve
+report-lock-btn = Lock
+report-unlock-btn = Unlock
+report-lock-hint = Stop the publisher from changing this report in the portal
+report-unlock-hint = Let the publisher change this report in the portal again
 reports-loading = Loading reports…
 reports-no-reports = No reports submitted for this month.
 reports-submitted-of = submitted
@@ -433,6 +660,13 @@ month-9 = September
 month-10 = October
 month-11 = November
 month-12 = December
+weekday-1 = Monday
+weekday-2 = Tuesday
+weekday-3 = Wednesday
+weekday-4 = Thursday
+weekday-5 = Friday
+weekday-6 = Saturday
+weekday-7 = Sunday
 
 # User activity filter
 user-filter-activity = Activity
@@ -461,6 +695,9 @@ absence-form-required-error = Please fill in all required fields.
 absence-form-date-order-error = End date must be after start date.
 absence-delete-title = Delete Absence
 absence-delete-confirm = Are you sure you want to delete this absence?
+absence-requests-title = Requested through the publisher portal
+absence-request-accept = Accept
+absence-request-decline = Decline
 
 # Field service groups
 group-loading = Loading groups…
@@ -493,6 +730,8 @@ user-detail-no-group = No Group
 nav-privileges = Privileges
 nav-maintenance = Maintenance
 nav-public-talks = Public Talks
+nav-conflicts = Conflicts
+nav-meeting-attendance = Attendance
 
 # Privileges page
 page-privileges = Privileges
@@ -527,6 +766,7 @@ priv-congregation-bible-study-reader = CBS Reader
 priv-weekend-pray = Weekend Pray
 priv-weekend-chairman = Weekend Chairman
 priv-watchtower-conductor = Watchtower Conductor
+priv-watchtower-reader = Watchtower Reader
 priv-public-talks = Public Talks
 priv-public-talks-away = Public Talks — Away
 priv-stage = Stage
@@ -545,13 +785,50 @@ priv-territory = Territory
 
 # Maintenance page
 page-maintenance = Maintenance
-empty-maintenance-title = No maintenance tasks yet
-empty-maintenance-desc = Maintenance tasks and schedules will appear here.
 
 # Public Talks page
 page-public-talks = Public Talks
-empty-public-talks-title = No public talks scheduled yet
-empty-public-talks-desc = Incoming and outgoing public talks will appear here.
+page-conflicts = Schedule Conflicts
+page-meeting-attendance = Meeting Attendance
+talks-loading = Loading talks…
+talks-view-schedule = Schedule
+talks-view-outlines = Outlines
+talks-view-speakers = Speakers
+talks-view-history = History
+talks-incoming = Incoming talks
+talks-incoming-hint = Planned on the weekend meeting schedule.
+talks-outgoing = Outgoing talks
+talks-add-outgoing = ＋ Add talk
+talks-none-planned = Nothing planned.
+talks-date = Date
+talks-outline = Outline
+talks-visited-congregation = Congregation visited
+talks-speaker-required = Choose a speaker.
+talks-outgoing-required = Date and congregation are required.
+talks-search = Search…
+talks-no-outlines = No outlines yet. Paste the catalog to add them.
+talks-last-given = Last given
+talks-import-title = Add outlines
+talks-import-hint = Paste one outline per line, starting with its number. Existing numbers get the new title.
+talks-import-count = outlines recognised
+talks-local-speakers = Speakers of this congregation
+talks-no-local-speakers = No brothers have the public talk privilege.
+talks-no-outlines-set = No outlines recorded
+talks-visiting-speakers = Visiting speakers
+talks-add-speaker = ＋ Add speaker
+talks-no-visiting-speakers = No visiting speakers yet.
+talks-phone = Phone
+talks-email = Email
+talks-speaker-outlines = Outlines prepared
+talks-unknown-outlines = Not in the catalog:
+talks-name-required = The speaker's name is required.
+talks-repeat-months = Warn when an outline repeats within (months)
+talks-months-invalid = Enter a number of months.
+talks-repeats-found = repeated outlines in the history
+talks-repeat-warning = Outline already given here on
+talks-given-here = Given in this congregation
+talks-given-away = Given in other congregations
+talks-no-history = No talks recorded yet.
 
 # Events page
 nav-events = Events
@@ -590,10 +867,107 @@ event-form-start-date = Start Date
 event-form-end-date = End Date
 event-form-description = Description
 event-delete-confirm = Are you sure?
+event-upcoming = Upcoming
+event-history = History
+event-history-empty = No past events yet. Events stay here once they are over.
+event-form-repeat = Repeats
+event-form-until = Until (optional)
+event-repeat-never = Does not repeat
+event-repeat-weekly = Every week
+event-repeat-monthly = Monthly
+event-repeat-yearly = Every year
+event-nth-1 = first
+event-nth-2 = second
+event-nth-3 = third
+event-nth-4 = fourth
+event-nth-last = last
+ics-export-for = Calendar file for
+ics-whole-congregation = Whole congregation
+ics-export-btn = Download calendar (.ics)
+ics-export-hint = Covers the past month and the coming year: events, meeting assignments, duties, field service meetings and territories due back. Importing the file again updates the entries already in your calendar.
+ics-field-service = Field service meeting
+ics-territory-due = Territory due back:
+ics-import-btn = Import calendar file (.ics)
+ics-import-hint = Assembly and convention dates sent by the circuit as a calendar file. Each entry is typed from its title; check the preview before importing.
+ics-events = events
+ics-no-events = No events were found in this file.
+ics-summary = Title
+ics-already-stored = already stored
+ics-import-duplicates = Import them anyway
+ics-duplicate-of = Already stored:
+ics-import-confirm = Import events
+ics-imported = events imported
+
+# Circuit overseer visit
+co-plan-visit = Plan visit
+co-title = Circuit overseer visit
+co-not-found = This event is not a circuit overseer visit, or it no longer exists.
+co-template-hint = This is the usual week of a visit. Adjust it and save to keep the plan.
+co-saved = Saved
+co-arrangements = Arrangements
+co-add-arrangement = Add
+co-kind = Arrangement
+co-time = Time
+co-place = Place
+co-person = Brother
+co-notes = Notes
+co-midweek-meeting = Midweek meeting
+co-weekend-meeting = Weekend meeting
+co-field-service = Meeting for field service
+co-pioneer-meeting = Meeting with the pioneers
+co-elders-meeting = Meeting with the elders and ministerial servants
+co-shepherding-call = Shepherding call
+co-other = Other
+co-meals = Meals
+co-meals-hint = Hosts are chosen among the publishers with the hospitality privilege.
+co-suggest-hosts = Suggest hosts
+co-no-hosts = No publisher has the hospitality privilege yet.
+co-lunch = Lunch
+co-dinner = Dinner
+co-checklist = Reports to prepare
+co-open = Open
+co-task-publisher-records = Congregation's Publisher Record cards
+co-task-reports = Monthly field service totals
+co-task-missing-reports = Publishers who have not reported
+co-task-pioneers = Pioneer list
+co-task-territory = Territory coverage
+co-task-schedules = Meeting schedules for the visit week
+co-task-accounts = Congregation accounts
+
+# Schedule conflicts
+conflict-source-duty = Duties
+conflict-conductor = Conductor
+conflict-shift = Shift
+conflict-absent = Away on this date
+conflict-during-event = No meeting this week:
+conflict-overbooked = Also assigned to
+conflict-brothers-only = Only brothers can take this part
+conflict-appointed-only = Only elders or ministerial servants can take this part
+conflict-elders-only = Only elders can take this part
+conflict-no-privilege = Not approved for this assignment
+conflict-gender-mismatch = Partner is not of the same gender
+conflict-inactive = Publisher is inactive
+conflict-date = Date
+conflict-person = Person
+conflict-module = Schedule
+conflict-place = Assignment
+conflict-issue = Problem
+conflict-found = conflicts in the schedules
+conflict-recheck = Check again
+conflict-see-all = See all
+conflict-all-modules = All schedules
+conflict-none = No conflicts this month
 
 # Dashboard upcoming events
 dash-upcoming-events = Upcoming Events
 dash-no-upcoming-events = No events in the next 60 days
+dash-sy-title = Service year
+dash-sy-vs-last-year = compared with the same months last year
+dash-sy-hours = hours
+dash-sy-studies = studies
+dash-sy-active = avg. active publishers
+dash-sy-no-group = No group
+dash-attendance-title = Average meeting attendance
 
 # Common buttons
 btn-delete = Delete
@@ -619,3 +993,227 @@ fsm-meeting-form-notes-placeholder = Optional notes…
 fsm-meeting-form-required-error = Please fill in all required fields.
 fsm-assign-to-anyone = Assign to anyone
 fsm-delete-confirm = Delete this meeting?
+
+# S-21 publisher record card
+s21-title = Congregation's Publisher Record
+s21-name = Name
+s21-birth-date = Date of birth
+s21-baptism-date = Date immersed
+s21-male = Male
+s21-female = Female
+s21-other-sheep = Other sheep
+s21-anointed = Anointed
+s21-elder = Elder
+s21-ms = Ministerial servant
+s21-regular-pioneer = Regular pioneer
+s21-special-pioneer = Special pioneer
+s21-field-missionary = Field missionary
+s21-service-year = Service Year
+s21-col-shared = Shared in Ministry
+s21-col-studies = Bible Studies
+s21-col-aux = Auxiliary Pioneer
+s21-col-hours = Hours (if pioneer or field missionary)
+s21-col-remarks = Remarks
+s21-total = Total
+s21-print-btn = Print S-21
+s21-print-all-btn = Print S-21 cards
+
+# S-1 monthly congregation totals
+s1-title = Congregation Report (S-1)
+s1-active-publishers = Active publishers
+s1-active-hint = months window, including this month
+s1-col-reports = reports
+s1-cat-publishers = Publishers
+s1-cat-aux-pioneers = Auxiliary pioneers
+s1-cat-regular-pioneers = Regular pioneers
+s1-cat-branch = Special pioneers & missionaries
+s1-branch-hint = Special pioneers and field missionaries report directly to the branch and are not included in the totals.
+s1-no-reports = No reports in this category.
+s1-studies-short = studies
+s1-print-btn = Print S-1
+
+# Midweek meeting schedule (S-140) and assignment slips (S-89)
+s140-title = Our Christian Life and Ministry Meeting Schedule
+s140-song = Song
+s140-conductor = Conductor
+s140-print-btn = Print S-140
+s89-title = Our Christian Life and Ministry Meeting Assignment
+s89-name = Name
+s89-assistant = Assistant
+s89-date = Date
+s89-part = Part number
+s89-given-in = To be given in:
+s89-note = Note to student: The source material and study point for your assignment are in the meeting workbook. Please prepare with the study point from the Apply Yourself to Reading and Teaching brochure.
+s89-print-btn = Print S-89 slips
+s89-week-btn = Slips
+
+# Meeting attendance (S-88)
+s88-title = Record of Meeting Attendance
+s88-service-year = Service year
+s88-month = Month
+s88-meetings = Number of meetings
+s88-total = Total attendance
+s88-average = Average attendance each week
+s88-year-average = Average attendance each month
+att-loading = Loading attendance…
+att-print-s88 = Print S-88
+att-entries = Attendance by meeting
+att-in-person = In person
+att-online = Online
+att-total = Total
+att-meetings = meetings
+att-average = average
+att-no-meetings = No meetings this month
+att-hint = Count everyone present, including children, and everyone following online. Clear both counts of a meeting that was not held.
+
+# Missing reports tracker
+missing-due-on = Reports are due on
+missing-overdue-since = Reports were due on
+missing-count-suffix = publishers have not reported yet
+missing-no-group = No group
+missing-all-reported = All reported
+missing-remind-btn = Remind
+missing-mark-follow-up = Followed up
+missing-undo-follow-up = Undo
+missing-followed-up-on = Followed up on
+missing-reminder-title = Report reminder
+missing-reminder-subject = Field service report reminder
+missing-reminder-hello = Hello
+missing-reminder-body = This is a friendly reminder to send your field service report for
+missing-reminder-thanks = Thank you very much!
+missing-no-contact = No phone number or email stored — copy the message and send it another way.
+missing-email-btn = Email
+missing-copy-btn = Copy message
+missing-copied = Copied ✓
+
+# Publisher activity status
+status-active = Active
+status-irregular = Irregular
+status-inactive = Inactive
+status-reactivated = Reactivated
+status-history = Status
+
+# Publisher portal
+landing-portal-link = Publisher? Submit your report in the portal
+portal-subtitle = Publisher portal
+portal-login-title = Sign in to the portal
+portal-login-desc = Use the username and password you received from the congregation secretary.
+portal-username = Username
+portal-no-account = This login has no portal account.
+portal-back-to-app = Back to the main app
+portal-signed-in-as = Signed in as
+portal-sign-out = Sign out
+portal-recent-reports = Your recent reports
+portal-approved = Approved
+portal-locked-hint = The secretary has locked this report. Ask them if something needs to change.
+portal-preached = I shared in the ministry this month
+portal-hours-hint = Hours and credits are only needed if you are a pioneer.
+portal-report-sent = Report sent. The secretary will review it.
+portal-send-report = Send report
+portal-update-report = Update report
+portal-absence-title = Let the congregation know you will be away
+portal-absence-hint = The secretary will add the absence once they have seen your request.
+portal-absence-send = Send request
+portal-absence-withdraw = Withdraw
+portal-account-title = Portal account
+portal-account-none = No portal account yet. Create one so this publisher can submit their own reports.
+portal-account-create = Create account
+portal-account-enabled = Enabled
+portal-account-disabled = Disabled
+portal-account-enable = Enable
+portal-account-disable = Disable
+portal-account-new-password = New password
+portal-account-reset-password = Set password
+portal-settings-title = Publisher portal
+portal-settings-desc = Publishers with a portal account can enter their own report for the current month and announce absences. The server enforces these limits, so the permission rules below must be installed once.
+portal-settings-apply = Install permission rules
+portal-settings-show-schema = Show rules
+portal-settings-applied = Permission rules installed.
+portal-settings-failed = Could not install the rules with this account. Ask the administrator of the database to run the rules shown below.
+
+# Pioneer hour goals
+pioneer-through = through
+pioneer-none = No pioneers in this service year.
+pioneer-alerts = pioneers significantly behind their hour requirement
+pioneer-pace-marker = Where the total should be by now
+pioneer-projected = Projected year end
+pioneer-credits = Credit
+pioneer-credits-counted = counted of reported
+pioneer-months-behind = months behind pace
+pioneer-on-pace = On pace
+pioneer-credit-limit = Monthly limit for hours plus credit
+pioneer-credit-limit-hint = Credit counts until a month reaches this total, or the month's requirement when that is higher. Leave empty to count credit only up to the requirement.
+pioneer-credit-limit-invalid = Enter a number of hours.
+
+# Bulk report entry
+bulk-open-btn = Grid entry
+bulk-all-publishers = All publishers
+bulk-keyboard-hint = ↑ ↓ or Enter move between publishers, Tab moves across. Changed rows are highlighted until saved.
+bulk-no-members = No publishers in this group.
+bulk-col-publisher = Publisher
+bulk-save-all = Save all
+bulk-unsaved = unsaved
+bulk-invalid = with errors
+bulk-fix-errors = Fix the highlighted rows before saving.
+bulk-created = created
+bulk-updated = updated
+bulk-err-number = Use whole numbers only.
+bulk-err-hours-range = More hours than the month has.
+bulk-err-not-preached = Marked as not preached but has figures.
+bulk-err-hours-pioneer = Hours are only reported by pioneers.
+bulk-err-credits-pioneer = Credit is only reported by pioneers.
+
+# CSV import/export of reports
+csv-open-btn = CSV
+csv-export-title = Export
+csv-export-hint = Download every report in a range of months as a CSV file. Notes are not included.
+csv-from = From
+csv-to = To
+csv-export-btn = Export CSV
+csv-export-bad-range = Choose a valid range of months.
+csv-exported = reports exported
+csv-import-title = Import
+csv-import-hint = Load reports from a spreadsheet saved as CSV. The first line must hold the column names; publishers are matched by name.
+csv-choose-file = Choose CSV file
+csv-read-error = The file could not be read.
+csv-rows = rows
+csv-mapping-title = Columns
+csv-col-ignore = — Ignore —
+csv-col-name = Full name
+csv-col-first-name = First name
+csv-col-last-name = Last name
+csv-col-year = Year
+csv-col-month = Month
+csv-col-year-month = Year and month
+csv-map-no-name = Map a full name column, or both first and last name.
+csv-map-no-period = Map a year-and-month column, or both year and month.
+csv-valid = ready
+csv-with-errors = with errors (skipped)
+csv-already-stored = already stored
+csv-overwrite = Overwrite stored reports
+csv-status = Status
+csv-new = New
+csv-duplicate-existing = Already stored
+csv-skipped = skipped
+csv-import-btn = Import
+csv-err-missing-name = No name.
+csv-err-unknown-publisher = No publisher with this name.
+csv-err-ambiguous-publisher = Several publishers have this name.
+csv-err-bad-period = Invalid year or month.
+csv-err-bad-number = not a whole number.
+csv-err-bad-flag = use yes or no.
+csv-err-duplicate-line = Same publisher and month as line
+
+# Month close
+month-closed = Month closed
+month-closed-on = Closed on
+month-closed-hint = Reports for this month are locked.
+month-close-hint = Close the month once the totals have been sent so its reports can no longer change.
+month-close-btn = Close month
+month-close-confirm = Lock every report of this month? Nobody can add, edit or delete them until the month is reopened.
+month-close-pending-warning = portal reports are still waiting for approval.
+month-reopen-btn = Reopen
+month-reopen-reason = Why does this month need to be reopened?
+month-close-history = History
+month-history-closed = Closed
+month-history-reopened = Reopened
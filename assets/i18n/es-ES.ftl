@@ -86,6 +86,9 @@ not-found-btn = Ir al inicio
 nav-dashboard = Inicio
 nav-user-list = Lista de usuarios
 nav-field-service-reports = Informes de Predicación
+nav-monthly-totals = Totales mensuales
+nav-missing-reports = Informes pendientes
+nav-pioneers = Precursores
 nav-absences = Ausencias
 nav-public-preaching = Predicación pública
 nav-field-service-groups = Grupos de Predicación
@@ -247,21 +250,161 @@ terr-map-click-hint = Haz clic en el mapa para agregar una dirección
 
 # Attendants page
 page-attendants = Acomodadores
-btn-assign-attendant = ＋ Asignar acomodador
-empty-attendants-title = Sin asignaciones aún
-empty-attendants-desc = Las asignaciones de acomodadores aparecerán aquí.
 
 # A/V platform page
 page-av-platform = Audio/Video y Plataforma
-btn-add-assignment = ＋ Añadir asignación
-empty-av-title = Sin asignaciones aún
-empty-av-desc = Las asignaciones de audio/video y plataforma aparecerán aquí.
+
+# Duty rotas (attendants, AV & platform)
+duty-settings = Ajustes
+duty-print = Imprimir cuadro
+duty-export = Exportar CSV
+duty-generate = Generar turnos
+duty-open-places = puestos libres
+duty-all-filled = Todos los puestos están cubiertos
+duty-loading = Cargando turnos…
+duty-no-meetings = No hay reuniones este mes.
+duty-places-per-meeting = Puestos por reunión
+duty-days-hint = Días habituales de reunión, usados en semanas sin programa.
+duty-midweek = Reunión de entre semana
+duty-weekend = Reunión del fin de semana
+duty-date = Fecha
+duty-meeting = Reunión
+
+# Cleaning
+cleaning-rota = Turnos
+cleaning-areas = Zonas
+cleaning-print = Imprimir
+cleaning-generate = Generar turnos
+cleaning-generate-title = Rotar la limpieza entre los grupos
+cleaning-generate-confirm = Las semanas ya planificadas en este periodo se reemplazarán y se borrarán sus marcas.
+cleaning-from = Desde
+cleaning-to = Hasta
+cleaning-deep-every = Limpieza a fondo cada … semanas (0 = nunca)
+cleaning-groups-hint = Los grupos se turnan en este orden, siguiendo después del último grupo que limpió.
+cleaning-loading = Cargando turnos de limpieza…
+cleaning-coordinators = Coordinadores:
+cleaning-no-groups = Cree grupos de servicio del campo para rotar la limpieza entre ellos.
+cleaning-no-areas = Aún no hay zonas de limpieza. Añada las zonas del Salón y sus listas de tareas.
+cleaning-no-group = Sin grupo
+cleaning-weekly = Semanal
+cleaning-deep = A fondo
+cleaning-checklist = Tareas
+cleaning-week = Semana
+cleaning-kind = Limpieza
+cleaning-group = Grupo
+cleaning-completed = Completado
+cleaning-add-area = ＋ Añadir zona
+cleaning-area-name = Zona
+cleaning-area-name-required = La zona necesita un nombre.
+cleaning-area-delete-confirm = ¿Eliminar esta zona y sus listas de tareas?
+cleaning-weekly-tasks = Tareas semanales
+cleaning-deep-tasks = Tareas de limpieza a fondo
+cleaning-tasks-placeholder = Una tarea por línea
+
+# Maintenance
+maint-work-orders = Órdenes de trabajo
+maint-preventive = Preventivo
+maint-assets = Equipos
+maint-loading = Cargando mantenimiento…
+maint-show-closed = Mostrar terminadas y canceladas
+maint-add-order = ＋ Añadir orden de trabajo
+maint-add-task = ＋ Añadir tarea preventiva
+maint-add-asset = ＋ Añadir equipo
+maint-no-orders = No hay órdenes de trabajo abiertas.
+maint-no-tasks = Aún no hay tareas preventivas.
+maint-no-assets = Aún no hay equipos registrados.
+maint-title = Título
+maint-title-required = El título es obligatorio.
+maint-task-placeholder = p. ej. Revisar los extintores
+maint-description = Descripción
+maint-asset = Equipo
+maint-status = Estado
+maint-status-open = Abierta
+maint-status-in-progress = En curso
+maint-status-done = Terminada
+maint-status-cancelled = Cancelada
+maint-assignee = Asignada a
+maint-reported = Notificada
+maint-due = Vence
+maint-completed = Terminada el
+maint-cost-notes = Notas de costes
+maint-cost-notes-placeholder = Presupuestos, piezas compradas, quién pagó…
+maint-delete-confirm = ¿Eliminar definitivamente?
+maint-every = Cada
+maint-unit-day = día
+maint-unit-days = días
+maint-unit-week = semana
+maint-unit-weeks = semanas
+maint-unit-month = mes
+maint-unit-months = meses
+maint-unit-year = año
+maint-unit-years = años
+maint-last-done = Última vez
+maint-next-due = Próxima
+maint-never = Nunca
+maint-due-now = Pendiente ya
+maint-overdue = Atrasada
+maint-mark-done = Hecha hoy
+maint-notes = Notas
+maint-asset-name = Nombre
+maint-name-required = El nombre es obligatorio.
+maint-location = Ubicación
+maint-installed = Instalado
+maint-asset-notes-placeholder = Marca, modelo, número de serie…
+maint-tasks-count = tareas preventivas
+maint-open-orders-count = órdenes abiertas
+maint-asset-delete-confirm = ¿Eliminar este equipo? Sus tareas y órdenes se conservan.
+maint-alerts = Mantenimiento atrasado
+maint-alerts-more = más
+
+# Predicación pública
+pw-schedule = Programa
+pw-shifts = Turnos
+pw-locations = Ubicaciones
+pw-loading = Cargando predicación pública…
+pw-pair = Emparejar automáticamente
+pw-week-totals = Publicaciones · videos · revisitas:
+pw-no-shifts = No hay turnos esta semana.
+pw-no-shifts-desc = Añada ubicaciones y turnos semanales para empezar a planificar.
+pw-add-publisher = Añadir publicador
+pw-sign-me-up = Apuntarme
+pw-remove = Quitar
+pw-reported = Informado
+pw-report = Informe
+pw-placements = Publicaciones
+pw-videos = Videos
+pw-return-visits = Revisitas
+pw-notes = Notas
+pw-issue-unstaffed = Nadie apuntado
+pw-issue-needs-partner = Falta un compañero
+pw-issue-mixed-pair = Hermano y hermana de distintas familias
+pw-err-not-qualified = Este publicador no está aprobado para la predicación pública.
+pw-err-already = Ya está apuntado a este turno.
+pw-err-full = Este turno está completo.
+pw-err-absent = Este publicador estará ausente esa fecha.
+pw-err-overlapping = Ya está apuntado a otro turno a la misma hora.
+pw-add-location = ＋ Añadir ubicación
+pw-location-name = Nombre
+pw-location-name-required = El nombre es obligatorio.
+pw-description = Descripción
+pw-pick-point-hint = Haga clic en el mapa para situar el exhibidor.
+pw-no-point = Sin ubicar en el mapa
+pw-no-locations = Aún no hay ubicaciones.
+pw-location-delete-confirm = ¿Eliminar esta ubicación?
+pw-active = Activo
+pw-inactive = Inactivo
+pw-add-shift = ＋ Añadir turno
+pw-location = Ubicación
+pw-location-required = Elija una ubicación.
+pw-weekday = Día
+pw-start = Desde
+pw-end = Hasta
+pw-time-invalid = El turno debe terminar después de empezar.
+pw-capacity = Capacidad
+pw-shift-delete-confirm = ¿Eliminar este turno? Las inscripciones pasadas se conservan.
 
 # Public preaching page
 page-public-preaching = Predicación pública
-btn-add-arrangement = ＋ Añadir arreglo
-empty-public-preaching-title = Sin arreglos aún
-empty-public-preaching-desc = Los arreglos de predicación pública aparecerán aquí.
 
 # Field service groups page
 page-field-service-groups = Grupos de Predicación
@@ -271,6 +414,11 @@ empty-groups-desc = Crea grupos de predicación para organizar a tus publicadore
 
 # Field service reports page
 page-field-service-reports = Informes de Predicación
+page-monthly-totals = Totales mensuales (S-1)
+page-missing-reports = Informes pendientes
+page-pioneers = Horas de precursores
+page-bulk-reports = Cuadrícula de informes
+page-reports-csv = Importar y exportar CSV
 btn-add-report = ＋ Añadir informe
 empty-reports-title = Sin informes aún
 empty-reports-desc = Los informes de predicación aparecerán aquí.
@@ -283,18 +431,87 @@ empty-fs-meetings-desc = Las reuniones al ministerio programadas aparecerán aqu
 
 # Cleaning page
 page-cleaning = Limpieza
-empty-cleaning-title = Sin asignaciones de limpieza aún
-empty-cleaning-desc = Los horarios y asignaciones de limpieza aparecerán aquí.
 
 # Weekday meeting page
 page-weekday-meeting = Reunión entre semana
-empty-weekday-title = Sin asignaciones aún
-empty-weekday-desc = Las asignaciones de la reunión entre semana aparecerán aquí.
+midweek-loading = Cargando programa…
+midweek-week-of = Semana del
+midweek-create-btn = ＋ Crear programa
+midweek-open-slots = pendientes
+midweek-complete = Completo
+midweek-date = Fecha de la reunión
+midweek-date-required = La fecha de la reunión es obligatoria.
+midweek-bible-reading = Lectura semanal de la Biblia
+midweek-bible-reading-placeholder = p. ej. ISAÍAS 1, 2
+midweek-songs = Canciones
+midweek-classrooms = Salas
+midweek-chairman = Presidente
+midweek-aux-counselor = Consejero de la sala auxiliar
+midweek-opening-prayer = Oración inicial
+midweek-closing-prayer = Oración final
+midweek-part-kind = Parte
+midweek-part-title = Tema
+midweek-minutes = Min.
+midweek-lesson = Lección
+midweek-classroom = Sala
+midweek-classroom-main = Sala principal
+midweek-classroom-aux = Auxiliar
+midweek-student = Estudiante
+midweek-assigned = Asignado a
+midweek-householder = Ayudante
+midweek-reader = Lector
+midweek-not-eligible = no habilitado
+midweek-move-up = Subir
+midweek-move-down = Bajar
+midweek-remove-part = Quitar parte
+midweek-add-part = ＋ Añadir parte
+midweek-repeated-warning = Asignados más de una vez esta semana:
+midweek-delete-confirm = ¿Eliminar el programa de esta semana?
 
 # Weekend meeting page
 page-weekend-meeting = Reunión de fin de semana
-empty-weekend-title = Sin asignaciones aún
-empty-weekend-desc = Las asignaciones de la reunión de fin de semana aparecerán aquí.
+weekend-loading = Cargando programa…
+weekend-week-of = Semana del
+weekend-weeks-open = semanas con asignaciones pendientes
+weekend-all-filled = Todas las semanas completas
+weekend-create-btn = ＋ Crear programa
+weekend-no-meeting = no hay reunión esta semana
+weekend-cancelled-but-planned = Esta semana hay una asamblea o un congreso.
+weekend-open-slots = pendientes
+weekend-complete = Completo
+weekend-date = Fecha de la reunión
+weekend-date-required = La fecha de la reunión es obligatoria.
+weekend-songs = Canciones
+weekend-chairman = Presidente
+weekend-opening-prayer = Oración inicial
+weekend-closing-prayer = Oración final
+weekend-public-talk = Discurso público
+weekend-visiting-speaker = Orador visitante
+weekend-guest-from-catalog = Orador del catálogo
+weekend-speaker = Orador
+weekend-speaker-name = Nombre del orador
+weekend-speaker-congregation = Congregación
+weekend-talk-number = Bosquejo n.º
+weekend-talk-title = Título del discurso
+weekend-wt-conductor = Conductor de La Atalaya
+weekend-wt-reader = Lector de La Atalaya
+weekend-repeated-warning = Asignados más de una vez esta semana:
+weekend-delete-confirm = ¿Eliminar el programa de esta semana?
+
+# Fair rotation
+rotation-propose = Proponer asignaciones
+rotation-proposal-title = Asignaciones propuestas
+rotation-proposal-hint = Los puestos libres se dan a quien lleva más tiempo sin asignación, omitiendo ausencias y a quien ya participa en este programa. Acepta para rellenarlos y ajústalos antes de guardar.
+rotation-nothing-open = Todos los puestos ya están asignados.
+rotation-accept = Aceptar
+rotation-discard = Descartar
+rotation-kept = Ya asignado
+rotation-last-assigned = Última asignación
+rotation-never-assigned = Nunca asignado
+rotation-candidates = aptos
+rotation-absent = ausentes
+rotation-busy = ya en este programa
+rotation-no-candidate = Nadie disponible
 
 # Congregation settings page
 page-congregation-settings = Configuración de congregación
@@ -378,6 +595,7 @@ user-delete-title = Eliminar usuario
 user-delete-confirm = Esto eliminará permanentemente este usuario y todos sus datos. Esta acción no se puede deshacer.
 btn-delete = Eliminar
 btn-confirm = Confirmar
+btn-close = Cerrar
 user-detail-personal = Información personal
 user-detail-contact = Información de contacto
 
@@ -416,6 +634,15 @@ report-preached = Predicó
 report-detail-title = Detalles del informe
 report-btn-view = Ver
 report-filter-user-placeholder = Buscar por nombre…
+report-from-portal = Portal
+report-pending = Pendiente de revisión
+report-pending-count = pendientes de revisión
+report-locked = Bloqueado
+report-approve-btn = Aprobar
+report-lock-btn = Bloquear
+report-unlock-btn = Desbloquear
+report-lock-hint = Impedir que el publicador cambie este informe en el portal
+report-unlock-hint = Permitir de nuevo que el publicador cambie este informe en el portal
 reports-loading = Cargando informes…
 reports-no-reports = No hay informes para este mes.
 reports-submitted-of = enviados
@@ -433,6 +660,13 @@ month-9 = Septiembre
 month-10 = Octubre
 month-11 = Noviembre
 month-12 = Diciembre
+weekday-1 = Lunes
+weekday-2 = Martes
+weekday-3 = Miércoles
+weekday-4 = Jueves
+weekday-5 = Viernes
+weekday-6 = Sábado
+weekday-7 = Domingo
 
 # Filtro de actividad
 user-filter-activity = Actividad
@@ -461,6 +695,9 @@ absence-form-required-error = Por favor, completa todos los campos obligatorios.
 absence-form-date-order-error = La fecha de fin debe ser posterior a la de inicio.
 absence-delete-title = Eliminar ausencia
 absence-delete-confirm = ¿Estás seguro de que quieres eliminar esta ausencia?
+absence-requests-title = Solicitadas desde el portal del publicador
+absence-request-accept = Aceptar
+absence-request-decline = Rechazar
 
 # Grupos de servicio del campo
 group-loading = Cargando grupos…
@@ -493,6 +730,8 @@ user-detail-no-group = Sin grupo
 nav-privileges = Privilegios
 nav-maintenance = Mantenimiento
 nav-public-talks = Discursos Públicos
+nav-conflicts = Conflictos
+nav-meeting-attendance = Asistencia
 
 # Privileges page
 page-privileges = Privilegios
@@ -527,6 +766,7 @@ priv-congregation-bible-study-reader = Lector del Estudio Bíblico
 priv-weekend-pray = Oración (fin de semana)
 priv-weekend-chairman = Presidente (fin de semana)
 priv-watchtower-conductor = Director del Estudio de La Atalaya
+priv-watchtower-reader = Lector de La Atalaya
 priv-public-talks = Discursos públicos
 priv-public-talks-away = Discursos fuera
 priv-stage = Escenario
@@ -545,13 +785,50 @@ priv-territory = Territorio
 
 # Maintenance page
 page-maintenance = Mantenimiento
-empty-maintenance-title = Sin tareas de mantenimiento
-empty-maintenance-desc = Las tareas y programas de mantenimiento aparecerán aquí.
 
 # Public Talks page
 page-public-talks = Discursos Públicos
-empty-public-talks-title = Sin discursos públicos programados
-empty-public-talks-desc = Los discursos públicos entrantes y salientes aparecerán aquí.
+page-conflicts = Conflictos de programación
+page-meeting-attendance = Asistencia a las reuniones
+talks-loading = Cargando discursos…
+talks-view-schedule = Programa
+talks-view-outlines = Bosquejos
+talks-view-speakers = Oradores
+talks-view-history = Historial
+talks-incoming = Discursos recibidos
+talks-incoming-hint = Programados en la reunión de fin de semana.
+talks-outgoing = Discursos enviados
+talks-add-outgoing = ＋ Añadir discurso
+talks-none-planned = Nada programado.
+talks-date = Fecha
+talks-outline = Bosquejo
+talks-visited-congregation = Congregación visitada
+talks-speaker-required = Elija un orador.
+talks-outgoing-required = La fecha y la congregación son obligatorias.
+talks-search = Buscar…
+talks-no-outlines = Aún no hay bosquejos. Pegue el catálogo para añadirlos.
+talks-last-given = Última vez
+talks-import-title = Añadir bosquejos
+talks-import-hint = Pegue un bosquejo por línea, empezando por su número. Los números existentes reciben el nuevo título.
+talks-import-count = bosquejos reconocidos
+talks-local-speakers = Oradores de esta congregación
+talks-no-local-speakers = Ningún hermano tiene el privilegio de discurso público.
+talks-no-outlines-set = Sin bosquejos registrados
+talks-visiting-speakers = Oradores visitantes
+talks-add-speaker = ＋ Añadir orador
+talks-no-visiting-speakers = Aún no hay oradores visitantes.
+talks-phone = Teléfono
+talks-email = Correo electrónico
+talks-speaker-outlines = Bosquejos preparados
+talks-unknown-outlines = No están en el catálogo:
+talks-name-required = El nombre del orador es obligatorio.
+talks-repeat-months = Avisar si un bosquejo se repite en (meses)
+talks-months-invalid = Indique un número de meses.
+talks-repeats-found = bosquejos repetidos en el historial
+talks-repeat-warning = Bosquejo ya presentado aquí el
+talks-given-here = Presentados en esta congregación
+talks-given-away = Presentados en otras congregaciones
+talks-no-history = Aún no hay discursos registrados.
 
 # Events page
 nav-events = Eventos
@@ -590,10 +867,107 @@ event-form-start-date = Fecha de Inicio
 event-form-end-date = Fecha de Fin
 event-form-description = Descripción
 event-delete-confirm = ¿Seguro?
+event-upcoming = Próximos
+event-history = Historial
+event-history-empty = Aún no hay eventos pasados. Los eventos quedan aquí cuando terminan.
+event-form-repeat = Se repite
+event-form-until = Hasta (opcional)
+event-repeat-never = No se repite
+event-repeat-weekly = Cada semana
+event-repeat-monthly = Mensual
+event-repeat-yearly = Cada año
+event-nth-1 = primer
+event-nth-2 = segundo
+event-nth-3 = tercer
+event-nth-4 = cuarto
+event-nth-last = último
+ics-export-for = Archivo de calendario para
+ics-whole-congregation = Toda la congregación
+ics-export-btn = Descargar calendario (.ics)
+ics-export-hint = Abarca el último mes y el próximo año: eventos, asignaciones de reuniones, tareas, reuniones para el servicio y territorios por devolver. Importar el archivo de nuevo actualiza las entradas que ya están en su calendario.
+ics-field-service = Reunión para el servicio del campo
+ics-territory-due = Devolver territorio:
+ics-import-btn = Importar archivo de calendario (.ics)
+ics-import-hint = Fechas de asambleas enviadas por el circuito como archivo de calendario. Cada entrada se clasifica por su título; revise la vista previa antes de importar.
+ics-events = eventos
+ics-no-events = No se encontraron eventos en este archivo.
+ics-summary = Título
+ics-already-stored = ya guardados
+ics-import-duplicates = Importarlos de todos modos
+ics-duplicate-of = Ya guardado:
+ics-import-confirm = Importar eventos
+ics-imported = eventos importados
+
+# Visita del superintendente de circuito
+co-plan-visit = Planificar visita
+co-title = Visita del superintendente de circuito
+co-not-found = Este evento no es una visita del superintendente de circuito, o ya no existe.
+co-template-hint = Esta es la semana habitual de una visita. Ajústela y guárdela para conservar el plan.
+co-saved = Guardado
+co-arrangements = Actividades
+co-add-arrangement = Añadir
+co-kind = Actividad
+co-time = Hora
+co-place = Lugar
+co-person = Hermano
+co-notes = Notas
+co-midweek-meeting = Reunión de entre semana
+co-weekend-meeting = Reunión del fin de semana
+co-field-service = Reunión para la predicación
+co-pioneer-meeting = Reunión con los precursores
+co-elders-meeting = Reunión con los ancianos y siervos ministeriales
+co-shepherding-call = Visita de pastoreo
+co-other = Otra
+co-meals = Comidas
+co-meals-hint = Los anfitriones se eligen entre los publicadores con el privilegio de hospitalidad.
+co-suggest-hosts = Sugerir anfitriones
+co-no-hosts = Ningún publicador tiene aún el privilegio de hospitalidad.
+co-lunch = Almuerzo
+co-dinner = Cena
+co-checklist = Informes que preparar
+co-open = Abrir
+co-task-publisher-records = Tarjetas Registro de publicador de la congregación
+co-task-reports = Totales mensuales de predicación
+co-task-missing-reports = Publicadores que no han informado
+co-task-pioneers = Lista de precursores
+co-task-territory = Cobertura del territorio
+co-task-schedules = Programas de reuniones de la semana de la visita
+co-task-accounts = Cuentas de la congregación
+
+# Conflictos de programación
+conflict-source-duty = Tareas
+conflict-conductor = Conductor
+conflict-shift = Turno
+conflict-absent = Ausente en esta fecha
+conflict-during-event = No hay reunión esta semana:
+conflict-overbooked = También asignado a
+conflict-brothers-only = Solo los hermanos pueden tener esta parte
+conflict-appointed-only = Solo ancianos o siervos ministeriales pueden tener esta parte
+conflict-elders-only = Solo los ancianos pueden tener esta parte
+conflict-no-privilege = No aprobado para esta asignación
+conflict-gender-mismatch = El compañero no es del mismo sexo
+conflict-inactive = Publicador inactivo
+conflict-date = Fecha
+conflict-person = Persona
+conflict-module = Programa
+conflict-place = Asignación
+conflict-issue = Problema
+conflict-found = conflictos en los programas
+conflict-recheck = Comprobar de nuevo
+conflict-see-all = Ver todos
+conflict-all-modules = Todos los programas
+conflict-none = No hay conflictos este mes
 
 # Dashboard upcoming events
 dash-upcoming-events = Próximos Eventos
 dash-no-upcoming-events = No hay eventos en los próximos 60 días
+dash-sy-title = Año de servicio
+dash-sy-vs-last-year = comparado con los mismos meses del año pasado
+dash-sy-hours = horas
+dash-sy-studies = cursos
+dash-sy-active = media de publicadores activos
+dash-sy-no-group = Sin grupo
+dash-attendance-title = Promedio de asistencia a las reuniones
 
 # Common buttons
 btn-delete = Eliminar
@@ -619,3 +993,227 @@ fsm-meeting-form-notes-placeholder = Notas opcionales…
 fsm-meeting-form-required-error = Por favor, completa todos los campos obligatorios.
 fsm-assign-to-anyone = Asignar a cualquiera
 fsm-delete-confirm = ¿Eliminar esta reunión?
+
+# S-21 publisher record card
+s21-title = Registro de publicador de la congregación
+s21-name = Nombre
+s21-birth-date = Fecha de nacimiento
+s21-baptism-date = Fecha de bautismo
+s21-male = Hombre
+s21-female = Mujer
+s21-other-sheep = Otras ovejas
+s21-anointed = Ungido
+s21-elder = Anciano
+s21-ms = Siervo ministerial
+s21-regular-pioneer = Precursor regular
+s21-special-pioneer = Precursor especial
+s21-field-missionary = Misionero que sirve en el campo
+s21-service-year = Año de servicio
+s21-col-shared = Participó en el ministerio
+s21-col-studies = Cursos bíblicos
+s21-col-aux = Precursor auxiliar
+s21-col-hours = Horas (si es precursor o misionero)
+s21-col-remarks = Notas
+s21-total = Total
+s21-print-btn = Imprimir S-21
+s21-print-all-btn = Imprimir tarjetas S-21
+
+# S-1 monthly congregation totals
+s1-title = Informe de la congregación (S-1)
+s1-active-publishers = Publicadores activos
+s1-active-hint = meses de margen, incluido este mes
+s1-col-reports = informes
+s1-cat-publishers = Publicadores
+s1-cat-aux-pioneers = Precursores auxiliares
+s1-cat-regular-pioneers = Precursores regulares
+s1-cat-branch = Precursores especiales y misioneros
+s1-branch-hint = Los precursores especiales y los misioneros informan directamente a la sucursal y no se incluyen en los totales.
+s1-no-reports = No hay informes en esta categoría.
+s1-studies-short = cursos
+s1-print-btn = Imprimir S-1
+
+# Programa de entre semana (S-140) y hojas de asignación (S-89)
+s140-title = Programa de la reunión Vida y Ministerio Cristianos
+s140-song = Canción
+s140-conductor = Conductor
+s140-print-btn = Imprimir S-140
+s89-title = Asignación para la reunión Vida y Ministerio Cristianos
+s89-name = Nombre
+s89-assistant = Ayudante
+s89-date = Fecha
+s89-part = Número de intervención
+s89-given-in = Se presentará en:
+s89-note = Nota al estudiante: Encontrará la información y la lección para su asignación en la Guía de actividades. Prepárela con la lección del folleto Maestros.
+s89-print-btn = Imprimir hojas S-89
+s89-week-btn = Hojas
+
+# Asistencia a las reuniones (S-88)
+s88-title = Registro de asistencia a las reuniones
+s88-service-year = Año de servicio
+s88-month = Mes
+s88-meetings = Número de reuniones
+s88-total = Asistencia total
+s88-average = Promedio de asistencia semanal
+s88-year-average = Promedio de asistencia mensual
+att-loading = Cargando asistencia…
+att-print-s88 = Imprimir S-88
+att-entries = Asistencia por reunión
+att-in-person = En persona
+att-online = En línea
+att-total = Total
+att-meetings = reuniones
+att-average = promedio
+att-no-meetings = No hay reuniones este mes
+att-hint = Cuente a todos los presentes, incluidos los niños, y a todos los que siguen la reunión en línea. Borre ambas cifras de una reunión que no se celebró.
+
+# Missing reports tracker
+missing-due-on = Los informes vencen el
+missing-overdue-since = Los informes vencieron el
+missing-count-suffix = publicadores aún no han informado
+missing-no-group = Sin grupo
+missing-all-reported = Todos han informado
+missing-remind-btn = Recordar
+missing-mark-follow-up = Contactado
+missing-undo-follow-up = Deshacer
+missing-followed-up-on = Contactado el
+missing-reminder-title = Recordatorio de informe
+missing-reminder-subject = Recordatorio del informe de predicación
+missing-reminder-hello = Hola
+missing-reminder-body = Te recordamos amablemente que envíes tu informe de predicación de
+missing-reminder-thanks = ¡Muchas gracias!
+missing-no-contact = No hay teléfono ni correo guardados: copia el mensaje y envíalo por otro medio.
+missing-email-btn = Correo
+missing-copy-btn = Copiar mensaje
+missing-copied = Copiado ✓
+
+# Publisher activity status
+status-active = Activo
+status-irregular = Irregular
+status-inactive = Inactivo
+status-reactivated = Reactivado
+status-history = Estado
+
+# Portal del publicador
+landing-portal-link = ¿Eres publicador? Envía tu informe en el portal
+portal-subtitle = Portal del publicador
+portal-login-title = Iniciar sesión en el portal
+portal-login-desc = Usa el usuario y la contraseña que te dio el secretario de la congregación.
+portal-username = Usuario
+portal-no-account = Este acceso no tiene cuenta de portal.
+portal-back-to-app = Volver a la aplicación
+portal-signed-in-as = Sesión iniciada como
+portal-sign-out = Cerrar sesión
+portal-recent-reports = Tus informes recientes
+portal-approved = Aprobado
+portal-locked-hint = El secretario ha bloqueado este informe. Pregúntale si hay que cambiar algo.
+portal-preached = Participé en el ministerio este mes
+portal-hours-hint = Las horas y los créditos solo hacen falta si eres precursor.
+portal-report-sent = Informe enviado. El secretario lo revisará.
+portal-send-report = Enviar informe
+portal-update-report = Actualizar informe
+portal-absence-title = Avisa a la congregación de que estarás fuera
+portal-absence-hint = El secretario añadirá la ausencia cuando vea tu solicitud.
+portal-absence-send = Enviar solicitud
+portal-absence-withdraw = Retirar
+portal-account-title = Cuenta del portal
+portal-account-none = Todavía no hay cuenta de portal. Crea una para que este publicador pueda enviar sus propios informes.
+portal-account-create = Crear cuenta
+portal-account-enabled = Activa
+portal-account-disabled = Desactivada
+portal-account-enable = Activar
+portal-account-disable = Desactivar
+portal-account-new-password = Nueva contraseña
+portal-account-reset-password = Cambiar contraseña
+portal-settings-title = Portal del publicador
+portal-settings-desc = Los publicadores con cuenta de portal pueden introducir su propio informe del mes actual y avisar de ausencias. El servidor aplica estos límites, así que las reglas de permisos de abajo deben instalarse una vez.
+portal-settings-apply = Instalar reglas de permisos
+portal-settings-show-schema = Ver reglas
+portal-settings-applied = Reglas de permisos instaladas.
+portal-settings-failed = No se pudieron instalar las reglas con esta cuenta. Pide al administrador de la base de datos que ejecute las reglas que se muestran abajo.
+
+# Metas de horas de precursores
+pioneer-through = hasta
+pioneer-none = No hay precursores en este año de servicio.
+pioneer-alerts = precursores muy por debajo de su requisito de horas
+pioneer-pace-marker = Donde debería estar el total a estas alturas
+pioneer-projected = Proyección a fin de año
+pioneer-credits = Crédito
+pioneer-credits-counted = contado de lo informado
+pioneer-months-behind = meses de retraso
+pioneer-on-pace = Al día
+pioneer-credit-limit = Límite mensual de horas más crédito
+pioneer-credit-limit-hint = El crédito cuenta hasta que el mes alcanza este total, o el requisito del mes si es mayor. Déjelo vacío para contar el crédito solo hasta el requisito.
+pioneer-credit-limit-invalid = Indique un número de horas.
+
+# Introducción masiva de informes
+bulk-open-btn = Cuadrícula
+bulk-all-publishers = Todos los publicadores
+bulk-keyboard-hint = ↑ ↓ o Intro pasan de un publicador a otro, Tab avanza por columnas. Las filas cambiadas se resaltan hasta guardar.
+bulk-no-members = No hay publicadores en este grupo.
+bulk-col-publisher = Publicador
+bulk-save-all = Guardar todo
+bulk-unsaved = sin guardar
+bulk-invalid = con errores
+bulk-fix-errors = Corrige las filas resaltadas antes de guardar.
+bulk-created = creados
+bulk-updated = actualizados
+bulk-err-number = Usa solo números enteros.
+bulk-err-hours-range = Más horas de las que tiene el mes.
+bulk-err-not-preached = Marcado como que no predicó pero tiene cifras.
+bulk-err-hours-pioneer = Solo los precursores informan horas.
+bulk-err-credits-pioneer = Solo los precursores informan crédito.
+
+# Importación/exportación CSV de informes
+csv-open-btn = CSV
+csv-export-title = Exportar
+csv-export-hint = Descarga todos los informes de un rango de meses como archivo CSV. Las notas no se incluyen.
+csv-from = Desde
+csv-to = Hasta
+csv-export-btn = Exportar CSV
+csv-export-bad-range = Elige un rango de meses válido.
+csv-exported = informes exportados
+csv-import-title = Importar
+csv-import-hint = Carga informes desde una hoja de cálculo guardada como CSV. La primera línea debe tener los nombres de las columnas; los publicadores se buscan por nombre.
+csv-choose-file = Elegir archivo CSV
+csv-read-error = No se pudo leer el archivo.
+csv-rows = filas
+csv-mapping-title = Columnas
+csv-col-ignore = — Ignorar —
+csv-col-name = Nombre completo
+csv-col-first-name = Nombre
+csv-col-last-name = Apellidos
+csv-col-year = Año
+csv-col-month = Mes
+csv-col-year-month = Año y mes
+csv-map-no-name = Asigna una columna de nombre completo, o nombre y apellidos.
+csv-map-no-period = Asigna una columna de año y mes, o ambas por separado.
+csv-valid = listos
+csv-with-errors = con errores (se omiten)
+csv-already-stored = ya guardados
+csv-overwrite = Sobrescribir informes guardados
+csv-status = Estado
+csv-new = Nuevo
+csv-duplicate-existing = Ya guardado
+csv-skipped = omitidos
+csv-import-btn = Importar
+csv-err-missing-name = Sin nombre.
+csv-err-unknown-publisher = Ningún publicador con este nombre.
+csv-err-ambiguous-publisher = Varios publicadores tienen este nombre.
+csv-err-bad-period = Año o mes no válido.
+csv-err-bad-number = no es un número entero.
+csv-err-bad-flag = usa sí o no.
+csv-err-duplicate-line = Mismo publicador y mes que la línea
+
+# Cierre de mes
+month-closed = Mes cerrado
+month-closed-on = Cerrado el
+month-closed-hint = Los informes de este mes están bloqueados.
+month-close-hint = Cierra el mes cuando se hayan enviado los totales para que sus informes ya no cambien.
+month-close-btn = Cerrar mes
+month-close-confirm = ¿Bloquear todos los informes de este mes? Nadie podrá añadirlos, editarlos ni eliminarlos hasta que se reabra el mes.
+month-close-pending-warning = informes del portal siguen pendientes de aprobación.
+month-reopen-btn = Reabrir
+month-reopen-reason = ¿Por qué hay que reabrir este mes?
+month-close-history = Historial
+month-history-closed = Cerrado
+month-history-reopened = Reabierto
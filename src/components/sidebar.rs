@@ -68,6 +68,21 @@ pub fn AppSidebar() -> Element {
                     icon: "📊",
                     label: t!("nav-field-service-reports"),
                 }
+                NavItem {
+                    to: Route::AppMonthlyTotals {},
+                    icon: "🧮",
+                    label: t!("nav-monthly-totals"),
+                }
+                NavItem {
+                    to: Route::AppMissingReports {},
+                    icon: "📭",
+                    label: t!("nav-missing-reports"),
+                }
+                NavItem {
+                    to: Route::AppPioneers {},
+                    icon: "⏱️",
+                    label: t!("nav-pioneers"),
+                }
                 NavItem {
                     to: Route::AppAbsences {},
                     icon: "📅",
@@ -133,6 +148,16 @@ pub fn AppSidebar() -> Element {
                     icon: "🎤",
                     label: t!("nav-public-talks"),
                 }
+                NavItem {
+                    to: Route::AppConflicts {},
+                    icon: "⚠️",
+                    label: t!("nav-conflicts"),
+                }
+                NavItem {
+                    to: Route::AppMeetingAttendance {},
+                    icon: "📈",
+                    label: t!("nav-meeting-attendance"),
+                }
 
                 NavDivider {}
 
@@ -466,6 +491,9 @@ fn route_section(route: &Route) -> Option<DockSection> {
     match route {
         Route::AppUsers {}
         | Route::AppFieldServiceReports {}
+        | Route::AppMonthlyTotals {}
+        | Route::AppMissingReports {}
+        | Route::AppPioneers {}
         | Route::AppAbsences {}
         | Route::AppUserDetail { .. }
         | Route::AppUserSettings {} => Some(DockSection::Users),
@@ -480,13 +508,16 @@ fn route_section(route: &Route) -> Option<DockSection> {
         | Route::AppCleaning {}
         | Route::AppWeekdayMeeting {}
         | Route::AppWeekendMeeting {}
-        | Route::AppPublicTalks {} => Some(DockSection::Meetings),
+        | Route::AppPublicTalks {}
+        | Route::AppConflicts {}
+        | Route::AppMeetingAttendance {} => Some(DockSection::Meetings),
 
         Route::AppCongregationSettings {}
         | Route::AppCongregationPermissions {}
         | Route::AppPrivileges {}
         | Route::AppMaintenance {}
         | Route::AppEvents {}
+        | Route::AppCoVisit { .. }
         | Route::AppCustom {} => Some(DockSection::Congregation),
 
         _ => None,
@@ -650,6 +681,24 @@ fn MobileSectionPanel(section: DockSection, on_close: Callback<()>) -> Element {
                                     route: Route::AppFieldServiceReports {},
                                     on_close,
                                 }
+                                DockNavItem {
+                                    icon: "🧮",
+                                    label: t!("nav-monthly-totals"),
+                                    route: Route::AppMonthlyTotals {},
+                                    on_close,
+                                }
+                                DockNavItem {
+                                    icon: "📭",
+                                    label: t!("nav-missing-reports"),
+                                    route: Route::AppMissingReports {},
+                                    on_close,
+                                }
+                                DockNavItem {
+                                    icon: "⏱️",
+                                    label: t!("nav-pioneers"),
+                                    route: Route::AppPioneers {},
+                                    on_close,
+                                }
                                 DockNavItem {
                                     icon: "📅",
                                     label: t!("nav-absences"),
@@ -720,6 +769,18 @@ fn MobileSectionPanel(section: DockSection, on_close: Callback<()>) -> Element {
                                     route: Route::AppPublicTalks {},
                                     on_close,
                                 }
+                                DockNavItem {
+                                    icon: "⚠️",
+                                    label: t!("nav-conflicts"),
+                                    route: Route::AppConflicts {},
+                                    on_close,
+                                }
+                                DockNavItem {
+                                    icon: "📈",
+                                    label: t!("nav-meeting-attendance"),
+                                    route: Route::AppMeetingAttendance {},
+                                    on_close,
+                                }
                             },
                             DockSection::Congregation => rsx! {
                                 DockNavItem {
@@ -240,6 +240,35 @@ pub async fn connect_online(config: &OnlineConfig, password: &str) -> surrealdb:
     Ok(Arc::new(db))
 }
 
+/// Sign in to the publisher portal with a limited `publisher` RECORD access.
+///
+/// Portal accounts can only touch their own reports and absence requests;
+/// the rules live in [`crate::models::portal::portal_schema`]. The session
+/// has no congregation passphrase, so nothing encrypted is readable.
+pub async fn connect_portal(
+    congregation_uid: &str,
+    username: &str,
+    password: &str,
+) -> surrealdb::Result<Db> {
+    let db = surrealdb::engine::any::connect(CLOUD_ENDPOINT).await?;
+    db.signin(surrealdb::opt::auth::Record {
+        namespace: congregation_uid.to_string(),
+        database: DB_NAME.to_string(),
+        access: "publisher".to_string(),
+        params: serde_json::json!({
+            "username": username,
+            "password": password,
+        }),
+    })
+    .await?;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(std::time::Duration::from_millis(350)).await;
+
+    db.use_ns(congregation_uid).use_db(DB_NAME).await?;
+    Ok(Arc::new(db))
+}
+
 /// Register a new admin user via SurrealDB RECORD access signup.
 /// Used once during onboarding to create the first user.
 pub async fn signup_online(
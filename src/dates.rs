@@ -0,0 +1,222 @@
+//! Calendar dates.
+//!
+//! Dates travel as ISO `"YYYY-MM-DD"` strings, as stored by the models, and
+//! months as `(year, month)` pairs. Everything that reads the clock or shifts
+//! a date goes through here, so pages, schedules and reports agree on what
+//! "today" and "next month" are.
+
+// ── Clock ─────────────────────────────────────────────────────────────────────
+
+/// Today's date in the browser's local time.
+#[cfg(target_arch = "wasm32")]
+pub fn today_str() -> String {
+    let d = js_sys::Date::new_0();
+    format!(
+        "{:04}-{:02}-{:02}",
+        d.get_full_year() as u32,
+        d.get_month() + 1,
+        d.get_date()
+    )
+}
+
+/// Today's date (UTC) from the system clock.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn today_str() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    local_date(secs as i64)
+}
+
+/// Date in the browser's local time of an instant given in seconds since
+/// 1970-01-01 UTC.
+#[cfg(target_arch = "wasm32")]
+pub fn local_date(secs: i64) -> String {
+    let d = js_sys::Date::new_0();
+    d.set_time(secs as f64 * 1000.0);
+    iso_date(d.get_full_year() as i32, (d.get_month() + 1) as u8, d.get_date() as u8)
+}
+
+/// Date (UTC) of an instant given in seconds since 1970-01-01 UTC, matching
+/// [`today_str`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn local_date(secs: i64) -> String {
+    let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+    iso_date(y, m, d)
+}
+
+/// Today's calendar `(year, month)`.
+pub fn current_year_month() -> (i32, u8) {
+    parse_date(&today_str()).map(|(y, m, _)| (y, m)).unwrap_or((1970, 1))
+}
+
+// ── Arithmetic ────────────────────────────────────────────────────────────────
+
+/// Days since 1970-01-01 for a civil date (Howard Hinnant's algorithm).
+pub fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
+    let y = if m <= 2 { y as i64 - 1 } else { y as i64 };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (m as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date for a day count since 1970-01-01.
+pub fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let y = (yoe + era * 400 + if m <= 2 { 1 } else { 0 }) as i32;
+    (y, m, d)
+}
+
+/// `"YYYY-MM-DD"` → `(year, month, day)`.
+pub fn parse_date(iso: &str) -> Option<(i32, u8, u8)> {
+    let mut parts = iso.get(0..10)?.splitn(3, '-');
+    let y = parts.next()?.parse().ok()?;
+    let m: u8 = parts.next()?.parse().ok()?;
+    let d: u8 = parts.next()?.parse().ok()?;
+    ((1..=12).contains(&m) && (1..=31).contains(&d)).then_some((y, m, d))
+}
+
+/// `(year, month, day)` → `"YYYY-MM-DD"`.
+pub fn iso_date(y: i32, m: u8, d: u8) -> String {
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+fn to_days(iso: &str) -> Option<i64> {
+    parse_date(iso).map(|(y, m, d)| days_from_civil(y, m, d))
+}
+
+fn from_days(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    iso_date(y, m, d)
+}
+
+/// Shift a date by `delta` days. Invalid input is returned unchanged.
+pub fn add_days(iso: &str, delta: i64) -> String {
+    to_days(iso).map(|d| from_days(d + delta)).unwrap_or_else(|| iso.to_string())
+}
+
+/// Whole days from `from` to `to` (negative when `to` is earlier).
+pub fn days_between(from: &str, to: &str) -> Option<i64> {
+    Some(to_days(to)? - to_days(from)?)
+}
+
+/// Shift a calendar month by `delta` months (negative goes back in time).
+pub fn add_months(year: i32, month: u8, delta: i32) -> (i32, u8) {
+    let index = year * 12 + (month as i32 - 1) + delta;
+    (index.div_euclid(12), (index.rem_euclid(12) + 1) as u8)
+}
+
+/// Number of days in a calendar month.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    let (ny, nm) = add_months(year, month, 1);
+    (days_from_civil(ny, nm, 1) - days_from_civil(year, month, 1)) as u8
+}
+
+/// Shift a date by whole months, clamping the day to the target month's
+/// length. Invalid input is returned unchanged.
+pub fn add_months_iso(iso: &str, delta: i32) -> String {
+    let Some((y, m, d)) = parse_date(iso) else { return iso.to_string() };
+    let (ty, tm) = add_months(y, m, delta);
+    iso_date(ty, tm, d.min(days_in_month(ty, tm)))
+}
+
+/// Day of the week, 0 = Monday … 6 = Sunday.
+pub fn weekday(iso: &str) -> Option<u8> {
+    // 1970-01-01 was a Thursday.
+    to_days(iso).map(|d| ((d + 3).rem_euclid(7)) as u8)
+}
+
+/// Monday of the week containing `iso`.
+pub fn week_start(iso: &str) -> String {
+    match weekday(iso) {
+        Some(wd) => add_days(iso, -(wd as i64)),
+        None => iso.to_string(),
+    }
+}
+
+/// Mondays falling in a calendar month: the meeting weeks a monthly
+/// schedule covers.
+pub fn mondays_in_month(year: i32, month: u8) -> Vec<String> {
+    let first = days_from_civil(year, month, 1);
+    let offset = (7 - (first + 3).rem_euclid(7)) % 7;
+    (0..5)
+        .map(|w| first + offset + w * 7)
+        .map(civil_from_days)
+        .take_while(|&(y, m, _)| (y, m) == (year, month))
+        .map(|(y, m, d)| iso_date(y, m, d))
+        .collect()
+}
+
+/// Mondays of every week from the one containing `from` up to `to`.
+/// Empty when either date is invalid or `to` comes first.
+pub fn weeks_between(from: &str, to: &str) -> Vec<String> {
+    let (Some(first), Some(last)) = (to_days(&week_start(from)), to_days(to)) else {
+        return vec![];
+    };
+    (first..=last).step_by(7).map(from_days).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_arithmetic_round_trips() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+        assert_eq!(add_days("2025-12-30", 3), "2026-01-02");
+        assert_eq!(days_between("2026-03-01", "2026-02-27"), Some(-2));
+        assert_eq!(weekday("2026-10-18"), Some(6));
+        assert_eq!(week_start("2026-10-18"), "2026-10-12");
+        assert_eq!(add_months_iso("2026-03-31", -1), "2026-02-28");
+        assert_eq!(add_months_iso("2026-11-15", 14), "2028-01-15");
+    }
+
+    #[test]
+    fn add_months_crosses_year_boundaries() {
+        assert_eq!(add_months(2026, 1, -1), (2025, 12));
+        assert_eq!(add_months(2026, 3, -5), (2025, 10));
+        assert_eq!(add_months(2025, 12, 1), (2026, 1));
+        assert_eq!(add_months(2026, 6, 0), (2026, 6));
+    }
+
+    #[test]
+    fn counts_days_in_month() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2026, 2), 28);
+        assert_eq!(days_in_month(2100, 2), 28);
+        assert_eq!(days_in_month(2026, 4), 30);
+        assert_eq!(days_in_month(2026, 12), 31);
+    }
+
+    #[test]
+    fn lists_mondays_of_a_month() {
+        assert_eq!(
+            mondays_in_month(2026, 6),
+            vec!["2026-06-01", "2026-06-08", "2026-06-15", "2026-06-22", "2026-06-29"]
+        );
+        assert_eq!(mondays_in_month(2026, 2).first().map(String::as_str), Some("2026-02-02"));
+        assert_eq!(mondays_in_month(2026, 2).len(), 4);
+        assert_eq!(
+            weeks_between("2026-10-18", "2026-10-26"),
+            vec!["2026-10-12", "2026-10-19", "2026-10-26"]
+        );
+        assert!(weeks_between("2026-10-18", "2026-10-01").is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+//! Model values for unit tests.
+//!
+//! Each helper returns a plain, valid record keyed by a short name; tests
+//! change what they care about with struct update syntax, e.g.
+//! `User { gender: Gender::Female, ..user("a") }`.
+
+use surrealdb::types::RecordId;
+
+use crate::models::field_service_report::{FieldServiceReport, ReportSource};
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::{Gender, User, UserType};
+
+/// `table:key`.
+pub fn rid(table: &str, key: &str) -> RecordId {
+    RecordId::parse_simple(&format!("{table}:{key}")).unwrap()
+}
+
+/// `user:key`.
+pub fn user_id(key: &str) -> RecordId {
+    rid("user", key)
+}
+
+/// An active baptized brother whose first name is `key`.
+pub fn user(key: &str) -> User {
+    User {
+        id: Some(user_id(key)),
+        first_name: key.to_string(),
+        last_name: String::new(),
+        birthday: None,
+        baptism_date: None,
+        phone: None,
+        address: None,
+        email: None,
+        password: None,
+        user_type: UserType::BaptizedPublisher,
+        gender: Gender::Male,
+        appointment: None,
+        family_head: false,
+        congregations: vec![],
+        active: true,
+    }
+}
+
+/// Privileges of `user:key`, all of them off.
+pub fn privileges(key: &str) -> UserPrivileges {
+    UserPrivileges {
+        id: None,
+        publisher: user_id(key),
+        weekday_pray: false,
+        weekday_chairman: false,
+        aux_chairman: false,
+        treasures: false,
+        spiritual_gems: false,
+        bible_reading: false,
+        field_ministry_discussion: false,
+        starting_conversation: false,
+        following_up: false,
+        making_disciples: false,
+        assistant: false,
+        student_talk: false,
+        living_as_christians: false,
+        congregation_bible_study: false,
+        congregation_bible_study_reader: false,
+        weekend_pray: false,
+        weekend_chairman: false,
+        watchtower_conductor: false,
+        watchtower_reader: false,
+        public_talks: false,
+        public_talks_away: false,
+        stage: false,
+        audio: false,
+        video: false,
+        microphones: false,
+        attendant: false,
+        zoom_attendant: false,
+        hospitality: false,
+        interpreter: false,
+        field_service_meeting: false,
+        public_witnessing: false,
+        cleaning: false,
+        maintenance: false,
+        territory: false,
+    }
+}
+
+/// An approved secretary-entered report of `user:key` for a month in which
+/// they preached, with no numbers filled in.
+pub fn report(key: &str, year: i32, month: u8) -> FieldServiceReport {
+    FieldServiceReport {
+        id: None,
+        publisher: user_id(key),
+        year,
+        month,
+        hours: None,
+        credits: None,
+        bible_studies: None,
+        auxiliary_pioneer: false,
+        preached: true,
+        source: ReportSource::Secretary,
+        approved: true,
+        locked: false,
+        notes: None,
+        placements: None,
+        videos: None,
+        return_visits: None,
+    }
+}
@@ -0,0 +1,86 @@
+//! Display formatting shared by pages and printed reports.
+//!
+//! Names and dates follow the congregation's [`NameFormat`] and
+//! [`DateFormat`], overridden by the user's own preferences; events show the
+//! label of their type, or their own title for "other". Kept out of `pages` so
+//! that `reports` can use it without depending on the UI layer.
+
+use dioxus_i18n::t;
+
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::event::{CongregationEvent, EventType};
+
+/// Returns `"FirstLast"` or `"LastFirst"`.
+pub fn effective_name_format(
+    cong: Option<&Congregation>,
+    user_prefs_name: &str,
+) -> NameFormat {
+    if !user_prefs_name.is_empty() {
+        return if user_prefs_name == "LastFirst" { NameFormat::LastFirst } else { NameFormat::FirstLast };
+    }
+    cong.map(|c| c.name_format.clone()).unwrap_or_default()
+}
+
+/// Returns `"YMD"`, `"DMY"`, or `"MDY"`.
+pub fn effective_date_format(
+    cong: Option<&Congregation>,
+    user_prefs_date: &str,
+) -> DateFormat {
+    match user_prefs_date {
+        "DMY" => return DateFormat::DMY,
+        "MDY" => return DateFormat::MDY,
+        "YMD" => return DateFormat::YMD,
+        _ => {}
+    }
+    cong.map(|c| c.date_format.clone()).unwrap_or_default()
+}
+
+pub fn format_name(first: &str, last: &str, fmt: &NameFormat) -> String {
+    match fmt {
+        NameFormat::LastFirst => format!("{last} {first}"),
+        NameFormat::FirstLast => format!("{first} {last}"),
+    }
+}
+
+/// Convert an ISO date string (`YYYY-MM-DD`) to the display format.
+pub fn format_date(iso: &str, fmt: &DateFormat) -> String {
+    if iso.len() != 10 { return iso.to_string(); }
+    let parts: Vec<&str> = iso.splitn(3, '-').collect();
+    if parts.len() != 3 { return iso.to_string(); }
+    let (y, m, d) = (parts[0], parts[1], parts[2]);
+    match fmt {
+        DateFormat::YMD => format!("{y}-{m}-{d}"),
+        DateFormat::DMY => format!("{d}/{m}/{y}"),
+        DateFormat::MDY => format!("{m}/{d}/{y}"),
+    }
+}
+
+/// Pattern hint shown in the date label.
+pub fn date_format_hint(fmt: &DateFormat) -> &'static str {
+    match fmt {
+        DateFormat::YMD => "YYYY-MM-DD",
+        DateFormat::DMY => "DD/MM/YYYY",
+        DateFormat::MDY => "MM/DD/YYYY",
+    }
+}
+
+pub fn event_type_label(et: &EventType) -> String {
+    match et {
+        EventType::CircuitAssembly => t!("event-type-circuit-assembly"),
+        EventType::Memorial => t!("event-type-memorial"),
+        EventType::CircuitOverseerVisit => t!("event-type-circuit-overseer"),
+        EventType::RegionalConvention => t!("event-type-regional-convention"),
+        EventType::Other => t!("event-type-other"),
+    }
+}
+
+pub fn event_display_title(e: &CongregationEvent) -> String {
+    match &e.event_type {
+        EventType::Other => e
+            .title
+            .clone()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| t!("event-type-other")),
+        other => event_type_label(other),
+    }
+}
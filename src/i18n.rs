@@ -1,4 +1,4 @@
-use dioxus_i18n::prelude::I18nConfig;
+use dioxus_i18n::prelude::{I18nConfig, i18n};
 use dioxus_i18n::unic_langid::{LanguageIdentifier, langid};
 
 const EN_US_FTL: &str = include_str!("../assets/i18n/en-US.ftl");
@@ -24,6 +24,12 @@ pub fn init_config() -> I18nConfig {
         .with_locale((langid!("es"), ES_ES_FTL))
 }
 
+/// BCP 47 tag of the language the app is shown in right now, e.g. `es-ES`.
+/// Must be called from within a component or one of its handlers.
+pub fn active_language() -> String {
+    i18n().language().to_string()
+}
+
 /// Detect the user's preferred language from the runtime environment.
 pub fn detect_locale() -> LanguageIdentifier {
     let raw = raw_locale();
@@ -4,25 +4,34 @@ use dioxus_i18n::prelude::*;
 mod components;
 mod crypto;
 mod database;
+mod dates;
+#[cfg(test)]
+mod fixtures;
+mod format;
 mod i18n;
 mod models;
 mod pages;
+mod reports;
+mod schedule;
 
 use pages::{
-    Landing, NotFound,
+    Landing, NotFound, Portal,
     app::{
         AppAbsences, AppAttendants, AppAvPlatform, AppCleaning, AppCongregationPermissions,
         AppCongregationSettings, AppDashboard, AppFieldServiceGroups, AppFieldServiceMeetings,
         AppFieldServiceReports, AppLayout, AppMaintenance, AppPrivileges, AppPublicPreaching,
         AppPublicTalks, AppTerritory, AppUsers, AppWeekdayMeeting, AppWeekendMeeting,
-        AppNewCongregation, AppUserSettings, AppUserDetail, AppEvents, AppCustom
+        AppNewCongregation, AppUserSettings, AppUserDetail, AppEvents, AppCustom, AppMonthlyTotals,
+        AppMissingReports, AppPioneers, AppBulkReports, AppReportsCsv, AppCoVisit, AppConflicts,
+        AppMeetingAttendance
     },
 };
 
 /// All application routes.
 ///
-/// Two logical zones:
+/// Three logical zones:
 /// - `/`         → [`Landing`]: first-time setup / mode selector.
+/// - `/portal`   → [`Portal`]: limited self-reporting for publishers.
 /// - `/app/**`   → authenticated zone wrapped by [`AppLayout`], which
 ///                  redirects to `/` when no database connection is active.
 #[derive(Debug, Clone, Routable, PartialEq)]
@@ -31,6 +40,8 @@ pub enum Route {
     // ── Unauthenticated ────────────────────────────────────────────────────
     #[route("/")]
     Landing {},
+    #[route("/portal")]
+    Portal {},
 
     // ── Authenticated app ──────────────────────────────────────────────────
     #[layout(AppLayout)]
@@ -46,6 +57,16 @@ pub enum Route {
         AppUserDetail { id: String },
         #[route("/app/field-service-reports")]
         AppFieldServiceReports {},
+        #[route("/app/field-service-reports/bulk")]
+        AppBulkReports {},
+        #[route("/app/field-service-reports/csv")]
+        AppReportsCsv {},
+        #[route("/app/field-service-reports/totals")]
+        AppMonthlyTotals {},
+        #[route("/app/field-service-reports/missing")]
+        AppMissingReports {},
+        #[route("/app/field-service-reports/pioneers")]
+        AppPioneers {},
         #[route("/app/absences")]
         AppAbsences {},
 
@@ -72,6 +93,10 @@ pub enum Route {
         AppWeekendMeeting {},
         #[route("/app/meetings/public-talks")]
         AppPublicTalks {},
+        #[route("/app/meetings/conflicts")]
+        AppConflicts {},
+        #[route("/app/meetings/attendance")]
+        AppMeetingAttendance {},
 
         // Congregation section
         #[route("/app/congregation/settings")]
@@ -84,6 +109,8 @@ pub enum Route {
         AppMaintenance {},
         #[route("/app/congregation/events")]
         AppEvents {},
+        #[route("/app/congregation/events/:id/visit")]
+        AppCoVisit { id: String },
         #[route("/app/congregation/custom")]
         AppCustom {},
         
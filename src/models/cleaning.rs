@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const AREA_TABLE: &str = "cleaning_area";
+pub const WEEK_TABLE: &str = "cleaning_week";
+
+/// How thorough a week's cleaning is. A deep clean covers the weekly tasks
+/// plus the deep ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum CleaningKind {
+    #[default]
+    Weekly,
+    Deep,
+}
+
+impl CleaningKind {
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Weekly => "weekly",
+            Self::Deep => "deep",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "deep" => Self::Deep,
+            _ => Self::Weekly,
+        }
+    }
+}
+
+// ── CleaningArea ──────────────────────────────────────────────────────────────
+
+/// A part of the Kingdom Hall with its checklists. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CleaningArea {
+    pub id: Option<RecordId>,
+    pub name: String,
+    /// Tasks of every cleaning.
+    #[serde(default)]
+    pub weekly: Vec<String>,
+    /// Extra tasks of a deep clean.
+    #[serde(default)]
+    pub deep: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CleaningAreaData {
+    pub name: String,
+    pub weekly: Vec<String>,
+    pub deep: Vec<String>,
+}
+
+impl CleaningArea {
+    /// Every area, by name.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(AREA_TABLE).await?;
+        rows.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: CleaningAreaData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(AREA_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: CleaningAreaData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── CleaningWeek ──────────────────────────────────────────────────────────────
+
+/// A checklist task ticked off. Tasks are matched by text, so reordering a
+/// checklist keeps the ticks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CheckedTask {
+    pub area: RecordId, // → cleaning_area record
+    pub task: String,
+}
+
+/// The group cleaning the hall in one week, and what it has done. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CleaningWeek {
+    pub id: Option<RecordId>,
+    pub week_start: String, // "YYYY-MM-DD", Monday of the week
+    pub kind: CleaningKind,
+    pub group: Option<RecordId>, // → field_service_group record
+    #[serde(default)]
+    pub done: Vec<CheckedTask>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CleaningWeekData {
+    pub week_start: String,
+    pub kind: CleaningKind,
+    pub group: Option<RecordId>,
+    pub done: Vec<CheckedTask>,
+}
+
+impl From<CleaningWeek> for CleaningWeekData {
+    fn from(w: CleaningWeek) -> Self {
+        Self { week_start: w.week_start, kind: w.kind, group: w.group, done: w.done }
+    }
+}
+
+impl CleaningWeek {
+    pub fn blank(week_start: String) -> Self {
+        Self { id: None, week_start, kind: CleaningKind::Weekly, group: None, done: vec![] }
+    }
+
+    /// Weeks starting between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM cleaning_week WHERE week_start >= $from AND week_start <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.week_start.cmp(&b.week_start));
+        Ok(rows)
+    }
+
+    /// The latest week before `week_start` that had a group, to carry the
+    /// rotation on from.
+    pub async fn last_assigned_before(
+        db: &Db,
+        week_start: &str,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query(
+                "SELECT * FROM cleaning_week WHERE week_start < $week AND group != NONE \
+                 ORDER BY week_start DESC LIMIT 1",
+            )
+            .bind(("week", week_start.to_string()))
+            .await?
+            .take(0)?;
+        Ok(rows.into_iter().next())
+    }
+
+    /// Create or update several weeks in a single transaction. Weeks with an
+    /// id are updated, the rest created.
+    pub async fn save_many(db: &Db, weeks: Vec<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        if weeks.is_empty() {
+            return Ok(());
+        }
+        let mut query = db.query("BEGIN TRANSACTION");
+        for (i, week) in weeks.into_iter().enumerate() {
+            let data_var = format!("data{i}");
+            query = match week.id.clone() {
+                Some(id) => {
+                    let id_var = format!("id{i}");
+                    query
+                        .query(format!("UPDATE ${id_var} CONTENT ${data_var}"))
+                        .bind((id_var, id))
+                }
+                None => query.query(format!("CREATE {WEEK_TABLE} CONTENT ${data_var}")),
+            }
+            .bind((data_var, CleaningWeekData::from(week)));
+        }
+        query.query("COMMIT TRANSACTION").await?.check()?;
+        Ok(())
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
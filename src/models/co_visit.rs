@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const TABLE: &str = "co_visit";
+
+/// What happens at one point of the circuit overseer's week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum ArrangementKind {
+    MidweekMeeting,
+    WeekendMeeting,
+    FieldService,
+    PioneerMeeting,
+    /// With the elders and ministerial servants.
+    EldersMeeting,
+    ShepherdingCall,
+    Other,
+}
+
+impl ArrangementKind {
+    pub const ALL: [ArrangementKind; 7] = [
+        ArrangementKind::MidweekMeeting,
+        ArrangementKind::WeekendMeeting,
+        ArrangementKind::FieldService,
+        ArrangementKind::PioneerMeeting,
+        ArrangementKind::EldersMeeting,
+        ArrangementKind::ShepherdingCall,
+        ArrangementKind::Other,
+    ];
+
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::MidweekMeeting => "midweek",
+            Self::WeekendMeeting => "weekend",
+            Self::FieldService => "field_service",
+            Self::PioneerMeeting => "pioneers",
+            Self::EldersMeeting => "elders",
+            Self::ShepherdingCall => "shepherding",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|k| k.key() == key)
+            .unwrap_or(Self::Other)
+    }
+}
+
+/// One entry of the week's program.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct Arrangement {
+    pub kind: ArrangementKind,
+    pub date: String, // "YYYY-MM-DD"
+    pub time: String, // "HH:MM", empty = not set yet
+    pub place: String,
+    /// Brother who conducts, accompanies the overseer or is visited.
+    pub person: Option<RecordId>, // → user record
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum Meal {
+    Lunch,
+    Dinner,
+}
+
+/// A meal offered to the overseer (and his wife) by a household.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MealHost {
+    pub date: String,
+    pub meal: Meal,
+    pub host: Option<RecordId>, // → user record with the hospitality privilege
+}
+
+/// Something the overseer reviews during the visit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum Preparation {
+    PublisherRecords,
+    FieldServiceReports,
+    MissingReports,
+    PioneerList,
+    TerritoryCoverage,
+    MeetingSchedules,
+    Accounts,
+}
+
+impl Preparation {
+    pub const ALL: [Preparation; 7] = [
+        Preparation::PublisherRecords,
+        Preparation::FieldServiceReports,
+        Preparation::MissingReports,
+        Preparation::PioneerList,
+        Preparation::TerritoryCoverage,
+        Preparation::MeetingSchedules,
+        Preparation::Accounts,
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ChecklistItem {
+    pub task: Preparation,
+    pub done: bool,
+}
+
+/// Plan for one circuit overseer visit, attached to its event. Plaintext:
+/// only links to user records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CoVisit {
+    pub id: Option<RecordId>,
+    pub event: RecordId, // → congregation_event record
+    pub arrangements: Vec<Arrangement>,
+    pub meals: Vec<MealHost>,
+    pub checklist: Vec<ChecklistItem>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CoVisitData {
+    pub event: RecordId,
+    pub arrangements: Vec<Arrangement>,
+    pub meals: Vec<MealHost>,
+    pub checklist: Vec<ChecklistItem>,
+    pub notes: String,
+}
+
+impl From<CoVisit> for CoVisitData {
+    fn from(v: CoVisit) -> Self {
+        Self {
+            event: v.event,
+            arrangements: v.arrangements,
+            meals: v.meals,
+            checklist: v.checklist,
+            notes: v.notes,
+        }
+    }
+}
+
+impl CoVisit {
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db.select(TABLE).await?;
+        Ok(rows)
+    }
+
+    /// The plan attached to `event`, if one was started.
+    pub async fn for_event(
+        db: &Db,
+        event: &RecordId,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM co_visit WHERE event = $event LIMIT 1")
+            .bind(("event", event.clone()))
+            .await?
+            .take(0)?;
+        Ok(rows.into_iter().next())
+    }
+
+    /// Create the plan, or update it when it has an id.
+    pub async fn save(db: &Db, visit: Self) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let saved: Option<Self> = match visit.id.clone() {
+            Some(id) => db.update(id).content(CoVisitData::from(visit)).await?,
+            None => db.create(TABLE).content(CoVisitData::from(visit)).await?,
+        };
+        Ok(saved)
+    }
+
+    /// Delete the plan of an event (e.g. when deleting the event).
+    pub async fn delete_by_event(
+        db: &Db,
+        event_id: RecordId,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query("DELETE co_visit WHERE event = $id")
+            .bind(("id", event_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const TABLE: &str = "duty_rota";
+pub const SETTINGS_TABLE: &str = "duty_settings";
+pub const SETTINGS_KEY: &str = "settings";
+
+/// Support duty at a meeting. Each one maps to a privilege flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum DutyRole {
+    Attendant,
+    ZoomAttendant,
+    Stage,
+    Audio,
+    Video,
+    Microphones,
+}
+
+impl DutyRole {
+    pub const ALL: [DutyRole; 6] = [
+        DutyRole::Attendant,
+        DutyRole::ZoomAttendant,
+        DutyRole::Stage,
+        DutyRole::Audio,
+        DutyRole::Video,
+        DutyRole::Microphones,
+    ];
+
+    /// Stable identifier for `<select>` values and exports.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Attendant => "attendant",
+            Self::ZoomAttendant => "zoom_attendant",
+            Self::Stage => "stage",
+            Self::Audio => "audio",
+            Self::Video => "video",
+            Self::Microphones => "microphones",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum MeetingKind {
+    Midweek,
+    Weekend,
+}
+
+/// One place on the rota.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct Duty {
+    pub role: DutyRole,
+    pub person: Option<RecordId>, // → user record
+}
+
+/// Who serves at one meeting. Plaintext: only links to user records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct DutyRota {
+    pub id: Option<RecordId>,
+    pub date: String, // "YYYY-MM-DD"
+    pub meeting: MeetingKind,
+    /// Places of every role, grouped by role in [`DutyRole::ALL`] order.
+    pub duties: Vec<Duty>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct DutyRotaData {
+    pub date: String,
+    pub meeting: MeetingKind,
+    pub duties: Vec<Duty>,
+}
+
+impl From<DutyRota> for DutyRotaData {
+    fn from(r: DutyRota) -> Self {
+        Self { date: r.date, meeting: r.meeting, duties: r.duties }
+    }
+}
+
+impl DutyRota {
+    pub fn blank(date: String, meeting: MeetingKind) -> Self {
+        Self { id: None, date, meeting, duties: vec![] }
+    }
+
+    /// Rotas for meetings between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM duty_rota WHERE date >= $from AND date <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    /// Create or update several rotas in a single transaction. Rotas with an
+    /// id are updated, the rest created.
+    pub async fn save_many(db: &Db, rotas: Vec<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        if rotas.is_empty() {
+            return Ok(());
+        }
+        let mut query = db.query("BEGIN TRANSACTION");
+        for (i, rota) in rotas.into_iter().enumerate() {
+            let data_var = format!("data{i}");
+            query = match rota.id.clone() {
+                Some(id) => {
+                    let id_var = format!("id{i}");
+                    query
+                        .query(format!("UPDATE ${id_var} CONTENT ${data_var}"))
+                        .bind((id_var, id))
+                }
+                None => query.query(format!("CREATE {TABLE} CONTENT ${data_var}")),
+            }
+            .bind((data_var, DutyRotaData::from(rota)));
+        }
+        query.query("COMMIT TRANSACTION").await?.check()?;
+        Ok(())
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: DutyRotaData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: DutyRotaData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── DutySettings ──────────────────────────────────────────────────────────────
+
+/// Flat data stored in `duty_settings:settings`. Not sensitive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct DutySettings {
+    pub attendants: u8,
+    pub zoom_attendants: u8,
+    pub stage: u8,
+    pub audio: u8,
+    pub video: u8,
+    pub microphones: u8,
+    /// Days after Monday the meetings are usually held, for weeks without
+    /// a program yet.
+    pub midweek_offset: u8,
+    pub weekend_offset: u8,
+}
+
+impl Default for DutySettings {
+    fn default() -> Self {
+        Self {
+            attendants: 2,
+            zoom_attendants: 1,
+            stage: 1,
+            audio: 1,
+            video: 1,
+            microphones: 2,
+            midweek_offset: 2,
+            weekend_offset: 6,
+        }
+    }
+}
+
+impl DutySettings {
+    /// Places to fill for `role` at every meeting.
+    pub fn count(&self, role: DutyRole) -> u8 {
+        match role {
+            DutyRole::Attendant => self.attendants,
+            DutyRole::ZoomAttendant => self.zoom_attendants,
+            DutyRole::Stage => self.stage,
+            DutyRole::Audio => self.audio,
+            DutyRole::Video => self.video,
+            DutyRole::Microphones => self.microphones,
+        }
+    }
+
+    pub fn set_count(&mut self, role: DutyRole, n: u8) {
+        let field = match role {
+            DutyRole::Attendant => &mut self.attendants,
+            DutyRole::ZoomAttendant => &mut self.zoom_attendants,
+            DutyRole::Stage => &mut self.stage,
+            DutyRole::Audio => &mut self.audio,
+            DutyRole::Video => &mut self.video,
+            DutyRole::Microphones => &mut self.microphones,
+        };
+        *field = n;
+    }
+
+    /// Stored settings, or the defaults when none were saved yet.
+    pub async fn get(db: &Db) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut res = db
+            .query(format!("SELECT * FROM {}:{}", SETTINGS_TABLE, SETTINGS_KEY))
+            .await?;
+        let record: Option<Self> = res.take(0)?;
+        Ok(record.unwrap_or_default())
+    }
+
+    pub async fn upsert(db: &Db, settings: &Self) -> Result<(), Box<dyn std::error::Error>> {
+        db.query(format!("UPSERT {}:{} CONTENT $data", SETTINGS_TABLE, SETTINGS_KEY))
+            .bind(("data", settings.clone()))
+            .await?
+            .check()?;
+        Ok(())
+    }
+}
@@ -2,65 +2,10 @@ use serde::{Deserialize, Serialize};
 use surrealdb::types::{RecordId, SurrealValue};
 
 use crate::database::Db;
+use crate::dates::today_str;
 
 pub const TABLE: &str = "congregation_event";
 
-// ── Date helpers ──────────────────────────────────────────────────────────────
-
-/// Convert Unix seconds to an ISO 8601 date string (`"YYYY-MM-DD"`).
-/// Uses the Howard Hinnant civil-calendar algorithm.
-fn unix_secs_to_date(secs: u64) -> String {
-    let z = secs / 86400 + 719468;
-    let era = z / 146097;
-    let doe = z - era * 146097;
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-    let mp = (5 * doy + 2) / 153;
-    let d = doy - (153 * mp + 2) / 5 + 1;
-    let m = if mp < 10 { mp + 3 } else { mp - 9 };
-    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
-    format!("{:04}-{:02}-{:02}", y, m, d)
-}
-
-#[cfg(target_arch = "wasm32")]
-pub fn today_str() -> String {
-    let d = js_sys::Date::new_0();
-    format!(
-        "{:04}-{:02}-{:02}",
-        d.get_full_year() as u32,
-        d.get_month() + 1,
-        d.get_date()
-    )
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-pub fn today_str() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    unix_secs_to_date(secs)
-}
-
-#[cfg(target_arch = "wasm32")]
-pub fn add_days_str(days: u32) -> String {
-    let ms = js_sys::Date::now() + days as f64 * 86_400_000.0;
-    let secs = (ms / 1000.0) as u64;
-    unix_secs_to_date(secs)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-pub fn add_days_str(days: u32) -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-        + days as u64 * 86_400;
-    unix_secs_to_date(secs)
-}
-
 // ── Event type enum ───────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, SurrealValue)]
@@ -74,6 +19,30 @@ pub enum EventType {
     Other,
 }
 
+// ── Recurrence ────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum Frequency {
+    Weekly,
+    /// Same weekday in the same week of every month: "every 2nd Tuesday".
+    MonthlyWeekday,
+    Yearly,
+}
+
+/// How an event repeats. Every occurrence lasts as many days as the first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// Week of the month for [`Frequency::MonthlyWeekday`]: 1–4, or 5 for
+    /// the last one.
+    #[serde(default)]
+    pub nth: u8,
+    /// No occurrence starts after this date. `None` = repeats indefinitely.
+    pub until: Option<String>,
+}
+
 // ── DB structs ────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
@@ -85,6 +54,9 @@ pub struct CongregationEvent {
     pub event_type: EventType,
     pub title: Option<String>,
     pub description: Option<String>,
+    /// Set on a series; `start_date`/`end_date` are then its first occurrence.
+    #[serde(default)]
+    pub recurrence: Option<Recurrence>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
@@ -95,39 +67,65 @@ pub struct CongregationEventData {
     pub event_type: EventType,
     pub title: Option<String>,
     pub description: Option<String>,
+    pub recurrence: Option<Recurrence>,
 }
 
 // ── CRUD ──────────────────────────────────────────────────────────────────────
 
 impl CongregationEvent {
-    /// Load all events, automatically pruning those whose `end_date` is before today.
-    pub async fn all_prune(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
-        let today = today_str();
-        db.query("DELETE congregation_event WHERE end_date < $today")
-            .bind(("today", today))
-            .await?;
-        let mut rows: Vec<Self> = db.select(TABLE).await?;
+    /// Stored events that have not ended yet, by start date. A series is
+    /// listed once, as stored, while occurrences are still to come.
+    pub async fn current(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query(
+                "SELECT * FROM congregation_event WHERE end_date >= $today \
+                 OR (recurrence != NONE AND (recurrence.until = NONE OR recurrence.until >= $today))",
+            )
+            .bind(("today", today_str()))
+            .await?
+            .take(0)?;
         rows.sort_by(|a, b| a.start_date.cmp(&b.start_date));
         Ok(rows)
     }
 
-    /// Events that are ongoing or start within `days` days from today (for dashboard).
-    pub async fn upcoming(db: &Db, days: u32) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
-        let today = today_str();
-        let until = add_days_str(days);
-        let mut rows: Vec<Self> = db
+    /// Stored events that started before `date`, series included as stored.
+    /// `schedule::recurrence::history` turns them into the past occurrences.
+    pub async fn stored_before(db: &Db, date: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM congregation_event WHERE start_date < $date")
+            .bind(("date", date.to_string()))
+            .await?
+            .take(0)?;
+        Ok(rows)
+    }
+
+    /// Stored events overlapping the inclusive date range `from`..=`to`, past
+    /// ones included, plus every series that starts by `to`. Series are
+    /// returned as stored; `schedule::recurrence::expand` turns them into
+    /// their occurrences in the range.
+    pub async fn in_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
             .query(
                 "SELECT * FROM congregation_event \
-                 WHERE end_date >= $today AND start_date <= $until",
+                 WHERE (end_date >= $from AND start_date <= $to) \
+                 OR (recurrence != NONE AND start_date <= $to)",
             )
-            .bind(("today", today))
-            .bind(("until", until))
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
             .await?
             .take(0)?;
-        rows.sort_by(|a, b| a.start_date.cmp(&b.start_date));
         Ok(rows)
     }
 
+    pub async fn get(db: &Db, id: RecordId) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.select(id).await?;
+        Ok(row)
+    }
+
     pub async fn create(
         db: &Db,
         data: CongregationEventData,
@@ -136,6 +134,25 @@ impl CongregationEvent {
         Ok(created)
     }
 
+    /// Create several events in a single transaction.
+    pub async fn create_many(
+        db: &Db,
+        events: Vec<CongregationEventData>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let mut query = db.query("BEGIN TRANSACTION");
+        for (i, data) in events.into_iter().enumerate() {
+            let data_var = format!("data{i}");
+            query = query
+                .query(format!("CREATE {TABLE} CONTENT ${data_var}"))
+                .bind((data_var, data));
+        }
+        query.query("COMMIT TRANSACTION").await?.check()?;
+        Ok(())
+    }
+
     pub async fn update(
         db: &Db,
         id: RecordId,
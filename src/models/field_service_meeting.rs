@@ -41,6 +41,22 @@ impl FieldServiceMeeting {
         Ok(rows)
     }
 
+    /// Meetings dated between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM field_service_meeting WHERE date >= $from AND date <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
     pub async fn create(
         db: &Db,
         data: FieldServiceMeetingData,
@@ -1,13 +1,27 @@
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 use surrealdb::types::{RecordId, SurrealValue};
 
 use crate::crypto::{CryptoError, SessionCrypto};
 use crate::database::Db;
 
+use super::month_close::{self, closed_error, guard_periods, guarded};
+
 fn bool_true() -> bool { true }
 
 pub const TABLE: &str = "field_service_report";
 
+/// Who entered a report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum ReportSource {
+    #[default]
+    Secretary,
+    /// Submitted by the publisher through the portal.
+    Publisher,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
 pub struct FieldServiceReport {
@@ -24,6 +38,16 @@ pub struct FieldServiceReport {
     /// Old records without this field deserialize as true (preached by default).
     #[serde(default = "bool_true")]
     pub preached: bool,
+    #[serde(default)]
+    pub source: ReportSource,
+    /// Portal submissions stay unapproved until the secretary reviews them.
+    /// Old records without this field deserialize as approved.
+    #[serde(default = "bool_true")]
+    pub approved: bool,
+    /// Locked by the secretary; locked reports can no longer be changed from
+    /// the portal. Closing the month does not set this, see [`Self::is_locked`].
+    #[serde(default)]
+    pub locked: bool,
     pub notes: Option<String>, // encrypted
     // Legacy fields kept for backward-compatible deserialization only.
     #[serde(default)]
@@ -35,7 +59,7 @@ pub struct FieldServiceReport {
 }
 
 /// Payload for creating or updating a field service report.
-#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
 pub struct FieldServiceReportData {
     pub publisher: RecordId,
@@ -47,9 +71,22 @@ pub struct FieldServiceReportData {
     pub bible_studies: Option<u32>,
     pub auxiliary_pioneer: bool,
     pub preached: bool,
+    #[serde(default)]
+    pub source: ReportSource,
+    #[serde(default = "bool_true")]
+    pub approved: bool,
+    #[serde(default)]
+    pub locked: bool,
     pub notes: Option<String>,
 }
 
+/// Outcome of [`FieldServiceReport::save_batch`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BatchSummary {
+    pub created: usize,
+    pub updated: usize,
+}
+
 /// Minimal row for active-publisher queries (no decryption needed).
 #[derive(Debug, serde::Deserialize, SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
@@ -70,6 +107,11 @@ impl FieldServiceReport {
         Ok(self)
     }
 
+    /// Locked by the secretary, or in one of the `closed` months.
+    pub fn is_locked(&self, closed: &BTreeSet<(i32, u8)>) -> bool {
+        self.locked || closed.contains(&(self.year, self.month))
+    }
+
     /// All reports for a given month/year, decrypted.
     pub async fn by_month(
         db: &Db,
@@ -109,6 +151,67 @@ impl FieldServiceReport {
         Ok(decrypted)
     }
 
+    /// All reports between two months (both inclusive), decrypted and sorted
+    /// oldest first. Used for service-year views that span two calendar years.
+    pub async fn by_period(
+        db: &Db,
+        crypto: &SessionCrypto,
+        from: (i32, u8),
+        to: (i32, u8),
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query(
+                "SELECT * FROM field_service_report \
+                 WHERE (year * 12 + month) >= $from AND (year * 12 + month) <= $to",
+            )
+            .bind(("from", from.0 as i64 * 12 + from.1 as i64))
+            .bind(("to", to.0 as i64 * 12 + to.1 as i64))
+            .await?
+            .take(0)?;
+        let mut decrypted: Vec<Self> = rows
+            .into_iter()
+            .map(|r| r.decrypt(crypto).map_err(Into::into))
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+        decrypted.sort_by(|a, b| a.year.cmp(&b.year).then(a.month.cmp(&b.month)));
+        Ok(decrypted)
+    }
+
+    /// Portal submissions waiting for review, decrypted, oldest first.
+    pub async fn pending(
+        db: &Db,
+        crypto: &SessionCrypto,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM field_service_report WHERE approved = false")
+            .await?
+            .take(0)?;
+        let mut decrypted: Vec<Self> = rows
+            .into_iter()
+            .map(|r| r.decrypt(crypto).map_err(Into::into))
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+        decrypted.sort_by(|a, b| a.year.cmp(&b.year).then(a.month.cmp(&b.month)));
+        Ok(decrypted)
+    }
+
+    /// Set the review flags of a report without touching its figures.
+    pub async fn set_review(
+        db: &Db,
+        id: RecordId,
+        approved: bool,
+        locked: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query(guarded("UPDATE $id SET approved = $approved, locked = $locked;"))
+            .bind(("guard_periods", Vec::<Vec<i64>>::new()))
+            .bind(("guard_ids", vec![id.clone()]))
+            .bind(("id", id))
+            .bind(("approved", approved))
+            .bind(("locked", locked))
+            .await?
+            .check()
+            .map_err(closed_error)?;
+        Ok(())
+    }
+
     /// Returns the set of publisher record-ID strings (e.g. `"user:abc123"`)
     /// that have at least one report since `(since_year, since_month)` inclusive
     /// where `preached` is true.
@@ -152,7 +255,15 @@ impl FieldServiceReport {
         crypto: &SessionCrypto,
         data: FieldServiceReportData,
     ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
-        let row: Option<Self> = db.create(TABLE).content(data.encrypt(crypto)?).await?;
+        let row: Option<Self> = db
+            .query(guarded(&format!("CREATE {TABLE} CONTENT $data;")))
+            .bind(("guard_periods", guard_periods(&[(data.year, data.month)])))
+            .bind(("guard_ids", Vec::<RecordId>::new()))
+            .bind(("data", data.encrypt(crypto)?))
+            .await?
+            .check()
+            .map_err(closed_error)?
+            .take(0)?;
         row.map(|r| r.decrypt(crypto).map_err(Into::into)).transpose()
     }
 
@@ -162,15 +273,74 @@ impl FieldServiceReport {
         id: RecordId,
         data: FieldServiceReportData,
     ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
-        let row: Option<Self> = db.update(id).content(data.encrypt(crypto)?).await?;
+        let row: Option<Self> = db
+            .query(guarded("UPDATE $id CONTENT $data;"))
+            .bind(("guard_periods", guard_periods(&[(data.year, data.month)])))
+            .bind(("guard_ids", vec![id.clone()]))
+            .bind(("id", id))
+            .bind(("data", data.encrypt(crypto)?))
+            .await?
+            .check()
+            .map_err(closed_error)?
+            .take(0)?;
         row.map(|r| r.decrypt(crypto).map_err(Into::into)).transpose()
     }
 
-    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
-        db.delete(id).await
+    /// Create or update many reports in a single transaction.
+    ///
+    /// Rows with an id are updated, the rest created. Either every row is
+    /// stored or none is; nothing is stored when any row touches a closed month.
+    pub async fn save_batch(
+        db: &Db,
+        crypto: &SessionCrypto,
+        rows: Vec<(Option<RecordId>, FieldServiceReportData)>,
+    ) -> Result<BatchSummary, Box<dyn std::error::Error>> {
+        let ids: Vec<RecordId> = rows.iter().filter_map(|(id, _)| id.clone()).collect();
+        let periods: Vec<(i32, u8)> = rows.iter().map(|(_, d)| (d.year, d.month)).collect();
+
+        let mut summary = BatchSummary::default();
+        let mut query = db
+            .query("BEGIN TRANSACTION")
+            .query(month_close::GUARD)
+            .bind(("guard_periods", guard_periods(&periods)))
+            .bind(("guard_ids", ids));
+        for (i, (id, data)) in rows.into_iter().enumerate() {
+            let data_var = format!("data{i}");
+            let data = data.encrypt(crypto)?;
+            query = match id {
+                Some(id) => {
+                    summary.updated += 1;
+                    let id_var = format!("id{i}");
+                    query
+                        .query(format!("UPDATE ${id_var} CONTENT ${data_var}"))
+                        .bind((id_var, id))
+                }
+                None => {
+                    summary.created += 1;
+                    query.query(format!("CREATE {TABLE} CONTENT ${data_var}"))
+                }
+            }
+            .bind((data_var, data));
+        }
+        query.query("COMMIT TRANSACTION").await?.check().map_err(closed_error)?;
+        Ok(summary)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        Ok(db
+            .query(guarded("DELETE $id RETURN BEFORE;"))
+            .bind(("guard_periods", Vec::<Vec<i64>>::new()))
+            .bind(("guard_ids", vec![id.clone()]))
+            .bind(("id", id))
+            .await?
+            .check()
+            .map_err(closed_error)?
+            .take(0)?)
     }
 
     /// Delete all reports belonging to a publisher (e.g. when deleting the publisher).
+    /// Closed months are not protected here: removing a publisher removes
+    /// their whole record.
     pub async fn delete_by_publisher(
         db: &Db,
         publisher_id: RecordId,
@@ -181,3 +351,26 @@ impl FieldServiceReport {
         Ok(())
     }
 }
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::report;
+
+    #[test]
+    fn reopening_keeps_reports_locked_before_the_close() {
+        let locked = FieldServiceReport { locked: true, ..report("a", 2025, 9) };
+        let open = report("a", 2025, 9);
+        let closed = BTreeSet::from([(2025, 9)]);
+        assert!(locked.is_locked(&closed));
+        assert!(open.is_locked(&closed));
+        // Reopened: only the month's lock goes away.
+        let reopened = BTreeSet::new();
+        assert!(locked.is_locked(&reopened));
+        assert!(!open.is_locked(&reopened));
+    }
+}
@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const ASSET_TABLE: &str = "maintenance_asset";
+pub const TASK_TABLE: &str = "maintenance_task";
+pub const ORDER_TABLE: &str = "work_order";
+
+// ── Asset ─────────────────────────────────────────────────────────────────────
+
+/// A piece of equipment or part of the building that needs upkeep. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct Asset {
+    pub id: Option<RecordId>,
+    pub name: String,
+    #[serde(default)]
+    pub location: String,
+    /// Make, model, serial number or anything else worth keeping.
+    #[serde(default)]
+    pub notes: String,
+    pub installed: Option<String>, // "YYYY-MM-DD"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct AssetData {
+    pub name: String,
+    pub location: String,
+    pub notes: String,
+    pub installed: Option<String>,
+}
+
+impl Asset {
+    /// Every asset, by name.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(ASSET_TABLE).await?;
+        rows.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        Ok(rows)
+    }
+
+    pub async fn create(db: &Db, data: AssetData) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(ASSET_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: AssetData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── PreventiveTask ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum IntervalUnit {
+    Days,
+    Weeks,
+    #[default]
+    Months,
+    Years,
+}
+
+impl IntervalUnit {
+    pub const ALL: [IntervalUnit; 4] =
+        [IntervalUnit::Days, IntervalUnit::Weeks, IntervalUnit::Months, IntervalUnit::Years];
+
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Days => "days",
+            Self::Weeks => "weeks",
+            Self::Months => "months",
+            Self::Years => "years",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "days" => Self::Days,
+            "weeks" => Self::Weeks,
+            "years" => Self::Years,
+            _ => Self::Months,
+        }
+    }
+}
+
+/// Upkeep repeated at a fixed interval, e.g. inspecting the fire
+/// extinguishers every year. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct PreventiveTask {
+    pub id: Option<RecordId>,
+    pub title: String,
+    pub asset: Option<RecordId>, // → maintenance_asset record
+    pub every: u16,
+    pub unit: IntervalUnit,
+    /// Date the task was last carried out; `None` if never, which makes it due.
+    pub last_done: Option<String>,
+    pub assignee: Option<RecordId>, // → user record
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct PreventiveTaskData {
+    pub title: String,
+    pub asset: Option<RecordId>,
+    pub every: u16,
+    pub unit: IntervalUnit,
+    pub last_done: Option<String>,
+    pub assignee: Option<RecordId>,
+    pub notes: String,
+}
+
+impl From<PreventiveTask> for PreventiveTaskData {
+    fn from(t: PreventiveTask) -> Self {
+        Self {
+            title: t.title,
+            asset: t.asset,
+            every: t.every,
+            unit: t.unit,
+            last_done: t.last_done,
+            assignee: t.assignee,
+            notes: t.notes,
+        }
+    }
+}
+
+impl PreventiveTask {
+    /// Every preventive task, by title.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(TASK_TABLE).await?;
+        rows.sort_by(|a, b| a.title.to_lowercase().cmp(&b.title.to_lowercase()));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: PreventiveTaskData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(TASK_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: PreventiveTaskData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── WorkOrder ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum WorkOrderStatus {
+    #[default]
+    Open,
+    InProgress,
+    Done,
+    Cancelled,
+}
+
+impl WorkOrderStatus {
+    pub const ALL: [WorkOrderStatus; 4] = [
+        WorkOrderStatus::Open,
+        WorkOrderStatus::InProgress,
+        WorkOrderStatus::Done,
+        WorkOrderStatus::Cancelled,
+    ];
+
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::InProgress => "in_progress",
+            Self::Done => "done",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "in_progress" => Self::InProgress,
+            "done" => Self::Done,
+            "cancelled" => Self::Cancelled,
+            _ => Self::Open,
+        }
+    }
+
+    /// Still waiting to be finished.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Open | Self::InProgress)
+    }
+}
+
+/// A one-off repair or job. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WorkOrder {
+    pub id: Option<RecordId>,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub asset: Option<RecordId>, // → maintenance_asset record
+    pub status: WorkOrderStatus,
+    pub assignee: Option<RecordId>, // → user record
+    pub reported: String, // "YYYY-MM-DD"
+    pub due: Option<String>,
+    /// Set when the status becomes [`WorkOrderStatus::Done`].
+    pub completed: Option<String>,
+    /// Free text: quotes, parts bought, who paid.
+    #[serde(default)]
+    pub cost_notes: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WorkOrderData {
+    pub title: String,
+    pub description: String,
+    pub asset: Option<RecordId>,
+    pub status: WorkOrderStatus,
+    pub assignee: Option<RecordId>,
+    pub reported: String,
+    pub due: Option<String>,
+    pub completed: Option<String>,
+    pub cost_notes: String,
+}
+
+impl WorkOrder {
+    /// Every work order, newest first.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(ORDER_TABLE).await?;
+        rows.sort_by(|a, b| b.reported.cmp(&a.reported));
+        Ok(rows)
+    }
+
+    /// Orders still open or in progress.
+    pub async fn pending(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM work_order WHERE status IN $statuses")
+            .bind(("statuses", vec![WorkOrderStatus::Open, WorkOrderStatus::InProgress]))
+            .await?
+            .take(0)?;
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: WorkOrderData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(ORDER_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: WorkOrderData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
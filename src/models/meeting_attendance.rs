@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+use super::duty_rota::MeetingKind;
+
+pub const TABLE: &str = "meeting_attendance";
+
+/// Head count of one meeting. Plaintext: only numbers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MeetingAttendance {
+    pub id: Option<RecordId>,
+    pub date: String, // "YYYY-MM-DD"
+    pub meeting: MeetingKind,
+    /// Present at the Kingdom Hall.
+    pub in_person: u32,
+    /// Connected by video conference or telephone, counting every person
+    /// watching on one connection.
+    pub online: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MeetingAttendanceData {
+    pub date: String,
+    pub meeting: MeetingKind,
+    pub in_person: u32,
+    pub online: u32,
+}
+
+impl From<MeetingAttendance> for MeetingAttendanceData {
+    fn from(a: MeetingAttendance) -> Self {
+        Self {
+            date: a.date,
+            meeting: a.meeting,
+            in_person: a.in_person,
+            online: a.online,
+        }
+    }
+}
+
+impl MeetingAttendance {
+    pub fn blank(date: String, meeting: MeetingKind) -> Self {
+        Self {
+            id: None,
+            date,
+            meeting,
+            in_person: 0,
+            online: 0,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.in_person + self.online
+    }
+
+    /// Entries for meetings between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM meeting_attendance WHERE date >= $from AND date <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    /// Store a month's entries in a single transaction. Entries with an id
+    /// are updated, or deleted when both counts were cleared; new entries are
+    /// created unless they are empty.
+    pub async fn save_many(db: &Db, entries: Vec<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        let mut query = db.query("BEGIN TRANSACTION");
+        for (i, entry) in entries.into_iter().enumerate() {
+            let id_var = format!("id{i}");
+            let data_var = format!("data{i}");
+            query = match (entry.id.clone(), entry.total() > 0) {
+                (Some(id), true) => query
+                    .query(format!("UPDATE ${id_var} CONTENT ${data_var}"))
+                    .bind((id_var, id))
+                    .bind((data_var, MeetingAttendanceData::from(entry))),
+                (Some(id), false) => query.query(format!("DELETE ${id_var}")).bind((id_var, id)),
+                (None, true) => query
+                    .query(format!("CREATE {TABLE} CONTENT ${data_var}"))
+                    .bind((data_var, MeetingAttendanceData::from(entry))),
+                (None, false) => query,
+            };
+        }
+        query.query("COMMIT TRANSACTION").await?.check()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const TABLE: &str = "midweek_meeting";
+
+/// Program section a part belongs to, in meeting order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Section {
+    Treasures,
+    Ministry,
+    Living,
+}
+
+/// Kind of a midweek meeting part. Decides who may be assigned to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum PartKind {
+    /// Opening talk of "Treasures From God's Word".
+    Treasures,
+    SpiritualGems,
+    BibleReading,
+    StartingConversation,
+    FollowingUp,
+    MakingDisciples,
+    /// Student talk without a householder.
+    StudentTalk,
+    /// Discussion led by an elder in the ministry section.
+    FieldMinistryDiscussion,
+    LivingAsChristians,
+    /// Conducted by the assignee; the assistant is the reader.
+    CongregationBibleStudy,
+}
+
+impl PartKind {
+    pub const ALL: [PartKind; 10] = [
+        PartKind::Treasures,
+        PartKind::SpiritualGems,
+        PartKind::BibleReading,
+        PartKind::StartingConversation,
+        PartKind::FollowingUp,
+        PartKind::MakingDisciples,
+        PartKind::StudentTalk,
+        PartKind::FieldMinistryDiscussion,
+        PartKind::LivingAsChristians,
+        PartKind::CongregationBibleStudy,
+    ];
+
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Treasures => "treasures",
+            Self::SpiritualGems => "spiritual_gems",
+            Self::BibleReading => "bible_reading",
+            Self::StartingConversation => "starting_conversation",
+            Self::FollowingUp => "following_up",
+            Self::MakingDisciples => "making_disciples",
+            Self::StudentTalk => "student_talk",
+            Self::FieldMinistryDiscussion => "field_ministry_discussion",
+            Self::LivingAsChristians => "living_as_christians",
+            Self::CongregationBibleStudy => "congregation_bible_study",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.key() == key)
+    }
+
+    pub fn section(&self) -> Section {
+        match self {
+            Self::Treasures | Self::SpiritualGems | Self::BibleReading => Section::Treasures,
+            Self::StartingConversation
+            | Self::FollowingUp
+            | Self::MakingDisciples
+            | Self::StudentTalk
+            | Self::FieldMinistryDiscussion => Section::Ministry,
+            Self::LivingAsChristians | Self::CongregationBibleStudy => Section::Living,
+        }
+    }
+
+    /// Usual length in minutes.
+    pub fn default_minutes(&self) -> u8 {
+        match self {
+            Self::Treasures | Self::SpiritualGems => 10,
+            Self::BibleReading => 4,
+            Self::StartingConversation => 3,
+            Self::FollowingUp => 4,
+            Self::MakingDisciples | Self::StudentTalk => 5,
+            Self::FieldMinistryDiscussion => 5,
+            Self::LivingAsChristians => 15,
+            Self::CongregationBibleStudy => 30,
+        }
+    }
+
+    /// Given by a student of the school: gets counsel and an assignment slip,
+    /// and may be repeated in the auxiliary classroom.
+    pub fn is_student_part(&self) -> bool {
+        matches!(
+            self,
+            Self::BibleReading
+                | Self::StartingConversation
+                | Self::FollowingUp
+                | Self::MakingDisciples
+                | Self::StudentTalk
+        )
+    }
+
+    /// Needs a second person: a householder or the study reader.
+    pub fn has_assistant(&self) -> bool {
+        matches!(
+            self,
+            Self::StartingConversation
+                | Self::FollowingUp
+                | Self::MakingDisciples
+                | Self::CongregationBibleStudy
+        )
+    }
+}
+
+/// Where a part is given. Student parts may run in a second classroom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum Classroom {
+    #[default]
+    Main,
+    Auxiliary,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MidweekPart {
+    pub kind: PartKind,
+    /// Theme as printed in the meeting workbook.
+    pub title: String,
+    pub minutes: u8,
+    #[serde(default)]
+    pub classroom: Classroom,
+    pub assignee: Option<RecordId>, // → user record
+    /// Householder for student parts, reader for the congregation Bible study.
+    pub assistant: Option<RecordId>, // → user record
+    /// Study point from the teaching brochure, printed on the assignment slip.
+    #[serde(default)]
+    pub lesson: Option<u16>,
+}
+
+/// Program of one week's midweek meeting. Stored in plaintext: it holds
+/// the published program and record links only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MidweekMeeting {
+    pub id: Option<RecordId>,
+    pub week_start: String, // "YYYY-MM-DD", Monday of the week
+    pub date: String,       // "YYYY-MM-DD", day the meeting is held
+    /// Weekly Bible reading, e.g. "ISAIAH 1-2".
+    pub bible_reading: String,
+    pub opening_song: Option<u16>,
+    pub middle_song: Option<u16>,
+    pub closing_song: Option<u16>,
+    /// 1, or 2 when student parts are repeated in an auxiliary classroom.
+    pub classrooms: u8,
+    pub chairman: Option<RecordId>,
+    /// Counselor of the auxiliary classroom.
+    pub aux_counselor: Option<RecordId>,
+    pub opening_prayer: Option<RecordId>,
+    pub closing_prayer: Option<RecordId>,
+    pub parts: Vec<MidweekPart>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MidweekMeetingData {
+    pub week_start: String,
+    pub date: String,
+    pub bible_reading: String,
+    pub opening_song: Option<u16>,
+    pub middle_song: Option<u16>,
+    pub closing_song: Option<u16>,
+    pub classrooms: u8,
+    pub chairman: Option<RecordId>,
+    pub aux_counselor: Option<RecordId>,
+    pub opening_prayer: Option<RecordId>,
+    pub closing_prayer: Option<RecordId>,
+    pub parts: Vec<MidweekPart>,
+}
+
+impl From<MidweekMeeting> for MidweekMeetingData {
+    fn from(m: MidweekMeeting) -> Self {
+        Self {
+            week_start: m.week_start,
+            date: m.date,
+            bible_reading: m.bible_reading,
+            opening_song: m.opening_song,
+            middle_song: m.middle_song,
+            closing_song: m.closing_song,
+            classrooms: m.classrooms,
+            chairman: m.chairman,
+            aux_counselor: m.aux_counselor,
+            opening_prayer: m.opening_prayer,
+            closing_prayer: m.closing_prayer,
+            parts: m.parts,
+        }
+    }
+}
+
+impl MidweekMeeting {
+    /// Unsaved program with the usual parts of a week.
+    pub fn template(week_start: String, date: String) -> Self {
+        let part = |kind: PartKind| MidweekPart {
+            kind,
+            title: String::new(),
+            minutes: kind.default_minutes(),
+            classroom: Classroom::Main,
+            assignee: None,
+            assistant: None,
+            lesson: None,
+        };
+        Self {
+            id: None,
+            week_start,
+            date,
+            bible_reading: String::new(),
+            opening_song: None,
+            middle_song: None,
+            closing_song: None,
+            classrooms: 1,
+            chairman: None,
+            aux_counselor: None,
+            opening_prayer: None,
+            closing_prayer: None,
+            parts: [
+                PartKind::Treasures,
+                PartKind::SpiritualGems,
+                PartKind::BibleReading,
+                PartKind::StartingConversation,
+                PartKind::FollowingUp,
+                PartKind::MakingDisciples,
+                PartKind::LivingAsChristians,
+                PartKind::CongregationBibleStudy,
+            ]
+            .into_iter()
+            .map(part)
+            .collect(),
+        }
+    }
+
+    /// Meetings whose week starts between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM midweek_meeting WHERE week_start >= $from AND week_start <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: MidweekMeetingData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: MidweekMeetingData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
@@ -31,12 +31,45 @@ pub const TABLES: &[&str] = &[
     super::absence::TABLE,
     super::privilege::TABLE,
     super::field_service_meeting::TABLE,
+    super::midweek_meeting::TABLE,
+    super::weekend_meeting::TABLE,
+    super::public_talk::OUTLINE_TABLE,
+    super::public_talk::SPEAKER_TABLE,
+    super::public_talk::OUTGOING_TABLE,
+    super::public_talk::SETTINGS_TABLE,
+    super::duty_rota::TABLE,
+    super::duty_rota::SETTINGS_TABLE,
+    super::meeting_attendance::TABLE,
+    super::cleaning::AREA_TABLE,
+    super::cleaning::WEEK_TABLE,
+    super::maintenance::ASSET_TABLE,
+    super::maintenance::TASK_TABLE,
+    super::maintenance::ORDER_TABLE,
+    super::event::TABLE, // events travel with backups, mode switches and wipes
+    super::co_visit::TABLE,
+    super::public_witnessing::LOCATION_TABLE,
+    super::public_witnessing::SHIFT_TABLE,
+    super::public_witnessing::OCCURRENCE_TABLE,
+    super::field_service_report::TABLE, // reports travel with backups, mode switches and wipes
+    super::report_follow_up::TABLE,
+    super::month_close::TABLE,
+    super::pioneer::SETTINGS_TABLE,
+    super::portal::ABSENCE_REQUEST_TABLE,
     super::territory::TERRITORY_TABLE,
     super::territory::TERRITORY_ADDRESS_TABLE,
     super::territory::TERRITORY_ASSIGNMENT_TABLE,
     super::territory::TERRITORY_REQUEST_TABLE,
 ];
 
+/// Tables that [`wipe`] clears but that are never exported or imported.
+///
+/// Portal account passwords are `PERMISSIONS FOR select NONE`, so an export
+/// would carry accounts without their hash and restored accounts could not
+/// sign in. They stay in the database they were created in, and the secretary
+/// sets them up again after moving to another one. A wipe still deletes them
+/// so that no credentials outlive the data they belong to.
+const WIPE_ONLY: &[&str] = &[super::portal::ACCOUNT_TABLE];
+
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
@@ -159,7 +192,7 @@ pub async fn migrate_to_offline(online: &Db, local: &Db) -> Result<(), MigrateEr
 // ---------------------------------------------------------------------------
 
 /// Delete every record in every known table (child-first / reverse dependency
-/// order).
+/// order), including [`WIPE_ONLY`] ones.
 ///
 /// - **Offline**: clears the entire embedded database.
 /// - **Online**: clears only the records inside the configured
@@ -167,7 +200,7 @@ pub async fn migrate_to_offline(online: &Db, local: &Db) -> Result<(), MigrateEr
 ///   users on the same SurrealDB instance are unaffected (see module-level
 ///   note on isolation).
 pub async fn wipe(db: &Db) -> Result<(), MigrateError> {
-    for &table in TABLES.iter().rev() {
+    for &table in WIPE_ONLY.iter().chain(TABLES.iter().rev()) {
         db.query(format!("DELETE {table}")).await?;
     }
     Ok(())
@@ -1,15 +1,28 @@
 pub mod absence;
+pub mod cleaning;
+pub mod co_visit;
 pub mod congregation;
+pub mod duty_rota;
 pub mod emergency_contact;
 pub mod event;
 pub mod field_service_group;
 pub mod field_service_meeting;
 pub mod field_service_report;
+pub mod maintenance;
+pub mod meeting_attendance;
+pub mod midweek_meeting;
 pub mod migrate;
+pub mod month_close;
+pub mod pioneer;
+pub mod portal;
 pub mod privilege;
+pub mod public_talk;
+pub mod public_witnessing;
+pub mod report_follow_up;
 pub mod territory;
 pub mod user;
 pub mod user_prefs;
+pub mod weekend_meeting;
 
 // Re-export the database handle so models can be used without importing database directly.
 pub use crate::database::Db;
@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::crypto::{CryptoError, SessionCrypto};
+use crate::database::Db;
+
+pub const TABLE: &str = "month_close";
+
+/// Returned by the report model when a change touches a closed month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthClosedError {
+    pub year: i32,
+    pub month: u8,
+}
+
+impl std::fmt::Display for MonthClosedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Reports for {}-{:02} are closed; reopen the month first",
+            self.year, self.month
+        )
+    }
+}
+
+impl std::error::Error for MonthClosedError {}
+
+/// Start of the message [`GUARD`] throws, followed by `year-month`.
+const CLOSED_MARK: &str = "month closed: ";
+
+/// Statements that throw when a month is closed: any `[year, month]` in
+/// `$guard_periods`, or the month of any stored report in `$guard_ids`.
+///
+/// Run them in the same transaction as the write they protect, so a month
+/// cannot close between the check and the write; [`guarded`] does that for
+/// a single statement.
+pub(crate) const GUARD: &str = "\
+    LET $closed = (SELECT year, month FROM month_close WHERE closed = true \
+        AND ([year, month] INSIDE $guard_periods \
+            OR [year, month] INSIDE (SELECT VALUE [year, month] FROM field_service_report \
+                WHERE id IN $guard_ids))); \
+    IF $closed[0] { \
+        THROW 'month closed: ' + <string> $closed[0].year + '-' + <string> $closed[0].month; \
+    };";
+
+/// `write` behind [`GUARD`] in one block, which runs as one transaction.
+pub(crate) fn guarded(write: &str) -> String {
+    format!("{{ {GUARD} {write} }}")
+}
+
+/// `$guard_periods` for [`GUARD`].
+pub(crate) fn guard_periods(periods: &[(i32, u8)]) -> Vec<Vec<i64>> {
+    periods.iter().map(|&(y, m)| vec![y as i64, m as i64]).collect()
+}
+
+/// Turn the error [`GUARD`] throws back into a [`MonthClosedError`]; any
+/// other error passes through.
+pub(crate) fn closed_error(e: surrealdb::Error) -> Box<dyn std::error::Error> {
+    let msg = e.to_string();
+    let period = msg.split_once(CLOSED_MARK).and_then(|(_, rest)| {
+        let (year, month) = rest.split_once('-')?;
+        let month: String = month.chars().take_while(char::is_ascii_digit).collect();
+        Some((year.parse().ok()?, month.parse().ok()?))
+    });
+    match period {
+        Some((year, month)) => MonthClosedError { year, month }.into(),
+        None => e.into(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub enum CloseAction {
+    Closed,
+    Reopened,
+}
+
+/// One close or reopen of a month.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct CloseEntry {
+    pub action: CloseAction,
+    /// ISO 8601 date string, plaintext
+    pub date: String,
+    /// Why the month was reopened — encrypted at rest
+    pub reason: Option<String>,
+}
+
+/// Close state of one report month. A month without a record is open.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MonthClose {
+    pub id: Option<RecordId>,
+    pub year: i32, // plaintext
+    pub month: u8, // 1-12, plaintext
+    pub closed: bool,
+    /// Oldest first.
+    #[serde(default)]
+    pub history: Vec<CloseEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct MonthCloseData {
+    pub year: i32,
+    pub month: u8,
+    pub closed: bool,
+    pub history: Vec<CloseEntry>,
+}
+
+#[derive(Debug, serde::Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+struct PeriodRow {
+    year: i32,
+    month: u8,
+}
+
+impl MonthCloseData {
+    pub fn encrypt(mut self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        for entry in &mut self.history {
+            entry.reason = entry.reason.take().map(|r| crypto.encrypt(&r)).transpose()?;
+        }
+        Ok(self)
+    }
+}
+
+impl MonthClose {
+    pub fn decrypt(mut self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        for entry in &mut self.history {
+            entry.reason = entry.reason.take().map(|r| crypto.decrypt(&r)).transpose()?;
+        }
+        Ok(self)
+    }
+
+    /// The record for a month, decrypted, if it was ever closed.
+    pub async fn get(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM month_close WHERE year = $y AND month = $m")
+            .bind(("y", year))
+            .bind(("m", month))
+            .await?
+            .take(0)?;
+        rows.pop().map(|r| r.decrypt(crypto).map_err(Into::into)).transpose()
+    }
+
+    /// Every month currently closed.
+    pub async fn closed_months(db: &Db) -> Result<BTreeSet<(i32, u8)>, Box<dyn std::error::Error>> {
+        let rows: Vec<PeriodRow> = db
+            .query("SELECT year, month FROM month_close WHERE closed = true")
+            .await?
+            .take(0)?;
+        Ok(rows.into_iter().map(|r| (r.year, r.month)).collect())
+    }
+
+    /// Close a month. Its reports stay read-only while it is closed; their own
+    /// `locked` flags are left alone.
+    pub async fn close(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+        date: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let entry = CloseEntry { action: CloseAction::Closed, date, reason: None };
+        Self::record(db, crypto, year, month, true, entry).await
+    }
+
+    /// Reopen a closed month. Reports the secretary locked stay locked. A
+    /// reason is required and kept in the history.
+    pub async fn reopen(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+        date: String,
+        reason: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err("A reason is required to reopen a month".into());
+        }
+        let entry = CloseEntry { action: CloseAction::Reopened, date, reason: Some(reason) };
+        Self::record(db, crypto, year, month, false, entry).await
+    }
+
+    async fn record(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+        closed: bool,
+        entry: CloseEntry,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let existing = Self::get(db, crypto, year, month).await?;
+        if existing.as_ref().is_some_and(|m| m.closed) == closed {
+            return Ok(());
+        }
+        let mut history = existing.as_ref().map(|m| m.history.clone()).unwrap_or_default();
+        history.push(entry);
+        let data = MonthCloseData { year, month, closed, history }.encrypt(crypto)?;
+
+        match existing.and_then(|m| m.id) {
+            Some(id) => db.query("UPDATE $id CONTENT $data").bind(("id", id)),
+            None => db.query(format!("CREATE {TABLE} CONTENT $data")),
+        }
+        .bind(("data", data))
+        .await?
+        .check()?;
+        Ok(())
+    }
+}
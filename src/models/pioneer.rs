@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::SurrealValue;
+
+use crate::database::Db;
+
+pub const SETTINGS_TABLE: &str = "pioneer_settings";
+pub const SETTINGS_KEY: &str = "settings";
+
+// ── PioneerSettings ───────────────────────────────────────────────────────────
+
+/// Flat data stored in `pioneer_settings:settings`. Not sensitive.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct PioneerSettings {
+    /// Hours plus credit a pioneer may count in one month. Credit never
+    /// brings a month past this total, or past the month's requirement when
+    /// that is higher. `None` = credit only fills up the requirement.
+    pub credit_limit: Option<u32>,
+}
+
+impl PioneerSettings {
+    /// Stored settings, or the defaults when none were saved yet.
+    pub async fn get(db: &Db) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut res = db
+            .query(format!("SELECT credit_limit FROM {}:{}", SETTINGS_TABLE, SETTINGS_KEY))
+            .await?;
+        let record: Option<Self> = res.take(0)?;
+        Ok(record.unwrap_or_default())
+    }
+
+    pub async fn upsert(db: &Db, settings: &Self) -> Result<(), Box<dyn std::error::Error>> {
+        db.query(format!("UPSERT {}:{} CONTENT $data", SETTINGS_TABLE, SETTINGS_KEY))
+            .bind(("data", settings.clone()))
+            .await?
+            .check()?;
+        Ok(())
+    }
+}
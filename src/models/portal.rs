@@ -0,0 +1,299 @@
+//! Publisher portal: limited online accounts for self-reporting.
+//!
+//! A portal account belongs to one publisher and signs in through the
+//! `publisher` RECORD access instead of the full `user` access. Everything it
+//! may do is enforced by SurrealDB permissions (see [`portal_schema`]), not by
+//! the UI:
+//!
+//! * read its own [`FieldServiceReport`]s, and create or edit the one for
+//!   the current month while it is not locked and the month has not been
+//!   closed; earlier months are left to the secretary;
+//! * create, list and withdraw its own [`AbsenceRequest`]s.
+//!
+//! Portal sessions never hold the congregation passphrase, so they cannot
+//! read or write encrypted fields. Report notes are hidden from them and
+//! absence requests are stored in plain text until the secretary turns them
+//! into a regular (encrypted) absence.
+
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+use super::field_service_report::{self, FieldServiceReport, FieldServiceReportData};
+use super::month_close;
+
+pub const ACCOUNT_TABLE: &str = "portal_account";
+pub const ABSENCE_REQUEST_TABLE: &str = "absence_request";
+
+/// Name of the SurrealDB RECORD access used by portal accounts.
+pub const ACCESS: &str = "publisher";
+
+// ---------------------------------------------------------------------------
+// Accounts
+// ---------------------------------------------------------------------------
+
+/// A publisher's portal login. The password hash is never selected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct PortalAccount {
+    pub id: Option<RecordId>,
+    pub publisher: RecordId, // plaintext FK, used by the permission rules
+    pub username: String,    // plaintext: looked up at sign-in
+    pub enabled: bool,
+}
+
+impl PortalAccount {
+    pub async fn by_publisher(
+        db: &Db,
+        publisher: RecordId,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query(
+                "SELECT id, publisher, username, enabled FROM portal_account \
+                 WHERE publisher = $p LIMIT 1",
+            )
+            .bind(("p", publisher))
+            .await?
+            .take(0)?;
+        Ok(rows.into_iter().next())
+    }
+
+    /// The account of the signed-in portal session.
+    pub async fn current(db: &Db) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db
+            .query("SELECT id, publisher, username, enabled FROM ONLY $auth")
+            .await?
+            .take(0)?;
+        Ok(row)
+    }
+
+    /// Create an account; the password is hashed server-side with argon2.
+    pub async fn create(
+        db: &Db,
+        publisher: RecordId,
+        username: &str,
+        password: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query(
+            "CREATE portal_account SET publisher = $p, username = $u, \
+             password = crypto::argon2::generate($pw), enabled = true",
+        )
+        .bind(("p", publisher))
+        .bind(("u", username.trim().to_string()))
+        .bind(("pw", password.to_string()))
+        .await?
+        .check()?;
+        Ok(())
+    }
+
+    pub async fn set_password(
+        db: &Db,
+        id: RecordId,
+        password: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query("UPDATE $id SET password = crypto::argon2::generate($pw)")
+            .bind(("id", id))
+            .bind(("pw", password.to_string()))
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    pub async fn set_enabled(
+        db: &Db,
+        id: RecordId,
+        enabled: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query("UPDATE $id SET enabled = $e")
+            .bind(("id", id))
+            .bind(("e", enabled))
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    pub async fn delete_by_publisher(
+        db: &Db,
+        publisher: RecordId,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query("DELETE portal_account WHERE publisher = $p")
+            .bind(("p", publisher))
+            .await?;
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Reports submitted from the portal
+// ---------------------------------------------------------------------------
+
+/// The signed-in publisher's reports, newest first. Notes are never returned.
+pub async fn own_reports(
+    db: &Db,
+    publisher: RecordId,
+) -> Result<Vec<FieldServiceReport>, Box<dyn std::error::Error>> {
+    let mut rows: Vec<FieldServiceReport> = db
+        .query("SELECT * FROM field_service_report WHERE publisher = $p")
+        .bind(("p", publisher))
+        .await?
+        .take(0)?;
+    rows.sort_by(|a, b| b.year.cmp(&a.year).then(b.month.cmp(&a.month)));
+    Ok(rows)
+}
+
+/// Create or replace the publisher's report for `data.year`/`data.month`.
+///
+/// The server overrides `source`, `approved`, `locked` and `notes` for portal
+/// sessions, so the values sent here are only what the form shows.
+pub async fn submit_report(
+    db: &Db,
+    existing: Option<RecordId>,
+    data: FieldServiceReportData,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match existing {
+        Some(id) => {
+            let _: Option<FieldServiceReport> = db.update(id).content(data).await?;
+        }
+        None => {
+            let _: Option<FieldServiceReport> =
+                db.create(field_service_report::TABLE).content(data).await?;
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Absence requests
+// ---------------------------------------------------------------------------
+
+/// An absence announced by a publisher, waiting for the secretary.
+/// Stored in plain text because portal sessions cannot encrypt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct AbsenceRequest {
+    pub id: Option<RecordId>,
+    pub publisher: RecordId,
+    /// ISO 8601 date string.
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct AbsenceRequestData {
+    pub publisher: RecordId,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub reason: Option<String>,
+}
+
+impl AbsenceRequest {
+    /// All requests visible to the session, oldest start first. For a portal
+    /// session that is only its own.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(ABSENCE_REQUEST_TABLE).await?;
+        rows.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: AbsenceRequestData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.create(ABSENCE_REQUEST_TABLE).content(data).await?;
+        Ok(row)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Server schema
+// ---------------------------------------------------------------------------
+
+/// SurrealQL that installs the `publisher` access and the permission rules.
+///
+/// `tables` are the congregation tables (see
+/// [`super::migrate::TABLES`]); they stay fully available to the `user`
+/// access and closed to portal sessions, except for the rules below.
+/// Defining an access method needs owner rights on the database, so the
+/// statements may have to be run by whoever administers the instance.
+pub fn portal_schema(tables: &[&str]) -> String {
+    let mut q = String::new();
+    let full_user = r#"FOR select, create, update, delete WHERE $access = "user""#;
+    for table in tables.iter().filter(|t| !t.starts_with('_')) {
+        if [field_service_report::TABLE, month_close::TABLE, ACCOUNT_TABLE, ABSENCE_REQUEST_TABLE].contains(table) {
+            continue;
+        }
+        q.push_str(&format!(
+            "DEFINE TABLE IF NOT EXISTS {table} SCHEMALESS;\n\
+             ALTER TABLE {table} PERMISSIONS {full_user};\n"
+        ));
+    }
+
+    // Current calendar month, evaluated on the server.
+    let open_month = "year = time::year(time::now()) AND month = time::month(time::now())";
+    // Closing a month locks its reports; this also stops new ones.
+    let not_closed = "count(SELECT id FROM month_close \
+         WHERE year = $parent.year AND month = $parent.month AND closed = true) = 0";
+    q.push_str(&format!(
+        r#"DEFINE TABLE IF NOT EXISTS field_service_report SCHEMALESS;
+ALTER TABLE field_service_report PERMISSIONS
+    FOR select WHERE $access = "user" OR publisher = $auth.publisher
+    FOR create, update WHERE $access = "user"
+        OR (publisher = $auth.publisher AND locked = false AND {open_month} AND {not_closed})
+    FOR delete WHERE $access = "user";
+DEFINE FIELD OVERWRITE publisher ON field_service_report
+    VALUE IF $access = "publisher" THEN $auth.publisher ELSE $value END;
+DEFINE FIELD OVERWRITE source ON field_service_report
+    VALUE IF $access = "publisher" THEN "Publisher" ELSE $value END;
+DEFINE FIELD OVERWRITE approved ON field_service_report
+    VALUE IF $access = "publisher" THEN false ELSE $value END;
+DEFINE FIELD OVERWRITE locked ON field_service_report
+    VALUE IF $access = "publisher" THEN ($before.locked ?? false) ELSE $value END;
+DEFINE FIELD OVERWRITE notes ON field_service_report
+    VALUE IF $access = "publisher" THEN $before.notes ELSE $value END
+    PERMISSIONS FOR select WHERE $access = "user";
+
+DEFINE TABLE IF NOT EXISTS month_close SCHEMALESS;
+ALTER TABLE month_close PERMISSIONS
+    FOR select WHERE $access = "user" OR $access = "{ACCESS}"
+    FOR create, update, delete WHERE $access = "user";
+
+DEFINE TABLE IF NOT EXISTS portal_account SCHEMALESS;
+ALTER TABLE portal_account PERMISSIONS
+    FOR select WHERE $access = "user" OR id = $auth.id
+    FOR create, update, delete WHERE $access = "user";
+DEFINE FIELD OVERWRITE password ON portal_account PERMISSIONS FOR select NONE;
+DEFINE INDEX IF NOT EXISTS portal_account_username ON portal_account FIELDS username UNIQUE;
+
+DEFINE TABLE IF NOT EXISTS absence_request SCHEMALESS;
+ALTER TABLE absence_request PERMISSIONS
+    FOR select, create, delete WHERE $access = "user" OR publisher = $auth.publisher
+    FOR update WHERE $access = "user";
+DEFINE FIELD OVERWRITE publisher ON absence_request
+    VALUE IF $access = "publisher" THEN $auth.publisher ELSE $value END;
+
+DEFINE ACCESS OVERWRITE {ACCESS} ON DATABASE TYPE RECORD
+    SIGNIN (
+        SELECT * FROM portal_account
+        WHERE username = $username AND enabled = true
+            AND crypto::argon2::compare(password, $password)
+    )
+    DURATION FOR TOKEN 15m, FOR SESSION 12h;
+"#
+    ));
+    q
+}
+
+/// Run [`portal_schema`] on the connected database.
+pub async fn apply_schema(db: &Db) -> Result<(), Box<dyn std::error::Error>> {
+    db.query(portal_schema(super::migrate::TABLES))
+        .await?
+        .check()?;
+    Ok(())
+}
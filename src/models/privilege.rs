@@ -34,6 +34,7 @@ pub struct UserPrivileges {
     #[serde(default)] pub weekend_pray: bool,
     #[serde(default)] pub weekend_chairman: bool,
     #[serde(default)] pub watchtower_conductor: bool,
+    #[serde(default)] pub watchtower_reader: bool,
     #[serde(default)] pub public_talks: bool,
     #[serde(default)] pub public_talks_away: bool,
 
@@ -78,6 +79,7 @@ pub struct UserPrivilegesData {
     pub weekend_pray: bool,
     pub weekend_chairman: bool,
     pub watchtower_conductor: bool,
+    pub watchtower_reader: bool,
     pub public_talks: bool,
     pub public_talks_away: bool,
     pub stage: bool,
@@ -95,7 +97,7 @@ pub struct UserPrivilegesData {
     pub territory: bool,
 }
 
-pub const PRIV_TOTAL: usize = 33;
+pub const PRIV_TOTAL: usize = 34;
 
 impl UserPrivileges {
     /// Count how many privilege flags are enabled.
@@ -108,6 +110,7 @@ impl UserPrivileges {
             self.student_talk, self.living_as_christians,
             self.congregation_bible_study, self.congregation_bible_study_reader,
             self.weekend_pray, self.weekend_chairman, self.watchtower_conductor,
+            self.watchtower_reader,
             self.public_talks, self.public_talks_away,
             self.stage, self.audio, self.video, self.microphones,
             self.attendant, self.zoom_attendant,
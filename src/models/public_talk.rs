@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::crypto::{CryptoError, SessionCrypto};
+use crate::database::Db;
+
+pub const OUTLINE_TABLE: &str = "talk_outline";
+pub const SPEAKER_TABLE: &str = "talk_speaker";
+pub const OUTGOING_TABLE: &str = "outgoing_talk";
+pub const SETTINGS_TABLE: &str = "talk_settings";
+pub const SETTINGS_KEY: &str = "settings";
+
+// ── TalkOutline ───────────────────────────────────────────────────────────────
+
+/// One public talk outline of the catalog. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct TalkOutline {
+    pub id: Option<RecordId>,
+    pub number: u16,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct TalkOutlineData {
+    pub number: u16,
+    pub title: String,
+}
+
+impl TalkOutline {
+    /// The catalog, by outline number.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(OUTLINE_TABLE).await?;
+        rows.sort_by_key(|o| o.number);
+        Ok(rows)
+    }
+
+    /// Add or retitle outlines by number in one transaction.
+    pub async fn save_many(
+        db: &Db,
+        outlines: Vec<TalkOutlineData>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if outlines.is_empty() {
+            return Ok(());
+        }
+        db.query("BEGIN TRANSACTION")
+            .query(format!(
+                "FOR $o IN $outlines {{ \
+                     IF (SELECT id FROM {OUTLINE_TABLE} WHERE number = $o.number) {{ \
+                         UPDATE {OUTLINE_TABLE} SET title = $o.title WHERE number = $o.number; \
+                     }} ELSE {{ \
+                         CREATE {OUTLINE_TABLE} CONTENT $o; \
+                     }}; \
+                 }}"
+            ))
+            .bind(("outlines", outlines))
+            .query("COMMIT TRANSACTION")
+            .await?
+            .check()?;
+        Ok(())
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: TalkOutlineData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── TalkSpeaker ───────────────────────────────────────────────────────────────
+
+/// A brother who gives public talks, with the outlines he has prepared.
+/// Local speakers link to their publisher record; visiting speakers from
+/// other congregations carry their own contact data, encrypted at rest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct TalkSpeaker {
+    pub id: Option<RecordId>,
+    /// Set for speakers of this congregation (→ user record).
+    pub publisher: Option<RecordId>,
+    /// Visiting speaker's name — encrypted
+    pub name: Option<String>,
+    /// Home congregation of a visiting speaker, plaintext.
+    pub congregation: Option<String>,
+    /// Encrypted
+    pub phone: Option<String>,
+    /// Encrypted
+    pub email: Option<String>,
+    /// Outline numbers he can give.
+    #[serde(default)]
+    pub outlines: Vec<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct TalkSpeakerData {
+    pub publisher: Option<RecordId>,
+    pub name: Option<String>,
+    pub congregation: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub outlines: Vec<u16>,
+}
+
+impl From<TalkSpeaker> for TalkSpeakerData {
+    fn from(s: TalkSpeaker) -> Self {
+        Self {
+            publisher: s.publisher,
+            name: s.name,
+            congregation: s.congregation,
+            phone: s.phone,
+            email: s.email,
+            outlines: s.outlines,
+        }
+    }
+}
+
+impl TalkSpeakerData {
+    pub fn encrypt(self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        Ok(Self {
+            publisher: self.publisher,
+            name: self.name.map(|s| crypto.encrypt(&s)).transpose()?,
+            congregation: self.congregation,
+            phone: self.phone.map(|s| crypto.encrypt(&s)).transpose()?,
+            email: self.email.map(|s| crypto.encrypt(&s)).transpose()?,
+            outlines: self.outlines,
+        })
+    }
+}
+
+impl TalkSpeaker {
+    pub fn decrypt(self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        Ok(Self {
+            id: self.id,
+            publisher: self.publisher,
+            name: self.name.map(|s| crypto.decrypt(&s)).transpose()?,
+            congregation: self.congregation,
+            phone: self.phone.map(|s| crypto.decrypt(&s)).transpose()?,
+            email: self.email.map(|s| crypto.decrypt(&s)).transpose()?,
+            outlines: self.outlines,
+        })
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.publisher.is_some()
+    }
+
+    pub async fn all(
+        db: &Db,
+        crypto: &SessionCrypto,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db.select(SPEAKER_TABLE).await?;
+        rows.into_iter()
+            .map(|r| r.decrypt(crypto).map_err(Into::into))
+            .collect()
+    }
+
+    pub async fn create(
+        db: &Db,
+        crypto: &SessionCrypto,
+        data: TalkSpeakerData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.create(SPEAKER_TABLE).content(data.encrypt(crypto)?).await?;
+        row.map(|r| r.decrypt(crypto).map_err(Into::into))
+            .transpose()
+    }
+
+    pub async fn update(
+        db: &Db,
+        crypto: &SessionCrypto,
+        id: RecordId,
+        data: TalkSpeakerData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.update(id).content(data.encrypt(crypto)?).await?;
+        row.map(|r| r.decrypt(crypto).map_err(Into::into))
+            .transpose()
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── OutgoingTalk ──────────────────────────────────────────────────────────────
+
+/// A talk one of our speakers gives in another congregation. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct OutgoingTalk {
+    pub id: Option<RecordId>,
+    pub date: String, // "YYYY-MM-DD"
+    pub speaker: RecordId, // → user record
+    pub outline: Option<u16>,
+    /// Congregation being visited.
+    pub congregation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct OutgoingTalkData {
+    pub date: String,
+    pub speaker: RecordId,
+    pub outline: Option<u16>,
+    pub congregation: String,
+}
+
+impl OutgoingTalk {
+    /// All outgoing talks, newest first.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(OUTGOING_TABLE).await?;
+        rows.sort_by(|a, b| b.date.cmp(&a.date));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: OutgoingTalkData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(OUTGOING_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: OutgoingTalkData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── TalkSettings ──────────────────────────────────────────────────────────────
+
+/// Flat data stored in `talk_settings:settings`. Not sensitive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct TalkSettings {
+    /// Warn when an outline is given again within this many months.
+    pub repeat_months: u8,
+}
+
+impl Default for TalkSettings {
+    fn default() -> Self {
+        Self { repeat_months: 12 }
+    }
+}
+
+impl TalkSettings {
+    /// Stored settings, or the defaults when none were saved yet.
+    pub async fn get(db: &Db) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut res = db
+            .query(format!("SELECT repeat_months FROM {}:{}", SETTINGS_TABLE, SETTINGS_KEY))
+            .await?;
+        let record: Option<Self> = res.take(0)?;
+        Ok(record.unwrap_or_default())
+    }
+
+    pub async fn upsert(db: &Db, settings: &Self) -> Result<(), Box<dyn std::error::Error>> {
+        db.query(format!("UPSERT {}:{} CONTENT $data", SETTINGS_TABLE, SETTINGS_KEY))
+            .bind(("data", settings.clone()))
+            .await?
+            .check()?;
+        Ok(())
+    }
+}
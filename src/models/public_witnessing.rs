@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::database::Db;
+
+pub const LOCATION_TABLE: &str = "witnessing_location";
+pub const SHIFT_TABLE: &str = "witnessing_shift";
+pub const OCCURRENCE_TABLE: &str = "witnessing_occurrence";
+
+// ── WitnessingLocation ────────────────────────────────────────────────────────
+
+/// Where a cart or display stands. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WitnessingLocation {
+    pub id: Option<RecordId>,
+    pub name: String,
+    /// Directions, permissions, where the cart is stored.
+    #[serde(default)]
+    pub description: String,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WitnessingLocationData {
+    pub name: String,
+    pub description: String,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub active: bool,
+}
+
+impl WitnessingLocation {
+    /// Every location, by name.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(LOCATION_TABLE).await?;
+        rows.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: WitnessingLocationData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(LOCATION_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: WitnessingLocationData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── WitnessingShift ───────────────────────────────────────────────────────────
+
+/// Weekly shift template: the same weekday and hours every week. Plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WitnessingShift {
+    pub id: Option<RecordId>,
+    pub location: RecordId, // → witnessing_location record
+    /// Days after Monday, 0 = Monday … 6 = Sunday.
+    pub weekday: u8,
+    pub start: String, // "HH:MM"
+    pub end: String,   // "HH:MM"
+    /// Most publishers the shift takes.
+    pub capacity: u8,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WitnessingShiftData {
+    pub location: RecordId,
+    pub weekday: u8,
+    pub start: String,
+    pub end: String,
+    pub capacity: u8,
+    pub active: bool,
+}
+
+impl WitnessingShift {
+    /// Every shift, by weekday and start time.
+    pub async fn all(db: &Db) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db.select(SHIFT_TABLE).await?;
+        rows.sort_by(|a, b| (a.weekday, &a.start).cmp(&(b.weekday, &b.start)));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        data: WitnessingShiftData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let created: Option<Self> = db.create(SHIFT_TABLE).content(data).await?;
+        Ok(created)
+    }
+
+    pub async fn update(
+        db: &Db,
+        id: RecordId,
+        data: WitnessingShiftData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let updated: Option<Self> = db.update(id).content(data).await?;
+        Ok(updated)
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
+
+// ── ShiftOccurrence ───────────────────────────────────────────────────────────
+
+/// What was placed during one shift.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ShiftReport {
+    pub placements: u16,
+    pub videos: u16,
+    pub return_visits: u16,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// One date of a shift: who signed up and, afterwards, its report.
+/// Plaintext: only links to user records.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ShiftOccurrence {
+    pub id: Option<RecordId>,
+    pub shift: RecordId, // → witnessing_shift record
+    pub date: String,    // "YYYY-MM-DD"
+    #[serde(default)]
+    pub publishers: Vec<RecordId>,
+    pub report: Option<ShiftReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ShiftOccurrenceData {
+    pub shift: RecordId,
+    pub date: String,
+    pub publishers: Vec<RecordId>,
+    pub report: Option<ShiftReport>,
+}
+
+impl From<ShiftOccurrence> for ShiftOccurrenceData {
+    fn from(o: ShiftOccurrence) -> Self {
+        Self { shift: o.shift, date: o.date, publishers: o.publishers, report: o.report }
+    }
+}
+
+impl ShiftOccurrence {
+    pub fn blank(shift: RecordId, date: String) -> Self {
+        Self { id: None, shift, date, publishers: vec![], report: None }
+    }
+
+    /// Occurrences dated between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut rows: Vec<Self> = db
+            .query("SELECT * FROM witnessing_occurrence WHERE date >= $from AND date <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    /// Create or update several occurrences in a single transaction.
+    /// Occurrences with an id are updated, the rest created.
+    pub async fn save_many(db: &Db, occurrences: Vec<Self>) -> Result<(), Box<dyn std::error::Error>> {
+        if occurrences.is_empty() {
+            return Ok(());
+        }
+        let mut query = db.query("BEGIN TRANSACTION");
+        for (i, occurrence) in occurrences.into_iter().enumerate() {
+            let data_var = format!("data{i}");
+            query = match occurrence.id.clone() {
+                Some(id) => {
+                    let id_var = format!("id{i}");
+                    query
+                        .query(format!("UPDATE ${id_var} CONTENT ${data_var}"))
+                        .bind((id_var, id))
+                }
+                None => query.query(format!("CREATE {OCCURRENCE_TABLE} CONTENT ${data_var}")),
+            }
+            .bind((data_var, ShiftOccurrenceData::from(occurrence)));
+        }
+        query.query("COMMIT TRANSACTION").await?.check()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::crypto::{CryptoError, SessionCrypto};
+use crate::database::Db;
+
+pub const TABLE: &str = "report_follow_up";
+
+/// A note that someone contacted a publisher about a missing monthly report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ReportFollowUp {
+    pub id: Option<RecordId>,
+    pub publisher: RecordId, // plaintext FK
+    pub year: i32,           // plaintext: month of the missing report
+    pub month: u8,           // 1-12, plaintext
+    /// Who followed up (usually the group overseer). Plaintext FK.
+    pub marked_by: Option<RecordId>,
+    /// ISO 8601 date string — encrypted at rest
+    pub date: String,
+    pub note: Option<String>, // encrypted
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct ReportFollowUpData {
+    pub publisher: RecordId,
+    pub year: i32,
+    pub month: u8,
+    pub marked_by: Option<RecordId>,
+    pub date: String,
+    pub note: Option<String>,
+}
+
+impl ReportFollowUpData {
+    pub fn encrypt(self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        Ok(Self {
+            publisher: self.publisher,
+            year: self.year,
+            month: self.month,
+            marked_by: self.marked_by,
+            date: crypto.encrypt(&self.date)?,
+            note: self.note.map(|n| crypto.encrypt(&n)).transpose()?,
+        })
+    }
+}
+
+impl ReportFollowUp {
+    pub fn decrypt(self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        Ok(Self {
+            id: self.id,
+            publisher: self.publisher,
+            year: self.year,
+            month: self.month,
+            marked_by: self.marked_by,
+            date: crypto.decrypt(&self.date)?,
+            note: self.note.map(|n| crypto.decrypt(&n)).transpose()?,
+        })
+    }
+
+    /// All follow-ups recorded for a given month/year, decrypted.
+    pub async fn by_month(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM report_follow_up WHERE year = $y AND month = $m")
+            .bind(("y", year))
+            .bind(("m", month))
+            .await?
+            .take(0)?;
+        rows.into_iter()
+            .map(|r| r.decrypt(crypto).map_err(Into::into))
+            .collect()
+    }
+
+    pub async fn create(
+        db: &Db,
+        crypto: &SessionCrypto,
+        data: ReportFollowUpData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.create(TABLE).content(data.encrypt(crypto)?).await?;
+        row.map(|r| r.decrypt(crypto).map_err(Into::into)).transpose()
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+
+    /// Delete all follow-ups of a publisher (e.g. when deleting the publisher).
+    pub async fn delete_by_publisher(
+        db: &Db,
+        publisher_id: RecordId,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        db.query("DELETE report_follow_up WHERE publisher = $id")
+            .bind(("id", publisher_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -146,6 +146,9 @@ impl TerritoryAddress {
 
 // ── TerritoryAssignment ───────────────────────────────────────────────────────
 
+/// Months a territory may stay out before it is due back.
+pub const LOAN_MONTHS: i32 = 4;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
 #[surreal(crate = "surrealdb::types")]
 pub struct TerritoryAssignment {
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use surrealdb::types::{RecordId, SurrealValue};
+
+use crate::crypto::{CryptoError, SessionCrypto};
+use crate::database::Db;
+
+pub const TABLE: &str = "weekend_meeting";
+
+/// Program of one week's weekend meeting. Only the name of a visiting
+/// speaker is personal data; everything else is links and the published
+/// program, stored in plaintext.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WeekendMeeting {
+    pub id: Option<RecordId>,
+    pub week_start: String, // "YYYY-MM-DD", Monday of the week
+    pub date: String,       // "YYYY-MM-DD", day the meeting is held
+    pub chairman: Option<RecordId>,
+    pub opening_prayer: Option<RecordId>,
+    pub closing_prayer: Option<RecordId>,
+    /// Speaker from this congregation.
+    pub speaker: Option<RecordId>,
+    /// Visiting speaker from the talk catalog (→ talk_speaker record).
+    #[serde(default)]
+    pub guest_speaker: Option<RecordId>,
+    /// Speaker from another congregation — encrypted at rest.
+    #[serde(default)]
+    pub visiting_speaker: Option<String>,
+    /// Home congregation of the visiting speaker, plaintext.
+    #[serde(default)]
+    pub visiting_congregation: Option<String>,
+    /// Public talk outline number.
+    pub talk_number: Option<u16>,
+    pub talk_title: String,
+    pub opening_song: Option<u16>,
+    pub middle_song: Option<u16>,
+    pub closing_song: Option<u16>,
+    pub watchtower_conductor: Option<RecordId>,
+    pub watchtower_reader: Option<RecordId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, SurrealValue)]
+#[surreal(crate = "surrealdb::types")]
+pub struct WeekendMeetingData {
+    pub week_start: String,
+    pub date: String,
+    pub chairman: Option<RecordId>,
+    pub opening_prayer: Option<RecordId>,
+    pub closing_prayer: Option<RecordId>,
+    pub speaker: Option<RecordId>,
+    pub guest_speaker: Option<RecordId>,
+    pub visiting_speaker: Option<String>,
+    pub visiting_congregation: Option<String>,
+    pub talk_number: Option<u16>,
+    pub talk_title: String,
+    pub opening_song: Option<u16>,
+    pub middle_song: Option<u16>,
+    pub closing_song: Option<u16>,
+    pub watchtower_conductor: Option<RecordId>,
+    pub watchtower_reader: Option<RecordId>,
+}
+
+impl From<WeekendMeeting> for WeekendMeetingData {
+    fn from(m: WeekendMeeting) -> Self {
+        Self {
+            week_start: m.week_start,
+            date: m.date,
+            chairman: m.chairman,
+            opening_prayer: m.opening_prayer,
+            closing_prayer: m.closing_prayer,
+            speaker: m.speaker,
+            guest_speaker: m.guest_speaker,
+            visiting_speaker: m.visiting_speaker,
+            visiting_congregation: m.visiting_congregation,
+            talk_number: m.talk_number,
+            talk_title: m.talk_title,
+            opening_song: m.opening_song,
+            middle_song: m.middle_song,
+            closing_song: m.closing_song,
+            watchtower_conductor: m.watchtower_conductor,
+            watchtower_reader: m.watchtower_reader,
+        }
+    }
+}
+
+impl WeekendMeetingData {
+    pub fn encrypt(mut self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        self.visiting_speaker = self.visiting_speaker.map(|s| crypto.encrypt(&s)).transpose()?;
+        Ok(self)
+    }
+}
+
+impl WeekendMeeting {
+    pub fn decrypt(mut self, crypto: &SessionCrypto) -> Result<Self, CryptoError> {
+        self.visiting_speaker = self.visiting_speaker.map(|s| crypto.decrypt(&s)).transpose()?;
+        Ok(self)
+    }
+
+    /// Unsaved, empty program for a week.
+    pub fn blank(week_start: String, date: String) -> Self {
+        Self {
+            id: None,
+            week_start,
+            date,
+            chairman: None,
+            opening_prayer: None,
+            closing_prayer: None,
+            speaker: None,
+            guest_speaker: None,
+            visiting_speaker: None,
+            visiting_congregation: None,
+            talk_number: None,
+            talk_title: String::new(),
+            opening_song: None,
+            middle_song: None,
+            closing_song: None,
+            watchtower_conductor: None,
+            watchtower_reader: None,
+        }
+    }
+
+    /// Every program, oldest first: the history of talks given here.
+    pub async fn all(
+        db: &Db,
+        crypto: &SessionCrypto,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db.select(TABLE).await?;
+        let mut rows = rows
+            .into_iter()
+            .map(|r| r.decrypt(crypto))
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    /// Meetings whose week starts between `from` and `to` (inclusive), by date.
+    pub async fn by_range(
+        db: &Db,
+        crypto: &SessionCrypto,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let rows: Vec<Self> = db
+            .query("SELECT * FROM weekend_meeting WHERE week_start >= $from AND week_start <= $to")
+            .bind(("from", from.to_string()))
+            .bind(("to", to.to_string()))
+            .await?
+            .take(0)?;
+        let mut rows = rows
+            .into_iter()
+            .map(|r| r.decrypt(crypto))
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.sort_by(|a, b| a.date.cmp(&b.date));
+        Ok(rows)
+    }
+
+    pub async fn create(
+        db: &Db,
+        crypto: &SessionCrypto,
+        data: WeekendMeetingData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.create(TABLE).content(data.encrypt(crypto)?).await?;
+        row.map(|r| r.decrypt(crypto).map_err(Into::into))
+            .transpose()
+    }
+
+    pub async fn update(
+        db: &Db,
+        crypto: &SessionCrypto,
+        id: RecordId,
+        data: WeekendMeetingData,
+    ) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let row: Option<Self> = db.update(id).content(data.encrypt(crypto)?).await?;
+        row.map(|r| r.decrypt(crypto).map_err(Into::into))
+            .transpose()
+    }
+
+    pub async fn delete(db: &Db, id: RecordId) -> surrealdb::Result<Option<Self>> {
+        db.delete(id).await
+    }
+}
@@ -4,66 +4,16 @@ use surrealdb::types::RecordId;
 
 use crate::components::ResponsiveModal;
 use crate::database::{use_crypto, use_db};
+use crate::dates::{add_days, current_year_month, today_str};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
 use crate::models::absence::{Absence, AbsenceData};
 use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::portal::AbsenceRequest;
 use crate::models::user::User;
-use crate::pages::app::user::{
-    effective_date_format, effective_name_format, format_date, format_name,
-};
-
-// ── Platform date helpers ─────────────────────────────────────────────────────
-
-#[cfg(target_arch = "wasm32")]
-fn today_iso() -> String {
-    let d = js_sys::Date::new_0();
-    format!(
-        "{:04}-{:02}-{:02}",
-        d.get_full_year(),
-        d.get_month() + 1,
-        d.get_date()
-    )
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn today_iso() -> String {
-    "2026-06-10".to_string()
-}
-
-/// Returns yesterday's ISO date string — used as the expiry threshold.
-fn yesterday_iso() -> String {
-    let today = today_iso();
-    if today.len() != 10 {
-        return today;
-    }
-    let parts: Vec<&str> = today.splitn(3, '-').collect();
-    if parts.len() != 3 {
-        return today;
-    }
-    let y: i32 = parts[0].parse().unwrap_or(2026);
-    let m: u32 = parts[1].parse().unwrap_or(1);
-    let d: u32 = parts[2].parse().unwrap_or(1);
-    if d > 1 {
-        return format!("{:04}-{:02}-{:02}", y, m, d - 1);
-    }
-    let (py, pm) = if m == 1 { (y - 1, 12u32) } else { (y, m - 1) };
-    let last_day = match pm {
-        4 | 6 | 9 | 11 => 30,
-        2 => if (py % 4 == 0 && py % 100 != 0) || py % 400 == 0 { 29 } else { 28 },
-        _ => 31,
-    };
-    format!("{:04}-{:02}-{:02}", py, pm, last_day)
-}
 
 fn iso_month(iso: &str) -> Option<u8> { iso.get(5..7)?.parse().ok() }
 fn iso_year(iso: &str) -> Option<i32> { iso.get(0..4)?.parse().ok() }
 
-fn current_year_month() -> (i32, u8) {
-    let today = today_iso();
-    let y = iso_year(&today).unwrap_or(2026);
-    let m = iso_month(&today).unwrap_or(6);
-    (y, m)
-}
-
 fn normalize(s: &str) -> String {
     s.chars()
         .map(|c| match c {
@@ -151,18 +101,26 @@ pub fn AppAbsences() -> Element {
         let Some(db) = db_signal.read().db.clone() else { return vec![] };
         let crypto = crypto_signal.read().clone();
         // Auto-purge absences whose end_date < yesterday
-        let _ = Absence::delete_expired(&db, &crypto, &yesterday_iso()).await;
+        // Yesterday is the expiry threshold.
+        let _ = Absence::delete_expired(&db, &crypto, &add_days(&today_str(), -1)).await;
         let mut rows = Absence::all(&db, &crypto).await.unwrap_or_default();
         rows.sort_by(|a, b| b.start_date.cmp(&a.start_date));
         rows
     });
 
+    // Absences announced through the publisher portal, awaiting review.
+    let mut requests_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        AbsenceRequest::all(&db).await.unwrap_or_default()
+    });
+
     let mut bootstrapped = use_signal(|| false);
     use_effect(move || {
         if *bootstrapped.peek() { return; }
         bootstrapped.set(true);
         users_res.restart();
         absences_res.restart();
+        requests_res.restart();
     });
 
     // Modal signals
@@ -273,6 +231,85 @@ pub fn AppAbsences() -> Element {
     rsx! {
         div { class: "relative space-y-4 w-full pb-24",
 
+            // ── Portal requests ───────────────────────────────────────────
+            {
+                let requests = requests_res().unwrap_or_default();
+                let nf = name_fmt.read().clone();
+                let df = date_fmt.read().clone();
+                rsx! {
+                    if !requests.is_empty() {
+                        div { class: "bg-amber-50 rounded-xl border border-amber-200 p-4 space-y-2",
+                            h2 { class: "text-sm font-semibold text-amber-900", {t!("absence-requests-title")} }
+                            for req in requests {
+                                {
+                                    let name = users
+                                        .iter()
+                                        .find(|u| u.id.as_ref() == Some(&req.publisher))
+                                        .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+                                        .unwrap_or_default();
+                                    let range = match &req.end_date {
+                                        Some(e) => format!("{} → {}", format_date(&req.start_date, &df), format_date(e, &df)),
+                                        None => format_date(&req.start_date, &df),
+                                    };
+                                    let req_accept = req.clone();
+                                    let id_decline = req.id.clone();
+                                    rsx! {
+                                        div { class: "flex flex-wrap items-center justify-between gap-2 bg-white rounded-lg border border-amber-100 px-3 py-2",
+                                            div { class: "min-w-0",
+                                                p { class: "text-sm font-medium text-gray-900", "{name}" }
+                                                p { class: "text-xs text-gray-600", "{range}" }
+                                                if let Some(r) = &req.reason {
+                                                    p { class: "text-xs text-gray-500 italic truncate", "{r}" }
+                                                }
+                                            }
+                                            div { class: "flex gap-1.5 shrink-0",
+                                                button {
+                                                    class: "px-2.5 py-1 text-xs bg-emerald-600 text-white rounded-lg hover:bg-emerald-700 transition-colors",
+                                                    onclick: move |_| {
+                                                        let req = req_accept.clone();
+                                                        spawn(async move {
+                                                            let Some(db) = db_signal.read().db.clone() else { return };
+                                                            let crypto = crypto_signal.read().clone();
+                                                            let data = AbsenceData {
+                                                                user: req.publisher.clone(),
+                                                                start_date: req.start_date.clone(),
+                                                                end_date: req.end_date.clone(),
+                                                                reason: req.reason.clone(),
+                                                            };
+                                                            // Only drop the request once the absence is stored.
+                                                            if Absence::create(&db, &crypto, data).await.is_ok() {
+                                                                if let Some(id) = req.id {
+                                                                    let _ = AbsenceRequest::delete(&db, id).await;
+                                                                }
+                                                            }
+                                                            absences_res.restart();
+                                                            requests_res.restart();
+                                                        });
+                                                    },
+                                                    {t!("absence-request-accept")}
+                                                }
+                                                button {
+                                                    class: "px-2.5 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50 transition-colors",
+                                                    onclick: move |_| {
+                                                        let Some(id) = id_decline.clone() else { return };
+                                                        spawn(async move {
+                                                            let Some(db) = db_signal.read().db.clone() else { return };
+                                                            let _ = AbsenceRequest::delete(&db, id).await;
+                                                            requests_res.restart();
+                                                        });
+                                                    },
+                                                    {t!("absence-request-decline")}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // ── Month / year navigation ───────────────────────────────────
             div { class: "bg-white rounded-xl border border-gray-200 p-4",
                 div { class: "flex items-center justify-between gap-3",
@@ -702,7 +739,7 @@ fn AbsenceFormModal(
         });
     });
 
-    let date_hint = crate::pages::app::user::date_format_hint(&date_fmt);
+    let date_hint = crate::format::date_format_hint(&date_fmt);
     let title = if is_edit { t!("absence-form-title-edit") } else { t!("absence-form-title-add") };
 
     rsx! {
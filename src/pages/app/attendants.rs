@@ -1,23 +1,15 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
 
+use crate::models::duty_rota::DutyRole;
+use crate::pages::app::duty_rota::DutyRotaPage;
+
 #[component]
 pub fn AppAttendants() -> Element {
     rsx! {
-        div { class: "space-y-6 w-full",
-            div { class: "flex items-center justify-between",
-                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-attendants")} }
-                button { class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
-                    {t!("btn-assign-attendant")}
-                }
-            }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
-                    p { class: "text-4xl mb-3", "🚪" }
-                    p { class: "font-medium text-gray-600", {t!("empty-attendants-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-attendants-desc")} }
-                }
-            }
+        DutyRotaPage {
+            title: t!("page-attendants"),
+            roles: vec![DutyRole::Attendant, DutyRole::ZoomAttendant],
         }
     }
 }
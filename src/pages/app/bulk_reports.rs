@@ -0,0 +1,533 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_months, current_year_month};
+use crate::format::{effective_name_format, format_name};
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::field_service_group::FieldServiceGroup;
+use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData};
+use crate::models::user::{User, UserType};
+use crate::pages::app::user_detail::always_show_hours;
+use crate::reports::month_label;
+use crate::Route;
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Most hours anyone can report in a month.
+const MAX_MONTH_HOURS: u32 = 31 * 24;
+
+/// Editable columns, in tab order.
+const COL_PREACHED: usize = 0;
+const COL_HOURS: usize = 1;
+const COL_CREDITS: usize = 2;
+const COL_STUDIES: usize = 3;
+const COL_AUX: usize = 4;
+
+fn cell_id(row: usize, col: usize) -> String {
+    format!("bulk-cell-{row}-{col}")
+}
+
+fn focus_cell(row: usize, col: usize) {
+    let _ = document::eval(&format!(
+        "document.getElementById({:?})?.focus()",
+        cell_id(row, col)
+    ));
+}
+
+/// Empty → `Ok(None)`; a non-negative whole number → `Ok(Some(n))`.
+fn parse_count(s: &str) -> Result<Option<u32>, ()> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(|_| ())
+}
+
+// ── Grid row ──────────────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+struct GridRow {
+    publisher: RecordId,
+    name: String,
+    user_type: UserType,
+    existing: Option<FieldServiceReport>,
+    preached: bool,
+    hours: String,
+    credits: String,
+    studies: String,
+    aux: bool,
+    /// Changed since loading.
+    dirty: bool,
+}
+
+impl GridRow {
+    fn new(user: &User, publisher: RecordId, name: String, existing: Option<FieldServiceReport>) -> Self {
+        let num = |v: Option<u32>| v.map(|n| n.to_string()).unwrap_or_default();
+        Self {
+            publisher,
+            name,
+            user_type: user.user_type.clone(),
+            preached: existing.as_ref().is_none_or(|r| r.preached),
+            hours: num(existing.as_ref().and_then(|r| r.hours)),
+            credits: num(existing.as_ref().and_then(|r| r.credits)),
+            studies: num(existing.as_ref().and_then(|r| r.bible_studies)),
+            aux: existing.as_ref().is_some_and(|r| r.auxiliary_pioneer),
+            existing,
+            dirty: false,
+        }
+    }
+
+    fn reports_hours(&self) -> bool {
+        always_show_hours(&self.user_type) || self.aux
+    }
+
+    fn errors(&self) -> Vec<String> {
+        let mut errs = Vec::new();
+        let hours = parse_count(&self.hours);
+        let credits = parse_count(&self.credits);
+        let studies = parse_count(&self.studies);
+        if hours.is_err() || credits.is_err() || studies.is_err() {
+            errs.push(t!("bulk-err-number"));
+        }
+        let hours = hours.ok().flatten();
+        let credits = credits.ok().flatten();
+        let studies = studies.ok().flatten();
+        if hours.unwrap_or(0) + credits.unwrap_or(0) > MAX_MONTH_HOURS {
+            errs.push(t!("bulk-err-hours-range"));
+        }
+        if !self.preached && (hours.is_some() || credits.is_some() || studies.is_some() || self.aux) {
+            errs.push(t!("bulk-err-not-preached"));
+        }
+        if self.preached && hours.is_some() && !self.reports_hours() {
+            errs.push(t!("bulk-err-hours-pioneer"));
+        }
+        if self.preached && credits.is_some() && !always_show_hours(&self.user_type) {
+            errs.push(t!("bulk-err-credits-pioneer"));
+        }
+        errs
+    }
+
+    /// The payload to store, keeping review flags and notes of an existing report.
+    fn to_data(&self, year: i32, month: u8) -> FieldServiceReportData {
+        let num = |s: &str| if self.preached { parse_count(s).ok().flatten() } else { None };
+        FieldServiceReportData {
+            publisher: self.publisher.clone(),
+            year,
+            month,
+            hours: num(&self.hours),
+            credits: num(&self.credits),
+            bible_studies: num(&self.studies),
+            auxiliary_pioneer: self.preached && self.aux,
+            preached: self.preached,
+            source: self.existing.as_ref().map(|r| r.source).unwrap_or_default(),
+            approved: true,
+            locked: self.existing.as_ref().is_some_and(|r| r.locked),
+            notes: self.existing.as_ref().and_then(|r| r.notes.clone()),
+        }
+    }
+}
+
+// ── Main page ─────────────────────────────────────────────────────────────────
+
+/// Spreadsheet-like entry of one month's reports for a field service group.
+#[component]
+pub fn AppBulkReports() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    // Default to the previous month: the one being collected.
+    let (cur_year, cur_month) = current_year_month();
+    let (def_year, def_month) = add_months(cur_year, cur_month, -1);
+    let mut sel_year = use_signal(|| def_year);
+    let mut sel_month = use_signal(|| def_month);
+    // Index into the group list; `None` shows every publisher.
+    let mut sel_group: Signal<Option<usize>> = use_signal(|| Some(0));
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let groups_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        let mut groups = FieldServiceGroup::all(&db, &crypto).await.unwrap_or_default();
+        groups.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        groups
+    });
+
+    let mut reports_res = use_resource(move || {
+        let y = sel_year();
+        let m = sel_month();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            FieldServiceReport::by_month(&db, &crypto, y, m)
+                .await
+                .unwrap_or_default()
+        }
+    });
+
+    // ── Grid state, rebuilt whenever the month, group or data change ──────────
+    let mut rows: Signal<Vec<GridRow>> = use_signal(Vec::new);
+    let mut saving = use_signal(|| false);
+    let mut message: Signal<Option<(bool, String)>> = use_signal(|| None);
+
+    use_effect(move || {
+        let users = users_res().unwrap_or_default();
+        let groups = groups_res().unwrap_or_default();
+        let reports = reports_res().unwrap_or_default();
+        let nf = name_fmt();
+        let group = sel_group().and_then(|i| groups.get(i).cloned());
+
+        let by_publisher: HashMap<RecordId, FieldServiceReport> = reports
+            .into_iter()
+            .map(|r| (r.publisher.clone(), r))
+            .collect();
+        let mut built: Vec<GridRow> = users
+            .iter()
+            .filter(|u| u.active && !matches!(u.user_type, UserType::Student))
+            .filter_map(|u| {
+                let id = u.id.clone()?;
+                if let Some(g) = &group {
+                    if !g.members.contains(&id) {
+                        return None;
+                    }
+                }
+                let name = format_name(&u.first_name, &u.last_name, &nf);
+                let existing = by_publisher.get(&id).cloned();
+                Some(GridRow::new(u, id, name, existing))
+            })
+            .collect();
+        built.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        rows.set(built);
+    });
+
+    let is_loading =
+        users_res.read().is_none() || groups_res.read().is_none() || reports_res.read().is_none();
+    let groups = groups_res().unwrap_or_default();
+    let grid = rows();
+    let dirty_count = grid.iter().filter(|r| r.dirty).count();
+    let invalid_count = grid.iter().filter(|r| r.dirty && !r.errors().is_empty()).count();
+    let (year, month) = (sel_year(), sel_month());
+
+    let mut edit = move |i: usize, f: &dyn Fn(&mut GridRow)| {
+        let mut w = rows.write();
+        if let Some(row) = w.get_mut(i) {
+            f(row);
+            row.dirty = true;
+        }
+        message.set(None);
+    };
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+        message.set(None);
+    };
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let snapshot = rows.peek().clone();
+        let dirty: Vec<&GridRow> = snapshot.iter().filter(|r| r.dirty).collect();
+        if dirty.is_empty() {
+            return;
+        }
+        if dirty.iter().any(|r| !r.errors().is_empty()) {
+            message.set(Some((false, t!("bulk-fix-errors"))));
+            return;
+        }
+        let batch: Vec<(Option<RecordId>, FieldServiceReportData)> = dirty
+            .iter()
+            .map(|r| {
+                (
+                    r.existing.as_ref().and_then(|e| e.id.clone()),
+                    r.to_data(year, month),
+                )
+            })
+            .collect();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            match FieldServiceReport::save_batch(&db, &crypto, batch).await {
+                Ok(summary) => {
+                    message.set(Some((
+                        true,
+                        format!(
+                            "{} {} · {} {}",
+                            summary.created,
+                            t!("bulk-created"),
+                            summary.updated,
+                            t!("bulk-updated")
+                        ),
+                    )));
+                    reports_res.restart();
+                }
+                Err(e) => message.set(Some((false, e.to_string()))),
+            }
+            saving.set(false);
+        });
+    };
+
+    let nav_key = move |e: KeyboardEvent, row: usize, col: usize, count: usize| {
+        match e.key() {
+            Key::ArrowDown | Key::Enter => {
+                e.prevent_default();
+                if row + 1 < count {
+                    focus_cell(row + 1, col);
+                }
+            }
+            Key::ArrowUp => {
+                e.prevent_default();
+                if row > 0 {
+                    focus_cell(row - 1, col);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let input_cls = "w-16 px-2 py-1 text-sm text-right border border-gray-200 rounded-md focus:outline-none focus:ring-2 focus:ring-primary-500 disabled:bg-gray-50 disabled:text-gray-300";
+    let row_count = grid.len();
+
+    rsx! {
+        div { class: "space-y-4 w-full pb-24",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-bulk-reports")} }
+                Link {
+                    to: Route::AppFieldServiceReports {},
+                    class: "text-sm text-primary-600 hover:underline",
+                    {t!("page-field-service-reports")}
+                }
+            }
+
+            // ── Month + group ─────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex flex-wrap items-center gap-3",
+                div { class: "flex items-center gap-2",
+                    button {
+                        class: "px-3 py-1.5 rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 font-semibold",
+                        onclick: move |_| shift_month(-1),
+                        "‹"
+                    }
+                    span { class: "min-w-[9rem] text-center font-semibold text-gray-900",
+                        "{month_label(month)} {year}"
+                    }
+                    button {
+                        class: "px-3 py-1.5 rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 font-semibold",
+                        onclick: move |_| shift_month(1),
+                        "›"
+                    }
+                }
+                select {
+                    class: "flex-1 min-w-[10rem] px-2 py-1.5 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500",
+                    onchange: move |e| {
+                        sel_group.set(e.value().parse().ok());
+                        message.set(None);
+                    },
+                    for (i , g) in groups.iter().enumerate() {
+                        option { value: "{i}", selected: sel_group() == Some(i), "{g.name}" }
+                    }
+                    option { value: "", selected: sel_group().is_none(), {t!("bulk-all-publishers")} }
+                }
+            }
+
+            p { class: "text-xs text-gray-500 px-1", {t!("bulk-keyboard-hint")} }
+
+            // ── Grid ──────────────────────────────────────────────────────
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("reports-loading")} }
+                }
+            } else if grid.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-16 text-center",
+                    p { class: "text-4xl mb-3", "📋" }
+                    p { class: "font-medium text-gray-600", {t!("bulk-no-members")} }
+                }
+            } else {
+                div { class: "bg-white rounded-xl border border-gray-200 overflow-x-auto",
+                    table { class: "w-full text-sm",
+                        thead { class: "bg-gray-50 text-xs text-gray-500 uppercase tracking-wider",
+                            tr {
+                                th { class: "px-3 py-2 text-left", {t!("bulk-col-publisher")} }
+                                th { class: "px-2 py-2 text-center", {t!("report-preached")} }
+                                th { class: "px-2 py-2 text-right", {t!("report-form-hours")} }
+                                th { class: "px-2 py-2 text-right", {t!("report-form-credits")} }
+                                th { class: "px-2 py-2 text-right", {t!("report-form-bible-studies")} }
+                                th { class: "px-2 py-2 text-center", {t!("report-form-aux-pioneer")} }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for (i , row) in grid.iter().cloned().enumerate() {
+                                {
+                                    let errs = if row.dirty { row.errors() } else { vec![] };
+                                    let row_cls = if !errs.is_empty() {
+                                        "bg-red-50"
+                                    } else if row.dirty {
+                                        "bg-amber-50"
+                                    } else {
+                                        ""
+                                    };
+                                    let off = !row.preached;
+                                    let hours_off = off || !row.reports_hours();
+                                    let credits_off = off || !always_show_hours(&row.user_type);
+                                    let has_report = row.existing.is_some();
+                                    rsx! {
+                                        tr { key: "{i}", class: row_cls,
+                                            td { class: "px-3 py-1.5",
+                                                div { class: "flex items-center gap-1.5",
+                                                    span { class: "font-medium text-gray-900", "{row.name}" }
+                                                    if has_report && !row.dirty {
+                                                        span { class: "text-emerald-600 text-xs", "✓" }
+                                                    }
+                                                }
+                                                for err in errs.iter() {
+                                                    p { class: "text-xs text-red-600", "{err}" }
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-center",
+                                                input {
+                                                    id: cell_id(i, COL_PREACHED),
+                                                    r#type: "checkbox",
+                                                    class: "rounded border-gray-300 text-primary-600",
+                                                    checked: row.preached,
+                                                    onkeydown: move |e| nav_key(e, i, COL_PREACHED, row_count),
+                                                    oninput: move |e| {
+                                                        let v = e.checked();
+                                                        edit(i, &|r| r.preached = v);
+                                                    },
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-right",
+                                                input {
+                                                    id: cell_id(i, COL_HOURS),
+                                                    r#type: "text",
+                                                    inputmode: "numeric",
+                                                    class: input_cls,
+                                                    disabled: hours_off && row.hours.is_empty(),
+                                                    value: row.hours.clone(),
+                                                    onkeydown: move |e| nav_key(e, i, COL_HOURS, row_count),
+                                                    oninput: move |e| {
+                                                        let v = e.value();
+                                                        edit(i, &|r| r.hours = v.clone());
+                                                    },
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-right",
+                                                input {
+                                                    id: cell_id(i, COL_CREDITS),
+                                                    r#type: "text",
+                                                    inputmode: "numeric",
+                                                    class: input_cls,
+                                                    disabled: credits_off && row.credits.is_empty(),
+                                                    value: row.credits.clone(),
+                                                    onkeydown: move |e| nav_key(e, i, COL_CREDITS, row_count),
+                                                    oninput: move |e| {
+                                                        let v = e.value();
+                                                        edit(i, &|r| r.credits = v.clone());
+                                                    },
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-right",
+                                                input {
+                                                    id: cell_id(i, COL_STUDIES),
+                                                    r#type: "text",
+                                                    inputmode: "numeric",
+                                                    class: input_cls,
+                                                    disabled: off && row.studies.is_empty(),
+                                                    value: row.studies.clone(),
+                                                    onkeydown: move |e| nav_key(e, i, COL_STUDIES, row_count),
+                                                    oninput: move |e| {
+                                                        let v = e.value();
+                                                        edit(i, &|r| r.studies = v.clone());
+                                                    },
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-center",
+                                                input {
+                                                    id: cell_id(i, COL_AUX),
+                                                    r#type: "checkbox",
+                                                    class: "rounded border-gray-300 text-primary-600",
+                                                    disabled: off && !row.aux,
+                                                    checked: row.aux,
+                                                    onkeydown: move |e| nav_key(e, i, COL_AUX, row_count),
+                                                    oninput: move |e| {
+                                                        let v = e.checked();
+                                                        edit(i, &|r| r.aux = v);
+                                                    },
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // ── Save bar ──────────────────────────────────────────────────────
+        div { class: "fixed bottom-16 md:bottom-4 inset-x-4 md:left-auto md:right-6 z-30 flex flex-wrap items-center justify-end gap-3",
+            if let Some((ok, msg)) = message.read().clone() {
+                span {
+                    class: if ok { "px-3 py-2 rounded-lg bg-emerald-600 text-white text-sm shadow" } else { "px-3 py-2 rounded-lg bg-red-600 text-white text-sm shadow" },
+                    "{msg}"
+                }
+            }
+            if dirty_count > 0 {
+                span { class: "px-3 py-2 rounded-lg bg-white border border-gray-200 text-sm text-gray-700 shadow",
+                    "{dirty_count} "
+                    {t!("bulk-unsaved")}
+                    if invalid_count > 0 {
+                        span { class: "text-red-600", " · {invalid_count} " {t!("bulk-invalid")} }
+                    }
+                }
+            }
+            button {
+                class: "px-5 py-2.5 bg-primary-600 text-white rounded-xl font-medium shadow hover:bg-primary-700 transition-colors disabled:opacity-50",
+                disabled: saving() || dirty_count == 0,
+                onclick: on_save,
+                if saving() {
+                    {t!("btn-saving")}
+                } else {
+                    {t!("bulk-save-all")}
+                }
+            }
+        }
+    }
+}
@@ -1,21 +1,876 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db, Db};
+use crate::dates::{
+    add_months, add_months_iso, current_year_month, mondays_in_month, today_str, week_start,
+    weeks_between,
+};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::cleaning::{
+    CheckedTask, CleaningArea, CleaningAreaData, CleaningKind, CleaningWeek,
+};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::field_service_group::FieldServiceGroup;
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+use crate::reports::{month_label, print_html, table_document};
+use crate::schedule::cleaning::{is_checked, progress, rotation, tasks};
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Rota,
+    Areas,
+}
+
+fn kind_label(kind: CleaningKind) -> String {
+    match kind {
+        CleaningKind::Weekly => t!("cleaning-weekly"),
+        CleaningKind::Deep => t!("cleaning-deep"),
+    }
+}
+
+/// One task per non-empty line.
+fn parse_tasks(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Assign the weeks from `from` to `to` to `groups` in turn, carrying on
+/// from the last group that cleaned. Weeks already planned in the range are
+/// replaced and their ticks cleared. Returns how many weeks were planned.
+async fn generate(
+    db: &Db,
+    from: &str,
+    to: &str,
+    groups: &[RecordId],
+    deep_every: u8,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let weeks = weeks_between(from, to);
+    let (Some(first), Some(last)) = (weeks.first(), weeks.last()) else { return Ok(0) };
+    let stored = CleaningWeek::by_range(db, first, last).await?;
+    let after = CleaningWeek::last_assigned_before(db, first).await?.and_then(|w| w.group);
+    let planned: Vec<CleaningWeek> = rotation(&weeks, groups, after.as_ref(), deep_every)
+        .into_iter()
+        .map(|(week_start, group, kind)| CleaningWeek {
+            id: stored.iter().find(|s| s.week_start == week_start).and_then(|s| s.id.clone()),
+            week_start,
+            kind,
+            group: Some(group),
+            done: vec![],
+        })
+        .collect();
+    let count = planned.len();
+    CleaningWeek::save_many(db, planned).await?;
+    Ok(count)
+}
 
 #[component]
 pub fn AppCleaning() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+    let mut view = use_signal(|| View::Rota);
+    let mut generator_open = use_signal(|| false);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let groups_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        let mut groups = FieldServiceGroup::all(&db, &crypto).await.unwrap_or_default();
+        groups.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        groups
+    });
+
+    let mut areas_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        CleaningArea::all(&db).await.unwrap_or_default()
+    });
+
+    let mut weeks_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let (Some(from), Some(to)) = (weeks.first(), weeks.last()) else { return vec![] };
+            CleaningWeek::by_range(&db, from, to).await.unwrap_or_default()
+        }
+    });
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || groups_res.read().is_none()
+        || areas_res.read().is_none()
+        || weeks_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let areas = areas_res().unwrap_or_default();
+    let stored = weeks_res().unwrap_or_default();
+    let nf = name_fmt();
+    let df = date_fmt();
+
+    let groups: Vec<(RecordId, String)> = groups_res()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|g| Some((g.id?, g.name)))
+        .collect();
+    let group_name = |id: &RecordId| -> String {
+        groups
+            .iter()
+            .find(|(g, _)| g == id)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_default()
+    };
+
+    // Publishers holding the cleaning privilege coordinate the rota.
+    let coordinators: Vec<String> = privileges
+        .iter()
+        .filter(|p| p.cleaning)
+        .filter_map(|p| users.iter().find(|u| u.id.as_ref() == Some(&p.publisher)))
+        .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+        .collect();
+    let coordinator_names = coordinators.join(", ");
+
+    let weeks: Vec<CleaningWeek> = mondays_in_month(sel_year(), sel_month())
+        .into_iter()
+        .map(|w| {
+            stored
+                .iter()
+                .find(|s| s.week_start == w)
+                .cloned()
+                .unwrap_or_else(|| CleaningWeek::blank(w))
+        })
+        .collect();
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+    };
+
+    let month_title = format!("{} {}", month_label(sel_month()), sel_year());
+
+    let mut chart = vec![vec![
+        t!("cleaning-week"),
+        t!("cleaning-kind"),
+        t!("cleaning-group"),
+        t!("cleaning-completed"),
+    ]];
+    for week in &weeks {
+        let (done, total) = progress(week, &areas);
+        chart.push(vec![
+            format_date(&week.week_start, &df),
+            kind_label(week.kind),
+            week.group.as_ref().map(group_name).unwrap_or_default(),
+            if week.group.is_some() { format!("{done}/{total}") } else { String::new() },
+        ]);
+    }
+
+    let on_print = {
+        let month_title = month_title.clone();
+        move |_| print_html(table_document(&t!("page-cleaning"), &month_title, &chart))
+    };
+
+    let tab_cls = |active: bool| {
+        if active {
+            "px-3 py-1.5 text-sm rounded-lg bg-primary-600 text-white"
+        } else {
+            "px-3 py-1.5 text-sm rounded-lg text-gray-600 hover:bg-gray-100"
+        }
+    };
+    let rota_tab_cls = tab_cls(view() == View::Rota);
+    let areas_tab_cls = tab_cls(view() == View::Areas);
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            div { class: "flex items-center justify-between",
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
                 h1 { class: "text-2xl font-bold text-gray-900", {t!("page-cleaning")} }
-                button { class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
-                    {t!("btn-add-assignment")}
+                div { class: "flex flex-wrap gap-2",
+                    div { class: "flex gap-1 bg-white border border-gray-200 rounded-lg p-1",
+                        button {
+                            class: rota_tab_cls,
+                            onclick: move |_| view.set(View::Rota),
+                            {t!("cleaning-rota")}
+                        }
+                        button {
+                            class: areas_tab_cls,
+                            onclick: move |_| view.set(View::Areas),
+                            {t!("cleaning-areas")}
+                        }
+                    }
+                    if view() == View::Rota {
+                        button {
+                            class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                            disabled: is_loading,
+                            onclick: on_print,
+                            {t!("cleaning-print")}
+                        }
+                        button {
+                            class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                            disabled: is_loading || groups.is_empty(),
+                            onclick: move |_| generator_open.set(!generator_open()),
+                            {t!("cleaning-generate")}
+                        }
+                    }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("cleaning-loading")} }
+                }
+            } else if view() == View::Areas {
+                CleaningAreas {
+                    areas: areas.clone(),
+                    on_changed: move |_| areas_res.restart(),
+                }
+            } else {
+                if !coordinators.is_empty() {
+                    p { class: "text-sm text-gray-600",
+                        span { class: "font-medium text-gray-900", {t!("cleaning-coordinators")} }
+                        " {coordinator_names}"
+                    }
+                }
+
+                if generator_open() {
+                    CleaningGenerator {
+                        groups: groups.clone(),
+                        on_done: move |_| {
+                            generator_open.set(false);
+                            weeks_res.restart();
+                        },
+                    }
+                }
+
+                if groups.is_empty() {
+                    p { class: "text-sm text-amber-700", {t!("cleaning-no-groups")} }
+                }
+                if areas.is_empty() {
+                    p { class: "text-sm text-amber-700", {t!("cleaning-no-areas")} }
+                }
+
+                // ── Month navigation ──────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                        onclick: move |_| shift_month(-1),
+                        "‹"
+                    }
+                    p { class: "font-semibold text-gray-900", "{month_title}" }
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                        onclick: move |_| shift_month(1),
+                        "›"
+                    }
+                }
+
+                // ── Weeks ─────────────────────────────────────────────────
+                div { class: "space-y-3",
+                    for week in weeks.iter().cloned() {
+                        CleaningWeekCard {
+                            key: "{week.week_start}",
+                            week,
+                            groups: groups.clone(),
+                            areas: areas.clone(),
+                            date_fmt: df.clone(),
+                            on_saved: move |_| weeks_res.restart(),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── CleaningWeekCard ──────────────────────────────────────────────────────────
+
+/// One week of the rota. Every change is stored right away.
+#[component]
+fn CleaningWeekCard(
+    week: CleaningWeek,
+    groups: Vec<(RecordId, String)>,
+    areas: Vec<CleaningArea>,
+    date_fmt: DateFormat,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut expanded = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let save = move |w: CleaningWeek| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match CleaningWeek::save_many(&db, vec![w]).await {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = {
+        let id = week.id.clone();
+        move |_| {
+            let Some(id) = id.clone() else { return };
+            spawn(async move {
+                let Some(db) = db_signal.read().db.clone() else { return };
+                match CleaningWeek::delete(&db, id).await {
+                    Ok(_) => on_saved.call(()),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    let (done, total) = progress(&week, &areas);
+    let date_label = format_date(&week.week_start, &date_fmt);
+    let group_key = week.group.as_ref().map(rid_str).unwrap_or_default();
+    let progress_cls = if total > 0 && done == total {
+        "text-xs font-medium text-emerald-700"
+    } else {
+        "text-xs font-medium text-gray-500"
+    };
+    let input_cls = "px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    let on_group = {
+        let week = week.clone();
+        let groups = groups.clone();
+        move |e: Event<FormData>| {
+            let mut w = week.clone();
+            w.group = groups.iter().find(|(id, _)| rid_str(id) == e.value()).map(|(id, _)| id.clone());
+            save(w);
+        }
+    };
+
+    let on_kind = {
+        let week = week.clone();
+        move |e: Event<FormData>| {
+            let mut w = week.clone();
+            w.kind = CleaningKind::from_key(&e.value());
+            save(w);
+        }
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                p { class: "text-sm font-semibold text-gray-900", "{date_label}" }
+                div { class: "flex flex-wrap items-center gap-2",
+                    select { class: input_cls, onchange: on_group,
+                        option { value: "", selected: week.group.is_none(), {t!("cleaning-no-group")} }
+                        for (id , name) in groups.iter() {
+                            {
+                                let value = rid_str(id);
+                                let selected = value == group_key;
+                                rsx! {
+                                    option { key: "{value}", value: "{value}", selected, "{name}" }
+                                }
+                            }
+                        }
+                    }
+                    select { class: input_cls, onchange: on_kind,
+                        for kind in [CleaningKind::Weekly, CleaningKind::Deep] {
+                            option {
+                                value: kind.key(),
+                                selected: kind == week.kind,
+                                {kind_label(kind)}
+                            }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| expanded.set(!expanded()),
+                        {t!("cleaning-checklist")}
+                        span { class: "ml-2 {progress_cls}", "{done}/{total}" }
+                    }
+                    if week.id.is_some() {
+                        button {
+                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                            onclick: on_delete,
+                            {t!("btn-delete")}
+                        }
+                    }
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            if expanded() {
+                div { class: "grid grid-cols-1 sm:grid-cols-2 gap-4 pt-2 border-t border-gray-100",
+                    for (area_id , area) in areas.iter().filter_map(|a| Some((a.id.clone()?, a))) {
+                        {
+                            let list = tasks(area, week.kind);
+                            let area_key = rid_str(&area_id);
+                            rsx! {
+                                div { key: "{area_key}", class: "space-y-1",
+                                    p { class: "text-xs font-semibold uppercase tracking-wide text-gray-500", "{area.name}" }
+                                    for task in list {
+                                        {
+                                            let checked = is_checked(&week, &area_id, &task);
+                                            let week = week.clone();
+                                            let area_id = area_id.clone();
+                                            let task_text = task.clone();
+                                            rsx! {
+                                                label { key: "{task}", class: "flex items-center gap-2 text-sm text-gray-700",
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        checked,
+                                                        onchange: move |_| {
+                                                            let mut w = week.clone();
+                                                            if checked {
+                                                                w.done.retain(|d| !(d.area == area_id && d.task == task_text));
+                                                            } else {
+                                                                w.done.push(CheckedTask { area: area_id.clone(), task: task_text.clone() });
+                                                            }
+                                                            save(w);
+                                                        },
+                                                    }
+                                                    "{task}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── CleaningGenerator ─────────────────────────────────────────────────────────
+
+#[component]
+fn CleaningGenerator(groups: Vec<(RecordId, String)>, on_done: Callback<()>) -> Element {
+    let db_signal = use_db();
+    let first = week_start(&today_str());
+    let mut from = use_signal(|| first.clone());
+    let mut to = use_signal(|| add_months_iso(&first, 3));
+    let mut deep_every = use_signal(|| 4u8);
+    let mut included: Signal<Vec<RecordId>> = {
+        let all: Vec<RecordId> = groups.iter().map(|(id, _)| id.clone()).collect();
+        use_signal(|| all)
+    };
+    let mut confirming = use_signal(|| false);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_generate = {
+        let groups = groups.clone();
+        move |_| {
+            if *saving.peek() {
+                return;
+            }
+            // Keep the groups in their listed order.
+            let order: Vec<RecordId> = groups
+                .iter()
+                .map(|(id, _)| id.clone())
+                .filter(|id| included.peek().contains(id))
+                .collect();
+            let (f, t, every) = (from.peek().clone(), to.peek().clone(), *deep_every.peek());
+            saving.set(true);
+            spawn(async move {
+                let Some(db) = db_signal.read().db.clone() else {
+                    saving.set(false);
+                    return;
+                };
+                let result = generate(&db, &f, &t, &order, every).await;
+                saving.set(false);
+                match result {
+                    Ok(_) => on_done.call(()),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        }
+    };
+
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+    let can_generate = !included().is_empty() && !from().is_empty() && from() <= to();
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-4",
+            p { class: "text-sm font-semibold text-gray-900", {t!("cleaning-generate-title")} }
+            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("cleaning-from")} }
+                    input {
+                        r#type: "date",
+                        class: input_cls,
+                        value: "{from}",
+                        oninput: move |e| from.set(e.value()),
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("cleaning-to")} }
+                    input {
+                        r#type: "date",
+                        class: input_cls,
+                        value: "{to}",
+                        oninput: move |e| to.set(e.value()),
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("cleaning-deep-every")} }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        max: "52",
+                        class: input_cls,
+                        value: "{deep_every}",
+                        oninput: move |e| {
+                            if let Ok(n) = e.value().trim().parse::<u8>() {
+                                deep_every.set(n.min(52));
+                            }
+                        },
+                    }
+                }
+            }
+            p { class: "text-xs text-gray-500", {t!("cleaning-groups-hint")} }
+            div { class: "flex flex-wrap gap-3",
+                for (id , name) in groups.iter().cloned() {
+                    {
+                        let checked = included().contains(&id);
+                        let id_key = rid_str(&id);
+                        rsx! {
+                            label { key: "{id_key}", class: "flex items-center gap-2 text-sm text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    checked,
+                                    onchange: move |_| {
+                                        if checked {
+                                            included.write().retain(|g| g != &id);
+                                        } else {
+                                            included.write().push(id.clone());
+                                        }
+                                    },
+                                }
+                                "{name}"
+                            }
+                        }
+                    }
                 }
             }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            div { class: "flex flex-wrap items-center justify-end gap-2",
+                if confirming() {
+                    span { class: "text-sm text-gray-600 mr-2", {t!("cleaning-generate-confirm")} }
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| confirming.set(false),
+                        {t!("btn-cancel")}
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                        disabled: saving() || !can_generate,
+                        onclick: on_generate,
+                        {t!("btn-confirm")}
+                    }
+                } else {
+                    button {
+                        class: "px-4 py-2 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                        disabled: !can_generate,
+                        onclick: move |_| confirming.set(true),
+                        {t!("cleaning-generate")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── CleaningAreas ─────────────────────────────────────────────────────────────
+
+#[derive(Clone, Default, PartialEq)]
+struct AreaForm {
+    id: Option<RecordId>,
+    name: String,
+    weekly: String,
+    deep: String,
+}
+
+impl AreaForm {
+    fn from_area(area: &CleaningArea) -> Self {
+        Self {
+            id: area.id.clone(),
+            name: area.name.clone(),
+            weekly: area.weekly.join("\n"),
+            deep: area.deep.join("\n"),
+        }
+    }
+}
+
+/// Areas of the hall and their weekly and deep-clean checklists.
+#[component]
+fn CleaningAreas(areas: Vec<CleaningArea>, on_changed: Callback<()>) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<AreaForm>> = use_signal(|| None);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        if f.name.trim().is_empty() {
+            error.set(Some(t!("cleaning-area-name-required")));
+            return;
+        }
+        let data = CleaningAreaData {
+            name: f.name.trim().to_string(),
+            weekly: parse_tasks(&f.weekly),
+            deep: parse_tasks(&f.deep),
+        };
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => CleaningArea::update(&db, id, data).await.map(|_| ()),
+                None => CleaningArea::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match CleaningArea::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let input_cls = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+    let textarea_cls = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500 resize-none";
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some(),
+                    onclick: move |_| form.set(Some(AreaForm::default())),
+                    {t!("cleaning-add-area")}
+                }
+            }
+
+            if let Some(f) = form() {
+                div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("cleaning-area-name")} }
+                        input {
+                            class: input_cls,
+                            value: f.name.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.name = e.value();
+                                }
+                            },
+                        }
+                    }
+                    div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("cleaning-weekly-tasks")} }
+                            textarea {
+                                class: textarea_cls,
+                                rows: "6",
+                                placeholder: t!("cleaning-tasks-placeholder"),
+                                value: f.weekly.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.weekly = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("cleaning-deep-tasks")} }
+                            textarea {
+                                class: textarea_cls,
+                                rows: "6",
+                                placeholder: t!("cleaning-tasks-placeholder"),
+                                value: f.deep.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.deep = e.value();
+                                    }
+                                },
+                            }
+                        }
+                    }
+                    if let Some(err) = error() {
+                        p { class: "text-sm text-red-600", "{err}" }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| {
+                                form.set(None);
+                                error.set(None);
+                            },
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                            disabled: saving(),
+                            onclick: on_save,
+                            {t!("btn-save")}
+                        }
+                    }
+                }
+            }
+
+            if areas.is_empty() && form().is_none() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
                     p { class: "text-4xl mb-3", "🧹" }
-                    p { class: "font-medium text-gray-600", {t!("empty-cleaning-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-cleaning-desc")} }
+                    p { class: "font-medium text-gray-600", {t!("cleaning-no-areas")} }
+                }
+            }
+
+            for area in areas.iter().cloned() {
+                {
+                    let id = area.id.clone();
+                    let deleting = id.is_some() && confirm_delete() == id;
+                    rsx! {
+                        div { key: "{area.name}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+                            div { class: "flex flex-wrap items-center justify-between gap-2",
+                                p { class: "text-sm font-semibold text-gray-900", "{area.name}" }
+                                div { class: "flex items-center gap-2",
+                                    if deleting {
+                                        span { class: "text-sm text-gray-600 mr-2", {t!("cleaning-area-delete-confirm")} }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: move |_| confirm_delete.set(None),
+                                            {t!("btn-cancel")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| {
+                                                    if let Some(id) = id.clone() {
+                                                        on_delete(id);
+                                                    }
+                                                }
+                                            },
+                                            {t!("btn-confirm")}
+                                        }
+                                    } else {
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: {
+                                                let area = area.clone();
+                                                move |_| form.set(Some(AreaForm::from_area(&area)))
+                                            },
+                                            {t!("btn-edit")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| confirm_delete.set(id.clone())
+                                            },
+                                            {t!("btn-delete")}
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3 text-sm text-gray-700",
+                                div {
+                                    p { class: "text-xs font-semibold uppercase tracking-wide text-gray-500", {t!("cleaning-weekly-tasks")} }
+                                    ul { class: "list-disc list-inside",
+                                        for task in area.weekly.iter() {
+                                            li { key: "{task}", "{task}" }
+                                        }
+                                    }
+                                }
+                                div {
+                                    p { class: "text-xs font-semibold uppercase tracking-wide text-gray-500", {t!("cleaning-deep-tasks")} }
+                                    ul { class: "list-disc list-inside",
+                                        for task in area.deep.iter() {
+                                            li { key: "{task}", "{task}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -0,0 +1,530 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::weekday;
+use crate::format::{effective_date_format, effective_name_format, event_display_title, format_date};
+use crate::models::absence::Absence;
+use crate::models::co_visit::{Arrangement, ArrangementKind, CoVisit, Preparation};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::event::{self, CongregationEvent, EventType};
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+use crate::pages::app::conflicts::ConflictWarnings;
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::schedule::co_visit::{
+    apply_picks, host_slots, hosts, kind_label, meal_label, task_label, template, visit_days,
+};
+use crate::schedule::conflicts::Source;
+use crate::schedule::rotation::{propose, Reason, Requirement};
+use crate::schedule::weekday_label;
+use crate::Route;
+
+/// Page where the reviewed item can be found, if the app keeps it.
+fn task_route(task: Preparation) -> Option<Route> {
+    match task {
+        Preparation::PublisherRecords => Some(Route::AppUsers {}),
+        Preparation::FieldServiceReports => Some(Route::AppMonthlyTotals {}),
+        Preparation::MissingReports => Some(Route::AppMissingReports {}),
+        Preparation::PioneerList => Some(Route::AppPioneers {}),
+        Preparation::TerritoryCoverage => Some(Route::AppTerritory {}),
+        Preparation::MeetingSchedules => Some(Route::AppWeekdayMeeting {}),
+        Preparation::Accounts => None,
+    }
+}
+
+/// "Tuesday 2026-10-20", in the chosen date format.
+fn day_label(date: &str, date_fmt: &DateFormat) -> String {
+    format!(
+        "{} {}",
+        weekday(date).map(weekday_label).unwrap_or_default(),
+        format_date(date, date_fmt)
+    )
+}
+
+/// Planner for the circuit overseer visit of one event; `id` is the
+/// event's record key.
+#[component]
+pub fn AppCoVisit(id: String) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let event_id = RecordId::parse_simple(&format!("{}:{}", event::TABLE, id)).ok();
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let event_res = {
+        let event_id = event_id.clone();
+        use_resource(move || {
+            let event_id = event_id.clone();
+            async move {
+                let (Some(db), Some(id)) = (db_signal.read().db.clone(), event_id) else {
+                    return None;
+                };
+                CongregationEvent::get(&db, id).await.ok().flatten()
+            }
+        })
+    };
+
+    // Every plan: this visit's, and earlier ones for fair hosting.
+    let visits_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        CoVisit::all(&db).await.unwrap_or_default()
+    });
+
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let absences_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        Absence::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let is_loading = event_res.read().is_none()
+        || visits_res.read().is_none()
+        || users_res.read().is_none()
+        || privs_res.read().is_none()
+        || absences_res.read().is_none();
+
+    let event = event_res()
+        .flatten()
+        .filter(|e| e.event_type == EventType::CircuitOverseerVisit);
+    let visits = visits_res().unwrap_or_default();
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+            Link {
+                to: Route::AppEvents {},
+                class: "text-sm text-gray-500 hover:text-gray-700",
+                "‹ "
+                {t!("nav-events")}
+            }
+
+            if is_loading {
+                div { class: "flex justify-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("priv-loading")} }
+                }
+            } else if let (Some(event), Some(event_id)) = (event, event_id.clone()) {
+                {
+                    let stored = visits.iter().find(|v| v.event == event_id).cloned();
+                    let key = if stored.is_some() { "stored" } else { "template" };
+                    let visit = stored.unwrap_or_else(|| template(event_id.clone(), &event.start_date));
+                    let history = hosts(
+                        &visits
+                            .iter()
+                            .filter(|v| v.event != event_id)
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    );
+                    rsx! {
+                        VisitPlanner {
+                            key: "{key}",
+                            event,
+                            visit,
+                            users: users_res().unwrap_or_default(),
+                            privileges: privs_res().unwrap_or_default(),
+                            absences: absences_res().unwrap_or_default(),
+                            history,
+                            name_fmt: name_fmt(),
+                            date_fmt: date_fmt(),
+                        }
+                    }
+                }
+            } else {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-14 text-center",
+                    p { class: "font-medium text-gray-600", {t!("co-not-found")} }
+                }
+            }
+        }
+    }
+}
+
+// ── VisitPlanner ──────────────────────────────────────────────────────────────
+
+#[component]
+fn VisitPlanner(
+    event: CongregationEvent,
+    visit: CoVisit,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    absences: Vec<Absence>,
+    /// `(host, date)` of meals at earlier visits, balanced by the rotation.
+    history: Vec<(RecordId, String)>,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+) -> Element {
+    let db_signal = use_db();
+    let mut draft = use_signal(|| visit.clone());
+    let mut reasons: Signal<HashMap<usize, String>> = use_signal(HashMap::new);
+    let mut saving = use_signal(|| false);
+    let mut saved = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut edit = move |f: &dyn Fn(&mut CoVisit)| {
+        f(&mut draft.write());
+        saved.set(false);
+    };
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let visit = draft.peek().clone();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = CoVisit::save(&db, visit).await;
+            saving.set(false);
+            match result {
+                Ok(Some(stored)) => {
+                    draft.set(stored);
+                    saved.set(true);
+                    error.set(None);
+                }
+                Ok(None) => {}
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_suggest = {
+        let users = users.clone();
+        let privileges = privileges.clone();
+        move |_| {
+            let slots = host_slots(&draft.peek());
+            let picks = propose(&users, &privileges, &absences, &history, &slots);
+            let picks: Vec<_> = picks.into_iter().filter(|p| p.reason != Reason::Kept).collect();
+            reasons.set(
+                picks
+                    .iter()
+                    .filter_map(|p| Some((p.key.parse().ok()?, p.reason.explain())))
+                    .collect(),
+            );
+            apply_picks(&mut draft.write(), &picks);
+            saved.set(false);
+        }
+    };
+
+    let d = draft();
+    let nf = name_fmt;
+    let df = date_fmt;
+    let title = event_display_title(&event);
+    let date_range = if event.start_date == event.end_date {
+        format_date(&event.start_date, &df)
+    } else {
+        format!("{} – {}", format_date(&event.start_date, &df), format_date(&event.end_date, &df))
+    };
+
+    let mut days = visit_days(&event.start_date);
+    days.extend(d.arrangements.iter().map(|a| a.date.clone()));
+    days.sort();
+    days.dedup();
+    let first_day = days.first().cloned().unwrap_or_default();
+    let last_day = days.last().cloned().unwrap_or_default();
+
+    let brothers: Vec<&User> = users
+        .iter()
+        .filter(|u| u.active && Requirement::Brother.allows(u))
+        .collect();
+    let hospitable: Vec<&User> = users
+        .iter()
+        .filter(|u| u.active)
+        .filter(|u| {
+            privileges
+                .iter()
+                .any(|p| Some(&p.publisher) == u.id.as_ref() && p.hospitality)
+        })
+        .collect();
+    let open_meals = d.meals.iter().filter(|m| m.host.is_none()).count();
+    let done = d.checklist.iter().filter(|c| c.done).count();
+    let total = d.checklist.len();
+
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        // ── Header ────────────────────────────────────────────────────────
+        div { class: "flex flex-wrap items-center justify-between gap-3",
+            div {
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("co-title")} }
+                p { class: "text-sm text-gray-500", "{title} · {date_range}" }
+            }
+            div { class: "flex items-center gap-3",
+                if saved() {
+                    span { class: "text-xs text-emerald-700", {t!("co-saved")} }
+                }
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: on_save,
+                    {t!("btn-save")}
+                }
+            }
+        }
+        if d.id.is_none() {
+            p { class: "text-xs text-gray-500", {t!("co-template-hint")} }
+        }
+        if let Some(err) = error() {
+            p { class: "text-sm text-red-600", "{err}" }
+        }
+        // Checked against the stored plan, so again after each save.
+        if d.id.is_some() && !saving() {
+            ConflictWarnings {
+                key: "{first_day}-{saved}",
+                from: first_day.clone(),
+                to: last_day.clone(),
+                source: Source::CoVisit,
+                name_fmt: nf.clone(),
+                date_fmt: df.clone(),
+            }
+        }
+
+        // ── Arrangements ──────────────────────────────────────────────────
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-4",
+            p { class: "text-sm font-semibold text-gray-900", {t!("co-arrangements")} }
+            for date in days {
+                {
+                    let mut rows: Vec<(usize, Arrangement)> = d
+                        .arrangements
+                        .iter()
+                        .cloned()
+                        .enumerate()
+                        .filter(|(_, a)| a.date == date)
+                        .collect();
+                    rows.sort_by(|a, b| a.1.time.cmp(&b.1.time));
+                    let heading = day_label(&date, &df);
+                    let new_date = date.clone();
+                    rsx! {
+                        div { key: "{date}", class: "space-y-2",
+                            div { class: "flex items-center justify-between gap-2",
+                                p { class: "text-xs font-semibold text-gray-500 uppercase", "{heading}" }
+                                button {
+                                    class: "text-xs text-primary-600 hover:underline",
+                                    onclick: move |_| {
+                                        let date = new_date.clone();
+                                        edit(&move |v| {
+                                            v.arrangements.push(Arrangement {
+                                                kind: ArrangementKind::Other,
+                                                date: date.clone(),
+                                                time: String::new(),
+                                                place: String::new(),
+                                                person: None,
+                                                notes: String::new(),
+                                            })
+                                        });
+                                    },
+                                    "＋ "
+                                    {t!("co-add-arrangement")}
+                                }
+                            }
+                            for (i , a) in rows {
+                                {
+                                    let options = person_options(brothers.clone(), a.person.as_ref(), &users, &nf);
+                                    rsx! {
+                                        div { key: "{i}", class: "grid grid-cols-2 sm:grid-cols-6 gap-2 items-end",
+                                            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                                span { {t!("co-kind")} }
+                                                select {
+                                                    class: input_cls,
+                                                    onchange: move |e| {
+                                                        let kind = ArrangementKind::from_key(&e.value());
+                                                        edit(&move |v| v.arrangements[i].kind = kind);
+                                                    },
+                                                    for kind in ArrangementKind::ALL {
+                                                        option { value: kind.key(), selected: kind == a.kind, {kind_label(kind)} }
+                                                    }
+                                                }
+                                            }
+                                            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                                span { {t!("co-time")} }
+                                                input {
+                                                    r#type: "time",
+                                                    class: input_cls,
+                                                    value: a.time.clone(),
+                                                    onchange: move |e| {
+                                                        let time = e.value();
+                                                        edit(&move |v| v.arrangements[i].time = time.clone());
+                                                    },
+                                                }
+                                            }
+                                            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                                span { {t!("co-place")} }
+                                                input {
+                                                    class: input_cls,
+                                                    value: a.place.clone(),
+                                                    oninput: move |e| {
+                                                        let place = e.value();
+                                                        edit(&move |v| v.arrangements[i].place = place.clone());
+                                                    },
+                                                }
+                                            }
+                                            PersonSelect {
+                                                label: t!("co-person"),
+                                                options,
+                                                selected: a.person.clone(),
+                                                on_change: move |p: Option<RecordId>| {
+                                                    edit(&move |v| v.arrangements[i].person = p.clone());
+                                                },
+                                            }
+                                            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                                span { {t!("co-notes")} }
+                                                input {
+                                                    class: input_cls,
+                                                    value: a.notes.clone(),
+                                                    oninput: move |e| {
+                                                        let notes = e.value();
+                                                        edit(&move |v| v.arrangements[i].notes = notes.clone());
+                                                    },
+                                                }
+                                            }
+                                            button {
+                                                class: "p-1.5 text-gray-400 hover:text-red-600 hover:bg-red-50 rounded-lg transition-colors justify-self-start",
+                                                title: t!("btn-delete"),
+                                                onclick: move |_| {
+                                                    edit(&move |v| {
+                                                        v.arrangements.remove(i);
+                                                    });
+                                                },
+                                                "🗑️"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // ── Meals ─────────────────────────────────────────────────────────
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                div {
+                    p { class: "text-sm font-semibold text-gray-900", {t!("co-meals")} }
+                    p { class: "text-xs text-gray-500", {t!("co-meals-hint")} }
+                }
+                button {
+                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                    disabled: open_meals == 0,
+                    onclick: on_suggest,
+                    {t!("co-suggest-hosts")}
+                }
+            }
+            if hospitable.is_empty() {
+                p { class: "text-xs text-amber-700", {t!("co-no-hosts")} }
+            }
+            div { class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-3",
+                for (i , meal) in d.meals.iter().cloned().enumerate() {
+                    {
+                        let label = format!("{} · {}", day_label(&meal.date, &df), meal_label(meal.meal));
+                        let options = person_options(hospitable.clone(), meal.host.as_ref(), &users, &nf);
+                        // One household a day keeps the load spread.
+                        let repeated = meal.host.as_ref().is_some_and(|h| {
+                            d.meals.iter().filter(|m| m.date == meal.date && m.host.as_ref() == Some(h)).count() > 1
+                        });
+                        let reason = reasons.read().get(&i).cloned();
+                        rsx! {
+                            div { key: "{i}",
+                                PersonSelect {
+                                    label,
+                                    options,
+                                    selected: meal.host.clone(),
+                                    repeated,
+                                    on_change: move |p: Option<RecordId>| {
+                                        reasons.write().remove(&i);
+                                        edit(&move |v| v.meals[i].host = p.clone());
+                                    },
+                                }
+                                if let Some(reason) = reason {
+                                    p { class: "text-xs text-gray-500 mt-1", "{reason}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // ── Checklist ─────────────────────────────────────────────────────
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+            div { class: "flex items-center justify-between gap-2",
+                p { class: "text-sm font-semibold text-gray-900", {t!("co-checklist")} }
+                span { class: "text-xs text-gray-500 tabular-nums", "{done} / {total}" }
+            }
+            ul { class: "space-y-2",
+                for (i , task, checked) in d.checklist.iter().enumerate().map(|(i, c)| (i, c.task, c.done)) {
+                    li { key: "{i}", class: "flex items-center justify-between gap-2",
+                        label { class: "flex items-center gap-2 text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked,
+                                onchange: move |_| edit(&move |v| v.checklist[i].done = !checked),
+                            }
+                            {task_label(task)}
+                        }
+                        if let Some(route) = task_route(task) {
+                            Link {
+                                to: route,
+                                class: "text-xs text-primary-600 hover:underline shrink-0",
+                                {t!("co-open")}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // ── Notes ─────────────────────────────────────────────────────────
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+            p { class: "text-sm font-semibold text-gray-900", {t!("co-notes")} }
+            textarea {
+                class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500 resize-none",
+                rows: "4",
+                value: d.notes.clone(),
+                oninput: move |e| {
+                    let notes = e.value();
+                    edit(&move |v| v.notes = notes.clone());
+                },
+            }
+        }
+    }
+}
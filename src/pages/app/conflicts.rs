@@ -0,0 +1,345 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::crypto::SessionCrypto;
+use crate::database::{use_crypto, use_db, Db};
+use crate::dates::{add_days, add_months, current_year_month, mondays_in_month, week_start};
+use crate::format::{
+    effective_date_format, effective_name_format, event_display_title, format_date, format_name,
+};
+use crate::models::absence::Absence;
+use crate::models::co_visit::CoVisit;
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::duty_rota::DutyRota;
+use crate::models::event::CongregationEvent;
+use crate::models::field_service_meeting::FieldServiceMeeting;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_witnessing::ShiftOccurrence;
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::reports::{month_label, print_html, table_document};
+use crate::schedule::conflicts::{
+    check, co_visit_assignments, duty_assignments, field_service_assignments,
+    midweek_assignments, weekend_assignments, witnessing_assignments, Conflict, Issue, Source,
+};
+use crate::schedule::recurrence;
+use crate::Route;
+
+/// First and last day of the weeks a month's schedules cover.
+pub fn month_range(year: i32, month: u8) -> (String, String) {
+    let weeks = mondays_in_month(year, month);
+    match (weeks.first(), weeks.last()) {
+        (Some(first), Some(last)) => (first.clone(), add_days(last, 6)),
+        _ => (String::new(), String::new()),
+    }
+}
+
+/// Every assignment dated between `from` and `to`, checked against the
+/// rest. Returns the conflicts and the publishers they name.
+pub async fn load_conflicts(
+    db: &Db,
+    crypto: &SessionCrypto,
+    from: &str,
+    to: &str,
+) -> Result<(Vec<Conflict>, Vec<User>), Box<dyn std::error::Error>> {
+    let users = User::all(db, crypto).await?;
+    let privileges = UserPrivileges::all(db).await?;
+    let absences = Absence::all(db, crypto).await?;
+    let events = recurrence::expand(&CongregationEvent::in_range(db, from, to).await?, from, to);
+    let week = week_start(from);
+    let midweek = MidweekMeeting::by_range(db, &week, to).await?;
+    let weekend = WeekendMeeting::by_range(db, crypto, &week, to).await?;
+    let rotas = DutyRota::by_range(db, from, to).await?;
+    let field_service = FieldServiceMeeting::by_range(db, from, to).await?;
+    let shifts = ShiftOccurrence::by_range(db, from, to).await?;
+    let visits = CoVisit::all(db).await?;
+
+    let mut assignments = midweek_assignments(&midweek, &privileges);
+    assignments.extend(weekend_assignments(&weekend, &privileges));
+    assignments.extend(duty_assignments(&rotas, &privileges));
+    assignments.extend(field_service_assignments(&field_service, &privileges));
+    assignments.extend(witnessing_assignments(&shifts, &privileges));
+    assignments.extend(co_visit_assignments(&visits));
+    assignments.retain(|a| a.date.as_str() >= from && a.date.as_str() <= to);
+
+    Ok((check(&assignments, &users, &absences, &events), users))
+}
+
+fn issue_text(issue: &Issue) -> String {
+    match issue {
+        Issue::DuringEvent(event) => format!("{} {}", issue.label(), event_display_title(event)),
+        _ => issue.label(),
+    }
+}
+
+/// Header row plus one row per conflict, for the screen and for printing.
+fn conflict_rows(
+    conflicts: &[Conflict],
+    users: &[User],
+    name_fmt: &NameFormat,
+    date_fmt: &DateFormat,
+) -> Vec<Vec<String>> {
+    let mut rows = vec![vec![
+        t!("conflict-date"),
+        t!("conflict-person"),
+        t!("conflict-module"),
+        t!("conflict-place"),
+        t!("conflict-issue"),
+    ]];
+    for c in conflicts {
+        let a = &c.assignment;
+        let name = users
+            .iter()
+            .find(|u| u.id.as_ref() == Some(&a.person))
+            .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+            .unwrap_or_else(|| "—".to_string());
+        rows.push(vec![
+            format_date(&a.date, date_fmt),
+            name,
+            a.source.label(),
+            a.label.clone(),
+            issue_text(&c.issue),
+        ]);
+    }
+    rows
+}
+
+// ── ConflictWarnings ──────────────────────────────────────────────────────────
+
+/// Conflicts of one module's assignments between `from` and `to`, shown on
+/// its page. Give it a `key` with the dates so it reloads when they change.
+#[component]
+pub fn ConflictWarnings(
+    from: String,
+    to: String,
+    source: Source,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+
+    let mut conflicts_res = use_resource(move || {
+        let (from, to) = (from.clone(), to.clone());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return (vec![], vec![]) };
+            let crypto = crypto_signal.read().clone();
+            load_conflicts(&db, &crypto, &from, &to).await.unwrap_or_default()
+        }
+    });
+
+    let (conflicts, users) = conflicts_res().unwrap_or_default();
+    let mine: Vec<Conflict> = conflicts.into_iter().filter(|c| c.assignment.source == source).collect();
+    if mine.is_empty() {
+        return rsx! {};
+    }
+    let rows = conflict_rows(&mine, &users, &name_fmt, &date_fmt);
+    let count = mine.len();
+
+    rsx! {
+        div { class: "bg-amber-50 border border-amber-200 rounded-xl p-4 space-y-2",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                p { class: "text-sm font-semibold text-amber-800",
+                    "⚠ {count} "
+                    {t!("conflict-found")}
+                }
+                div { class: "flex items-center gap-3",
+                    button {
+                        class: "text-xs text-amber-800 hover:underline",
+                        onclick: move |_| conflicts_res.restart(),
+                        {t!("conflict-recheck")}
+                    }
+                    Link {
+                        to: Route::AppConflicts {},
+                        class: "text-xs text-amber-800 hover:underline",
+                        {t!("conflict-see-all")}
+                    }
+                }
+            }
+            ul { class: "space-y-1",
+                for (i , row) in rows.into_iter().skip(1).enumerate() {
+                    li { key: "{i}", class: "text-xs text-amber-900",
+                        span { class: "font-medium", "{row[0]} · {row[1]}" }
+                        " — {row[3]}: {row[4]}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Consolidated report ───────────────────────────────────────────────────────
+
+#[component]
+pub fn AppConflicts() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+    let mut filter: Signal<Option<Source>> = use_signal(|| None);
+
+    let mut conflicts_res = use_resource(move || {
+        let (from, to) = month_range(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return Ok((vec![], vec![])) };
+            let crypto = crypto_signal.read().clone();
+            load_conflicts(&db, &crypto, &from, &to).await.map_err(|e| e.to_string())
+        }
+    });
+
+    let is_loading = conflicts_res.read().is_none();
+    let (conflicts, users, error) = match conflicts_res() {
+        Some(Ok((conflicts, users))) => (conflicts, users, None),
+        Some(Err(e)) => (vec![], vec![], Some(e)),
+        None => (vec![], vec![], None),
+    };
+    let shown: Vec<Conflict> = conflicts
+        .iter()
+        .filter(|c| filter().is_none_or(|s| c.assignment.source == s))
+        .cloned()
+        .collect();
+    let rows = conflict_rows(&shown, &users, &name_fmt(), &date_fmt());
+    let month_title = format!("{} {}", month_label(sel_month()), sel_year());
+    let sources = [
+        Source::Midweek,
+        Source::Weekend,
+        Source::Duty,
+        Source::FieldService,
+        Source::Witnessing,
+        Source::CoVisit,
+    ];
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+    };
+
+    let on_print = {
+        let rows = rows.clone();
+        let month_title = month_title.clone();
+        move |_| print_html(table_document(&t!("page-conflicts"), &month_title, &rows))
+    };
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-conflicts")} }
+                div { class: "flex flex-wrap gap-2",
+                    select {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg bg-white text-gray-700",
+                        onchange: move |e| {
+                            let i = e.value().parse::<usize>().ok();
+                            filter.set(i.and_then(|i| sources.get(i).copied()));
+                        },
+                        option { value: "", selected: filter().is_none(), {t!("conflict-all-modules")} }
+                        for (i , source) in sources.iter().enumerate() {
+                            option { value: "{i}", selected: filter() == Some(*source), {source.label()} }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        onclick: move |_| conflicts_res.restart(),
+                        {t!("conflict-recheck")}
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        disabled: is_loading || shown.is_empty(),
+                        onclick: on_print,
+                        {t!("duty-print")}
+                    }
+                }
+            }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(-1),
+                    "‹"
+                }
+                div { class: "text-center",
+                    p { class: "font-semibold text-gray-900", "{month_title}" }
+                    if !is_loading {
+                        if shown.is_empty() {
+                            p { class: "text-xs text-emerald-700", {t!("conflict-none")} }
+                        } else {
+                            p { class: "text-xs text-amber-700",
+                                "{shown.len()} "
+                                {t!("conflict-found")}
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(1),
+                    "›"
+                }
+            }
+
+            if let Some(err) = error {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("priv-loading")} }
+                }
+            } else if !shown.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 overflow-x-auto",
+                    table { class: "w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-xs text-gray-500 border-b border-gray-200",
+                                for (i , head) in rows[0].iter().enumerate() {
+                                    th { key: "{i}", class: "px-4 py-2 font-medium", "{head}" }
+                                }
+                            }
+                        }
+                        tbody {
+                            for (i , row) in rows.iter().skip(1).enumerate() {
+                                tr { key: "{i}", class: "border-b border-gray-100 last:border-0",
+                                    td { class: "px-4 py-2 tabular-nums whitespace-nowrap", "{row[0]}" }
+                                    td { class: "px-4 py-2 font-medium text-gray-900", "{row[1]}" }
+                                    td { class: "px-4 py-2 text-gray-600", "{row[2]}" }
+                                    td { class: "px-4 py-2 text-gray-600", "{row[3]}" }
+                                    td { class: "px-4 py-2 text-amber-800", "{row[4]}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,13 +3,14 @@ use dioxus_i18n::t;
 
 use crate::components::ThemePreview;
 use crate::crypto::{KeyStore, SessionCrypto};
-use crate::database::{use_crypto, use_db};
+use crate::database::{DatabaseMode, use_crypto, use_db};
 use crate::models::congregation::{AccentColor, Congregation, CongregationData, DateFormat, NameFormat, Theme, TimeFormat};
 use crate::models::user::{User, UserData};
 use crate::models::absence::{Absence, AbsenceData};
 use crate::models::emergency_contact::{EmergencyContact, EmergencyContactData};
 use crate::models::field_service_group::{FieldServiceGroup, FieldServiceGroupData};
 use crate::models::migrate;
+use crate::models::portal;
 
 #[component]
 fn FormField(label: String, children: Element) -> Element {
@@ -404,6 +405,10 @@ pub fn AppCongregationSettings() -> Element {
                     }
                 }
 
+                if db_signal.read().mode == DatabaseMode::Online {
+                    PortalSettings {}
+                }
+
                 // ── Danger Zone ───────────────────────────────────────────
                 div { class: "bg-red-50 rounded-xl border border-red-200 overflow-hidden mt-8",
                     div { class: "px-6 py-4 border-b border-red-200 bg-red-100/50",
@@ -767,3 +772,77 @@ pub fn AppCongregationSettings() -> Element {
         }
     }
 }
+
+// ── Publisher portal ──────────────────────────────────────────────────────────
+
+/// Installs the `publisher` access and permission rules on the server.
+/// When the signed-in account may not define access methods, the schema is
+/// shown so the instance owner can run it by hand.
+#[component]
+fn PortalSettings() -> Element {
+    let db_signal = use_db();
+    let mut applying = use_signal(|| false);
+    let mut result: Signal<Option<Result<(), String>>> = use_signal(|| None);
+    let mut show_schema = use_signal(|| false);
+    let code = db_signal.read().congregation_uid.clone().unwrap_or_default();
+    let schema = portal::portal_schema(migrate::TABLES);
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 overflow-hidden",
+            div { class: "px-6 py-4 border-b border-gray-200 bg-gray-50",
+                h2 { class: "text-lg font-semibold text-gray-800", {t!("portal-settings-title")} }
+            }
+            div { class: "p-6 space-y-4",
+                p { class: "text-sm text-gray-600", {t!("portal-settings-desc")} }
+                div { class: "text-sm",
+                    span { class: "text-gray-500", {t!("onboarding-congregation-code")} ": " }
+                    span { class: "font-mono text-gray-800 select-all", "{code}" }
+                }
+                if let Some(res) = result.read().clone() {
+                    match res {
+                        Ok(()) => rsx! {
+                            div { class: "bg-green-50 border border-green-200 rounded-lg p-3 text-green-700 text-sm",
+                                {t!("portal-settings-applied")}
+                            }
+                        },
+                        Err(e) => rsx! {
+                            div { class: "bg-red-50 border border-red-200 rounded-lg p-3 text-red-700 text-sm space-y-1",
+                                p { {t!("portal-settings-failed")} }
+                                p { class: "text-xs font-mono", "{e}" }
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-wrap gap-2",
+                    button {
+                        class: "px-4 py-2 bg-primary-600 text-white rounded-lg text-sm font-medium hover:bg-primary-700 transition-colors disabled:opacity-50",
+                        disabled: applying(),
+                        onclick: move |_| {
+                            let Some(db) = db_signal.read().db.clone() else { return };
+                            applying.set(true);
+                            spawn(async move {
+                                let res = portal::apply_schema(&db).await.map_err(|e| e.to_string());
+                                if res.is_err() {
+                                    show_schema.set(true);
+                                }
+                                result.set(Some(res));
+                                applying.set(false);
+                            });
+                        },
+                        {t!("portal-settings-apply")}
+                    }
+                    button {
+                        class: "px-4 py-2 border border-gray-200 rounded-lg text-sm text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| show_schema.set(!show_schema()),
+                        {t!("portal-settings-show-schema")}
+                    }
+                }
+                if show_schema() {
+                    pre { class: "text-xs bg-gray-50 border border-gray-200 rounded-lg p-3 overflow-x-auto select-all whitespace-pre",
+                        "{schema}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,24 +2,26 @@ use dioxus::prelude::*;
 use dioxus_i18n::t;
 
 use crate::database::{ls_get, use_crypto, use_db};
+use crate::dates::{add_days, add_months, current_year_month, today_str};
+use crate::format::{effective_name_format, event_display_title, event_type_label, format_name};
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::duty_rota::MeetingKind;
 use crate::models::event::{CongregationEvent, EventType};
 use crate::models::field_service_report::FieldServiceReport;
+use crate::models::maintenance::{PreventiveTask, WorkOrder};
+use crate::models::meeting_attendance::MeetingAttendance;
+use crate::models::pioneer::PioneerSettings;
 use crate::models::territory::{Territory, TerritoryAssignment};
 use crate::models::user::{Appointment, User, UserType};
-use crate::pages::app::events::{event_display_title, event_type_label};
-
-// ── Platform helpers ──────────────────────────────────────────────────────────
-
-#[cfg(target_arch = "wasm32")]
-fn current_year_month() -> (i32, u8) {
-    let d = js_sys::Date::new_0();
-    (d.get_full_year() as i32, (d.get_month() + 1) as u8)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year_month() -> (i32, u8) {
-    (2026, 6)
-}
+use crate::Route;
+use crate::reports::analytics::{Comparison, ServiceYearStats, YearOverYear};
+use crate::reports::duty_chart::meeting_label;
+use crate::reports::month_label;
+use crate::reports::pioneer::pioneer_progress;
+use crate::reports::s88::S88Year;
+use crate::reports::service_year::{service_year_bounds, service_year_of};
+use crate::schedule::maintenance::overdue;
+use crate::schedule::recurrence;
 
 /// Hex color for each user type — used in inline styles so Tailwind scanning
 /// doesn't need to see the class names.
@@ -39,6 +41,26 @@ fn type_color(ut: &UserType) -> &'static str {
 pub fn AppDashboard() -> Element {
     let db_signal = use_db();
     let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
 
     let users_res = use_resource(move || async move {
         let Some(db) = db_signal.read().db.clone() else { return vec![] };
@@ -55,9 +77,69 @@ pub fn AppDashboard() -> Element {
             .unwrap_or_default()
     });
 
+    // Service year to date, next to the same months of the previous year.
+    let sy = service_year_of(cy, cm);
+    let sy_res = use_resource(move || async move {
+        let db = db_signal.read().db.clone()?;
+        let crypto = crypto_signal.read().clone();
+        let current = ServiceYearStats::load(&db, &crypto, sy, (cy, cm)).await.ok()?;
+        let previous = ServiceYearStats::load(&db, &crypto, sy - 1, (cy - 1, cm)).await.ok()?;
+        let yoy = YearOverYear::between(&current, &previous);
+        Some((current, yoy))
+    });
+
+    // Meeting attendance of the service year so far.
+    let attendance_res = use_resource(move || async move {
+        let db = db_signal.read().db.clone()?;
+        let entries = MeetingAttendance::by_range(&db, &format!("{:04}-09-01", sy - 1), &today_str())
+            .await
+            .ok()?;
+        let years: Vec<S88Year> = [MeetingKind::Midweek, MeetingKind::Weekend]
+            .into_iter()
+            .map(|kind| S88Year::compute(sy, kind, &entries))
+            .collect();
+        years.iter().any(|y| y.months.iter().any(|m| m.meetings > 0)).then_some(years)
+    });
+
+    // Pioneers significantly behind pace, up to the last month whose reports are due.
+    let pioneer_alerts_res = use_resource(move || {
+        let nf = name_fmt();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            let (ly, lm) = add_months(cy, cm, -1);
+            let psy = service_year_of(ly, lm);
+            let (from, to) = service_year_bounds(psy);
+            let users = User::all(&db, &crypto).await.unwrap_or_default();
+            let reports = FieldServiceReport::by_period(&db, &crypto, from, to)
+                .await
+                .unwrap_or_default();
+            let settings = PioneerSettings::get(&db).await.unwrap_or_default();
+            pioneer_progress(&users, &reports, &settings, psy, (ly, lm))
+                .into_iter()
+                .filter(|p| p.needs_attention())
+                .filter_map(|p| {
+                    let u = users.iter().find(|u| u.id.as_ref() == Some(&p.publisher))?;
+                    Some((format_name(&u.first_name, &u.last_name, &nf), p.months_behind()))
+                })
+                .collect::<Vec<(String, f64)>>()
+        }
+    });
+
+    // Preventive maintenance and work orders past their due date.
+    let maintenance_alerts_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let tasks = PreventiveTask::all(&db).await.unwrap_or_default();
+        let orders = WorkOrder::pending(&db).await.unwrap_or_default();
+        overdue(&tasks, &orders, &today_str())
+    });
+
     let events_res = use_resource(move || async move {
         let Some(db) = db_signal.read().db.clone() else { return vec![] };
-        CongregationEvent::upcoming(&db, 60).await.unwrap_or_default()
+        let from = today_str();
+        let to = add_days(&from, 60);
+        let rows = CongregationEvent::in_range(&db, &from, &to).await.unwrap_or_default();
+        recurrence::expand(&rows, &from, &to)
     });
 
     let my_terr_res = use_resource(move || {
@@ -291,6 +373,193 @@ pub fn AppDashboard() -> Element {
                         }
                     }
                 }
+                // ── Service year to date ──────────────────────────────────────
+                if let Some((stats, yoy)) = sy_res().flatten() {
+                    div { class: "bg-white rounded-xl border border-gray-200 p-5 space-y-4",
+                        div { class: "flex items-baseline justify-between gap-3",
+                            p { class: "text-sm font-semibold text-gray-700",
+                                {format!("{} {}", t!("dash-sy-title"), stats.service_year)}
+                            }
+                            p { class: "text-xs text-gray-400", {t!("dash-sy-vs-last-year")} }
+                        }
+                        div { class: "grid grid-cols-3 gap-4",
+                            YoyStat { label: t!("dash-sy-hours"), value: yoy.hours, decimals: 0 }
+                            YoyStat {
+                                label: t!("dash-sy-studies"),
+                                value: yoy.bible_studies,
+                                decimals: 0,
+                            }
+                            YoyStat {
+                                label: t!("dash-sy-active"),
+                                value: yoy.avg_active_publishers,
+                                decimals: 1,
+                            }
+                        }
+                        if !stats.per_group.is_empty() {
+                            div { class: "divide-y divide-gray-100 border-t border-gray-100",
+                                for g in stats.per_group.iter() {
+                                    {
+                                        let name = if g.group.is_some() {
+                                            g.name.clone()
+                                        } else {
+                                            t!("dash-sy-no-group")
+                                        };
+                                        let hours = g.totals.hours;
+                                        let studies = g.totals.bible_studies;
+                                        let avg = format!("{:.1}", g.totals.avg_hours());
+                                        rsx! {
+                                            div { class: "flex items-center justify-between gap-3 py-2 text-sm",
+                                                span { class: "text-gray-700 truncate", "{name}" }
+                                                div { class: "flex items-center gap-4 text-xs text-gray-500 tabular-nums shrink-0",
+                                                    span { "{hours} " {t!("dash-sy-hours")} }
+                                                    span { "{studies} " {t!("dash-sy-studies")} }
+                                                    span { "⌀ {avg} h" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Meeting attendance ────────────────────────────────────────
+                if let Some(years) = attendance_res().flatten() {
+                    {
+                        let months = years[0].months.iter().filter(|m| (m.year, m.month) <= (cy, cm)).count();
+                        let max_avg = years
+                            .iter()
+                            .flat_map(|y| y.months.iter())
+                            .map(|m| m.average())
+                            .fold(1.0, f64::max);
+                        rsx! {
+                            div { class: "bg-white rounded-xl border border-gray-200 p-5 space-y-4",
+                                div { class: "flex items-baseline justify-between gap-3",
+                                    p { class: "text-sm font-semibold text-gray-700", {t!("dash-attendance-title")} }
+                                    Link {
+                                        to: Route::AppMeetingAttendance {},
+                                        class: "text-xs text-gray-500 hover:underline",
+                                        {t!("nav-meeting-attendance")}
+                                    }
+                                }
+                                div { class: "flex items-end gap-2 h-32",
+                                    for i in 0..months {
+                                        div { class: "flex-1 flex flex-col items-center gap-1 h-full",
+                                            div { class: "flex-1 w-full flex items-end justify-center gap-0.5",
+                                                for (k , y) in years.iter().enumerate() {
+                                                    {
+                                                        let avg = y.months[i].average();
+                                                        let color = if k == 0 { "#60a5fa" } else { "#a855f7" };
+                                                        let style = format!(
+                                                            "height: {:.0}%; background-color: {};",
+                                                            avg / max_avg * 100.0,
+                                                            color,
+                                                        );
+                                                        let tip = format!("{}: {:.0}", meeting_label(y.meeting), avg);
+                                                        rsx! {
+                                                            div { class: "w-1/2 max-w-3 rounded-t", style: "{style}", title: "{tip}" }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            span { class: "text-[10px] text-gray-400",
+                                                {month_label(years[0].months[i].month).chars().take(3).collect::<String>()}
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "flex flex-wrap gap-5 text-xs",
+                                    for (k , y) in years.iter().enumerate() {
+                                        {
+                                            let swatch = format!(
+                                                "background-color: {};",
+                                                if k == 0 { "#60a5fa" } else { "#a855f7" },
+                                            );
+                                            let avg = format!("{:.0}", y.average());
+                                            rsx! {
+                                                div { class: "flex items-center gap-1.5",
+                                                    span { class: "inline-block w-2.5 h-2.5 rounded-full", style: "{swatch}" }
+                                                    span { class: "text-gray-600", {meeting_label(y.meeting)} }
+                                                    span { class: "text-gray-400 tabular-nums", "⌀ {avg}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Pioneer alerts ────────────────────────────────────────────
+                {
+                    let alerts = pioneer_alerts_res().unwrap_or_default();
+                    rsx! {
+                        if !alerts.is_empty() {
+                            div { class: "bg-amber-50 rounded-xl border border-amber-200 p-5 space-y-2",
+                                div { class: "flex items-center justify-between gap-3",
+                                    p { class: "text-sm font-semibold text-amber-900",
+                                        "⚠️ "
+                                        {t!("pioneer-alerts")}
+                                    }
+                                    Link {
+                                        to: Route::AppPioneers {},
+                                        class: "text-xs text-amber-800 hover:underline",
+                                        {t!("nav-pioneers")}
+                                    }
+                                }
+                                for (name , behind) in alerts {
+                                    div { class: "flex items-center justify-between text-sm",
+                                        span { class: "text-gray-800", "{name}" }
+                                        span { class: "text-xs text-amber-800 tabular-nums",
+                                            {format!("{behind:.1} ")}
+                                            {t!("pioneer-months-behind")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Maintenance alerts ────────────────────────────────────────
+                {
+                    let alerts = maintenance_alerts_res().unwrap_or_default();
+                    let more = alerts.len().saturating_sub(5);
+                    rsx! {
+                        if !alerts.is_empty() {
+                            div { class: "bg-red-50 rounded-xl border border-red-200 p-5 space-y-2",
+                                div { class: "flex items-center justify-between gap-3",
+                                    p { class: "text-sm font-semibold text-red-900",
+                                        "🔧 "
+                                        {t!("maint-alerts")}
+                                    }
+                                    Link {
+                                        to: Route::AppMaintenance {},
+                                        class: "text-xs text-red-800 hover:underline",
+                                        {t!("nav-maintenance")}
+                                    }
+                                }
+                                for (title , due) in alerts.into_iter().take(5) {
+                                    div { class: "flex items-center justify-between text-sm",
+                                        span { class: "text-gray-800", "{title}" }
+                                        span { class: "text-xs text-red-800 tabular-nums",
+                                            {due.unwrap_or_else(|| t!("maint-due-now"))}
+                                        }
+                                    }
+                                }
+                                if more > 0 {
+                                    p { class: "text-xs text-red-800",
+                                        "+{more} "
+                                        {t!("maint-alerts-more")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // ── Row 3: Upcoming events ─────────────────────────────────────────
                 div { class: "bg-white rounded-xl border border-gray-200 p-5",
                     p { class: "text-sm font-semibold text-gray-700 mb-3",
@@ -368,3 +637,24 @@ pub fn AppDashboard() -> Element {
         }
     }
 }
+
+// ── YoyStat ───────────────────────────────────────────────────────────────────
+
+/// Service-year figure with its change against the previous year.
+#[component]
+fn YoyStat(label: String, value: Comparison, decimals: usize) -> Element {
+    let current = format!("{:.*}", decimals, value.current);
+    let (badge, badge_cls) = match value.percent_change() {
+        Some(p) if p >= 0.5 => (format!("▲ {p:.0}%"), "text-emerald-600"),
+        Some(p) if p <= -0.5 => (format!("▼ {:.0}%", -p), "text-red-600"),
+        Some(_) => ("＝".to_string(), "text-gray-400"),
+        None => ("—".to_string(), "text-gray-400"),
+    };
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            p { class: "text-xs font-medium text-gray-500", "{label}" }
+            p { class: "text-2xl font-extrabold text-gray-900 leading-none tabular-nums", "{current}" }
+            p { class: "text-xs font-medium {badge_cls}", "{badge}" }
+        }
+    }
+}
@@ -0,0 +1,569 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_days, add_months, add_months_iso, current_year_month, mondays_in_month};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::absence::Absence;
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::duty_rota::{Duty, DutyRole, DutyRota, DutySettings, MeetingKind};
+use crate::models::event::CongregationEvent;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::pages::app::conflicts::{month_range, ConflictWarnings};
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::reports::duty_chart::{meeting_label, table};
+use crate::reports::{csv, download_file, month_label, print_html, table_document};
+use crate::schedule::duties::{
+    apply_picks, assignments, fit, label as duty_label, meeting_dates, qualifies, requirement,
+    rotation_slots,
+};
+use crate::schedule::conflicts::Source;
+use crate::schedule::midweek::eligible;
+use crate::schedule::recurrence;
+use crate::schedule::rotation::{propose, Pick, Reason};
+use crate::schedule::{midweek, weekday_label, weekend};
+
+/// Rotas of the month's meetings: the stored ones, and blank ones for
+/// meetings without a rota yet, each fitted to the configured places.
+fn month_rotas(
+    dates: &[(String, MeetingKind)],
+    stored: &[DutyRota],
+    settings: &DutySettings,
+    roles: &[DutyRole],
+) -> Vec<DutyRota> {
+    dates
+        .iter()
+        .map(|(date, kind)| {
+            let mut rota = stored
+                .iter()
+                .find(|r| &r.date == date && r.meeting == *kind)
+                .cloned()
+                .unwrap_or_else(|| DutyRota::blank(date.clone(), *kind));
+            fit(&mut rota, settings, roles);
+            rota
+        })
+        .collect()
+}
+
+/// Duty rota page shared by the attendants and the AV & platform pages;
+/// `roles` are the duties it plans.
+#[component]
+pub fn DutyRotaPage(title: String, roles: Vec<DutyRole>) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let absences_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        Absence::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut settings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return DutySettings::default() };
+        DutySettings::get(&db).await.unwrap_or_default()
+    });
+
+    // Meeting programs and events of the month decide the meeting dates.
+    let programs_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let empty = (vec![], vec![], vec![]);
+            let Some(db) = db_signal.read().db.clone() else { return empty };
+            let crypto = crypto_signal.read().clone();
+            let (Some(from), Some(to)) = (weeks.first(), weeks.last()) else { return empty };
+            let midweek = MidweekMeeting::by_range(&db, from, to).await.unwrap_or_default();
+            let weekend = WeekendMeeting::by_range(&db, &crypto, from, to).await.unwrap_or_default();
+            let until = add_days(to, 6);
+            let rows = CongregationEvent::in_range(&db, from, &until).await.unwrap_or_default();
+            let events = recurrence::expand(&rows, from, &until);
+            (midweek, weekend, events)
+        }
+    });
+
+    // The month's rotas plus a year before it, for fair rotation.
+    let mut rotas_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let (Some(first), Some(last)) = (weeks.first(), weeks.last()) else { return vec![] };
+            let from = add_months_iso(first, -12);
+            DutyRota::by_range(&db, &from, &add_days(last, 6)).await.unwrap_or_default()
+        }
+    });
+
+    let mut proposal: Signal<Option<(Vec<DutyRota>, Vec<Pick>)>> = use_signal(|| None);
+    let mut settings_open = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || absences_res.read().is_none()
+        || settings_res.read().is_none()
+        || programs_res.read().is_none()
+        || rotas_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let absences = absences_res().unwrap_or_default();
+    let settings = settings_res().unwrap_or_default();
+    let (midweek_programs, weekend_programs, events) = programs_res().unwrap_or_default();
+    let stored = rotas_res().unwrap_or_default();
+    let weeks = mondays_in_month(sel_year(), sel_month());
+    let nf = name_fmt();
+    let df = date_fmt();
+    let (conflicts_from, conflicts_to) = month_range(sel_year(), sel_month());
+
+    let dates = meeting_dates(&weeks, &midweek_programs, &weekend_programs, &events, &settings);
+    let rotas = month_rotas(&dates, &stored, &settings, &roles);
+    let first_date = dates.first().map(|(d, _)| d.clone()).unwrap_or_default();
+    let history = assignments(
+        &stored.iter().filter(|r| r.date < first_date).cloned().collect::<Vec<_>>(),
+        &roles,
+    );
+    // People with a part in a meeting program are not asked to serve too.
+    let also_serving: Vec<(String, RecordId)> = midweek_programs
+        .iter()
+        .flat_map(|m| midweek::people_in(m).into_iter().map(|id| (m.date.clone(), id.clone())))
+        .chain(
+            weekend_programs
+                .iter()
+                .flat_map(|m| weekend::people_in(m).into_iter().map(|id| (m.date.clone(), id.clone()))),
+        )
+        .collect();
+
+    let name_of = {
+        let users = users.clone();
+        let nf = nf.clone();
+        move |id: &RecordId| -> String {
+            users
+                .iter()
+                .find(|u| u.id.as_ref() == Some(id))
+                .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+                .unwrap_or_else(|| "—".to_string())
+        }
+    };
+    let open_count = rotas
+        .iter()
+        .flat_map(|r| r.duties.iter())
+        .filter(|d| roles.contains(&d.role) && d.person.is_none())
+        .count();
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+        proposal.set(None);
+    };
+
+    let on_propose = {
+        let rotas = rotas.clone();
+        let roles = roles.clone();
+        let users = users.clone();
+        let privileges = privileges.clone();
+        let absences = absences.clone();
+        let also_serving = also_serving.clone();
+        move |_| {
+            let slots = rotation_slots(&rotas, &roles, &also_serving);
+            let picks = propose(&users, &privileges, &absences, &history, &slots);
+            let picks = picks.into_iter().filter(|p| p.reason != Reason::Kept).collect();
+            proposal.set(Some((rotas.clone(), picks)));
+        }
+    };
+
+    let on_accept = move |_| {
+        let Some((mut rotas, picks)) = proposal.peek().clone() else { return };
+        apply_picks(&mut rotas, &picks);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match DutyRota::save_many(&db, rotas).await {
+                Ok(()) => {
+                    proposal.set(None);
+                    rotas_res.restart();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    // Store one place right away, creating the meeting's rota if needed.
+    let set_place = move |mut rota: DutyRota, index: usize, person: Option<RecordId>| {
+        if let Some(duty) = rota.duties.get_mut(index) {
+            duty.person = person;
+        }
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match DutyRota::save_many(&db, vec![rota]).await {
+                Ok(()) => rotas_res.restart(),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let month_title = format!("{} {}", month_label(sel_month()), sel_year());
+    let chart = table(&rotas, &roles, &name_of, &df);
+
+    let on_print = {
+        let title = title.clone();
+        let month_title = month_title.clone();
+        let chart = chart.clone();
+        move |_| print_html(table_document(&title, &month_title, &chart))
+    };
+
+    let on_export = {
+        let keys: Vec<&str> = roles.iter().map(|r| r.key()).collect();
+        let filename = format!("duties-{}-{:04}-{:02}.csv", keys.join("-"), sel_year(), sel_month());
+        move |_| download_file(&filename, "text/csv;charset=utf-8", csv::write(&chart))
+    };
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", "{title}" }
+                div { class: "flex flex-wrap gap-2",
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| settings_open.set(!settings_open()),
+                        {t!("duty-settings")}
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        disabled: is_loading,
+                        onclick: on_print,
+                        {t!("duty-print")}
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        disabled: is_loading,
+                        onclick: on_export,
+                        {t!("duty-export")}
+                    }
+                    button {
+                        class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                        disabled: is_loading || proposal().is_some(),
+                        onclick: on_propose,
+                        {t!("duty-generate")}
+                    }
+                }
+            }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(-1),
+                    "‹"
+                }
+                div { class: "text-center",
+                    p { class: "font-semibold text-gray-900", "{month_title}" }
+                    if !is_loading {
+                        if open_count > 0 {
+                            p { class: "text-xs text-amber-700",
+                                "{open_count} "
+                                {t!("duty-open-places")}
+                            }
+                        } else {
+                            p { class: "text-xs text-emerald-700", {t!("duty-all-filled")} }
+                        }
+                    }
+                }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(1),
+                    "›"
+                }
+            }
+
+            if settings_open() && !is_loading {
+                DutySettingsForm {
+                    key: "{settings.attendants}-{settings.midweek_offset}-{settings.weekend_offset}",
+                    settings: settings.clone(),
+                    roles: roles.clone(),
+                    on_saved: move |_| {
+                        settings_open.set(false);
+                        settings_res.restart();
+                    },
+                }
+            }
+
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            ConflictWarnings {
+                key: "{conflicts_from}",
+                from: conflicts_from.clone(),
+                to: conflicts_to.clone(),
+                source: Source::Duty,
+                name_fmt: nf.clone(),
+                date_fmt: df.clone(),
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("duty-loading")} }
+                }
+            } else if let Some((proposed, picks)) = proposal() {
+
+                // ── Proposal ──────────────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                    p { class: "text-sm font-semibold text-gray-900", {t!("rotation-proposal-title")} }
+                    p { class: "text-xs text-gray-500", {t!("rotation-proposal-hint")} }
+                    if picks.is_empty() {
+                        p { class: "text-sm text-gray-500", {t!("rotation-nothing-open")} }
+                    }
+                    ul { class: "divide-y divide-gray-100",
+                        for pick in picks.iter() {
+                            {
+                                let place = pick
+                                    .key
+                                    .split_once('-')
+                                    .and_then(|(r, d)| Some((r.parse::<usize>().ok()?, d.parse::<usize>().ok()?)))
+                                    .and_then(|(r, d)| Some((proposed.get(r)?, proposed.get(r)?.duties.get(d)?)));
+                                let place_label = place
+                                    .map(|(rota, duty)| {
+                                        format!(
+                                            "{} · {} · {}",
+                                            format_date(&rota.date, &df),
+                                            meeting_label(rota.meeting),
+                                            duty_label(duty.role),
+                                        )
+                                    })
+                                    .unwrap_or_default();
+                                let person = pick.person.as_ref().map(&name_of).unwrap_or_else(|| "—".to_string());
+                                rsx! {
+                                    li { key: "{pick.key}", class: "py-1.5",
+                                        div { class: "flex flex-wrap justify-between gap-2 text-sm",
+                                            span { class: "text-gray-600", "{place_label}" }
+                                            span { class: "font-medium text-gray-900", "{person}" }
+                                        }
+                                        p { class: "text-xs text-gray-500", {pick.reason.explain()} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| proposal.set(None),
+                            {t!("rotation-discard")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                            onclick: on_accept,
+                            {t!("rotation-accept")}
+                        }
+                    }
+                }
+            } else if rotas.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                    p { class: "font-medium text-gray-600", {t!("duty-no-meetings")} }
+                }
+            } else {
+
+                // ── Meetings ──────────────────────────────────────────────
+                div { class: "space-y-3",
+                    for rota in rotas.iter().cloned() {
+                        {
+                            let date_label = format_date(&rota.date, &df);
+                            let kind_label = meeting_label(rota.meeting);
+                            let mut load: HashMap<RecordId, usize> = HashMap::new();
+                            for id in rota.duties.iter().filter_map(|d| d.person.as_ref()) {
+                                *load.entry(id.clone()).or_default() += 1;
+                            }
+                            for (_, id) in also_serving.iter().filter(|(d, _)| *d == rota.date) {
+                                *load.entry(id.clone()).or_default() += 1;
+                            }
+                            let places: Vec<(usize, Duty)> = rota
+                                .duties
+                                .iter()
+                                .cloned()
+                                .enumerate()
+                                .filter(|(_, d)| roles.contains(&d.role))
+                                .collect();
+                            rsx! {
+                                div { key: "{rota.date}-{kind_label}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                                    p { class: "text-sm font-semibold text-gray-900",
+                                        "{date_label}"
+                                        span { class: "ml-2 font-normal text-gray-500", "· {kind_label}" }
+                                    }
+                                    div { class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-3",
+                                        for (index , duty) in places {
+                                            {
+                                                let role = duty.role;
+                                                let options = person_options(
+                                                    eligible(&users, &privileges, |p| qualifies(role, p))
+                                                        .into_iter()
+                                                        .filter(|u| requirement(role).allows(u))
+                                                        .collect(),
+                                                    duty.person.as_ref(),
+                                                    &users,
+                                                    &nf,
+                                                );
+                                                let repeated = duty
+                                                    .person
+                                                    .as_ref()
+                                                    .is_some_and(|id| load.get(id).copied().unwrap_or(0) > 1);
+                                                let rota = rota.clone();
+                                                rsx! {
+                                                    PersonSelect {
+                                                        key: "{index}",
+                                                        label: duty_label(role),
+                                                        options,
+                                                        selected: duty.person.clone(),
+                                                        repeated,
+                                                        on_change: move |v| set_place(rota.clone(), index, v),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── DutySettingsForm ──────────────────────────────────────────────────────────
+
+#[component]
+fn DutySettingsForm(settings: DutySettings, roles: Vec<DutyRole>, on_saved: Callback<()>) -> Element {
+    let db_signal = use_db();
+    let mut draft = use_signal(|| settings.clone());
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let s = draft.peek().clone();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = DutySettings::upsert(&db, &s).await;
+            saving.set(false);
+            match result {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let d = draft();
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-4",
+            p { class: "text-sm font-semibold text-gray-900", {t!("duty-places-per-meeting")} }
+            div { class: "grid grid-cols-2 sm:grid-cols-4 gap-3",
+                for role in roles.iter().copied() {
+                    label { key: "{role.key()}", class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {duty_label(role)} }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            max: "20",
+                            class: input_cls,
+                            value: "{d.count(role)}",
+                            oninput: move |e| {
+                                if let Ok(n) = e.value().trim().parse::<u8>() {
+                                    draft.write().set_count(role, n.min(20));
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            p { class: "text-xs text-gray-500", {t!("duty-days-hint")} }
+            div { class: "grid grid-cols-2 sm:grid-cols-4 gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("duty-midweek")} }
+                    select {
+                        class: input_cls,
+                        onchange: move |e| draft.write().midweek_offset = e.value().parse().unwrap_or(2),
+                        for day in 0..5u8 {
+                            option { value: "{day}", selected: day == d.midweek_offset, {weekday_label(day)} }
+                        }
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("duty-weekend")} }
+                    select {
+                        class: input_cls,
+                        onchange: move |e| draft.write().weekend_offset = e.value().parse().unwrap_or(6),
+                        for day in 5..7u8 {
+                            option { value: "{day}", selected: day == d.weekend_offset, {weekday_label(day)} }
+                        }
+                    }
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: on_save,
+                    {t!("btn-save")}
+                }
+            }
+        }
+    }
+}
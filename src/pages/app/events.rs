@@ -2,42 +2,58 @@ use dioxus::prelude::*;
 use dioxus_i18n::t;
 use surrealdb::types::RecordId;
 
-use crate::database::use_db;
-use crate::models::event::{CongregationEvent, CongregationEventData, EventType, today_str};
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_months_iso, today_str};
+use crate::format::{effective_name_format, event_display_title, event_type_label, format_name};
+use crate::models::co_visit::CoVisit;
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::duty_rota::DutyRota;
+use crate::models::event::{
+    CongregationEvent, CongregationEventData, EventType, Frequency, Recurrence,
+};
+use crate::models::field_service_meeting::FieldServiceMeeting;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::territory::{Territory, TerritoryAssignment};
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::reports::ics::{ImportedEvent, duplicate_of, parse};
+use crate::reports::{download_file, ics};
+use crate::schedule::recurrence::{self, next_occurrence, week_of_month};
+use crate::Route;
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
+fn rid_key(id: &RecordId) -> String {
+    match &id.key {
+        surrealdb::types::RecordIdKey::String(k) => k.clone(),
+        surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
 fn rid_str(id: &RecordId) -> String {
-    format!(
-        "{}:{}",
-        id.table,
-        match &id.key {
-            surrealdb::types::RecordIdKey::String(k) => k.clone(),
-            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
-            _ => String::new(),
-        }
-    )
+    format!("{}:{}", id.table, rid_key(id))
 }
 
-pub fn event_type_label(et: &EventType) -> String {
-    match et {
-        EventType::CircuitAssembly => t!("event-type-circuit-assembly"),
-        EventType::Memorial => t!("event-type-memorial"),
-        EventType::CircuitOverseerVisit => t!("event-type-circuit-overseer"),
-        EventType::RegionalConvention => t!("event-type-regional-convention"),
-        EventType::Other => t!("event-type-other"),
-    }
+/// Event types with the keys their `<select>` options use.
+const EVENT_TYPES: [(&str, EventType); 5] = [
+    ("circuit_assembly", EventType::CircuitAssembly),
+    ("memorial", EventType::Memorial),
+    ("circuit_overseer", EventType::CircuitOverseerVisit),
+    ("regional_convention", EventType::RegionalConvention),
+    ("other", EventType::Other),
+];
+
+fn event_type_key(et: &EventType) -> &'static str {
+    EVENT_TYPES.iter().find(|(_, t)| t == et).map(|(k, _)| *k).unwrap_or("other")
 }
 
-pub fn event_display_title(e: &CongregationEvent) -> String {
-    match &e.event_type {
-        EventType::Other => e
-            .title
-            .clone()
-            .filter(|s| !s.is_empty())
-            .unwrap_or_else(|| t!("event-type-other")),
-        other => event_type_label(other),
-    }
+fn event_type_from_key(key: &str) -> EventType {
+    EVENT_TYPES
+        .into_iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, t)| t)
+        .unwrap_or(EventType::Other)
 }
 
 fn event_badge_cls(et: &EventType) -> &'static str {
@@ -59,6 +75,9 @@ struct EventFormData {
     event_type: EventType,
     title: String,
     description: String,
+    /// `""`, `"weekly"`, `"monthly"`, `"monthly_last"` or `"yearly"`.
+    repeat: String,
+    until: String,
 }
 
 impl EventFormData {
@@ -69,11 +88,46 @@ impl EventFormData {
             event_type: e.event_type.clone(),
             title: e.title.clone().unwrap_or_default(),
             description: e.description.clone().unwrap_or_default(),
+            repeat: match &e.recurrence {
+                None => "",
+                Some(r) => match r.frequency {
+                    Frequency::Weekly => "weekly",
+                    Frequency::MonthlyWeekday
+                        if r.nth >= 5 && week_of_month(&e.start_date) == 4 =>
+                    {
+                        "monthly_last"
+                    }
+                    Frequency::MonthlyWeekday => "monthly",
+                    Frequency::Yearly => "yearly",
+                },
+            }
+            .to_string(),
+            until: e
+                .recurrence
+                .as_ref()
+                .and_then(|r| r.until.clone())
+                .unwrap_or_default(),
         }
     }
 
+    fn recurrence(&self) -> Option<Recurrence> {
+        let (frequency, nth) = match self.repeat.as_str() {
+            "weekly" => (Frequency::Weekly, 0),
+            "monthly" => (Frequency::MonthlyWeekday, week_of_month(&self.start_date)),
+            "monthly_last" => (Frequency::MonthlyWeekday, 5),
+            "yearly" => (Frequency::Yearly, 0),
+            _ => return None,
+        };
+        Some(Recurrence {
+            frequency,
+            nth,
+            until: Some(self.until.clone()).filter(|s| !s.is_empty()),
+        })
+    }
+
     fn into_model_data(self) -> CongregationEventData {
         CongregationEventData {
+            recurrence: self.recurrence(),
             start_date: self.start_date,
             end_date: self.end_date,
             title: if matches!(self.event_type, EventType::Other) {
@@ -90,6 +144,7 @@ impl EventFormData {
         !self.start_date.is_empty()
             && !self.end_date.is_empty()
             && self.end_date >= self.start_date
+            && (self.repeat.is_empty() || self.until.is_empty() || self.until >= self.start_date)
     }
 }
 
@@ -103,7 +158,20 @@ pub fn AppEvents() -> Element {
         let Some(db) = db_signal.read().db.clone() else {
             return vec![];
         };
-        CongregationEvent::all_prune(&db).await.unwrap_or_default()
+        CongregationEvent::current(&db).await.unwrap_or_default()
+    });
+
+    let mut show_history = use_signal(|| false);
+    let mut history_res = use_resource(move || async move {
+        if !show_history() {
+            return vec![];
+        }
+        let Some(db) = db_signal.read().db.clone() else {
+            return vec![];
+        };
+        let today = today_str();
+        let rows = CongregationEvent::stored_before(&db, &today).await.unwrap_or_default();
+        recurrence::history(&rows, &today)
     });
 
     let mut bootstrapped = use_signal(|| false);
@@ -120,15 +188,79 @@ pub fn AppEvents() -> Element {
     let mut pending_delete: Signal<Option<RecordId>> = use_signal(|| None);
 
     let is_loading = events_res.read().is_none();
-    let events: Vec<CongregationEvent> = events_res().unwrap_or_default();
+    let history_loading = history_res.read().is_none();
+    let history: Vec<CongregationEvent> = history_res().unwrap_or_default();
+
+    // Each event shown on its next occurrence; editing opens the stored one.
+    let today = today_str();
+    let mut events: Vec<(CongregationEvent, CongregationEvent)> = events_res()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|e| {
+            let (start_date, end_date) = next_occurrence(&e, &today)?;
+            Some((CongregationEvent { start_date, end_date, ..e.clone() }, e))
+        })
+        .collect();
+    events.sort_by(|a, b| a.0.start_date.cmp(&b.0.start_date));
+
+    let tab_cls = |active: bool| {
+        if active {
+            "px-3 py-1.5 text-sm rounded-lg bg-primary-600 text-white"
+        } else {
+            "px-3 py-1.5 text-sm rounded-lg text-gray-600 hover:bg-gray-100"
+        }
+    };
+    let upcoming_tab_cls = tab_cls(!show_history());
+    let history_tab_cls = tab_cls(show_history());
 
     rsx! {
         div { class: "space-y-5 w-full pb-10",
 
             // ── Header ────────────────────────────────────────────────────
-            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-events")} }
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-events")} }
+                div { class: "flex gap-1 bg-white border border-gray-200 rounded-lg p-1",
+                    button {
+                        class: upcoming_tab_cls,
+                        onclick: move |_| show_history.set(false),
+                        {t!("event-upcoming")}
+                    }
+                    button {
+                        class: history_tab_cls,
+                        onclick: move |_| show_history.set(true),
+                        {t!("event-history")}
+                    }
+                }
+            }
 
-            if is_loading {
+            if show_history() {
+                if history_loading {
+                    div { class: "flex justify-center py-20 text-gray-400",
+                        span { class: "text-sm animate-pulse", {t!("priv-loading")} }
+                    }
+                } else if history.is_empty() {
+                    div { class: "bg-white rounded-xl border border-gray-200 px-6 py-14 text-center",
+                        p { class: "text-4xl mb-3", "🗂️" }
+                        p { class: "font-medium text-gray-600", {t!("event-history-empty")} }
+                    }
+                } else {
+                    div { class: "space-y-2",
+                        for (i , event) in history.iter().cloned().enumerate() {
+                            {
+                                let year = event.start_date.get(0..4).unwrap_or_default().to_string();
+                                let new_year = i == 0
+                                    || history[i - 1].start_date.get(0..4) != Some(year.as_str());
+                                rsx! {
+                                    if new_year {
+                                        h2 { class: "pt-2 text-sm font-semibold text-gray-500", "{year}" }
+                                    }
+                                    HistoryCard { event }
+                                }
+                            }
+                        }
+                    }
+                }
+            } else if is_loading {
                 div { class: "flex justify-center py-20 text-gray-400",
                     span { class: "text-sm animate-pulse", {t!("priv-loading")} }
                 }
@@ -140,10 +272,10 @@ pub fn AppEvents() -> Element {
                 }
             } else {
                 div { class: "space-y-2",
-                    for event in events.clone() {
+                    for (event , stored) in events.clone() {
                         {
                             let e_id = event.id.clone();
-                            let e_edit = event.clone();
+                            let e_edit = stored;
                             let confirming = pending_delete
                                 .read()
                                 .as_ref()
@@ -166,9 +298,11 @@ pub fn AppEvents() -> Element {
                                         if let Some(rid) = rid {
                                             spawn(async move {
                                                 if let Some(db) = db_signal.read().db.clone() {
+                                                    let _ = CoVisit::delete_by_event(&db, rid.clone()).await;
                                                     let _ = CongregationEvent::delete(&db, rid).await;
                                                 }
                                                 events_res.restart();
+                                                history_res.restart();
                                             });
                                         }
                                     },
@@ -179,6 +313,11 @@ pub fn AppEvents() -> Element {
                     }
                 }
             }
+
+            if !show_history() {
+                CalendarExport {}
+                CalendarImport { on_imported: move |_| events_res.restart() }
+            }
         }
 
         // ── Floating add button ───────────────────────────────────────────
@@ -198,6 +337,7 @@ pub fn AppEvents() -> Element {
                 on_close: move |_| modal_open.set(false),
                 on_saved: move |_| {
                     events_res.restart();
+                    history_res.restart();
                     modal_open.set(false);
                 },
             }
@@ -227,6 +367,16 @@ fn EventCard(
     } else {
         format!("{} – {}", event.start_date, event.end_date)
     };
+    let repeats = event
+        .recurrence
+        .as_ref()
+        .map(|r| recurrence::label(r, &event.start_date));
+    // A visit gets its week planned on its own page.
+    let visit_route = event
+        .id
+        .as_ref()
+        .filter(|_| event.event_type == EventType::CircuitOverseerVisit)
+        .map(|id| Route::AppCoVisit { id: rid_key(id) });
 
     rsx! {
         div { class: "bg-white rounded-xl border border-gray-200 px-4 py-3",
@@ -235,6 +385,9 @@ fn EventCard(
                     div { class: "flex flex-wrap items-center gap-2 mb-1",
                         span { class: "{badge_cls}", "{type_label}" }
                         span { class: "text-sm font-semibold text-gray-900", "{display_title}" }
+                        if let Some(repeats) = repeats {
+                            span { class: "text-xs text-gray-500", "↻ {repeats}" }
+                        }
                     }
                     p { class: "text-xs text-gray-500 tabular-nums", "{date_range}" }
                     if let Some(ref desc) = event.description {
@@ -261,6 +414,13 @@ fn EventCard(
                             {t!("btn-cancel")}
                         }
                     } else {
+                        if let Some(route) = visit_route {
+                            Link {
+                                to: route,
+                                class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                {t!("co-plan-visit")}
+                            }
+                        }
                         button {
                             class: "p-1.5 text-gray-400 hover:text-primary-600 hover:bg-primary-50 rounded-lg transition-colors",
                             onclick: move |_| on_edit.call(()),
@@ -278,6 +438,400 @@ fn EventCard(
     }
 }
 
+// ── HistoryCard ───────────────────────────────────────────────────────────────
+
+/// A past event or past occurrence of a series, kept for the record.
+#[component]
+fn HistoryCard(event: CongregationEvent) -> Element {
+    let badge_cls = format!(
+        "inline-flex items-center px-2 py-0.5 rounded-full text-xs font-medium {}",
+        event_badge_cls(&event.event_type)
+    );
+    let type_label = event_type_label(&event.event_type);
+    let display_title = event_display_title(&event);
+    let date_range = if event.start_date == event.end_date {
+        event.start_date.clone()
+    } else {
+        format!("{} – {}", event.start_date, event.end_date)
+    };
+    let description = event.description.clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "bg-gray-50 rounded-xl border border-gray-200 px-4 py-3",
+            div { class: "flex flex-wrap items-center gap-2 mb-1",
+                span { class: "{badge_cls}", "{type_label}" }
+                span { class: "text-sm font-semibold text-gray-700", "{display_title}" }
+                if event.recurrence.is_some() {
+                    span { class: "text-xs text-gray-400", "↻" }
+                }
+            }
+            p { class: "text-xs text-gray-500 tabular-nums", "{date_range}" }
+            if !description.is_empty() {
+                p { class: "text-sm text-gray-500 mt-1 line-clamp-2", "{description}" }
+            }
+        }
+    }
+}
+
+// ── CalendarExport ────────────────────────────────────────────────────────────
+
+/// Download the coming year as an `.ics` file: everything for the whole
+/// congregation, or the events and assignments of one publisher.
+#[component]
+fn CalendarExport() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                name_fmt.set(effective_name_format(
+                    cong_snap.as_ref().and_then(|o| o.as_ref()),
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut person: Signal<String> = use_signal(String::new);
+    let mut exporting = use_signal(|| false);
+
+    let nf = name_fmt();
+    let users: Vec<User> = users_res().unwrap_or_default();
+    let options: Vec<(String, String)> = users
+        .iter()
+        .filter(|u| u.active)
+        .filter_map(|u| {
+            Some((rid_str(u.id.as_ref()?), format_name(&u.first_name, &u.last_name, &nf)))
+        })
+        .collect();
+
+    let on_export = move |_| {
+        let users = users.clone();
+        let nf = nf.clone();
+        let who = person();
+        let cong = congregation_res().flatten();
+        exporting.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                exporting.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            let today = today_str();
+            let from = add_months_iso(&today, -1);
+            let to = add_months_iso(&today, 12);
+            let chosen = users
+                .iter()
+                .find(|u| u.id.as_ref().is_some_and(|id| rid_str(id) == who));
+            let p = chosen.and_then(|u| u.id.as_ref());
+            let (cong_uid, cong_name) = cong.map(|c| (c.uid, c.name)).unwrap_or_default();
+
+            let rows = CongregationEvent::in_range(&db, &from, &to).await.unwrap_or_default();
+            let events = recurrence::expand(&rows, &from, &to);
+            let field_service =
+                FieldServiceMeeting::by_range(&db, &from, &to).await.unwrap_or_default();
+            let loans = match p {
+                Some(p) => TerritoryAssignment::active_for_user(&db, p).await,
+                None => TerritoryAssignment::active(&db).await,
+            }
+            .unwrap_or_default();
+            let territories = Territory::all(&db).await.unwrap_or_default();
+            let midweek = MidweekMeeting::by_range(&db, &from, &to).await.unwrap_or_default();
+            let weekend =
+                WeekendMeeting::by_range(&db, &crypto, &from, &to).await.unwrap_or_default();
+            let rotas = DutyRota::by_range(&db, &from, &to).await.unwrap_or_default();
+
+            let mut items = ics::event_items(&events, p, &cong_uid);
+            items.extend(ics::field_service_items(&field_service, &users, &nf, p, &cong_uid));
+            items.extend(ics::territory_items(&loans, &territories, &users, &nf, p, &cong_uid));
+            items.extend(ics::meeting_items(&midweek, &weekend, &rotas, &users, &nf, p, &cong_uid));
+            items.sort_by(|a, b| a.start.cmp(&b.start));
+
+            let (name, filename) = match chosen {
+                Some(u) => (
+                    format!("{cong_name} · {}", format_name(&u.first_name, &u.last_name, &nf)),
+                    format!("calendar-{}-{}.ics", u.first_name, u.last_name)
+                        .to_lowercase()
+                        .replace(' ', "-"),
+                ),
+                None => (cong_name, "calendar-congregation.ics".to_string()),
+            };
+            download_file(
+                &filename,
+                "text/calendar;charset=utf-8",
+                ics::write(&name, &ics::now_stamp(), &items),
+            );
+            exporting.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 px-4 py-3 flex flex-wrap items-end gap-3",
+            div { class: "flex-1 min-w-48",
+                label { class: "block text-xs font-medium text-gray-500 mb-1", {t!("ics-export-for")} }
+                select {
+                    class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500",
+                    onchange: move |e| person.set(e.value()),
+                    option { value: "", selected: person().is_empty(), {t!("ics-whole-congregation")} }
+                    for (value, label) in options {
+                        option { value: "{value}", selected: person() == value, "{label}" }
+                    }
+                }
+            }
+            button {
+                class: "px-4 py-2 text-sm font-medium border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 transition-colors disabled:opacity-50",
+                disabled: exporting(),
+                onclick: on_export,
+                {t!("ics-export-btn")}
+            }
+            p { class: "w-full text-xs text-gray-400", {t!("ics-export-hint")} }
+        }
+    }
+}
+
+// ── CalendarImport ────────────────────────────────────────────────────────────
+
+/// Read an `.ics` file from the circuit, preview the events it holds and
+/// create the ones that are not stored yet.
+#[component]
+fn CalendarImport(on_imported: Callback<()>) -> Element {
+    let db_signal = use_db();
+
+    let mut file_name = use_signal(String::new);
+    let mut parsed: Signal<Vec<ImportedEvent>> = use_signal(Vec::new);
+    let mut skipped: Signal<Vec<usize>> = use_signal(Vec::new);
+    let mut with_duplicates = use_signal(|| false);
+    let mut importing = use_signal(|| false);
+    let mut import_msg: Signal<Option<(bool, String)>> = use_signal(|| None);
+
+    // Events already stored over the dates the file covers.
+    let mut existing_res = use_resource(move || {
+        let events = parsed();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let from = events.iter().map(|e| e.start.clone()).min();
+            let to = events.iter().map(|e| e.end.clone()).max();
+            let (Some(from), Some(to)) = (from, to) else { return vec![] };
+            let rows = CongregationEvent::in_range(&db, &from, &to).await.unwrap_or_default();
+            recurrence::expand(&rows, &from, &to)
+        }
+    });
+
+    let events = parsed();
+    let existing = existing_res().unwrap_or_default();
+    let checking = existing_res.read().is_none();
+    let duplicates: Vec<Option<String>> = events
+        .iter()
+        .map(|e| duplicate_of(e, &existing).map(event_display_title))
+        .collect();
+    let chosen: Vec<usize> = (0..events.len())
+        .filter(|i| !skipped.read().contains(i))
+        .filter(|&i| with_duplicates() || duplicates[i].is_none())
+        .collect();
+    let duplicate_count = duplicates.iter().filter(|d| d.is_some()).count();
+    let chosen_count = chosen.len();
+
+    let on_import = {
+        let events = events.clone();
+        let chosen = chosen.clone();
+        move |_| {
+            if *importing.peek() || checking || chosen.is_empty() {
+                return;
+            }
+            let batch: Vec<CongregationEventData> =
+                chosen.iter().map(|&i| events[i].clone().into_data()).collect();
+            let count = batch.len();
+            importing.set(true);
+            import_msg.set(None);
+            spawn(async move {
+                let Some(db) = db_signal.read().db.clone() else {
+                    importing.set(false);
+                    return;
+                };
+                match CongregationEvent::create_many(&db, batch).await {
+                    Ok(()) => {
+                        import_msg.set(Some((true, format!("{count} {}", t!("ics-imported")))));
+                        parsed.set(vec![]);
+                        skipped.set(vec![]);
+                        file_name.set(String::new());
+                        existing_res.restart();
+                        on_imported.call(());
+                    }
+                    Err(e) => import_msg.set(Some((false, e.to_string()))),
+                }
+                importing.set(false);
+            });
+        }
+    };
+
+    let msg_cls = |ok: bool| {
+        if ok { "text-sm text-emerald-700" } else { "text-sm text-red-600" }
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 px-4 py-3 space-y-3",
+            div { class: "flex flex-wrap items-center gap-3",
+                div { class: "relative",
+                    input {
+                        r#type: "file",
+                        accept: ".ics,text/calendar",
+                        class: "absolute inset-0 opacity-0 w-full h-full cursor-pointer",
+                        onchange: move |e| {
+                            let Some(file) = e.files().into_iter().next() else { return };
+                            import_msg.set(None);
+                            spawn(async move {
+                                match file.read_string().await {
+                                    Ok(text) => {
+                                        let events = parse(&text);
+                                        if events.is_empty() {
+                                            import_msg.set(Some((false, t!("ics-no-events"))));
+                                        }
+                                        file_name.set(file.name());
+                                        skipped.set(vec![]);
+                                        parsed.set(events);
+                                    }
+                                    Err(_) => import_msg.set(Some((false, t!("csv-read-error")))),
+                                }
+                            });
+                        },
+                    }
+                    span { class: "inline-flex px-4 py-2 rounded-lg border border-gray-200 text-sm text-gray-700 hover:bg-gray-50",
+                        "📥 "
+                        {t!("ics-import-btn")}
+                    }
+                }
+                if !file_name().is_empty() {
+                    span { class: "text-sm text-gray-600", "{file_name} · {events.len()} " {t!("ics-events")} }
+                }
+                if events.is_empty() {
+                    if let Some((ok, msg)) = import_msg.read().clone() {
+                        span { class: msg_cls(ok), "{msg}" }
+                    }
+                }
+            }
+            if events.is_empty() {
+                p { class: "text-xs text-gray-400", {t!("ics-import-hint")} }
+            } else {
+                if duplicate_count > 0 {
+                    div { class: "flex flex-wrap items-center gap-3 text-sm",
+                        span { class: "text-amber-700", "{duplicate_count} " {t!("ics-already-stored")} }
+                        label { class: "flex items-center gap-1.5 text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                class: "rounded border-gray-300 text-primary-600",
+                                checked: with_duplicates(),
+                                oninput: move |e| with_duplicates.set(e.checked()),
+                            }
+                            {t!("ics-import-duplicates")}
+                        }
+                    }
+                }
+                div { class: "overflow-x-auto border border-gray-100 rounded-lg max-h-[28rem]",
+                    table { class: "w-full text-sm",
+                        thead { class: "bg-gray-50 text-xs text-gray-500 uppercase tracking-wider sticky top-0",
+                            tr {
+                                th { class: "px-2 py-2" }
+                                th { class: "px-2 py-2 text-left", {t!("event-form-start-date")} }
+                                th { class: "px-2 py-2 text-left", {t!("event-form-end-date")} }
+                                th { class: "px-2 py-2 text-left", {t!("ics-summary")} }
+                                th { class: "px-2 py-2 text-left", {t!("event-form-type")} }
+                                th { class: "px-2 py-2 text-left", {t!("csv-status")} }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for (i , event) in events.iter().cloned().enumerate() {
+                                {
+                                    let duplicate = duplicates[i].clone();
+                                    let included = chosen.contains(&i);
+                                    let row_cls = if duplicate.is_some() { "bg-amber-50" } else { "" };
+                                    let type_key = event_type_key(&event.event_type);
+                                    rsx! {
+                                        tr { key: "{i}", class: row_cls,
+                                            td { class: "px-2 py-1.5",
+                                                input {
+                                                    r#type: "checkbox",
+                                                    class: "rounded border-gray-300 text-primary-600",
+                                                    checked: included,
+                                                    disabled: duplicate.is_some() && !with_duplicates(),
+                                                    oninput: move |e| {
+                                                        if e.checked() {
+                                                            skipped.write().retain(|&s| s != i);
+                                                        } else {
+                                                            skipped.write().push(i);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 tabular-nums", "{event.start}" }
+                                            td { class: "px-2 py-1.5 tabular-nums", "{event.end}" }
+                                            td { class: "px-2 py-1.5 font-medium text-gray-900", "{event.summary}" }
+                                            td { class: "px-2 py-1.5",
+                                                select {
+                                                    class: "px-2 py-1 text-sm border border-gray-200 rounded-lg bg-white",
+                                                    onchange: move |e| {
+                                                        if let Some(ev) = parsed.write().get_mut(i) {
+                                                            ev.event_type = event_type_from_key(&e.value());
+                                                        }
+                                                    },
+                                                    for (key , kind) in EVENT_TYPES {
+                                                        option { value: key, selected: key == type_key, {event_type_label(&kind)} }
+                                                    }
+                                                }
+                                            }
+                                            td { class: "px-2 py-1.5 text-xs",
+                                                if let Some(title) = duplicate {
+                                                    span { class: "text-amber-700", {t!("ics-duplicate-of")} " {title}" }
+                                                } else {
+                                                    span { class: "text-emerald-700", {t!("csv-new")} }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-wrap items-center justify-end gap-3",
+                    if let Some((ok, msg)) = import_msg.read().clone() {
+                        span { class: msg_cls(ok), "{msg}" }
+                    }
+                    button {
+                        class: "px-4 py-2 text-sm border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                        onclick: move |_| {
+                            parsed.set(vec![]);
+                            file_name.set(String::new());
+                        },
+                        {t!("btn-cancel")}
+                    }
+                    button {
+                        class: "px-4 py-2 rounded-lg bg-primary-600 text-white text-sm font-medium hover:bg-primary-700 disabled:opacity-50",
+                        disabled: importing() || checking || chosen.is_empty(),
+                        onclick: on_import,
+                        {t!("ics-import-confirm")}
+                        " ({chosen_count})"
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ── EventModal ────────────────────────────────────────────────────────────────
 
 #[component]
@@ -306,6 +860,19 @@ fn EventModal(
 
     let f = form.read().clone();
     let is_other = matches!(f.event_type, EventType::Other);
+    // Monthly options name the weekday of the start date. "Last" is offered
+    // on its own only in the fourth week; from the 29th on it is the same.
+    let monthly = |nth: u8| Recurrence { frequency: Frequency::MonthlyWeekday, nth, until: None };
+    let week = week_of_month(&f.start_date);
+    let mut repeat_options: Vec<(&str, String)> = vec![
+        ("", t!("event-repeat-never")),
+        ("weekly", t!("event-repeat-weekly")),
+        ("monthly", recurrence::label(&monthly(week), &f.start_date)),
+    ];
+    if week == 4 || f.repeat == "monthly_last" {
+        repeat_options.push(("monthly_last", recurrence::label(&monthly(5), &f.start_date)));
+    }
+    repeat_options.push(("yearly", t!("event-repeat-yearly")));
     let valid = f.is_valid();
     let sub = *submitting.read();
 
@@ -377,13 +944,7 @@ fn EventModal(
                         select {
                             class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500",
                             onchange: move |e| {
-                                let new_type = match e.value().as_str() {
-                                    "circuit_assembly" => EventType::CircuitAssembly,
-                                    "memorial" => EventType::Memorial,
-                                    "circuit_overseer" => EventType::CircuitOverseerVisit,
-                                    "regional_convention" => EventType::RegionalConvention,
-                                    _ => EventType::Other,
-                                };
+                                let new_type = event_type_from_key(&e.value());
                                 let was_other = matches!(form.read().event_type, EventType::Other);
                                 form.write().event_type = new_type.clone();
                                 // Clear custom title when switching away from Other
@@ -460,6 +1021,35 @@ fn EventModal(
                         }
                     }
 
+                    // Repeat
+                    div { class: "grid grid-cols-2 gap-3",
+                        div { class: "space-y-1",
+                            label { class: "block text-xs font-semibold text-gray-500 uppercase tracking-wide",
+                                {t!("event-form-repeat")}
+                            }
+                            select {
+                                class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500",
+                                onchange: move |e| form.write().repeat = e.value(),
+                                for (value , label) in repeat_options {
+                                    option { value: "{value}", selected: f.repeat == value, "{label}" }
+                                }
+                            }
+                        }
+                        if !f.repeat.is_empty() {
+                            div { class: "space-y-1",
+                                label { class: "block text-xs font-semibold text-gray-500 uppercase tracking-wide",
+                                    {t!("event-form-until")}
+                                }
+                                input {
+                                    r#type: "date",
+                                    class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500",
+                                    value: f.until.clone(),
+                                    onchange: move |e| form.write().until = e.value(),
+                                }
+                            }
+                        }
+                    }
+
                     // Description
                     div { class: "space-y-1",
                         label { class: "block text-xs font-semibold text-gray-500 uppercase tracking-wide",
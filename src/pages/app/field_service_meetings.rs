@@ -6,63 +6,14 @@ use surrealdb::types::RecordId;
 
 use crate::components::ResponsiveModal;
 use crate::database::{use_crypto, use_db};
-use crate::models::congregation::{Congregation, NameFormat};
+use crate::dates::{current_year_month, days_in_month, iso_date, today_str, weekday};
+use crate::format::{effective_date_format, effective_name_format, format_name};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
 use crate::models::field_service_meeting::{FieldServiceMeeting, FieldServiceMeetingData};
 use crate::models::privilege::UserPrivileges;
 use crate::models::user::User;
-use crate::pages::app::user::{effective_name_format, format_name};
-
-// ── Platform helpers ──────────────────────────────────────────────────────────
-
-#[cfg(target_arch = "wasm32")]
-fn today_iso() -> String {
-    let d = js_sys::Date::new_0();
-    format!(
-        "{:04}-{:02}-{:02}",
-        d.get_full_year(),
-        d.get_month() + 1,
-        d.get_date()
-    )
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn today_iso() -> String {
-    "2026-06-11".to_string()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn current_year_month() -> (i32, u8) {
-    let d = js_sys::Date::new_0();
-    (d.get_full_year() as i32, (d.get_month() + 1) as u8)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year_month() -> (i32, u8) {
-    (2026, 6)
-}
-
-// ── Calendar helpers ──────────────────────────────────────────────────────────
-
-fn days_in_month(y: i32, m: u8) -> u8 {
-    match m {
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if (y % 4 == 0 && y % 100 != 0) || y % 400 == 0 {
-                29
-            } else {
-                28
-            }
-        }
-        _ => 31,
-    }
-}
-
-/// Tomohiko Sakamoto: 0=Sunday, 1=Monday, …, 6=Saturday.
-fn day_of_week(y: i32, m: u8, d: u8) -> u8 {
-    const T: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-    let y = if (m as i32) < 3 { y - 1 } else { y };
-    ((y + y / 4 - y / 100 + y / 400 + T[m as usize - 1] + d as i32) % 7) as u8
-}
+use crate::pages::app::conflicts::{month_range, ConflictWarnings};
+use crate::schedule::conflicts::Source;
 
 fn rid_str(id: &RecordId) -> String {
     format!(
@@ -95,8 +46,9 @@ pub fn AppFieldServiceMeetings() -> Element {
     let congregation_res = use_context::<Resource<Option<Congregation>>>();
     let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
 
-    // Name format
+    // Name and date format
     let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
     {
         let uid = uid.clone();
         use_effect(move || {
@@ -110,6 +62,10 @@ pub fn AppFieldServiceMeetings() -> Element {
                     cong_ref,
                     prefs.name_format.as_deref().unwrap_or(""),
                 ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
             });
         });
     }
@@ -166,7 +122,7 @@ pub fn AppFieldServiceMeetings() -> Element {
 
     // Derived
     let is_loading = meetings_res.read().is_none() || users_res.read().is_none();
-    let today = today_iso();
+    let today = today_str();
     let meetings: Vec<FieldServiceMeeting> = meetings_res().unwrap_or_default();
     let users: Vec<User> = users_res().unwrap_or_default();
     let privs: Vec<UserPrivileges> = privs_res().unwrap_or_default();
@@ -193,9 +149,10 @@ pub fn AppFieldServiceMeetings() -> Element {
     // Calendar values
     let year = sel_year();
     let month = sel_month();
+    let (conflicts_from, conflicts_to) = month_range(year, month);
     let total_days = days_in_month(year, month) as u32;
-    // Offset for Monday-first layout: (Sun=0 + 6) % 7 → 6; (Mon=1 + 6) % 7 → 0
-    let offset = (day_of_week(year, month, 1) as u32 + 6) % 7;
+    // Monday-first layout: blank cells before the 1st.
+    let offset = weekday(&iso_date(year, month, 1)).unwrap_or(0) as u32;
 
     // Month picker abbreviations
     let month_picker_labels: Vec<String> = (1u8..=12)
@@ -337,6 +294,15 @@ pub fn AppFieldServiceMeetings() -> Element {
                 }
             }
 
+            ConflictWarnings {
+                key: "{conflicts_from}",
+                from: conflicts_from.clone(),
+                to: conflicts_to.clone(),
+                source: Source::FieldService,
+                name_fmt: name_fmt.read().clone(),
+                date_fmt: date_fmt(),
+            }
+
             // ── Calendar ───────────────────────────────────────────────────
             if is_loading {
                 div { class: "flex-1 flex justify-center py-20 text-gray-400",
@@ -377,7 +343,7 @@ pub fn AppFieldServiceMeetings() -> Element {
                         // Day cells
                         for day in 1..=total_days {
                             {
-                                let date_str = format!("{:04}-{:02}-{:02}", year, month, day);
+                                let date_str = iso_date(year, month, day as u8);
                                 let is_past = date_str < today;
                                 let is_today_day = date_str == today;
                                 let day_meetings = meetings_by_date.get(&date_str).cloned().unwrap_or_default();
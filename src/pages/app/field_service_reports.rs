@@ -6,24 +6,21 @@ use surrealdb::types::RecordId;
 
 use crate::components::ResponsiveModal;
 use crate::database::{use_crypto, use_db};
+use crate::dates::{current_year_month, today_str};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
 use crate::models::congregation::{Congregation, DateFormat, NameFormat};
-use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData};
+use crate::models::field_service_report::{
+    FieldServiceReport, FieldServiceReportData, ReportSource,
+};
+use crate::models::month_close::{CloseAction, MonthClose};
 use crate::models::user::{Appointment, User, UserType};
-use crate::pages::app::user::{effective_date_format, effective_name_format, format_name};
 use crate::pages::app::user_detail::always_show_hours;
+use crate::reports::print_html;
+use crate::Route;
+use crate::reports::s21::{self, S21Card};
+use crate::reports::service_year::{service_year_bounds, service_year_of};
 
-// ── Platform helpers ──────────────────────────────────────────────────────────
-
-#[cfg(target_arch = "wasm32")]
-fn current_year_month() -> (i32, u8) {
-    let d = js_sys::Date::new_0();
-    (d.get_full_year() as i32, (d.get_month() + 1) as u8)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year_month() -> (i32, u8) {
-    (2026, 6)
-}
+// ── Helpers ───────────────────────────────────────────────────────────────────
 
 fn month_name(m: u8) -> &'static str {
     match m {
@@ -180,6 +177,7 @@ pub fn AppFieldServiceReports() -> Element {
     let mut show_picker = use_signal(|| false);
 
     let mut filters = use_signal(Filters::default);
+    let mut printing = use_signal(|| false);
 
     // ── Resources ─────────────────────────────────────────────────────────────
     let mut users_res = use_resource(move || async move {
@@ -200,6 +198,16 @@ pub fn AppFieldServiceReports() -> Element {
         }
     });
 
+    let mut month_close_res = use_resource(move || {
+        let y = sel_year();
+        let m = sel_month();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return None };
+            let crypto = crypto_signal.read().clone();
+            MonthClose::get(&db, &crypto, y, m).await.ok().flatten()
+        }
+    });
+
     let mut restarted = use_signal(|| false);
     use_effect(move || {
         if *restarted.peek() { return; }
@@ -217,6 +225,8 @@ pub fn AppFieldServiceReports() -> Element {
 
     let mut delete_open = use_signal(|| false);
     let mut delete_id: Signal<Option<RecordId>> = use_signal(|| None);
+    // Refused deletes and reviews (e.g. in a closed month).
+    let mut action_error: Signal<Option<String>> = use_signal(|| None);
 
     // ── Computed: join + filter ───────────────────────────────────────────────
     let combined = use_memo(move || {
@@ -280,13 +290,82 @@ pub fn AppFieldServiceReports() -> Element {
 
     let is_loading = users_res.read().is_none() || reports_res.read().is_none();
     let submitted_count = combined.read().iter().filter(|(_, r)| r.is_some()).count();
+    let pending_count = combined
+        .read()
+        .iter()
+        .filter(|(_, r)| r.as_ref().is_some_and(|r| !r.approved))
+        .count();
     let total_count = combined.read().len();
+    let month_state = month_close_res().flatten();
+    let month_closed = month_state.as_ref().is_some_and(|m| m.closed);
 
     rsx! {
         div { class: "space-y-5 w-full pb-10",
 
             // ── Header ────────────────────────────────────────────────────
-            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-field-service-reports")} }
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-field-service-reports")} }
+                div { class: "flex flex-wrap gap-2",
+                    Link {
+                        to: Route::AppBulkReports {},
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors",
+                        "▦ "
+                        {t!("bulk-open-btn")}
+                    }
+                    Link {
+                        to: Route::AppReportsCsv {},
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors",
+                        "⇅ "
+                        {t!("csv-open-btn")}
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors disabled:opacity-50",
+                        disabled: *printing.read(),
+                        onclick: move |_| {
+                            if *printing.peek() {
+                                return;
+                            }
+                            printing.set(true);
+                            let sy = service_year_of(sel_year(), sel_month());
+                            let users = users_res().unwrap_or_default();
+                            let nf = name_fmt.read().clone();
+                            let df = date_fmt.read().clone();
+                            spawn(async move {
+                                let Some(db) = db_signal.read().db.clone() else {
+                                    printing.set(false);
+                                    return;
+                                };
+                                let crypto = crypto_signal.read().clone();
+                                let (from, to) = service_year_bounds(sy);
+                                let reports = FieldServiceReport::by_period(&db, &crypto, from, to)
+                                    .await
+                                    .unwrap_or_default();
+                                let mut publishers: Vec<User> = users
+                                    .into_iter()
+                                    .filter(|u| !matches!(u.user_type, UserType::Student))
+                                    .collect();
+                                publishers.sort_by(|a, b| {
+                                    normalize(&format!("{} {}", a.last_name, a.first_name))
+                                        .cmp(&normalize(&format!("{} {}", b.last_name, b.first_name)))
+                                });
+                                // Inactive (soft-deleted) publishers only get a card when
+                                // they still reported during the service year.
+                                let cards: Vec<S21Card> = publishers
+                                    .into_iter()
+                                    .map(|u| S21Card::build(u, sy, &reports))
+                                    .filter(|c| c.user.active || c.has_reports())
+                                    .collect();
+                                if !cards.is_empty() {
+                                    print_html(s21::render(&cards, &nf, &df));
+                                }
+                                printing.set(false);
+                            });
+                        },
+                        "🖨 "
+                        {t!("s21-print-all-btn")}
+                    }
+                }
+            }
 
             // ── Month / year navigation ───────────────────────────────────
             div { class: "bg-white rounded-xl border border-gray-200 p-4",
@@ -492,9 +571,36 @@ pub fn AppFieldServiceReports() -> Element {
                 div { class: "text-xs text-gray-500 px-1",
                     "{submitted_count} / {total_count} "
                     {t!("reports-submitted-of")}
+                    if pending_count > 0 {
+                        span { class: "ml-2 inline-flex px-1.5 py-0.5 rounded-full bg-amber-100 text-amber-800 font-medium",
+                            "{pending_count} "
+                            {t!("report-pending-count")}
+                        }
+                    }
                 }
             }
 
+            // ── Month close ───────────────────────────────────────────────
+            if !is_loading {
+                // Keyed by month so half-finished actions do not carry over.
+                MonthCloseCard {
+                    key: "{sel_year()}-{sel_month()}",
+                    year: sel_year(),
+                    month: sel_month(),
+                    state: month_state.clone(),
+                    pending: pending_count,
+                    date_fmt: date_fmt.read().clone(),
+                    on_changed: move |_| {
+                        month_close_res.restart();
+                        reports_res.restart();
+                    },
+                }
+            }
+
+            if let Some(err) = action_error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
             // ── Report list ───────────────────────────────────────────────
             if is_loading {
                 div { class: "flex justify-center items-center py-20 text-gray-400",
@@ -514,12 +620,14 @@ pub fn AppFieldServiceReports() -> Element {
                             let u_edit = user.clone();
                             let r_edit = report.clone();
                             let r_del_id = report.as_ref().and_then(|r| r.id.clone());
+                            let r_review_id = r_del_id.clone();
                             let u_del = user.clone();
                             rsx! {
                                 ReportCard {
                                     user: user.clone(),
                                     report: report.clone(),
                                     name_fmt: name_fmt.read().clone(),
+                                    month_closed,
                                     on_view: move |_| {
                                         if let Some(ref r) = r_view {
                                             viewing.set(Some((r.clone(), u_view.clone())));
@@ -549,6 +657,17 @@ pub fn AppFieldServiceReports() -> Element {
                                         // keep reference to user name for confirm dialog (unused here)
                                         let _ = u_del.first_name.clone();
                                     },
+                                    on_review: move |(approved, locked): (bool, bool)| {
+                                        let Some(rid) = r_review_id.clone() else { return };
+                                        spawn(async move {
+                                            let Some(db) = db_signal.read().db.clone() else { return };
+                                            match FieldServiceReport::set_review(&db, rid, approved, locked).await {
+                                                Ok(_) => action_error.set(None),
+                                                Err(e) => action_error.set(Some(e.to_string())),
+                                            }
+                                            reports_res.restart();
+                                        });
+                                    },
                                 }
                             }
                         }
@@ -608,7 +727,10 @@ pub fn AppFieldServiceReports() -> Element {
                                     if let Some(rid) = delete_id.read().clone() {
                                         spawn(async move {
                                             let Some(db) = db_signal.read().db.clone() else { return };
-                                            let _ = FieldServiceReport::delete(&db, rid).await;
+                                            match FieldServiceReport::delete(&db, rid).await {
+                                                Ok(_) => action_error.set(None),
+                                                Err(e) => action_error.set(Some(e.to_string())),
+                                            }
                                             reports_res.restart();
                                         });
                                     }
@@ -631,9 +753,13 @@ fn ReportCard(
     user: User,
     report: Option<FieldServiceReport>,
     name_fmt: NameFormat,
+    /// The month is closed: reports can be viewed but not changed.
+    month_closed: bool,
     on_view: Callback<()>,
     on_edit: Callback<()>,
     on_delete: Callback<()>,
+    /// `(approved, locked)` to store on the report.
+    on_review: Callback<(bool, bool)>,
 ) -> Element {
     let display_name = format_name(&user.first_name, &user.last_name, &name_fmt);
     let type_label = match &user.user_type {
@@ -650,6 +776,11 @@ fn ReportCard(
         Appointment::MinisterialServant => t!("user-appointment-ms"),
     });
     let has_report = report.is_some();
+    let pending = report.as_ref().is_some_and(|r| !r.approved);
+    let locked = report.as_ref().is_some_and(|r| r.locked);
+    let from_portal = report
+        .as_ref()
+        .is_some_and(|r| r.source == ReportSource::Publisher);
 
     rsx! {
         div { class: "bg-white rounded-xl border border-gray-200 p-4",
@@ -666,6 +797,22 @@ fn ReportCard(
                                 "{al}"
                             }
                         }
+                        if from_portal {
+                            span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-sky-100 text-sky-800",
+                                {t!("report-from-portal")}
+                            }
+                        }
+                        if pending {
+                            span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-amber-100 text-amber-800 font-medium",
+                                {t!("report-pending")}
+                            }
+                        }
+                        if locked {
+                            span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-gray-100 text-gray-600",
+                                "🔒 "
+                                {t!("report-locked")}
+                            }
+                        }
                     }
 
                     if let Some(ref r) = report {
@@ -709,28 +856,236 @@ fn ReportCard(
                 }
 
                 // Right: action buttons
-                div { class: "flex gap-1.5 shrink-0",
-                    if has_report {
+                div { class: "flex flex-wrap justify-end gap-1.5 shrink-0",
+                    if month_closed {
+                        if has_report {
+                            button {
+                                class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                onclick: move |_| on_view.call(()),
+                                {t!("report-btn-view")}
+                            }
+                        }
+                    } else {
+                        if pending {
+                            button {
+                                class: "px-2.5 py-1 text-xs bg-emerald-600 text-white rounded-lg hover:bg-emerald-700 transition-colors",
+                                onclick: move |_| on_review.call((true, locked)),
+                                {t!("report-approve-btn")}
+                            }
+                        }
+                        if has_report {
+                            button {
+                                class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                title: if locked { t!("report-unlock-hint") } else { t!("report-lock-hint") },
+                                onclick: move |_| on_review.call((!pending, !locked)),
+                                if locked {
+                                    {t!("report-unlock-btn")}
+                                } else {
+                                    {t!("report-lock-btn")}
+                                }
+                            }
+                            button {
+                                class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                onclick: move |_| on_view.call(()),
+                                {t!("report-btn-view")}
+                            }
+                        }
                         button {
                             class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
-                            onclick: move |_| on_view.call(()),
-                            {t!("report-btn-view")}
+                            onclick: move |_| on_edit.call(()),
+                            if has_report {
+                                {t!("btn-edit")}
+                            } else {
+                                {t!("report-add-btn")}
+                            }
                         }
-                    }
-                    button {
-                        class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
-                        onclick: move |_| on_edit.call(()),
                         if has_report {
-                            {t!("btn-edit")}
-                        } else {
-                            {t!("report-add-btn")}
+                            button {
+                                class: "px-2.5 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50 transition-colors",
+                                onclick: move |_| on_delete.call(()),
+                                {t!("btn-delete")}
+                            }
                         }
                     }
-                    if has_report {
+                }
+            }
+        }
+    }
+}
+
+// ── MonthCloseCard ────────────────────────────────────────────────────────────
+
+/// Close the month once its totals are sent, or reopen it with a reason.
+#[component]
+fn MonthCloseCard(
+    year: i32,
+    month: u8,
+    state: Option<MonthClose>,
+    /// Portal reports still waiting for approval.
+    pending: usize,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let mut confirming = use_signal(|| false);
+    let mut reopening = use_signal(|| false);
+    let mut reason = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let closed = state.as_ref().is_some_and(|m| m.closed);
+    let history = state.map(|m| m.history).unwrap_or_default();
+    let closed_on = history
+        .iter()
+        .rev()
+        .find(|e| e.action == CloseAction::Closed)
+        .map(|e| format_date(&e.date, &date_fmt))
+        .unwrap_or_default();
+
+    let mut run = move |reopen: Option<String>| {
+        if *busy.peek() {
+            return;
+        }
+        busy.set(true);
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                busy.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            let result = match reopen {
+                Some(r) => MonthClose::reopen(&db, &crypto, year, month, today_str(), r).await,
+                None => MonthClose::close(&db, &crypto, year, month, today_str()).await,
+            };
+            match result {
+                Ok(()) => {
+                    confirming.set(false);
+                    reopening.set(false);
+                    reason.set(String::new());
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    let card_cls = if closed {
+        "bg-gray-50 rounded-xl border border-gray-300 p-4 space-y-3"
+    } else {
+        "bg-white rounded-xl border border-gray-200 p-4 space-y-3"
+    };
+
+    rsx! {
+        div { class: card_cls,
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                if closed {
+                    div {
+                        p { class: "text-sm font-semibold text-gray-800",
+                            "🔒 "
+                            {t!("month-closed")}
+                        }
+                        p { class: "text-xs text-gray-500",
+                            {t!("month-closed-on")}
+                            " {closed_on} · "
+                            {t!("month-closed-hint")}
+                        }
+                    }
+                    if !reopening() {
                         button {
-                            class: "px-2.5 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50 transition-colors",
-                            onclick: move |_| on_delete.call(()),
-                            {t!("btn-delete")}
+                            class: "px-3 py-1.5 text-sm border border-gray-300 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors",
+                            onclick: move |_| reopening.set(true),
+                            {t!("month-reopen-btn")}
+                        }
+                    }
+                } else {
+                    p { class: "text-xs text-gray-500", {t!("month-close-hint")} }
+                    if !confirming() {
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors",
+                            onclick: move |_| confirming.set(true),
+                            "🔒 "
+                            {t!("month-close-btn")}
+                        }
+                    }
+                }
+            }
+
+            // Close confirmation
+            if !closed && confirming() {
+                div { class: "rounded-lg bg-gray-50 border border-gray-200 p-3 space-y-2",
+                    p { class: "text-sm text-gray-700", {t!("month-close-confirm")} }
+                    if pending > 0 {
+                        p { class: "text-sm text-amber-700",
+                            "⚠️ {pending} "
+                            {t!("month-close-pending-warning")}
+                        }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-100",
+                            onclick: move |_| confirming.set(false),
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                            disabled: busy(),
+                            onclick: move |_| run(None),
+                            {t!("month-close-btn")}
+                        }
+                    }
+                }
+            }
+
+            // Reopen with a reason
+            if closed && reopening() {
+                div { class: "rounded-lg bg-white border border-gray-200 p-3 space-y-2",
+                    label { class: "block text-sm text-gray-700", {t!("month-reopen-reason")} }
+                    textarea {
+                        class: "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500",
+                        rows: "2",
+                        value: reason(),
+                        oninput: move |e| reason.set(e.value()),
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-100",
+                            onclick: move |_| reopening.set(false),
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700 disabled:opacity-50",
+                            disabled: busy() || reason().trim().is_empty(),
+                            onclick: move |_| run(Some(reason())),
+                            {t!("month-reopen-btn")}
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            if !history.is_empty() {
+                details { class: "text-xs text-gray-500",
+                    summary { class: "cursor-pointer select-none", {t!("month-close-history")} }
+                    ul { class: "mt-2 space-y-1",
+                        for entry in history.iter().rev() {
+                            li {
+                                span { class: "tabular-nums", {format_date(&entry.date, &date_fmt)} }
+                                " · "
+                                if entry.action == CloseAction::Closed {
+                                    {t!("month-history-closed")}
+                                } else {
+                                    {t!("month-history-reopened")}
+                                }
+                                if let Some(r) = &entry.reason {
+                                    span { class: "italic", " — {r}" }
+                                }
+                            }
                         }
                     }
                 }
@@ -861,6 +1216,8 @@ fn ReportEditModal(
 
     let f = form.read().clone();
     let existing_id = target.existing.as_ref().and_then(|r| r.id.clone());
+    let existing_source = target.existing.as_ref().map(|r| r.source).unwrap_or_default();
+    let existing_locked = target.existing.as_ref().is_some_and(|r| r.locked);
     let pub_id = target.publisher_id.clone();
     let year = target.year;
     let month = target.month;
@@ -881,6 +1238,10 @@ fn ReportEditModal(
             bible_studies: fd.bible_studies.trim().parse().ok(),
             auxiliary_pioneer: fd.auxiliary_pioneer,
             preached: fd.preached,
+            // Saving from here counts as the secretary's review.
+            source: existing_source,
+            approved: true,
+            locked: existing_locked,
             notes: (!fd.notes.trim().is_empty()).then(|| fd.notes.trim().to_string()),
         };
         let eid = existing_id.clone();
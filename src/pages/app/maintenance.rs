@@ -1,16 +1,1338 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::today_str;
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::maintenance::{
+    Asset, AssetData, IntervalUnit, PreventiveTask, PreventiveTaskData, WorkOrder, WorkOrderData,
+    WorkOrderStatus,
+};
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::schedule::maintenance::{next_due, order_is_overdue, task_is_overdue};
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Orders,
+    Tasks,
+    Assets,
+}
+
+fn status_label(status: WorkOrderStatus) -> String {
+    match status {
+        WorkOrderStatus::Open => t!("maint-status-open"),
+        WorkOrderStatus::InProgress => t!("maint-status-in-progress"),
+        WorkOrderStatus::Done => t!("maint-status-done"),
+        WorkOrderStatus::Cancelled => t!("maint-status-cancelled"),
+    }
+}
+
+fn status_badge(status: WorkOrderStatus) -> &'static str {
+    match status {
+        WorkOrderStatus::Open => "bg-amber-100 text-amber-800",
+        WorkOrderStatus::InProgress => "bg-blue-100 text-blue-700",
+        WorkOrderStatus::Done => "bg-emerald-100 text-emerald-700",
+        WorkOrderStatus::Cancelled => "bg-gray-100 text-gray-600",
+    }
+}
+
+fn unit_label(unit: IntervalUnit, every: u16) -> String {
+    match (unit, every == 1) {
+        (IntervalUnit::Days, true) => t!("maint-unit-day"),
+        (IntervalUnit::Days, false) => t!("maint-unit-days"),
+        (IntervalUnit::Weeks, true) => t!("maint-unit-week"),
+        (IntervalUnit::Weeks, false) => t!("maint-unit-weeks"),
+        (IntervalUnit::Months, true) => t!("maint-unit-month"),
+        (IntervalUnit::Months, false) => t!("maint-unit-months"),
+        (IntervalUnit::Years, true) => t!("maint-unit-year"),
+        (IntervalUnit::Years, false) => t!("maint-unit-years"),
+    }
+}
+
+fn asset_name(assets: &[Asset], id: Option<&RecordId>) -> String {
+    id.and_then(|id| assets.iter().find(|a| a.id.as_ref() == Some(id)))
+        .map(|a| a.name.clone())
+        .unwrap_or_default()
+}
+
+fn person_name(users: &[User], id: Option<&RecordId>, nf: &NameFormat) -> String {
+    id.and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+        .map(|u| format_name(&u.first_name, &u.last_name, nf))
+        .unwrap_or_default()
+}
+
+/// Assignee choices: brothers and sisters with the maintenance privilege.
+fn assignee_options(
+    users: &[User],
+    privileges: &[UserPrivileges],
+    current: Option<&RecordId>,
+    nf: &NameFormat,
+) -> Vec<(String, String)> {
+    let eligible: Vec<&User> = users
+        .iter()
+        .filter(|u| {
+            privileges
+                .iter()
+                .any(|p| p.maintenance && u.id.as_ref() == Some(&p.publisher))
+        })
+        .collect();
+    person_options(eligible, current, users, nf)
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
 
 #[component]
 pub fn AppMaintenance() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let mut view = use_signal(|| View::Orders);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let mut assets_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        Asset::all(&db).await.unwrap_or_default()
+    });
+
+    let mut tasks_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        PreventiveTask::all(&db).await.unwrap_or_default()
+    });
+
+    let mut orders_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        WorkOrder::all(&db).await.unwrap_or_default()
+    });
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || assets_res.read().is_none()
+        || tasks_res.read().is_none()
+        || orders_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let assets = assets_res().unwrap_or_default();
+    let tasks = tasks_res().unwrap_or_default();
+    let orders = orders_res().unwrap_or_default();
+
+    let today = today_str();
+    let overdue_orders = orders.iter().filter(|o| order_is_overdue(o, &today)).count();
+    let overdue_tasks = tasks.iter().filter(|t| task_is_overdue(t, &today)).count();
+
+    let tab = |active: bool| {
+        if active {
+            "px-3 py-1.5 text-sm rounded-lg bg-primary-600 text-white"
+        } else {
+            "px-3 py-1.5 text-sm rounded-lg text-gray-600 hover:bg-gray-100"
+        }
+    };
+    let orders_tab = tab(view() == View::Orders);
+    let tasks_tab = tab(view() == View::Tasks);
+    let assets_tab = tab(view() == View::Assets);
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-maintenance")} }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
+        div { class: "space-y-5 w-full pb-10",
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-maintenance")} }
+                div { class: "flex gap-1 bg-white border border-gray-200 rounded-lg p-1",
+                    button { class: orders_tab, onclick: move |_| view.set(View::Orders),
+                        {t!("maint-work-orders")}
+                        if overdue_orders > 0 {
+                            span { class: "ml-1.5 px-1.5 rounded-full bg-red-100 text-red-700 text-xs", "{overdue_orders}" }
+                        }
+                    }
+                    button { class: tasks_tab, onclick: move |_| view.set(View::Tasks),
+                        {t!("maint-preventive")}
+                        if overdue_tasks > 0 {
+                            span { class: "ml-1.5 px-1.5 rounded-full bg-red-100 text-red-700 text-xs", "{overdue_tasks}" }
+                        }
+                    }
+                    button { class: assets_tab, onclick: move |_| view.set(View::Assets),
+                        {t!("maint-assets")}
+                    }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("maint-loading")} }
+                }
+            } else {
+                {
+                    match view() {
+                        View::Orders => rsx! {
+                            WorkOrders {
+                                orders,
+                                assets,
+                                users,
+                                privileges,
+                                name_fmt: name_fmt(),
+                                date_fmt: date_fmt(),
+                                on_changed: move |_| orders_res.restart(),
+                            }
+                        },
+                        View::Tasks => rsx! {
+                            PreventiveTasks {
+                                tasks,
+                                assets,
+                                users,
+                                privileges,
+                                name_fmt: name_fmt(),
+                                date_fmt: date_fmt(),
+                                on_changed: move |_| tasks_res.restart(),
+                            }
+                        },
+                        View::Assets => rsx! {
+                            AssetRegister {
+                                assets,
+                                tasks,
+                                orders,
+                                date_fmt: date_fmt(),
+                                on_changed: move |_| assets_res.restart(),
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Shared form pieces ────────────────────────────────────────────────────────
+
+const INPUT_CLS: &str = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+const TEXTAREA_CLS: &str = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500 resize-none";
+
+#[component]
+fn AssetSelect(assets: Vec<Asset>, selected: Option<RecordId>, on_change: Callback<Option<RecordId>>) -> Element {
+    let current = selected.as_ref().map(rid_str).unwrap_or_default();
+    rsx! {
+        label { class: "flex flex-col gap-1 text-xs text-gray-500 min-w-0",
+            span { {t!("maint-asset")} }
+            select {
+                class: INPUT_CLS,
+                onchange: {
+                    let assets = assets.clone();
+                    move |e: Event<FormData>| {
+                        let value = e.value();
+                        on_change.call(
+                            assets.iter().filter_map(|a| a.id.clone()).find(|id| rid_str(id) == value),
+                        )
+                    }
+                },
+                option { value: "", selected: current.is_empty(), "—" }
+                for asset in assets.iter().filter(|a| a.id.is_some()) {
+                    {
+                        let value = asset.id.as_ref().map(rid_str).unwrap_or_default();
+                        let selected = value == current;
+                        rsx! {
+                            option { key: "{value}", value: "{value}", selected, "{asset.name}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── WorkOrders ────────────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+struct OrderForm {
+    id: Option<RecordId>,
+    title: String,
+    description: String,
+    asset: Option<RecordId>,
+    status: WorkOrderStatus,
+    assignee: Option<RecordId>,
+    reported: String,
+    due: String,
+    completed: Option<String>,
+    cost_notes: String,
+}
+
+impl OrderForm {
+    fn new() -> Self {
+        Self {
+            id: None,
+            title: String::new(),
+            description: String::new(),
+            asset: None,
+            status: WorkOrderStatus::Open,
+            assignee: None,
+            reported: today_str(),
+            due: String::new(),
+            completed: None,
+            cost_notes: String::new(),
+        }
+    }
+
+    fn from_order(o: &WorkOrder) -> Self {
+        Self {
+            id: o.id.clone(),
+            title: o.title.clone(),
+            description: o.description.clone(),
+            asset: o.asset.clone(),
+            status: o.status,
+            assignee: o.assignee.clone(),
+            reported: o.reported.clone(),
+            due: o.due.clone().unwrap_or_default(),
+            completed: o.completed.clone(),
+            cost_notes: o.cost_notes.clone(),
+        }
+    }
+
+    /// The completion date is kept while done, set on the day it first
+    /// becomes done and cleared when reopened.
+    fn data(&self) -> WorkOrderData {
+        let completed = match self.status {
+            WorkOrderStatus::Done => self.completed.clone().or_else(|| Some(today_str())),
+            _ => None,
+        };
+        WorkOrderData {
+            title: self.title.trim().to_string(),
+            description: self.description.trim().to_string(),
+            asset: self.asset.clone(),
+            status: self.status,
+            assignee: self.assignee.clone(),
+            reported: self.reported.clone(),
+            due: non_empty(&self.due),
+            completed,
+            cost_notes: self.cost_notes.trim().to_string(),
+        }
+    }
+}
+
+#[component]
+fn WorkOrders(
+    orders: Vec<WorkOrder>,
+    assets: Vec<Asset>,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<OrderForm>> = use_signal(|| None);
+    let mut show_all = use_signal(|| false);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        if f.title.trim().is_empty() {
+            error.set(Some(t!("maint-title-required")));
+            return;
+        }
+        let data = f.data();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => WorkOrder::update(&db, id, data).await.map(|_| ()),
+                None => WorkOrder::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match WorkOrder::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let today = today_str();
+    let shown: Vec<WorkOrder> = orders
+        .iter()
+        .filter(|o| show_all() || o.status.is_pending())
+        .cloned()
+        .collect();
+    let hidden = orders.len() - shown.len();
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                label { class: "flex items-center gap-2 text-sm text-gray-600",
+                    input {
+                        r#type: "checkbox",
+                        checked: show_all(),
+                        onchange: move |_| show_all.set(!show_all()),
+                    }
+                    {t!("maint-show-closed")}
+                    if hidden > 0 {
+                        span { class: "text-xs text-gray-400", "({hidden})" }
+                    }
+                }
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some(),
+                    onclick: move |_| form.set(Some(OrderForm::new())),
+                    {t!("maint-add-order")}
+                }
+            }
+
+            if let Some(f) = form() {
+                div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-title")} }
+                        input {
+                            class: INPUT_CLS,
+                            value: f.title.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.title = e.value();
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-description")} }
+                        textarea {
+                            class: TEXTAREA_CLS,
+                            rows: "3",
+                            value: f.description.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.description = e.value();
+                                }
+                            },
+                        }
+                    }
+                    div { class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                        AssetSelect {
+                            assets: assets.clone(),
+                            selected: f.asset.clone(),
+                            on_change: move |v| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.asset = v;
+                                }
+                            },
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-status")} }
+                            select {
+                                class: INPUT_CLS,
+                                onchange: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.status = WorkOrderStatus::from_key(&e.value());
+                                    }
+                                },
+                                for status in WorkOrderStatus::ALL {
+                                    option {
+                                        value: status.key(),
+                                        selected: status == f.status,
+                                        {status_label(status)}
+                                    }
+                                }
+                            }
+                        }
+                        PersonSelect {
+                            label: t!("maint-assignee"),
+                            options: assignee_options(&users, &privileges, f.assignee.as_ref(), &name_fmt),
+                            selected: f.assignee.clone(),
+                            on_change: move |v| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.assignee = v;
+                                }
+                            },
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-reported")} }
+                            input {
+                                r#type: "date",
+                                class: INPUT_CLS,
+                                value: f.reported.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.reported = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-due")} }
+                            input {
+                                r#type: "date",
+                                class: INPUT_CLS,
+                                value: f.due.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.due = e.value();
+                                    }
+                                },
+                            }
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-cost-notes")} }
+                        textarea {
+                            class: TEXTAREA_CLS,
+                            rows: "2",
+                            placeholder: t!("maint-cost-notes-placeholder"),
+                            value: f.cost_notes.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.cost_notes = e.value();
+                                }
+                            },
+                        }
+                    }
+                    if let Some(err) = error() {
+                        p { class: "text-sm text-red-600", "{err}" }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| {
+                                form.set(None);
+                                error.set(None);
+                            },
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                            disabled: saving(),
+                            onclick: on_save,
+                            {t!("btn-save")}
+                        }
+                    }
+                }
+            }
+
+            if shown.is_empty() && form().is_none() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
                     p { class: "text-4xl mb-3", "🔧" }
-                    p { class: "font-medium text-gray-600", {t!("empty-maintenance-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-maintenance-desc")} }
+                    p { class: "font-medium text-gray-600", {t!("maint-no-orders")} }
+                }
+            }
+
+            for order in shown {
+                {
+                    let id = order.id.clone();
+                    let key = id.as_ref().map(rid_str).unwrap_or_default();
+                    let deleting = id.is_some() && confirm_delete() == id;
+                    let overdue = order_is_overdue(&order, &today);
+                    let badge = status_badge(order.status);
+                    let asset = asset_name(&assets, order.asset.as_ref());
+                    let assignee = person_name(&users, order.assignee.as_ref(), &name_fmt);
+                    let reported = format_date(&order.reported, &date_fmt);
+                    let due = order.due.as_deref().map(|d| format_date(d, &date_fmt));
+                    let completed = order.completed.as_deref().map(|d| format_date(d, &date_fmt));
+                    let due_cls = if overdue { "text-red-600 font-medium" } else { "text-gray-500" };
+                    rsx! {
+                        div { key: "{key}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+                            div { class: "flex flex-wrap items-start justify-between gap-2",
+                                div { class: "min-w-0",
+                                    p { class: "text-sm font-semibold text-gray-900",
+                                        "{order.title}"
+                                        span { class: "ml-2 inline-flex px-2 py-0.5 rounded-full text-xs font-medium {badge}",
+                                            {status_label(order.status)}
+                                        }
+                                    }
+                                    p { class: "text-xs text-gray-500",
+                                        {t!("maint-reported")}
+                                        " {reported}"
+                                        if !asset.is_empty() {
+                                            " · {asset}"
+                                        }
+                                        if !assignee.is_empty() {
+                                            " · {assignee}"
+                                        }
+                                    }
+                                }
+                                div { class: "flex items-center gap-2",
+                                    if deleting {
+                                        span { class: "text-sm text-gray-600 mr-2", {t!("maint-delete-confirm")} }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: move |_| confirm_delete.set(None),
+                                            {t!("btn-cancel")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| {
+                                                    if let Some(id) = id.clone() {
+                                                        on_delete(id);
+                                                    }
+                                                }
+                                            },
+                                            {t!("btn-confirm")}
+                                        }
+                                    } else {
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                            disabled: form().is_some(),
+                                            onclick: {
+                                                let order = order.clone();
+                                                move |_| form.set(Some(OrderForm::from_order(&order)))
+                                            },
+                                            {t!("btn-edit")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| confirm_delete.set(id.clone())
+                                            },
+                                            {t!("btn-delete")}
+                                        }
+                                    }
+                                }
+                            }
+                            if !order.description.is_empty() {
+                                p { class: "text-sm text-gray-700 whitespace-pre-line", "{order.description}" }
+                            }
+                            div { class: "flex flex-wrap gap-x-4 gap-y-1 text-xs",
+                                if let Some(due) = due {
+                                    span { class: due_cls,
+                                        {t!("maint-due")}
+                                        " {due}"
+                                    }
+                                }
+                                if let Some(completed) = completed {
+                                    span { class: "text-emerald-700",
+                                        {t!("maint-completed")}
+                                        " {completed}"
+                                    }
+                                }
+                                if !order.cost_notes.is_empty() {
+                                    span { class: "text-gray-500",
+                                        {t!("maint-cost-notes")}
+                                        ": {order.cost_notes}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── PreventiveTasks ───────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+struct TaskForm {
+    id: Option<RecordId>,
+    title: String,
+    asset: Option<RecordId>,
+    every: u16,
+    unit: IntervalUnit,
+    last_done: String,
+    assignee: Option<RecordId>,
+    notes: String,
+}
+
+impl TaskForm {
+    fn new() -> Self {
+        Self {
+            id: None,
+            title: String::new(),
+            asset: None,
+            every: 1,
+            unit: IntervalUnit::Years,
+            last_done: String::new(),
+            assignee: None,
+            notes: String::new(),
+        }
+    }
+
+    fn from_task(t: &PreventiveTask) -> Self {
+        Self {
+            id: t.id.clone(),
+            title: t.title.clone(),
+            asset: t.asset.clone(),
+            every: t.every,
+            unit: t.unit,
+            last_done: t.last_done.clone().unwrap_or_default(),
+            assignee: t.assignee.clone(),
+            notes: t.notes.clone(),
+        }
+    }
+
+    fn data(&self) -> PreventiveTaskData {
+        PreventiveTaskData {
+            title: self.title.trim().to_string(),
+            asset: self.asset.clone(),
+            every: self.every.max(1),
+            unit: self.unit,
+            last_done: non_empty(&self.last_done),
+            assignee: self.assignee.clone(),
+            notes: self.notes.trim().to_string(),
+        }
+    }
+}
+
+#[component]
+fn PreventiveTasks(
+    tasks: Vec<PreventiveTask>,
+    assets: Vec<Asset>,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<TaskForm>> = use_signal(|| None);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        if f.title.trim().is_empty() {
+            error.set(Some(t!("maint-title-required")));
+            return;
+        }
+        let data = f.data();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => PreventiveTask::update(&db, id, data).await.map(|_| ()),
+                None => PreventiveTask::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    // Record that the task was carried out today.
+    let on_done = move |task: PreventiveTask| {
+        let Some(id) = task.id.clone() else { return };
+        let mut data = PreventiveTaskData::from(task);
+        data.last_done = Some(today_str());
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match PreventiveTask::update(&db, id, data).await {
+                Ok(_) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match PreventiveTask::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let today = today_str();
+    // Most urgent first; tasks never carried out lead.
+    let mut sorted = tasks.clone();
+    sorted.sort_by_key(next_due);
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some(),
+                    onclick: move |_| form.set(Some(TaskForm::new())),
+                    {t!("maint-add-task")}
+                }
+            }
+
+            if let Some(f) = form() {
+                div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-title")} }
+                        input {
+                            class: INPUT_CLS,
+                            placeholder: t!("maint-task-placeholder"),
+                            value: f.title.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.title = e.value();
+                                }
+                            },
+                        }
+                    }
+                    div { class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                        AssetSelect {
+                            assets: assets.clone(),
+                            selected: f.asset.clone(),
+                            on_change: move |v| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.asset = v;
+                                }
+                            },
+                        }
+                        div { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-every")} }
+                            div { class: "flex gap-2",
+                                input {
+                                    r#type: "number",
+                                    min: "1",
+                                    max: "999",
+                                    class: INPUT_CLS,
+                                    value: "{f.every}",
+                                    oninput: move |e| {
+                                        if let Ok(n) = e.value().trim().parse::<u16>() {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.every = n.clamp(1, 999);
+                                            }
+                                        }
+                                    },
+                                }
+                                select {
+                                    class: INPUT_CLS,
+                                    onchange: move |e| {
+                                        if let Some(f) = form.write().as_mut() {
+                                            f.unit = IntervalUnit::from_key(&e.value());
+                                        }
+                                    },
+                                    for unit in IntervalUnit::ALL {
+                                        option {
+                                            value: unit.key(),
+                                            selected: unit == f.unit,
+                                            {unit_label(unit, f.every)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-last-done")} }
+                            input {
+                                r#type: "date",
+                                class: INPUT_CLS,
+                                value: f.last_done.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.last_done = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        PersonSelect {
+                            label: t!("maint-assignee"),
+                            options: assignee_options(&users, &privileges, f.assignee.as_ref(), &name_fmt),
+                            selected: f.assignee.clone(),
+                            on_change: move |v| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.assignee = v;
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-notes")} }
+                        textarea {
+                            class: TEXTAREA_CLS,
+                            rows: "2",
+                            value: f.notes.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.notes = e.value();
+                                }
+                            },
+                        }
+                    }
+                    if let Some(err) = error() {
+                        p { class: "text-sm text-red-600", "{err}" }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| {
+                                form.set(None);
+                                error.set(None);
+                            },
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                            disabled: saving(),
+                            onclick: on_save,
+                            {t!("btn-save")}
+                        }
+                    }
+                }
+            }
+
+            if sorted.is_empty() && form().is_none() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                    p { class: "text-4xl mb-3", "🧯" }
+                    p { class: "font-medium text-gray-600", {t!("maint-no-tasks")} }
+                }
+            }
+
+            for task in sorted {
+                {
+                    let id = task.id.clone();
+                    let key = id.as_ref().map(rid_str).unwrap_or_default();
+                    let deleting = id.is_some() && confirm_delete() == id;
+                    let overdue = task_is_overdue(&task, &today);
+                    let asset = asset_name(&assets, task.asset.as_ref());
+                    let assignee = person_name(&users, task.assignee.as_ref(), &name_fmt);
+                    let interval = format!("{} {} {}", t!("maint-every"), task.every, unit_label(task.unit, task.every));
+                    let last_done = task
+                        .last_done
+                        .as_deref()
+                        .map(|d| format_date(d, &date_fmt))
+                        .unwrap_or_else(|| t!("maint-never"));
+                    let due = next_due(&task)
+                        .map(|d| format_date(&d, &date_fmt))
+                        .unwrap_or_else(|| t!("maint-due-now"));
+                    let due_cls = if overdue { "text-red-600 font-medium" } else { "text-gray-500" };
+                    rsx! {
+                        div { key: "{key}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+                            div { class: "flex flex-wrap items-start justify-between gap-2",
+                                div { class: "min-w-0",
+                                    p { class: "text-sm font-semibold text-gray-900",
+                                        "{task.title}"
+                                        if overdue {
+                                            span { class: "ml-2 inline-flex px-2 py-0.5 rounded-full text-xs font-medium bg-red-100 text-red-700",
+                                                {t!("maint-overdue")}
+                                            }
+                                        }
+                                    }
+                                    p { class: "text-xs text-gray-500",
+                                        "{interval}"
+                                        if !asset.is_empty() {
+                                            " · {asset}"
+                                        }
+                                        if !assignee.is_empty() {
+                                            " · {assignee}"
+                                        }
+                                    }
+                                }
+                                div { class: "flex items-center gap-2",
+                                    if deleting {
+                                        span { class: "text-sm text-gray-600 mr-2", {t!("maint-delete-confirm")} }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: move |_| confirm_delete.set(None),
+                                            {t!("btn-cancel")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| {
+                                                    if let Some(id) = id.clone() {
+                                                        on_delete(id);
+                                                    }
+                                                }
+                                            },
+                                            {t!("btn-confirm")}
+                                        }
+                                    } else {
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-emerald-600 text-white rounded-lg hover:bg-emerald-700",
+                                            onclick: {
+                                                let task = task.clone();
+                                                move |_| on_done(task.clone())
+                                            },
+                                            {t!("maint-mark-done")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                            disabled: form().is_some(),
+                                            onclick: {
+                                                let task = task.clone();
+                                                move |_| form.set(Some(TaskForm::from_task(&task)))
+                                            },
+                                            {t!("btn-edit")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| confirm_delete.set(id.clone())
+                                            },
+                                            {t!("btn-delete")}
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "flex flex-wrap gap-x-4 gap-y-1 text-xs",
+                                span { class: "text-gray-500",
+                                    {t!("maint-last-done")}
+                                    ": {last_done}"
+                                }
+                                span { class: due_cls,
+                                    {t!("maint-next-due")}
+                                    ": {due}"
+                                }
+                            }
+                            if !task.notes.is_empty() {
+                                p { class: "text-sm text-gray-700 whitespace-pre-line", "{task.notes}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Assets ────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Default, PartialEq)]
+struct AssetForm {
+    id: Option<RecordId>,
+    name: String,
+    location: String,
+    notes: String,
+    installed: String,
+}
+
+impl AssetForm {
+    fn from_asset(a: &Asset) -> Self {
+        Self {
+            id: a.id.clone(),
+            name: a.name.clone(),
+            location: a.location.clone(),
+            notes: a.notes.clone(),
+            installed: a.installed.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Register of equipment. Deleting an asset leaves its tasks and work
+/// orders in place, just without an asset.
+#[component]
+fn AssetRegister(
+    assets: Vec<Asset>,
+    tasks: Vec<PreventiveTask>,
+    orders: Vec<WorkOrder>,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<AssetForm>> = use_signal(|| None);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        if f.name.trim().is_empty() {
+            error.set(Some(t!("maint-name-required")));
+            return;
+        }
+        let data = AssetData {
+            name: f.name.trim().to_string(),
+            location: f.location.trim().to_string(),
+            notes: f.notes.trim().to_string(),
+            installed: non_empty(&f.installed),
+        };
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => Asset::update(&db, id, data).await.map(|_| ()),
+                None => Asset::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match Asset::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some(),
+                    onclick: move |_| form.set(Some(AssetForm::default())),
+                    {t!("maint-add-asset")}
+                }
+            }
+
+            if let Some(f) = form() {
+                div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                    div { class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-asset-name")} }
+                            input {
+                                class: INPUT_CLS,
+                                value: f.name.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.name = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-location")} }
+                            input {
+                                class: INPUT_CLS,
+                                value: f.location.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.location = e.value();
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("maint-installed")} }
+                            input {
+                                r#type: "date",
+                                class: INPUT_CLS,
+                                value: f.installed.clone(),
+                                oninput: move |e| {
+                                    if let Some(f) = form.write().as_mut() {
+                                        f.installed = e.value();
+                                    }
+                                },
+                            }
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("maint-notes")} }
+                        textarea {
+                            class: TEXTAREA_CLS,
+                            rows: "2",
+                            placeholder: t!("maint-asset-notes-placeholder"),
+                            value: f.notes.clone(),
+                            oninput: move |e| {
+                                if let Some(f) = form.write().as_mut() {
+                                    f.notes = e.value();
+                                }
+                            },
+                        }
+                    }
+                    if let Some(err) = error() {
+                        p { class: "text-sm text-red-600", "{err}" }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| {
+                                form.set(None);
+                                error.set(None);
+                            },
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                            disabled: saving(),
+                            onclick: on_save,
+                            {t!("btn-save")}
+                        }
+                    }
+                }
+            }
+
+            if assets.is_empty() && form().is_none() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                    p { class: "text-4xl mb-3", "🏷️" }
+                    p { class: "font-medium text-gray-600", {t!("maint-no-assets")} }
+                }
+            }
+
+            for asset in assets.iter().cloned() {
+                {
+                    let id = asset.id.clone();
+                    let key = id.as_ref().map(rid_str).unwrap_or_default();
+                    let deleting = id.is_some() && confirm_delete() == id;
+                    let task_count = tasks.iter().filter(|t| t.asset.is_some() && t.asset == id).count();
+                    let open_count = orders
+                        .iter()
+                        .filter(|o| o.status.is_pending() && o.asset.is_some() && o.asset == id)
+                        .count();
+                    let installed = asset.installed.as_deref().map(|d| format_date(d, &date_fmt));
+                    rsx! {
+                        div { key: "{key}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+                            div { class: "flex flex-wrap items-start justify-between gap-2",
+                                div { class: "min-w-0",
+                                    p { class: "text-sm font-semibold text-gray-900", "{asset.name}" }
+                                    p { class: "text-xs text-gray-500",
+                                        if !asset.location.is_empty() {
+                                            "{asset.location} · "
+                                        }
+                                        "{task_count} "
+                                        {t!("maint-tasks-count")}
+                                        " · {open_count} "
+                                        {t!("maint-open-orders-count")}
+                                    }
+                                }
+                                div { class: "flex items-center gap-2",
+                                    if deleting {
+                                        span { class: "text-sm text-gray-600 mr-2", {t!("maint-asset-delete-confirm")} }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: move |_| confirm_delete.set(None),
+                                            {t!("btn-cancel")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| {
+                                                    if let Some(id) = id.clone() {
+                                                        on_delete(id);
+                                                    }
+                                                }
+                                            },
+                                            {t!("btn-confirm")}
+                                        }
+                                    } else {
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                            disabled: form().is_some(),
+                                            onclick: {
+                                                let asset = asset.clone();
+                                                move |_| form.set(Some(AssetForm::from_asset(&asset)))
+                                            },
+                                            {t!("btn-edit")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| confirm_delete.set(id.clone())
+                                            },
+                                            {t!("btn-delete")}
+                                        }
+                                    }
+                                }
+                            }
+                            if let Some(installed) = installed {
+                                p { class: "text-xs text-gray-500",
+                                    {t!("maint-installed")}
+                                    ": {installed}"
+                                }
+                            }
+                            if !asset.notes.is_empty() {
+                                p { class: "text-sm text-gray-700 whitespace-pre-line", "{asset.notes}" }
+                            }
+                        }
+                    }
                 }
             }
         }
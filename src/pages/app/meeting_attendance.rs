@@ -0,0 +1,372 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_days, add_months, current_year_month, mondays_in_month, weekday};
+use crate::format::{effective_date_format, format_date};
+use crate::models::congregation::{Congregation, DateFormat};
+use crate::models::duty_rota::{DutySettings, MeetingKind};
+use crate::models::event::CongregationEvent;
+use crate::models::meeting_attendance::MeetingAttendance;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::reports::duty_chart::meeting_label;
+use crate::reports::s88::{month_attendance, render, MonthAttendance, S88Year};
+use crate::reports::service_year::service_year_of;
+use crate::reports::{month_label, print_html};
+use crate::schedule::duties::meeting_dates;
+use crate::schedule::recurrence;
+use crate::schedule::weekday_label;
+
+/// Entries for the month's meetings: the stored ones, and blank ones for
+/// meetings not counted yet.
+fn month_entries(
+    dates: &[(String, MeetingKind)],
+    stored: &[MeetingAttendance],
+    prefix: &str,
+) -> Vec<MeetingAttendance> {
+    let mut entries: Vec<MeetingAttendance> = stored
+        .iter()
+        .filter(|e| e.date.starts_with(prefix))
+        .cloned()
+        .collect();
+    for (date, kind) in dates {
+        if date.starts_with(prefix) && !entries.iter().any(|e| &e.date == date && e.meeting == *kind) {
+            entries.push(MeetingAttendance::blank(date.clone(), *kind));
+        }
+    }
+    entries.sort_by(|a, b| a.date.cmp(&b.date));
+    entries
+}
+
+#[component]
+pub fn AppMeetingAttendance() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let settings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return DutySettings::default() };
+        DutySettings::get(&db).await.unwrap_or_default()
+    });
+
+    // Meeting programs and events of the month decide the meeting dates.
+    let programs_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let empty = (vec![], vec![], vec![]);
+            let Some(db) = db_signal.read().db.clone() else { return empty };
+            let crypto = crypto_signal.read().clone();
+            let (Some(from), Some(to)) = (weeks.first(), weeks.last()) else { return empty };
+            let midweek = MidweekMeeting::by_range(&db, from, to).await.unwrap_or_default();
+            let weekend = WeekendMeeting::by_range(&db, &crypto, from, to).await.unwrap_or_default();
+            let until = add_days(to, 6);
+            let rows = CongregationEvent::in_range(&db, from, &until).await.unwrap_or_default();
+            let events = recurrence::expand(&rows, from, &until);
+            (midweek, weekend, events)
+        }
+    });
+
+    // The selected service year and the one before it, as on the S-88.
+    let mut entries_res = use_resource(move || {
+        let sy = service_year_of(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let from = format!("{:04}-09-01", sy - 2);
+            let to = format!("{:04}-08-31", sy);
+            MeetingAttendance::by_range(&db, &from, &to).await.unwrap_or_default()
+        }
+    });
+
+    let is_loading = settings_res.read().is_none()
+        || programs_res.read().is_none()
+        || entries_res.read().is_none();
+    let settings = settings_res().unwrap_or_default();
+    let (midweek_programs, weekend_programs, events) = programs_res().unwrap_or_default();
+    let stored = entries_res().unwrap_or_default();
+    let df = date_fmt();
+
+    let year = sel_year();
+    let month = sel_month();
+    let sy = service_year_of(year, month);
+    let weeks = mondays_in_month(year, month);
+    let dates = meeting_dates(&weeks, &midweek_programs, &weekend_programs, &events, &settings);
+    let prefix = format!("{year:04}-{month:02}");
+    let entries = month_entries(&dates, &stored, &prefix);
+    let kinds = [MeetingKind::Midweek, MeetingKind::Weekend];
+    let summary: Vec<(MeetingKind, MonthAttendance)> = kinds
+        .iter()
+        .map(|&kind| (kind, month_attendance(&stored, kind, year, month)))
+        .collect();
+    let years: Vec<S88Year> = kinds.iter().map(|&kind| S88Year::compute(sy, kind, &stored)).collect();
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+    };
+
+    let on_print = {
+        let stored = stored.clone();
+        move |_| print_html(render(sy, &stored))
+    };
+
+    let month_title = format!("{} {}", month_label(month), year);
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-meeting-attendance")} }
+                button {
+                    class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                    disabled: is_loading,
+                    onclick: on_print,
+                    {t!("att-print-s88")}
+                }
+            }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(-1),
+                    "‹"
+                }
+                p { class: "font-semibold text-gray-900", "{month_title}" }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(1),
+                    "›"
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("att-loading")} }
+                }
+            } else {
+
+                // ── Month summary ─────────────────────────────────────────
+                div { class: "grid grid-cols-1 sm:grid-cols-2 gap-4",
+                    for (kind , m) in summary.iter() {
+                        div { class: "bg-white rounded-xl border border-gray-200 p-5 space-y-2",
+                            p { class: "text-sm font-semibold text-gray-700", {meeting_label(*kind)} }
+                            div { class: "flex items-baseline gap-2",
+                                span { class: "text-4xl font-extrabold text-primary-600 leading-none tabular-nums",
+                                    {format!("{:.0}", m.average())}
+                                }
+                                span { class: "text-xs text-gray-400", {t!("att-average")} }
+                            }
+                            p { class: "text-xs text-gray-500 tabular-nums",
+                                "{m.meetings} "
+                                {t!("att-meetings")}
+                                " · {m.total()} "
+                                {t!("att-total")}
+                                " · {m.in_person} "
+                                {t!("att-in-person")}
+                                " · {m.online} "
+                                {t!("att-online")}
+                            }
+                        }
+                    }
+                }
+
+                // ── Entries ───────────────────────────────────────────────
+                AttendanceForm {
+                    key: "{prefix}-{stored.len()}",
+                    entries: entries.clone(),
+                    date_fmt: df.clone(),
+                    on_saved: move |_| entries_res.restart(),
+                }
+
+                // ── Service year (S-88) ───────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3 overflow-x-auto",
+                    p { class: "text-sm font-semibold text-gray-900",
+                        {format!("{} {}", t!("s88-service-year"), sy)}
+                    }
+                    table { class: "w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-xs text-gray-500",
+                                th { class: "py-1 pr-3", {t!("s88-month")} }
+                                for y in years.iter() {
+                                    th { class: "py-1 pr-3", colspan: "3", {meeting_label(y.meeting)} }
+                                }
+                            }
+                            tr { class: "text-left text-xs text-gray-400",
+                                th {}
+                                for _ in years.iter() {
+                                    th { class: "py-1 pr-3 font-normal", {t!("att-meetings")} }
+                                    th { class: "py-1 pr-3 font-normal", {t!("att-total")} }
+                                    th { class: "py-1 pr-3 font-normal", {t!("att-average")} }
+                                }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for i in 0..12 {
+                                tr { class: "tabular-nums",
+                                    td { class: "py-1.5 pr-3 text-gray-700", {month_label(years[0].months[i].month)} }
+                                    for y in years.iter() {
+                                        {
+                                            let m = y.months[i];
+                                            let (meetings, total, avg) = if m.meetings == 0 {
+                                                (String::new(), String::new(), String::new())
+                                            } else {
+                                                (m.meetings.to_string(), m.total().to_string(), format!("{:.0}", m.average()))
+                                            };
+                                            rsx! {
+                                                td { class: "py-1.5 pr-3 text-gray-500", "{meetings}" }
+                                                td { class: "py-1.5 pr-3 text-gray-500", "{total}" }
+                                                td { class: "py-1.5 pr-3 font-medium text-gray-900", "{avg}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            tr { class: "font-semibold tabular-nums",
+                                td { class: "py-1.5 pr-3 text-gray-700", {t!("s88-year-average")} }
+                                for y in years.iter() {
+                                    td { colspan: "2" }
+                                    td { class: "py-1.5 pr-3 text-gray-900", {format!("{:.0}", y.average())} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── AttendanceForm ────────────────────────────────────────────────────────────
+
+#[component]
+fn AttendanceForm(
+    entries: Vec<MeetingAttendance>,
+    date_fmt: DateFormat,
+    on_saved: EventHandler<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut draft = use_signal(|| entries.clone());
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let entries = draft.peek().clone();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = MeetingAttendance::save_many(&db, entries).await;
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    error.set(None);
+                    on_saved.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let input_cls = "w-24 px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+            div { class: "flex items-center justify-between gap-3",
+                p { class: "text-sm font-semibold text-gray-900", {t!("att-entries")} }
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: on_save,
+                    {t!("btn-save")}
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            if draft().is_empty() {
+                p { class: "text-sm text-gray-500", {t!("att-no-meetings")} }
+            } else {
+                div { class: "overflow-x-auto",
+                    table { class: "w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-xs text-gray-500",
+                                th { class: "py-1 pr-3", {t!("duty-date")} }
+                                th { class: "py-1 pr-3", {t!("duty-meeting")} }
+                                th { class: "py-1 pr-3", {t!("att-in-person")} }
+                                th { class: "py-1 pr-3", {t!("att-online")} }
+                                th { class: "py-1 pr-3", {t!("att-total")} }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for (i , entry) in draft().into_iter().enumerate() {
+                                {
+                                    let day = weekday(&entry.date).map(weekday_label).unwrap_or_default();
+                                    let date = format_date(&entry.date, &date_fmt);
+                                    let total = entry.total();
+                                    rsx! {
+                                        tr { key: "{entry.date}-{i}",
+                                            td { class: "py-1.5 pr-3 text-gray-700 whitespace-nowrap", "{day} {date}" }
+                                            td { class: "py-1.5 pr-3 text-gray-500", {meeting_label(entry.meeting)} }
+                                            td { class: "py-1.5 pr-3",
+                                                input {
+                                                    r#type: "number",
+                                                    min: "0",
+                                                    class: input_cls,
+                                                    value: "{entry.in_person}",
+                                                    oninput: move |e| draft.write()[i].in_person = e.value().trim().parse().unwrap_or(0),
+                                                }
+                                            }
+                                            td { class: "py-1.5 pr-3",
+                                                input {
+                                                    r#type: "number",
+                                                    min: "0",
+                                                    class: input_cls,
+                                                    value: "{entry.online}",
+                                                    oninput: move |e| draft.write()[i].online = e.value().trim().parse().unwrap_or(0),
+                                                }
+                                            }
+                                            td { class: "py-1.5 pr-3 font-medium text-gray-900 tabular-nums", "{total}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                p { class: "text-xs text-gray-400", {t!("att-hint")} }
+            }
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{ls_get, use_crypto, use_db};
+use crate::dates::{add_months, current_year_month, today_str};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::field_service_group::FieldServiceGroup;
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::report_follow_up::{ReportFollowUp, ReportFollowUpData};
+use crate::models::user::User;
+use crate::reports::missing::{
+    dialable, due_date, is_overdue, missing_by_group, reminder_message, url_encode,
+};
+use crate::reports::month_label;
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+/// Publisher the reminder dialog is open for.
+#[derive(Clone, PartialEq)]
+struct ReminderTarget {
+    name: String,
+    phone: Option<String>,
+    email: Option<String>,
+    message: String,
+}
+
+// ── Main page ─────────────────────────────────────────────────────────────────
+
+#[component]
+pub fn AppMissingReports() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    // Reports are collected for the month that just ended.
+    let (cur_year, cur_month) = current_year_month();
+    let (def_year, def_month) = add_months(cur_year, cur_month, -1);
+    let mut sel_year = use_signal(|| def_year);
+    let mut sel_month = use_signal(|| def_month);
+
+    let mut reminder: Signal<Option<ReminderTarget>> = use_signal(|| None);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let mut users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut groups_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        FieldServiceGroup::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut reports_res = use_resource(move || {
+        let y = sel_year();
+        let m = sel_month();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            FieldServiceReport::by_month(&db, &crypto, y, m)
+                .await
+                .unwrap_or_default()
+        }
+    });
+
+    let mut follow_res = use_resource(move || {
+        let y = sel_year();
+        let m = sel_month();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            ReportFollowUp::by_month(&db, &crypto, y, m)
+                .await
+                .unwrap_or_default()
+        }
+    });
+
+    let mut restarted = use_signal(|| false);
+    use_effect(move || {
+        if *restarted.peek() { return; }
+        restarted.set(true);
+        users_res.restart();
+        groups_res.restart();
+        reports_res.restart();
+        follow_res.restart();
+    });
+
+    // ── Computed ──────────────────────────────────────────────────────────────
+    let tracker = use_memo(move || {
+        let users = users_res().unwrap_or_default();
+        let groups = groups_res().unwrap_or_default();
+        let reports = reports_res().unwrap_or_default();
+        let nf = name_fmt();
+        let mut out = missing_by_group(&users, &groups, &reports);
+        for g in out.iter_mut() {
+            g.missing.sort_by_key(|u| format_name(&u.first_name, &u.last_name, &nf));
+        }
+        out
+    });
+
+    let names = use_memo(move || {
+        let nf = name_fmt();
+        users_res()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|u| {
+                let id = u.id.as_ref().map(rid_str)?;
+                Some((id, format_name(&u.first_name, &u.last_name, &nf)))
+            })
+            .collect::<HashMap<String, String>>()
+    });
+
+    let follow_ups = use_memo(move || {
+        follow_res()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|f| (rid_str(&f.publisher), f))
+            .collect::<HashMap<String, ReportFollowUp>>()
+    });
+
+    let is_loading = users_res.read().is_none()
+        || groups_res.read().is_none()
+        || reports_res.read().is_none();
+    let missing_total: usize = tracker.read().iter().map(|g| g.missing.len()).sum();
+    let expected_total: usize = tracker.read().iter().map(|g| g.expected).sum();
+    let due = format_date(&due_date(sel_year(), sel_month()), &date_fmt());
+    let overdue = is_overdue(sel_year(), sel_month(), &today_str());
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-missing-reports")} }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4",
+                div { class: "flex items-center justify-between gap-3",
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 active:bg-gray-100 transition-colors select-none text-xl font-semibold",
+                        onclick: move |_| {
+                            let (y, m) = add_months(sel_year(), sel_month(), -1);
+                            sel_year.set(y);
+                            sel_month.set(m);
+                        },
+                        "‹"
+                    }
+                    span { class: "flex-1 text-center font-semibold text-gray-900",
+                        "{month_label(sel_month())} {sel_year()}"
+                    }
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 active:bg-gray-100 transition-colors select-none text-xl font-semibold",
+                        onclick: move |_| {
+                            let (y, m) = add_months(sel_year(), sel_month(), 1);
+                            sel_year.set(y);
+                            sel_month.set(m);
+                        },
+                        "›"
+                    }
+                }
+                if overdue && missing_total > 0 {
+                    p { class: "mt-3 text-sm text-amber-700 bg-amber-50 border border-amber-200 rounded-lg px-3 py-2",
+                        {format!("{} {due}", t!("missing-overdue-since"))}
+                    }
+                } else {
+                    p { class: "mt-3 text-xs text-gray-400 text-center",
+                        {format!("{} {due}", t!("missing-due-on"))}
+                    }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("reports-loading")} }
+                }
+            } else if expected_total == 0 {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-16 text-center",
+                    p { class: "text-4xl mb-3", "📭" }
+                    p { class: "font-medium text-gray-600", {t!("reports-no-reports")} }
+                }
+            } else {
+                div { class: "text-xs text-gray-500 px-1",
+                    "{missing_total} / {expected_total} "
+                    {t!("missing-count-suffix")}
+                }
+
+                div { class: "space-y-3",
+                    for entry in tracker.read().clone() {
+                        {
+                            let (title, overseer, assistant) = match &entry.group {
+                                Some(g) => (
+                                    g.name.clone(),
+                                    g.overseer.as_ref().and_then(|id| names.read().get(&rid_str(id)).cloned()),
+                                    g.assistant.as_ref().and_then(|id| names.read().get(&rid_str(id)).cloned()),
+                                ),
+                                None => (t!("missing-no-group"), None, None),
+                            };
+                            let missing_count = entry.missing.len();
+                            let expected = entry.expected;
+                            rsx! {
+                                div { class: "bg-white rounded-xl border border-gray-200 overflow-hidden",
+                                    div { class: "px-4 py-3 flex flex-wrap items-start justify-between gap-2 border-b border-gray-100",
+                                        div { class: "min-w-0",
+                                            p { class: "font-semibold text-gray-900", "{title}" }
+                                            if let Some(o) = overseer {
+                                                p { class: "text-xs text-gray-500",
+                                                    {t!("group-role-overseer")}
+                                                    ": {o}"
+                                                }
+                                            }
+                                            if let Some(a) = assistant {
+                                                p { class: "text-xs text-gray-500",
+                                                    {t!("group-role-assistant")}
+                                                    ": {a}"
+                                                }
+                                            }
+                                        }
+                                        if missing_count == 0 {
+                                            span { class: "inline-flex items-center px-2 py-0.5 rounded-full text-xs font-medium bg-emerald-100 text-emerald-700",
+                                                "✓ "
+                                                {t!("missing-all-reported")}
+                                            }
+                                        } else {
+                                            span { class: "inline-flex items-center px-2 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-700",
+                                                "{missing_count} / {expected}"
+                                            }
+                                        }
+                                    }
+                                    if missing_count > 0 {
+                                        div { class: "divide-y divide-gray-100",
+                                            for user in entry.missing.iter().cloned() {
+                                                {
+                                                    let pid = user.id.clone();
+                                                    let key = pid.as_ref().map(rid_str).unwrap_or_default();
+                                                    let follow = follow_ups.read().get(&key).cloned();
+                                                    let name = format_name(&user.first_name, &user.last_name, &name_fmt());
+                                                    let contact = [user.phone.clone(), user.email.clone()]
+                                                        .into_iter()
+                                                        .flatten()
+                                                        .filter(|s| !s.trim().is_empty())
+                                                        .collect::<Vec<_>>()
+                                                        .join(" · ");
+                                                    let target = ReminderTarget {
+                                                        name: name.clone(),
+                                                        phone: user.phone.clone().filter(|p| !p.trim().is_empty()),
+                                                        email: user.email.clone().filter(|e| !e.trim().is_empty()),
+                                                        message: reminder_message(&user.first_name, sel_year(), sel_month()),
+                                                    };
+                                                    let follow_label = follow.as_ref().map(|f| format_date(&f.date, &date_fmt()));
+                                                    let follow_id = follow.as_ref().and_then(|f| f.id.clone());
+                                                    rsx! {
+                                                        div { class: "px-4 py-2.5 flex flex-wrap items-center justify-between gap-2",
+                                                            div { class: "min-w-0",
+                                                                p { class: "text-sm font-medium text-gray-800 truncate", "{name}" }
+                                                                if !contact.is_empty() {
+                                                                    p { class: "text-xs text-gray-400 truncate", "{contact}" }
+                                                                }
+                                                                if let Some(d) = follow_label {
+                                                                    p { class: "text-xs text-emerald-600",
+                                                                        {format!("✓ {} {d}", t!("missing-followed-up-on"))}
+                                                                    }
+                                                                }
+                                                            }
+                                                            div { class: "flex items-center gap-2 shrink-0",
+                                                                button {
+                                                                    class: "px-3 py-1.5 text-xs border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 transition-colors",
+                                                                    onclick: move |_| reminder.set(Some(target.clone())),
+                                                                    {t!("missing-remind-btn")}
+                                                                }
+                                                                if let Some(fid) = follow_id {
+                                                                    button {
+                                                                        class: "px-3 py-1.5 text-xs border border-gray-200 rounded-lg text-gray-500 hover:bg-gray-50 transition-colors",
+                                                                        onclick: move |_| {
+                                                                            let fid = fid.clone();
+                                                                            spawn(async move {
+                                                                                let Some(db) = db_signal.read().db.clone() else { return };
+                                                                                let _ = ReportFollowUp::delete(&db, fid).await;
+                                                                                follow_res.restart();
+                                                                            });
+                                                                        },
+                                                                        {t!("missing-undo-follow-up")}
+                                                                    }
+                                                                } else if let Some(pid) = pid {
+                                                                    button {
+                                                                        class: "px-3 py-1.5 text-xs bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors",
+                                                                        onclick: move |_| {
+                                                                            let pid = pid.clone();
+                                                                            let (y, m) = (sel_year(), sel_month());
+                                                                            spawn(async move {
+                                                                                let Some(db) = db_signal.read().db.clone() else { return };
+                                                                                let crypto = crypto_signal.read().clone();
+                                                                                let marked_by = ls_get("theo_current_user_id")
+                                                                                    .await
+                                                                                    .and_then(|s| RecordId::parse_simple(&s).ok());
+                                                                                let data = ReportFollowUpData {
+                                                                                    publisher: pid,
+                                                                                    year: y,
+                                                                                    month: m,
+                                                                                    marked_by,
+                                                                                    date: today_str(),
+                                                                                    note: None,
+                                                                                };
+                                                                                let _ = ReportFollowUp::create(&db, &crypto, data).await;
+                                                                                follow_res.restart();
+                                                                            });
+                                                                        },
+                                                                        {t!("missing-mark-follow-up")}
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // ── Reminder dialog ────────────────────────────────────────────────
+        if let Some(target) = reminder.read().clone() {
+            ReminderModal { target, on_close: move |_| reminder.set(None) }
+        }
+    }
+}
+
+// ── ReminderModal ─────────────────────────────────────────────────────────────
+
+#[component]
+fn ReminderModal(target: ReminderTarget, on_close: Callback<()>) -> Element {
+    let mut message = use_signal(|| target.message.clone());
+    let mut copied = use_signal(|| false);
+
+    let body = url_encode(&message());
+    let sms_href = target.phone.as_deref().map(|p| format!("sms:{}?body={body}", dialable(p)));
+    let wa_href = target.phone.as_deref().map(|p| {
+        format!("https://wa.me/{}?text={body}", dialable(p).trim_start_matches('+'))
+    });
+    let mail_href = target.email.as_deref().map(|e| {
+        format!(
+            "mailto:{}?subject={}&body={body}",
+            e.trim(),
+            url_encode(&t!("missing-reminder-subject"))
+        )
+    });
+    let link_cls = "flex-1 px-3 py-2 text-sm text-center border border-gray-200 rounded-xl text-gray-700 hover:bg-gray-50 transition-colors";
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-center justify-center p-4 bg-black/40",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "bg-white rounded-2xl shadow-2xl w-full max-w-md p-6 space-y-4",
+                onclick: move |e| e.stop_propagation(),
+                div {
+                    h2 { class: "text-base font-semibold text-gray-900", {t!("missing-reminder-title")} }
+                    p { class: "text-sm text-gray-500", "{target.name}" }
+                }
+                textarea {
+                    class: "w-full h-40 px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500",
+                    value: "{message}",
+                    oninput: move |e| {
+                        message.set(e.value());
+                        copied.set(false);
+                    },
+                }
+                if sms_href.is_none() && mail_href.is_none() {
+                    p { class: "text-xs text-gray-400", {t!("missing-no-contact")} }
+                }
+                div { class: "flex flex-wrap gap-2",
+                    if let Some(href) = sms_href {
+                        a { class: link_cls, href: "{href}", "💬 SMS" }
+                    }
+                    if let Some(href) = wa_href {
+                        a {
+                            class: link_cls,
+                            href: "{href}",
+                            target: "_blank",
+                            rel: "noopener",
+                            "🟢 WhatsApp"
+                        }
+                    }
+                    if let Some(href) = mail_href {
+                        a { class: link_cls, href: "{href}", "✉️ " {t!("missing-email-btn")} }
+                    }
+                }
+                div { class: "flex gap-2 pt-2",
+                    button {
+                        class: "flex-1 px-4 py-2 text-sm border border-gray-200 rounded-xl text-gray-700 hover:bg-gray-50 transition-colors",
+                        onclick: move |_| {
+                            let eval = document::eval(
+                                "let text = await dioxus.recv(); await navigator.clipboard.writeText(text);",
+                            );
+                            let _ = eval.send(message());
+                            copied.set(true);
+                        },
+                        if copied() {
+                            {t!("missing-copied")}
+                        } else {
+                            {t!("missing-copy-btn")}
+                        }
+                    }
+                    button {
+                        class: "flex-1 px-4 py-2 text-sm bg-primary-600 text-white rounded-xl hover:bg-primary-700 transition-colors font-medium",
+                        onclick: move |_| on_close.call(()),
+                        {t!("btn-close")}
+                    }
+                }
+            }
+        }
+    }
+}
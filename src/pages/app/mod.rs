@@ -1,19 +1,28 @@
 pub mod absences;
 pub mod attendants;
 pub mod av_platform;
+pub mod bulk_reports;
 pub mod cleaning;
+pub mod co_visit;
+pub mod conflicts;
 pub mod congregation_permissions;
 pub mod congregation_settings;
 pub mod custom;
 pub mod dashboard;
+pub mod duty_rota;
 pub mod events;
 pub mod field_service_groups;
 pub mod field_service_meetings;
 pub mod field_service_reports;
 pub mod maintenance;
+pub mod meeting_attendance;
+pub mod missing_reports;
+pub mod monthly_totals;
+pub mod pioneers;
 pub mod privileges;
 pub mod public_preaching;
 pub mod public_talks;
+pub mod reports_csv;
 pub mod territory;
 pub mod user;
 pub mod user_detail;
@@ -25,7 +34,10 @@ pub mod user_settings;
 pub use absences::AppAbsences;
 pub use attendants::AppAttendants;
 pub use av_platform::AppAvPlatform;
+pub use bulk_reports::AppBulkReports;
 pub use cleaning::AppCleaning;
+pub use co_visit::AppCoVisit;
+pub use conflicts::AppConflicts;
 pub use congregation_permissions::AppCongregationPermissions;
 pub use congregation_settings::AppCongregationSettings;
 pub use dashboard::AppDashboard;
@@ -33,6 +45,11 @@ pub use field_service_groups::AppFieldServiceGroups;
 pub use field_service_meetings::AppFieldServiceMeetings;
 pub use field_service_reports::AppFieldServiceReports;
 pub use maintenance::AppMaintenance;
+pub use meeting_attendance::AppMeetingAttendance;
+pub use missing_reports::AppMissingReports;
+pub use monthly_totals::AppMonthlyTotals;
+pub use pioneers::AppPioneers;
+pub use reports_csv::AppReportsCsv;
 pub use custom::AppCustom;
 pub use events::AppEvents;
 pub use privileges::AppPrivileges;
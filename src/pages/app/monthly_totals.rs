@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_months, current_year_month};
+use crate::format::{effective_name_format, format_name};
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::user::User;
+use crate::reports::analytics::ACTIVE_WINDOW_MONTHS;
+use crate::reports::s1::{self, S1Category, S1Summary};
+use crate::reports::status::PublisherStatus;
+use crate::reports::{month_label, print_html};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+// ── Main page ─────────────────────────────────────────────────────────────────
+
+#[component]
+pub fn AppMonthlyTotals() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    // Default to the previous month: that is the one being sent to the branch.
+    let (cur_year, cur_month) = current_year_month();
+    let (def_year, def_month) = add_months(cur_year, cur_month, -1);
+    let mut sel_year = use_signal(|| def_year);
+    let mut sel_month = use_signal(|| def_month);
+    let mut expanded: Signal<Option<S1Category>> = use_signal(|| None);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let mut users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut summary_res = use_resource(move || {
+        let y = sel_year();
+        let m = sel_month();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return None };
+            let crypto = crypto_signal.read().clone();
+            S1Summary::load(&db, &crypto, y, m).await.ok()
+        }
+    });
+
+    let mut restarted = use_signal(|| false);
+    use_effect(move || {
+        if *restarted.peek() { return; }
+        restarted.set(true);
+        users_res.restart();
+        summary_res.restart();
+    });
+
+    let names = use_memo(move || {
+        let nf = name_fmt();
+        users_res()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|u| {
+                let id = u.id.as_ref().map(rid_str)?;
+                Some((id, format_name(&u.first_name, &u.last_name, &nf)))
+            })
+            .collect::<HashMap<String, String>>()
+    });
+
+    let summary = summary_res.read().clone().flatten();
+    let is_loading = summary_res.read().is_none();
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-monthly-totals")} }
+                button {
+                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50 transition-colors disabled:opacity-50",
+                    disabled: summary.is_none(),
+                    onclick: move |_| {
+                        let Some(s) = summary_res.read().clone().flatten() else { return };
+                        let cong_name = congregation_res
+                            .read()
+                            .clone()
+                            .flatten()
+                            .map(|c| c.name)
+                            .unwrap_or_default();
+                        print_html(s1::render(&s, &cong_name));
+                    },
+                    "🖨 "
+                    {t!("s1-print-btn")}
+                }
+            }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4",
+                div { class: "flex items-center justify-between gap-3",
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 active:bg-gray-100 transition-colors select-none text-xl font-semibold",
+                        onclick: move |_| {
+                            let (y, m) = add_months(sel_year(), sel_month(), -1);
+                            sel_year.set(y);
+                            sel_month.set(m);
+                            expanded.set(None);
+                        },
+                        "‹"
+                    }
+                    span { class: "flex-1 text-center font-semibold text-gray-900",
+                        "{month_label(sel_month())} {sel_year()}"
+                    }
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 active:bg-gray-100 transition-colors select-none text-xl font-semibold",
+                        onclick: move |_| {
+                            let (y, m) = add_months(sel_year(), sel_month(), 1);
+                            sel_year.set(y);
+                            sel_month.set(m);
+                            expanded.set(None);
+                        },
+                        "›"
+                    }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("reports-loading")} }
+                }
+            } else if let Some(s) = summary {
+                // ── Active publishers ─────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                    div {
+                        p { class: "text-sm font-medium text-gray-700", {t!("s1-active-publishers")} }
+                        p { class: "text-xs text-gray-400",
+                            {format!("{} {}", ACTIVE_WINDOW_MONTHS, t!("s1-active-hint"))}
+                        }
+                    }
+                    span { class: "text-3xl font-bold text-primary-700", "{s.active_publishers}" }
+                }
+
+                // ── Activity status ───────────────────────────────────────
+                div { class: "grid grid-cols-2 sm:grid-cols-4 gap-2",
+                    for status in [
+                        PublisherStatus::Active,
+                        PublisherStatus::Irregular,
+                        PublisherStatus::Inactive,
+                        PublisherStatus::Reactivated,
+                    ]
+                    {
+                        div { class: "bg-white rounded-xl border border-gray-200 px-4 py-3 flex items-center justify-between gap-2",
+                            span { class: "inline-flex px-2 py-0.5 rounded-full text-xs font-medium {status.badge_class()}",
+                                {status.label()}
+                            }
+                            span { class: "text-xl font-bold text-gray-900 tabular-nums",
+                                "{s.statuses.get(status)}"
+                            }
+                        }
+                    }
+                }
+
+                // ── Categories ────────────────────────────────────────────
+                div { class: "space-y-2",
+                    for cat in [
+                        S1Category::Publishers,
+                        S1Category::AuxiliaryPioneers,
+                        S1Category::RegularPioneers,
+                        S1Category::ReportedToBranch,
+                    ]
+                    {
+                        {
+                            let totals = s.category(cat).clone();
+                            let is_open = expanded() == Some(cat);
+                            let show_hours = cat != S1Category::Publishers;
+                            let mut rows: Vec<(String, Option<u32>, Option<u32>)> = totals
+                                .reports
+                                .iter()
+                                .map(|r| {
+                                    (
+                                        names.read().get(&rid_str(&r.publisher)).cloned().unwrap_or_default(),
+                                        r.hours,
+                                        r.bible_studies,
+                                    )
+                                })
+                                .collect();
+                            rows.sort_by(|a, b| a.0.cmp(&b.0));
+                            rsx! {
+                                div { class: "bg-white rounded-xl border border-gray-200 overflow-hidden",
+                                    button {
+                                        class: "w-full px-4 py-3 flex items-center justify-between gap-3 text-left hover:bg-gray-50 transition-colors",
+                                        onclick: move |_| {
+                                            expanded.set(if is_open { None } else { Some(cat) });
+                                        },
+                                        span { class: "font-medium text-gray-900", {cat.label()} }
+                                        div { class: "flex items-center gap-4 text-sm text-gray-600",
+                                            span { "{totals.reporting} " {t!("s1-col-reports")} }
+                                            if show_hours {
+                                                span { "{totals.hours} " {t!("report-form-hours")} }
+                                            }
+                                            span { "{totals.bible_studies} " {t!("report-form-bible-studies")} }
+                                            span { class: "text-xs text-gray-400",
+                                                if is_open {
+                                                    "▴"
+                                                } else {
+                                                    "▾"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if is_open {
+                                        if rows.is_empty() {
+                                            p { class: "px-4 pb-3 text-sm text-gray-400", {t!("s1-no-reports")} }
+                                        } else {
+                                            div { class: "border-t border-gray-100 divide-y divide-gray-100",
+                                                for (name , hours , studies) in rows {
+                                                    div { class: "px-4 py-2 flex items-center justify-between gap-3 text-sm",
+                                                        span { class: "text-gray-800", "{name}" }
+                                                        div { class: "flex items-center gap-4 text-gray-500",
+                                                            if show_hours {
+                                                                span { {format!("{} h", hours.unwrap_or(0))} }
+                                                            }
+                                                            span { {format!("{} {}", studies.unwrap_or(0), t!("s1-studies-short"))} }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                p { class: "text-xs text-gray-400 px-1", {t!("s1-branch-hint")} }
+            } else {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-16 text-center",
+                    p { class: "text-4xl mb-3", "🧮" }
+                    p { class: "font-medium text-gray-600", {t!("reports-no-reports")} }
+                }
+            }
+        }
+    }
+}
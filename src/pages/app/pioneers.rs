@@ -0,0 +1,314 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_months, current_year_month};
+use crate::format::{effective_name_format, format_name};
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::pioneer::PioneerSettings;
+use crate::models::user::User;
+use crate::reports::month_label;
+use crate::reports::pioneer::{PioneerProgress, pioneer_progress};
+use crate::reports::service_year::{service_year_bounds, service_year_of};
+use crate::Route;
+
+// ── Main page ─────────────────────────────────────────────────────────────────
+
+#[component]
+pub fn AppPioneers() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    // Progress is measured up to the last month whose reports are due.
+    let (cur_year, cur_month) = current_year_month();
+    let last_due = add_months(cur_year, cur_month, -1);
+    let mut sel_sy = use_signal(|| service_year_of(last_due.0, last_due.1));
+
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut settings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return PioneerSettings::default() };
+        PioneerSettings::get(&db).await.unwrap_or_default()
+    });
+
+    let reports_res = use_resource(move || {
+        let sy = sel_sy();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            let (from, to) = service_year_bounds(sy);
+            FieldServiceReport::by_period(&db, &crypto, from, to)
+                .await
+                .unwrap_or_default()
+        }
+    });
+
+    let is_loading = users_res.read().is_none()
+        || reports_res.read().is_none()
+        || settings_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let settings = settings_res().unwrap_or_default();
+    let sy = sel_sy();
+    let until = service_year_bounds(sy).1.min(last_due);
+    let mut progress =
+        pioneer_progress(&users, &reports_res().unwrap_or_default(), &settings, sy, until);
+    // Furthest behind first.
+    progress.sort_by(|a, b| {
+        b.needs_attention()
+            .cmp(&a.needs_attention())
+            .then(b.months_behind().total_cmp(&a.months_behind()))
+    });
+    let alerts = progress.iter().filter(|p| p.needs_attention()).count();
+    let nf = name_fmt.read().clone();
+    let name_of = |p: &PioneerProgress| {
+        users
+            .iter()
+            .find(|u| u.id.as_ref() == Some(&p.publisher))
+            .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+            .unwrap_or_default()
+    };
+    let rows: Vec<(String, PioneerProgress)> = progress.iter().map(|p| (name_of(p), p.clone())).collect();
+    let through = format!("{} {}", month_label(until.1), until.0);
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-pioneers")} }
+                Link {
+                    to: Route::AppFieldServiceReports {},
+                    class: "text-sm text-primary-600 hover:underline",
+                    {t!("page-field-service-reports")}
+                }
+            }
+
+            // ── Service year navigation ───────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| sel_sy.set(sel_sy() - 1),
+                    "‹"
+                }
+                div { class: "text-center",
+                    p { class: "font-semibold text-gray-900",
+                        {format!("{} {}", t!("dash-sy-title"), sy)}
+                    }
+                    p { class: "text-xs text-gray-500",
+                        {t!("pioneer-through")}
+                        " {through}"
+                    }
+                }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold disabled:opacity-40",
+                    disabled: sy >= service_year_of(last_due.0, last_due.1),
+                    onclick: move |_| sel_sy.set(sel_sy() + 1),
+                    "›"
+                }
+            }
+
+            if !is_loading {
+                CreditLimitForm { settings, on_changed: move |_| settings_res.restart() }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("reports-loading")} }
+                }
+            } else if rows.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-16 text-center",
+                    p { class: "text-4xl mb-3", "⏱️" }
+                    p { class: "font-medium text-gray-600", {t!("pioneer-none")} }
+                }
+            } else {
+                if alerts > 0 {
+                    div { class: "bg-amber-50 border border-amber-200 rounded-xl px-4 py-3 text-sm text-amber-900",
+                        "⚠️ {alerts} "
+                        {t!("pioneer-alerts")}
+                    }
+                }
+                div { class: "space-y-2",
+                    for (name , p) in rows {
+                        PioneerCard { name, progress: p }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── CreditLimitForm ───────────────────────────────────────────────────────────
+
+#[component]
+fn CreditLimitForm(settings: PioneerSettings, on_changed: Callback<()>) -> Element {
+    let db_signal = use_db();
+    let mut limit = use_signal(|| settings.credit_limit.map(|l| l.to_string()).unwrap_or_default());
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        let credit_limit = match limit().trim() {
+            "" => None,
+            s => match s.parse::<u32>() {
+                Ok(n) => Some(n),
+                Err(_) => {
+                    error.set(Some(t!("pioneer-credit-limit-invalid")));
+                    return;
+                }
+            },
+        };
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match PioneerSettings::upsert(&db, &PioneerSettings { credit_limit }).await {
+                Ok(()) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 flex flex-wrap items-end gap-3",
+            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                span { {t!("pioneer-credit-limit")} }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    max: "744",
+                    class: "w-24 px-2 py-1.5 text-sm border border-gray-200 rounded-lg",
+                    value: limit(),
+                    oninput: move |e| limit.set(e.value()),
+                }
+            }
+            button {
+                class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                onclick: on_save,
+                {t!("btn-save")}
+            }
+            p { class: "text-xs text-gray-400 basis-full", {t!("pioneer-credit-limit-hint")} }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+        }
+    }
+}
+
+// ── PioneerCard ───────────────────────────────────────────────────────────────
+
+#[component]
+fn PioneerCard(name: String, progress: PioneerProgress) -> Element {
+    let total = progress.total();
+    let goal = progress.annual_goal;
+    let pct = if goal == 0 { 0.0 } else { (total as f64 / goal as f64 * 100.0).min(100.0) };
+    let expected_pct = if goal == 0 {
+        0.0
+    } else {
+        (progress.expected_to_date() as f64 / goal as f64 * 100.0).min(100.0)
+    };
+    let projected = progress.projected().round() as u32;
+    let behind = progress.months_behind();
+    let alert = progress.needs_attention();
+    let bar_cls = if alert { "bg-amber-500" } else { "bg-primary-600" };
+    let card_cls = if alert {
+        "bg-white rounded-xl border border-amber-300 p-4 space-y-3"
+    } else {
+        "bg-white rounded-xl border border-gray-200 p-4 space-y-3"
+    };
+
+    rsx! {
+        div { class: card_cls,
+            div { class: "flex flex-wrap items-center justify-between gap-2",
+                div { class: "flex flex-wrap items-center gap-1.5",
+                    span { class: "text-sm font-semibold text-gray-900", "{name}" }
+                    span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-purple-100 text-purple-800",
+                        {progress.kind.label()}
+                    }
+                }
+                span { class: "text-sm tabular-nums text-gray-700",
+                    span { class: "font-semibold", "{total}" }
+                    " / {goal} h"
+                }
+            }
+
+            // Progress bar with a marker for where the pace should be.
+            div { class: "relative h-2 rounded-full bg-gray-100 overflow-hidden",
+                div {
+                    class: "absolute inset-y-0 left-0 rounded-full {bar_cls}",
+                    style: "width: {pct:.1}%",
+                }
+                div {
+                    class: "absolute inset-y-0 w-0.5 bg-gray-500",
+                    style: "left: {expected_pct:.1}%",
+                    title: t!("pioneer-pace-marker"),
+                }
+            }
+
+            div { class: "flex flex-wrap gap-x-4 gap-y-1 text-xs text-gray-600",
+                if progress.kind.is_yearly() {
+                    span {
+                        {t!("pioneer-projected")}
+                        ": "
+                        span { class: "font-medium text-gray-800", "{projected} h" }
+                    }
+                }
+                span {
+                    {t!("pioneer-credits")}
+                    ": "
+                    span { class: "font-medium text-gray-800", "{progress.credits_counted()}" }
+                    " / {progress.credits_reported()} "
+                    {t!("pioneer-credits-counted")}
+                }
+                if behind > 0.0 {
+                    span { class: if alert { "font-medium text-amber-700" } else { "" },
+                        {format!("{behind:.1} ")}
+                        {t!("pioneer-months-behind")}
+                    }
+                } else {
+                    span { class: "text-emerald-700", {t!("pioneer-on-pace")} }
+                }
+            }
+
+            // Month strip: requirement met or missed.
+            div { class: "flex flex-wrap gap-1",
+                for m in progress.months.iter() {
+                    {
+                        let cls = if m.met() {
+                            "px-1.5 py-0.5 rounded text-[10px] bg-emerald-100 text-emerald-800"
+                        } else {
+                            "px-1.5 py-0.5 rounded text-[10px] bg-red-100 text-red-700"
+                        };
+                        let label: String = month_label(m.month).chars().take(3).collect();
+                        let tip = format!("{} h + {} / {}", m.hours, m.credits_counted, m.goal);
+                        rsx! {
+                            span { class: cls, title: tip, "{label} {m.counted()}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,9 +3,9 @@ use dioxus_i18n::t;
 use surrealdb::types::RecordId;
 
 use crate::database::{use_crypto, use_db};
+use crate::format::{format_name, effective_name_format};
 use crate::models::privilege::{UserPrivileges, UserPrivilegesData, PRIV_TOTAL};
 use crate::models::user::{Appointment, Gender, User, UserType};
-use crate::pages::app::user::{format_name, effective_name_format};
 use crate::models::congregation::{Congregation, NameFormat};
 
 // ── Helper ────────────────────────────────────────────────────────────────────
@@ -61,6 +61,7 @@ struct PrivilegeFlags {
     weekend_pray: bool,
     weekend_chairman: bool,
     watchtower_conductor: bool,
+    watchtower_reader: bool,
     public_talks: bool,
     public_talks_away: bool,
     // Departments
@@ -101,6 +102,7 @@ impl PrivilegeFlags {
             weekend_pray: p.weekend_pray,
             weekend_chairman: p.weekend_chairman,
             watchtower_conductor: p.watchtower_conductor,
+            watchtower_reader: p.watchtower_reader,
             public_talks: p.public_talks,
             public_talks_away: p.public_talks_away,
             stage: p.stage,
@@ -128,6 +130,7 @@ impl PrivilegeFlags {
             self.student_talk, self.living_as_christians,
             self.congregation_bible_study, self.congregation_bible_study_reader,
             self.weekend_pray, self.weekend_chairman, self.watchtower_conductor,
+            self.watchtower_reader,
             self.public_talks, self.public_talks_away,
             self.stage, self.audio, self.video, self.microphones,
             self.attendant, self.zoom_attendant,
@@ -160,6 +163,7 @@ impl PrivilegeFlags {
             weekend_pray: self.weekend_pray,
             weekend_chairman: self.weekend_chairman,
             watchtower_conductor: self.watchtower_conductor,
+            watchtower_reader: self.watchtower_reader,
             public_talks: self.public_talks,
             public_talks_away: self.public_talks_away,
             stage: self.stage,
@@ -198,7 +202,7 @@ enum PrivFieldFilter {
     FieldMinistryDiscussion, StartingConversation, FollowingUp,
     MakingDisciples, Assistant, StudentTalk,
     LivingAsChristians, CongregationBibleStudy, CongregationBibleStudyReader,
-    WeekendPray, WeekendChairman, WatchtowerConductor,
+    WeekendPray, WeekendChairman, WatchtowerConductor, WatchtowerReader,
     PublicTalks, PublicTalksAway,
     Stage, Audio, Video, Microphones, Attendant, ZoomAttendant,
     Hospitality, Interpreter, FieldServiceMeeting,
@@ -226,6 +230,7 @@ impl PrivFieldFilter {
             "weekend_pray" => Self::WeekendPray,
             "weekend_chairman" => Self::WeekendChairman,
             "watchtower_conductor" => Self::WatchtowerConductor,
+            "watchtower_reader" => Self::WatchtowerReader,
             "public_talks" => Self::PublicTalks,
             "public_talks_away" => Self::PublicTalksAway,
             "stage" => Self::Stage,
@@ -267,6 +272,7 @@ fn has_privilege(p: &UserPrivileges, field: &PrivFieldFilter) -> bool {
         PrivFieldFilter::WeekendPray => p.weekend_pray,
         PrivFieldFilter::WeekendChairman => p.weekend_chairman,
         PrivFieldFilter::WatchtowerConductor => p.watchtower_conductor,
+        PrivFieldFilter::WatchtowerReader => p.watchtower_reader,
         PrivFieldFilter::PublicTalks => p.public_talks,
         PrivFieldFilter::PublicTalksAway => p.public_talks_away,
         PrivFieldFilter::Stage => p.stage,
@@ -506,6 +512,7 @@ pub fn AppPrivileges() -> Element {
                         option { value: "weekend_pray", {t!("priv-weekend-pray")} }
                         option { value: "weekend_chairman", {t!("priv-weekend-chairman")} }
                         option { value: "watchtower_conductor", {t!("priv-watchtower-conductor")} }
+                        option { value: "watchtower_reader", {t!("priv-watchtower-reader")} }
                         option { value: "public_talks", {t!("priv-public-talks")} }
                         option { value: "public_talks_away", {t!("priv-public-talks-away")} }
                     }
@@ -871,6 +878,11 @@ fn PrivilegeEditModal(
                                 checked: f.watchtower_conductor,
                                 on_change: move |v| flags.write().watchtower_conductor = v,
                             }
+                            PrivSwitch {
+                                label: t!("priv-watchtower-reader"),
+                                checked: f.watchtower_reader,
+                                on_change: move |v| flags.write().watchtower_reader = v,
+                            }
                             PrivSwitch {
                                 label: t!("priv-public-talks"),
                                 checked: f.public_talks,
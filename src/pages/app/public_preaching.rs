@@ -1,21 +1,1401 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{ls_get, use_crypto, use_db};
+use crate::dates::{add_days, today_str, week_start};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::absence::Absence;
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_witnessing::{
+    ShiftOccurrence, ShiftReport, WitnessingLocation, WitnessingLocationData, WitnessingShift,
+    WitnessingShiftData,
+};
+use crate::models::user::User;
+use crate::pages::app::conflicts::ConflictWarnings;
+use crate::pages::app::territory::LeafletMap;
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::schedule::conflicts::Source;
+use crate::schedule::midweek::eligible;
+use crate::schedule::rotation::{propose, Pick, Reason};
+use crate::schedule::witnessing::{
+    apply_picks, assignments, check_signup, issue, pairing_slots, week_dates,
+};
+use crate::schedule::weekday_label;
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Schedule,
+    Locations,
+    Shifts,
+}
+
+/// Weeks of sign-ups looked back on when pairing, for fair rotation.
+const HISTORY_WEEKS: i64 = 12;
+
+/// Map markers for every location with a point, as the JSON [`LeafletMap`]
+/// expects.
+fn locations_to_json(locations: &[WitnessingLocation]) -> String {
+    let items: Vec<String> = locations
+        .iter()
+        .filter_map(|l| {
+            Some(format!(
+                r#"{{"lat":{},"lng":{},"description":{:?}}}"#,
+                l.lat?, l.lng?, l.name
+            ))
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Middle of the located points, or somewhere neutral when there are none.
+fn locations_center(locations: &[WitnessingLocation]) -> (f64, f64) {
+    let points: Vec<(f64, f64)> = locations.iter().filter_map(|l| Some((l.lat?, l.lng?))).collect();
+    if points.is_empty() {
+        return (20.0, 0.0);
+    }
+    let n = points.len() as f64;
+    (
+        points.iter().map(|p| p.0).sum::<f64>() / n,
+        points.iter().map(|p| p.1).sum::<f64>() / n,
+    )
+}
+
+/// Occurrences of the week starting `week`: the stored ones, and blank ones
+/// for shifts nobody signed up for yet.
+fn week_occurrences(
+    week: &str,
+    shifts: &[WitnessingShift],
+    stored: &[ShiftOccurrence],
+) -> Vec<ShiftOccurrence> {
+    week_dates(week, shifts)
+        .into_iter()
+        .filter_map(|(date, shift)| {
+            let id = shift.id.clone()?;
+            Some(
+                stored
+                    .iter()
+                    .find(|o| o.shift == id && o.date == date)
+                    .cloned()
+                    .unwrap_or_else(|| ShiftOccurrence::blank(id, date)),
+            )
+        })
+        .collect()
+}
 
 #[component]
 pub fn AppPublicPreaching() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    // The signed-in publisher, for "sign me up".
+    let mut me: Signal<Option<String>> = use_signal(|| None);
+    use_effect(move || {
+        spawn(async move {
+            me.set(ls_get("theo_current_user_id").await);
+        });
+    });
+
+    let mut week = use_signal(|| week_start(&today_str()));
+    let mut view = use_signal(|| View::Schedule);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let absences_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        Absence::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut locations_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        WitnessingLocation::all(&db).await.unwrap_or_default()
+    });
+
+    let mut shifts_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        WitnessingShift::all(&db).await.unwrap_or_default()
+    });
+
+    // The week's occurrences plus the weeks before it, for fair rotation.
+    let mut occurrences_res = use_resource(move || {
+        let week = week();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let from = add_days(&week, -7 * HISTORY_WEEKS);
+            ShiftOccurrence::by_range(&db, &from, &add_days(&week, 6)).await.unwrap_or_default()
+        }
+    });
+
+    let mut proposal: Signal<Option<(Vec<ShiftOccurrence>, Vec<Pick>)>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || absences_res.read().is_none()
+        || locations_res.read().is_none()
+        || shifts_res.read().is_none()
+        || occurrences_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let absences = absences_res().unwrap_or_default();
+    let locations = locations_res().unwrap_or_default();
+    let shifts = shifts_res().unwrap_or_default();
+    let stored = occurrences_res().unwrap_or_default();
+    let nf = name_fmt();
+    let df = date_fmt();
+    let week_iso = week();
+    let week_end = add_days(&week_iso, 6);
+
+    let occurrences = week_occurrences(&week_iso, &shifts, &stored);
+    let history = assignments(
+        &stored.iter().filter(|o| o.date < week_iso).cloned().collect::<Vec<_>>(),
+    );
+    let me_id: Option<RecordId> = me().and_then(|s| {
+        users
+            .iter()
+            .filter_map(|u| u.id.clone())
+            .find(|id| rid_str(id) == s)
+    });
+
+    let name_of = {
+        let users = users.clone();
+        let nf = nf.clone();
+        move |id: &RecordId| -> String {
+            users
+                .iter()
+                .find(|u| u.id.as_ref() == Some(id))
+                .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+                .unwrap_or_else(|| "—".to_string())
+        }
+    };
+    let location_name = |id: &RecordId| -> String {
+        locations
+            .iter()
+            .find(|l| l.id.as_ref() == Some(id))
+            .map(|l| l.name.clone())
+            .unwrap_or_default()
+    };
+
+    let totals = occurrences
+        .iter()
+        .filter_map(|o| o.report.as_ref())
+        .fold(ShiftReport::default(), |mut sum, r| {
+            sum.placements += r.placements;
+            sum.videos += r.videos;
+            sum.return_visits += r.return_visits;
+            sum
+        });
+    let reported = occurrences.iter().filter(|o| o.report.is_some()).count();
+
+    let mut shift_week = move |delta: i64| {
+        week.set(add_days(&week(), 7 * delta));
+        proposal.set(None);
+    };
+
+    let on_propose = {
+        let occurrences = occurrences.clone();
+        let shifts = shifts.clone();
+        let users = users.clone();
+        let privileges = privileges.clone();
+        let absences = absences.clone();
+        move |_| {
+            let slots = pairing_slots(&occurrences, &shifts);
+            let picks = propose(&users, &privileges, &absences, &history, &slots);
+            let picks = picks.into_iter().filter(|p| p.reason != Reason::Kept).collect();
+            proposal.set(Some((occurrences.clone(), picks)));
+        }
+    };
+
+    let on_accept = move |_| {
+        let Some((mut occurrences, picks)) = proposal.peek().clone() else { return };
+        apply_picks(&mut occurrences, &picks);
+        let changed: Vec<ShiftOccurrence> = occurrences
+            .into_iter()
+            .filter(|o| o.id.is_some() || !o.publishers.is_empty())
+            .collect();
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match ShiftOccurrence::save_many(&db, changed).await {
+                Ok(()) => {
+                    proposal.set(None);
+                    occurrences_res.restart();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let week_title = format!(
+        "{} – {}",
+        format_date(&week_iso, &df),
+        format_date(&add_days(&week_iso, 6), &df)
+    );
+
+    let tab_cls = |active: bool| {
+        if active {
+            "px-3 py-1.5 text-sm rounded-lg bg-primary-600 text-white"
+        } else {
+            "px-3 py-1.5 text-sm rounded-lg text-gray-600 hover:bg-gray-100"
+        }
+    };
+    let schedule_tab_cls = tab_cls(view() == View::Schedule);
+    let locations_tab_cls = tab_cls(view() == View::Locations);
+    let shifts_tab_cls = tab_cls(view() == View::Shifts);
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            div { class: "flex items-center justify-between",
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
                 h1 { class: "text-2xl font-bold text-gray-900", {t!("page-public-preaching")} }
-                button { class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
-                    {t!("btn-add-arrangement")}
+                div { class: "flex flex-wrap gap-2",
+                    div { class: "flex gap-1 bg-white border border-gray-200 rounded-lg p-1",
+                        button {
+                            class: schedule_tab_cls,
+                            onclick: move |_| view.set(View::Schedule),
+                            {t!("pw-schedule")}
+                        }
+                        button {
+                            class: shifts_tab_cls,
+                            onclick: move |_| view.set(View::Shifts),
+                            {t!("pw-shifts")}
+                        }
+                        button {
+                            class: locations_tab_cls,
+                            onclick: move |_| view.set(View::Locations),
+                            {t!("pw-locations")}
+                        }
+                    }
+                    if view() == View::Schedule {
+                        button {
+                            class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                            disabled: is_loading || occurrences.is_empty() || proposal().is_some(),
+                            onclick: on_propose,
+                            {t!("pw-pair")}
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("pw-loading")} }
+                }
+            } else if view() == View::Locations {
+                WitnessingLocations {
+                    locations: locations.clone(),
+                    on_changed: move |_| locations_res.restart(),
+                }
+            } else if view() == View::Shifts {
+                WitnessingShifts {
+                    shifts: shifts.clone(),
+                    locations: locations.clone(),
+                    on_changed: move |_| shifts_res.restart(),
+                }
+            } else {
+
+                // ── Week navigation ───────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                        onclick: move |_| shift_week(-1),
+                        "‹"
+                    }
+                    div { class: "text-center",
+                        p { class: "font-semibold text-gray-900", "{week_title}" }
+                        if reported > 0 {
+                            p { class: "text-xs text-gray-500",
+                                {t!("pw-week-totals")}
+                                " {totals.placements} · {totals.videos} · {totals.return_visits}"
+                            }
+                        }
+                    }
+                    button {
+                        class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                        onclick: move |_| shift_week(1),
+                        "›"
+                    }
+                }
+
+                ConflictWarnings {
+                    key: "{week_iso}",
+                    from: week_iso.clone(),
+                    to: week_end.clone(),
+                    source: Source::Witnessing,
+                    name_fmt: nf.clone(),
+                    date_fmt: df.clone(),
+                }
+
+                if let Some((proposed, picks)) = proposal() {
+
+                    // ── Proposal ──────────────────────────────────────────
+                    div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                        p { class: "text-sm font-semibold text-gray-900", {t!("rotation-proposal-title")} }
+                        p { class: "text-xs text-gray-500", {t!("rotation-proposal-hint")} }
+                        if picks.is_empty() {
+                            p { class: "text-sm text-gray-500", {t!("rotation-nothing-open")} }
+                        }
+                        ul { class: "divide-y divide-gray-100",
+                            for pick in picks.iter() {
+                                {
+                                    let place_label = pick
+                                        .key
+                                        .split_once('-')
+                                        .and_then(|(i, _)| proposed.get(i.parse::<usize>().ok()?))
+                                        .and_then(|o| {
+                                            let shift = shifts.iter().find(|s| s.id.as_ref() == Some(&o.shift))?;
+                                            Some(format!(
+                                                "{} · {}–{} · {}",
+                                                format_date(&o.date, &df),
+                                                shift.start,
+                                                shift.end,
+                                                location_name(&shift.location),
+                                            ))
+                                        })
+                                        .unwrap_or_default();
+                                    let person = pick.person.as_ref().map(&name_of).unwrap_or_else(|| "—".to_string());
+                                    rsx! {
+                                        li { key: "{pick.key}", class: "py-1.5",
+                                            div { class: "flex flex-wrap justify-between gap-2 text-sm",
+                                                span { class: "text-gray-600", "{place_label}" }
+                                                span { class: "font-medium text-gray-900", "{person}" }
+                                            }
+                                            p { class: "text-xs text-gray-500", {pick.reason.explain()} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "flex justify-end gap-2",
+                            button {
+                                class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                onclick: move |_| proposal.set(None),
+                                {t!("rotation-discard")}
+                            }
+                            button {
+                                class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                                onclick: on_accept,
+                                {t!("rotation-accept")}
+                            }
+                        }
+                    }
+                } else if occurrences.is_empty() {
+                    div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                        p { class: "text-4xl mb-3", "📢" }
+                        p { class: "font-medium text-gray-600", {t!("pw-no-shifts")} }
+                        p { class: "text-sm mt-1", {t!("pw-no-shifts-desc")} }
+                    }
+                } else {
+
+                    // ── Shifts of the week ────────────────────────────────
+                    div { class: "space-y-3",
+                        for (occurrence , shift) in occurrences
+                            .iter()
+                            .filter_map(|o| Some((o.clone(), shifts.iter().find(|s| s.id.as_ref() == Some(&o.shift))?.clone())))
+                        {
+                            {
+                                let key = format!("{}-{}", rid_str(&occurrence.shift), occurrence.date);
+                                rsx! {
+                                    ShiftCard {
+                                        key: "{key}",
+                                        location: location_name(&shift.location),
+                                        occurrence,
+                                        shift,
+                                        week: occurrences.clone(),
+                                        shifts: shifts.clone(),
+                                        users: users.clone(),
+                                        privileges: privileges.clone(),
+                                        absences: absences.clone(),
+                                        me: me_id.clone(),
+                                        name_fmt: nf.clone(),
+                                        date_fmt: df.clone(),
+                                        on_saved: move |_| occurrences_res.restart(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── ShiftCard ─────────────────────────────────────────────────────────────────
+
+/// One date of a shift: who signed up, what is wrong with the pairing and
+/// the report. Every change is stored right away.
+#[component]
+fn ShiftCard(
+    occurrence: ShiftOccurrence,
+    shift: WitnessingShift,
+    location: String,
+    /// Every occurrence of the week, to catch overlapping sign-ups.
+    week: Vec<ShiftOccurrence>,
+    shifts: Vec<WitnessingShift>,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    absences: Vec<Absence>,
+    me: Option<RecordId>,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut report_open = use_signal(|| false);
+    let mut draft: Signal<ShiftReport> = use_signal(ShiftReport::default);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let save = move |occ: ShiftOccurrence| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match ShiftOccurrence::save_many(&db, vec![occ]).await {
+                Ok(()) => {
+                    error.set(None);
+                    on_saved.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let add = {
+        let occurrence = occurrence.clone();
+        let shift = shift.clone();
+        let week = week.clone();
+        let shifts = shifts.clone();
+        let privileges = privileges.clone();
+        let absences = absences.clone();
+        move |person: RecordId| {
+            let checked = check_signup(
+                &person,
+                &shift,
+                &occurrence.date,
+                &week,
+                &shifts,
+                &privileges,
+                &absences,
+            );
+            match checked {
+                Ok(()) => {
+                    let mut occ = occurrence.clone();
+                    occ.publishers.push(person);
+                    save(occ);
+                }
+                Err(e) => error.set(Some(e.label())),
+            }
+        }
+    };
+
+    let people: Vec<&User> = occurrence
+        .publishers
+        .iter()
+        .filter_map(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+        .collect();
+    let problem = issue(&people);
+    let chips: Vec<(String, RecordId, String)> = people
+        .iter()
+        .filter_map(|u| {
+            let id = u.id.clone()?;
+            Some((rid_str(&id), id, format_name(&u.first_name, &u.last_name, &name_fmt)))
+        })
+        .collect();
+    let full = occurrence.publishers.len() >= shift.capacity as usize;
+    let can_sign_me = me
+        .as_ref()
+        .is_some_and(|id| !occurrence.publishers.contains(id))
+        && !full;
+    let options = person_options(
+        eligible(&users, &privileges, |p| p.public_witnessing)
+            .into_iter()
+            .filter(|u| u.id.as_ref().is_some_and(|id| !occurrence.publishers.contains(id)))
+            .collect(),
+        None,
+        &users,
+        &name_fmt,
+    );
+    let date_label = format!(
+        "{} · {}",
+        weekday_label(shift.weekday),
+        format_date(&occurrence.date, &date_fmt)
+    );
+    let count = occurrence.publishers.len();
+    let capacity = shift.capacity;
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+            div { class: "flex flex-wrap items-start justify-between gap-2",
+                div {
+                    p { class: "text-sm font-semibold text-gray-900",
+                        "{date_label}"
+                        span { class: "ml-2 font-normal text-gray-500", "· {shift.start}–{shift.end}" }
+                    }
+                    p { class: "text-xs text-gray-500", "📍 {location}" }
+                }
+                div { class: "flex items-center gap-2",
+                    span { class: "text-xs text-gray-500", "{count}/{capacity}" }
+                    if let Some(problem) = problem {
+                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-800",
+                            {problem.label()}
+                        }
+                    }
+                    if occurrence.report.is_some() {
+                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-emerald-100 text-emerald-800",
+                            {t!("pw-reported")}
+                        }
+                    }
+                }
+            }
+
+            // ── Publishers ────────────────────────────────────────────────
+            div { class: "flex flex-wrap gap-2",
+                for (key , id , person) in chips {
+                    span { key: "{key}", class: "inline-flex items-center gap-1 px-2 py-1 rounded-lg bg-gray-100 text-sm text-gray-800",
+                        "{person}"
+                        button {
+                            class: "text-gray-400 hover:text-red-600",
+                            title: t!("pw-remove"),
+                            onclick: {
+                                let occurrence = occurrence.clone();
+                                move |_| {
+                                    let mut occ = occurrence.clone();
+                                    occ.publishers.retain(|p| p != &id);
+                                    save(occ);
+                                }
+                            },
+                            "✕"
+                        }
+                    }
+                }
+            }
+
+            if !full {
+                div { class: "flex flex-wrap items-end gap-2",
+                    div { class: "flex-1 min-w-[12rem]",
+                        PersonSelect {
+                            label: t!("pw-add-publisher"),
+                            options,
+                            selected: None,
+                            on_change: {
+                                let mut add = add.clone();
+                                move |v: Option<RecordId>| {
+                                    if let Some(id) = v {
+                                        add(id);
+                                    }
+                                }
+                            },
+                        }
+                    }
+                    if can_sign_me {
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                            onclick: {
+                                let mut add = add.clone();
+                                let me = me.clone();
+                                move |_| {
+                                    if let Some(id) = me.clone() {
+                                        add(id);
+                                    }
+                                }
+                            },
+                            {t!("pw-sign-me-up")}
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            // ── Report ────────────────────────────────────────────────────
+            if report_open() {
+                div { class: "border-t border-gray-100 pt-3 space-y-3",
+                    div { class: "grid grid-cols-3 gap-3",
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("pw-placements")} }
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                class: input_cls,
+                                value: "{draft().placements}",
+                                oninput: move |e| draft.write().placements = e.value().trim().parse().unwrap_or(0),
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("pw-videos")} }
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                class: input_cls,
+                                value: "{draft().videos}",
+                                oninput: move |e| draft.write().videos = e.value().trim().parse().unwrap_or(0),
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("pw-return-visits")} }
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                class: input_cls,
+                                value: "{draft().return_visits}",
+                                oninput: move |e| draft.write().return_visits = e.value().trim().parse().unwrap_or(0),
+                            }
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("pw-notes")} }
+                        textarea {
+                            class: "{input_cls} resize-none",
+                            rows: "2",
+                            value: draft().notes,
+                            oninput: move |e| draft.write().notes = e.value(),
+                        }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                            onclick: move |_| report_open.set(false),
+                            {t!("btn-cancel")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                            onclick: {
+                                let occurrence = occurrence.clone();
+                                move |_| {
+                                    let mut occ = occurrence.clone();
+                                    occ.report = Some(draft.peek().clone());
+                                    report_open.set(false);
+                                    save(occ);
+                                }
+                            },
+                            {t!("btn-save")}
+                        }
+                    }
+                }
+            } else if count > 0 {
+                div { class: "flex flex-wrap items-center justify-between gap-2 border-t border-gray-100 pt-3",
+                    if let Some(r) = occurrence.report.clone() {
+                        p { class: "text-xs text-gray-600",
+                            {t!("pw-placements")}
+                            " {r.placements} · "
+                            {t!("pw-videos")}
+                            " {r.videos} · "
+                            {t!("pw-return-visits")}
+                            " {r.return_visits}"
+                        }
+                    } else {
+                        span {}
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: {
+                            let report = occurrence.report.clone();
+                            move |_| {
+                                draft.set(report.clone().unwrap_or_default());
+                                report_open.set(true);
+                            }
+                        },
+                        {t!("pw-report")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── WitnessingLocations ───────────────────────────────────────────────────────
+
+#[derive(Clone, Default, PartialEq)]
+struct LocationForm {
+    id: Option<RecordId>,
+    name: String,
+    description: String,
+    lat: Option<f64>,
+    lng: Option<f64>,
+    active: bool,
+}
+
+impl LocationForm {
+    fn from_location(l: &WitnessingLocation) -> Self {
+        Self {
+            id: l.id.clone(),
+            name: l.name.clone(),
+            description: l.description.clone(),
+            lat: l.lat,
+            lng: l.lng,
+            active: l.active,
+        }
+    }
+}
+
+/// Cart locations on the territory map, and the form to place them.
+#[component]
+fn WitnessingLocations(locations: Vec<WitnessingLocation>, on_changed: Callback<()>) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<LocationForm>> = use_signal(|| None);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Map clicks always pick the point; the overview map is read-only.
+    let picking = use_signal(|| true);
+    let browsing = use_signal(|| false);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        if f.name.trim().is_empty() {
+            error.set(Some(t!("pw-location-name-required")));
+            return;
+        }
+        let data = WitnessingLocationData {
+            name: f.name.trim().to_string(),
+            description: f.description.trim().to_string(),
+            lat: f.lat,
+            lng: f.lng,
+            active: f.active,
+        };
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => WitnessingLocation::update(&db, id, data).await.map(|_| ()),
+                None => WitnessingLocation::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match WitnessingLocation::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let (clat, clng) = locations_center(&locations);
+    let located = locations.iter().any(|l| l.lat.is_some() && l.lng.is_some());
+    let zoom: u8 = if located { 13 } else { 2 };
+    let markers = locations_to_json(&locations);
+    let input_cls = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some(),
+                    onclick: move |_| form.set(Some(LocationForm { active: true, ..LocationForm::default() })),
+                    {t!("pw-add-location")}
+                }
+            }
+
+            if let Some(f) = form() {
+                {
+                    let (plat, plng) = match (f.lat, f.lng) {
+                        (Some(lat), Some(lng)) => (lat, lng),
+                        _ => (clat, clng),
+                    };
+                    let point = match (f.lat, f.lng) {
+                        (Some(lat), Some(lng)) => format!(r#"[{{"lat":{lat},"lng":{lng},"description":{:?}}}]"#, f.name),
+                        _ => "[]".to_string(),
+                    };
+                    let point_label = match (f.lat, f.lng) {
+                        (Some(lat), Some(lng)) => format!("{lat:.6}, {lng:.6}"),
+                        _ => t!("pw-no-point"),
+                    };
+                    let map_id = f
+                        .id
+                        .as_ref()
+                        .map(|id| rid_str(id).replace(':', "_"))
+                        .unwrap_or_else(|| "new".to_string());
+                    rsx! {
+                        div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                            div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                    span { {t!("pw-location-name")} }
+                                    input {
+                                        class: input_cls,
+                                        value: f.name.clone(),
+                                        oninput: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.name = e.value();
+                                            }
+                                        },
+                                    }
+                                }
+                                label { class: "flex items-center gap-2 text-sm text-gray-700 sm:mt-5",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: f.active,
+                                        onchange: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.active = e.checked();
+                                            }
+                                        },
+                                    }
+                                    {t!("pw-active")}
+                                }
+                            }
+                            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                span { {t!("pw-description")} }
+                                textarea {
+                                    class: "{input_cls} resize-none",
+                                    rows: "2",
+                                    value: f.description.clone(),
+                                    oninput: move |e| {
+                                        if let Some(f) = form.write().as_mut() {
+                                            f.description = e.value();
+                                        }
+                                    },
+                                }
+                            }
+                            div {
+                                div { class: "flex items-center justify-between mb-2",
+                                    p { class: "text-xs text-primary-600", {t!("pw-pick-point-hint")} }
+                                    span { class: "text-xs text-gray-500", "{point_label}" }
+                                }
+                                div { class: "h-56 sm:h-72 border border-gray-200 rounded-lg overflow-hidden",
+                                    LeafletMap {
+                                        map_id: "pw_{map_id}_e",
+                                        center_lat: plat,
+                                        center_lng: plng,
+                                        zoom: if f.lat.is_some() { 16 } else { zoom },
+                                        boundary_json: "[]".to_string(),
+                                        addresses_json: point,
+                                        draw_mode: ReadOnlySignal::new(picking),
+                                        single_point: true,
+                                        on_point: move |coord: String| {
+                                            if let Some((lat, lng)) = coord.split_once(',')
+                                                && let Some(f) = form.write().as_mut()
+                                            {
+                                                f.lat = lat.parse().ok();
+                                                f.lng = lng.parse().ok();
+                                            }
+                                        },
+                                    }
+                                }
+                            }
+                            if let Some(err) = error() {
+                                p { class: "text-sm text-red-600", "{err}" }
+                            }
+                            div { class: "flex justify-end gap-2",
+                                button {
+                                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                    onclick: move |_| {
+                                        form.set(None);
+                                        error.set(None);
+                                    },
+                                    {t!("btn-cancel")}
+                                }
+                                button {
+                                    class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                                    disabled: saving(),
+                                    onclick: on_save,
+                                    {t!("btn-save")}
+                                }
+                            }
+                        }
+                    }
+                }
+            } else if located {
+                div { class: "h-64 sm:h-96 bg-white border border-gray-200 rounded-xl overflow-hidden",
+                    LeafletMap {
+                        map_id: "pw_all",
+                        center_lat: clat,
+                        center_lng: clng,
+                        zoom,
+                        boundary_json: "[]".to_string(),
+                        addresses_json: markers,
+                        draw_mode: ReadOnlySignal::new(browsing),
+                        on_point: move |_: String| {},
+                    }
+                }
+            }
+
+            if locations.is_empty() && form().is_none() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                    p { class: "text-4xl mb-3", "📍" }
+                    p { class: "font-medium text-gray-600", {t!("pw-no-locations")} }
                 }
             }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
-                    p { class: "text-4xl mb-3", "📢" }
-                    p { class: "font-medium text-gray-600", {t!("empty-public-preaching-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-public-preaching-desc")} }
+
+            for location in locations.iter().cloned() {
+                {
+                    let id = location.id.clone();
+                    let deleting = id.is_some() && confirm_delete() == id;
+                    let key = id.as_ref().map(rid_str).unwrap_or_default();
+                    rsx! {
+                        div { key: "{key}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-1",
+                            div { class: "flex flex-wrap items-center justify-between gap-2",
+                                p { class: "text-sm font-semibold text-gray-900",
+                                    "{location.name}"
+                                    if !location.active {
+                                        span { class: "ml-2 px-2 py-0.5 rounded-full text-xs font-medium bg-gray-100 text-gray-500",
+                                            {t!("pw-inactive")}
+                                        }
+                                    }
+                                    if location.lat.is_none() {
+                                        span { class: "ml-2 text-xs font-normal text-amber-700", {t!("pw-no-point")} }
+                                    }
+                                }
+                                div { class: "flex items-center gap-2",
+                                    if deleting {
+                                        span { class: "text-sm text-gray-600 mr-2", {t!("pw-location-delete-confirm")} }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: move |_| confirm_delete.set(None),
+                                            {t!("btn-cancel")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| {
+                                                    if let Some(id) = id.clone() {
+                                                        on_delete(id);
+                                                    }
+                                                }
+                                            },
+                                            {t!("btn-confirm")}
+                                        }
+                                    } else {
+                                        button {
+                                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                            onclick: {
+                                                let location = location.clone();
+                                                move |_| form.set(Some(LocationForm::from_location(&location)))
+                                            },
+                                            {t!("btn-edit")}
+                                        }
+                                        button {
+                                            class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                            onclick: {
+                                                let id = id.clone();
+                                                move |_| confirm_delete.set(id.clone())
+                                            },
+                                            {t!("btn-delete")}
+                                        }
+                                    }
+                                }
+                            }
+                            if !location.description.is_empty() {
+                                p { class: "text-sm text-gray-600 whitespace-pre-line", "{location.description}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── WitnessingShifts ──────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+struct ShiftForm {
+    id: Option<RecordId>,
+    location: Option<RecordId>,
+    weekday: u8,
+    start: String,
+    end: String,
+    capacity: u8,
+    active: bool,
+}
+
+impl Default for ShiftForm {
+    fn default() -> Self {
+        Self {
+            id: None,
+            location: None,
+            weekday: 5,
+            start: "09:00".into(),
+            end: "11:00".into(),
+            capacity: 2,
+            active: true,
+        }
+    }
+}
+
+impl ShiftForm {
+    fn from_shift(s: &WitnessingShift) -> Self {
+        Self {
+            id: s.id.clone(),
+            location: Some(s.location.clone()),
+            weekday: s.weekday,
+            start: s.start.clone(),
+            end: s.end.clone(),
+            capacity: s.capacity,
+            active: s.active,
+        }
+    }
+}
+
+/// Weekly shift templates: location, weekday, hours and capacity.
+#[component]
+fn WitnessingShifts(
+    shifts: Vec<WitnessingShift>,
+    locations: Vec<WitnessingLocation>,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut form: Signal<Option<ShiftForm>> = use_signal(|| None);
+    let mut confirm_delete: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(f) = form.peek().clone() else { return };
+        let Some(location) = f.location.clone() else {
+            error.set(Some(t!("pw-location-required")));
+            return;
+        };
+        if f.start.is_empty() || f.end <= f.start {
+            error.set(Some(t!("pw-time-invalid")));
+            return;
+        }
+        let data = WitnessingShiftData {
+            location,
+            weekday: f.weekday,
+            start: f.start.clone(),
+            end: f.end.clone(),
+            capacity: f.capacity.max(1),
+            active: f.active,
+        };
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match f.id {
+                Some(id) => WitnessingShift::update(&db, id, data).await.map(|_| ()),
+                None => WitnessingShift::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    form.set(None);
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match WitnessingShift::delete(&db, id).await {
+                Ok(_) => {
+                    confirm_delete.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let location_name = |id: &RecordId| -> String {
+        locations
+            .iter()
+            .find(|l| l.id.as_ref() == Some(id))
+            .map(|l| l.name.clone())
+            .unwrap_or_default()
+    };
+    let location_options: Vec<(String, String)> = locations
+        .iter()
+        .filter(|l| l.active)
+        .filter_map(|l| Some((rid_str(l.id.as_ref()?), l.name.clone())))
+        .collect();
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex justify-end",
+                button {
+                    class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors disabled:opacity-50",
+                    disabled: form().is_some() || location_options.is_empty(),
+                    onclick: move |_| form.set(Some(ShiftForm::default())),
+                    {t!("pw-add-shift")}
+                }
+            }
+            if locations.is_empty() {
+                p { class: "text-sm text-amber-700", {t!("pw-no-locations")} }
+            }
+
+            if let Some(f) = form() {
+                {
+                    let current = f.location.as_ref().map(rid_str).unwrap_or_default();
+                    rsx! {
+                        div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+                            div { class: "grid grid-cols-2 sm:grid-cols-3 lg:grid-cols-6 gap-3",
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500 col-span-2",
+                                    span { {t!("pw-location")} }
+                                    select {
+                                        class: input_cls,
+                                        onchange: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.location = RecordId::parse_simple(&e.value()).ok();
+                                            }
+                                        },
+                                        option { value: "", selected: current.is_empty(), "—" }
+                                        for (value , name) in location_options.iter().cloned() {
+                                            option { value: value.clone(), selected: value == current, "{name}" }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                    span { {t!("pw-weekday")} }
+                                    select {
+                                        class: input_cls,
+                                        onchange: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.weekday = e.value().parse().unwrap_or(5);
+                                            }
+                                        },
+                                        for day in 0..7u8 {
+                                            option { value: "{day}", selected: day == f.weekday, {weekday_label(day)} }
+                                        }
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                    span { {t!("pw-start")} }
+                                    input {
+                                        r#type: "time",
+                                        class: input_cls,
+                                        value: f.start.clone(),
+                                        oninput: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.start = e.value();
+                                            }
+                                        },
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                    span { {t!("pw-end")} }
+                                    input {
+                                        r#type: "time",
+                                        class: input_cls,
+                                        value: f.end.clone(),
+                                        oninput: move |e| {
+                                            if let Some(f) = form.write().as_mut() {
+                                                f.end = e.value();
+                                            }
+                                        },
+                                    }
+                                }
+                                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                                    span { {t!("pw-capacity")} }
+                                    input {
+                                        r#type: "number",
+                                        min: "1",
+                                        max: "20",
+                                        class: input_cls,
+                                        value: "{f.capacity}",
+                                        oninput: move |e| {
+                                            if let Ok(n) = e.value().trim().parse::<u8>()
+                                                && let Some(f) = form.write().as_mut()
+                                            {
+                                                f.capacity = n.clamp(1, 20);
+                                            }
+                                        },
+                                    }
+                                }
+                            }
+                            label { class: "flex items-center gap-2 text-sm text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: f.active,
+                                    onchange: move |e| {
+                                        if let Some(f) = form.write().as_mut() {
+                                            f.active = e.checked();
+                                        }
+                                    },
+                                }
+                                {t!("pw-active")}
+                            }
+                            if let Some(err) = error() {
+                                p { class: "text-sm text-red-600", "{err}" }
+                            }
+                            div { class: "flex justify-end gap-2",
+                                button {
+                                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                    onclick: move |_| {
+                                        form.set(None);
+                                        error.set(None);
+                                    },
+                                    {t!("btn-cancel")}
+                                }
+                                button {
+                                    class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                                    disabled: saving(),
+                                    onclick: on_save,
+                                    {t!("btn-save")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if shifts.is_empty() && form().is_none() && !locations.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 px-6 py-12 text-center text-gray-400",
+                    p { class: "text-4xl mb-3", "🗓️" }
+                    p { class: "font-medium text-gray-600", {t!("pw-no-shifts")} }
+                }
+            }
+
+            if !shifts.is_empty() {
+                div { class: "bg-white rounded-xl border border-gray-200 divide-y divide-gray-100",
+                    for shift in shifts.iter().cloned() {
+                        {
+                            let id = shift.id.clone();
+                            let deleting = id.is_some() && confirm_delete() == id;
+                            let key = id.as_ref().map(rid_str).unwrap_or_default();
+                            let place = location_name(&shift.location);
+                            let row_cls = if shift.active {
+                                "px-4 py-3 flex flex-wrap items-center justify-between gap-2"
+                            } else {
+                                "px-4 py-3 flex flex-wrap items-center justify-between gap-2 opacity-60"
+                            };
+                            rsx! {
+                                div { key: "{key}", class: row_cls,
+                                    div {
+                                        p { class: "text-sm font-medium text-gray-900",
+                                            {weekday_label(shift.weekday)}
+                                            " · {shift.start}–{shift.end}"
+                                        }
+                                        p { class: "text-xs text-gray-500",
+                                            "📍 {place} · "
+                                            {t!("pw-capacity")}
+                                            " {shift.capacity}"
+                                            if !shift.active {
+                                                " · "
+                                                {t!("pw-inactive")}
+                                            }
+                                        }
+                                    }
+                                    div { class: "flex items-center gap-2",
+                                        if deleting {
+                                            span { class: "text-sm text-gray-600 mr-2", {t!("pw-shift-delete-confirm")} }
+                                            button {
+                                                class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                                onclick: move |_| confirm_delete.set(None),
+                                                {t!("btn-cancel")}
+                                            }
+                                            button {
+                                                class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                                onclick: {
+                                                    let id = id.clone();
+                                                    move |_| {
+                                                        if let Some(id) = id.clone() {
+                                                            on_delete(id);
+                                                        }
+                                                    }
+                                                },
+                                                {t!("btn-confirm")}
+                                            }
+                                        } else {
+                                            button {
+                                                class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                                                onclick: {
+                                                    let shift = shift.clone();
+                                                    move |_| form.set(Some(ShiftForm::from_shift(&shift)))
+                                                },
+                                                {t!("btn-edit")}
+                                            }
+                                            button {
+                                                class: "px-3 py-1.5 text-sm text-red-600 hover:bg-red-50 rounded-lg",
+                                                onclick: {
+                                                    let id = id.clone();
+                                                    move |_| confirm_delete.set(id.clone())
+                                                },
+                                                {t!("btn-delete")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -1,16 +1,1173 @@
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::today_str;
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_talk::{
+    OutgoingTalk, OutgoingTalkData, TalkOutline, TalkOutlineData, TalkSettings, TalkSpeaker,
+    TalkSpeakerData,
+};
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::schedule::midweek::eligible;
+use crate::schedule::talks::{last_given, parse_numbers, parse_outlines, repeated_since};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Display name of a speaker: the publisher's name for local speakers, the
+/// stored name and home congregation for visiting ones.
+pub fn speaker_label(speaker: &TalkSpeaker, users: &[User], name_fmt: &NameFormat) -> String {
+    if let Some(id) = &speaker.publisher {
+        return users
+            .iter()
+            .find(|u| u.id.as_ref() == Some(id))
+            .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+            .unwrap_or_else(|| "—".to_string());
+    }
+    let name = speaker.name.clone().unwrap_or_default();
+    match speaker.congregation.as_deref() {
+        Some(c) if !c.is_empty() => format!("{name} ({c})"),
+        _ => name,
+    }
+}
+
+fn outline_label(outlines: &[TalkOutline], number: Option<u16>) -> String {
+    match number {
+        Some(n) => match outlines.iter().find(|o| o.number == n) {
+            Some(o) => format!("#{n} {}", o.title),
+            None => format!("#{n}"),
+        },
+        None => "—".to_string(),
+    }
+}
+
+fn numbers_str(numbers: &[u16]) -> String {
+    numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+fn optional(v: String) -> Option<String> {
+    if v.trim().is_empty() { None } else { Some(v.trim().to_string()) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum View {
+    Schedule,
+    Outlines,
+    Speakers,
+    History,
+}
+
+const INPUT_CLS: &str = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+// ── Main page ─────────────────────────────────────────────────────────────────
 
 #[component]
 pub fn AppPublicTalks() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let mut view = use_signal(|| View::Schedule);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let mut outlines_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        TalkOutline::all(&db).await.unwrap_or_default()
+    });
+
+    let mut speakers_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        TalkSpeaker::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut outgoing_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        OutgoingTalk::all(&db).await.unwrap_or_default()
+    });
+
+    let meetings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        WeekendMeeting::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut settings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return TalkSettings::default() };
+        TalkSettings::get(&db).await.unwrap_or_default()
+    });
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || outlines_res.read().is_none()
+        || speakers_res.read().is_none()
+        || outgoing_res.read().is_none()
+        || meetings_res.read().is_none()
+        || settings_res.read().is_none();
+
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let outlines = outlines_res().unwrap_or_default();
+    let speakers = speakers_res().unwrap_or_default();
+    let outgoing = outgoing_res().unwrap_or_default();
+    let meetings = meetings_res().unwrap_or_default();
+    let settings = settings_res().unwrap_or_default();
+    let nf = name_fmt();
+    let df = date_fmt();
+
+    let tab = |v: View| {
+        if view() == v {
+            "px-3 py-1.5 text-sm rounded-lg bg-primary-600 text-white"
+        } else {
+            "px-3 py-1.5 text-sm rounded-lg text-gray-600 hover:bg-gray-100"
+        }
+    };
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-public-talks")} }
+                div { class: "flex flex-wrap gap-1 bg-white rounded-xl border border-gray-200 p-1",
+                    button { class: tab(View::Schedule), onclick: move |_| view.set(View::Schedule), {t!("talks-view-schedule")} }
+                    button { class: tab(View::Outlines), onclick: move |_| view.set(View::Outlines), {t!("talks-view-outlines")} }
+                    button { class: tab(View::Speakers), onclick: move |_| view.set(View::Speakers), {t!("talks-view-speakers")} }
+                    button { class: tab(View::History), onclick: move |_| view.set(View::History), {t!("talks-view-history")} }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("talks-loading")} }
+                }
+            } else {
+                {match view() {
+                    View::Schedule => rsx! {
+                        ScheduleView {
+                            users: users.clone(),
+                            privileges: privileges.clone(),
+                            outlines: outlines.clone(),
+                            speakers: speakers.clone(),
+                            outgoing: outgoing.clone(),
+                            meetings: meetings.clone(),
+                            name_fmt: nf.clone(),
+                            date_fmt: df.clone(),
+                            on_changed: move |_| outgoing_res.restart(),
+                        }
+                    },
+                    View::Outlines => rsx! {
+                        OutlinesView {
+                            outlines: outlines.clone(),
+                            meetings: meetings.clone(),
+                            date_fmt: df.clone(),
+                            on_changed: move |_| outlines_res.restart(),
+                        }
+                    },
+                    View::Speakers => rsx! {
+                        SpeakersView {
+                            users: users.clone(),
+                            privileges: privileges.clone(),
+                            outlines: outlines.clone(),
+                            speakers: speakers.clone(),
+                            name_fmt: nf.clone(),
+                            on_changed: move |_| speakers_res.restart(),
+                        }
+                    },
+                    View::History => rsx! {
+                        HistoryView {
+                            users: users.clone(),
+                            outlines: outlines.clone(),
+                            outgoing: outgoing.clone(),
+                            meetings: meetings.clone(),
+                            settings: settings.clone(),
+                            name_fmt: nf.clone(),
+                            date_fmt: df.clone(),
+                            on_changed: move |_| settings_res.restart(),
+                        }
+                    },
+                }}
+            }
+        }
+    }
+}
+
+// ── Schedule ──────────────────────────────────────────────────────────────────
+
+/// Upcoming incoming talks (from the weekend programs) and outgoing talks.
+#[component]
+fn ScheduleView(
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    outlines: Vec<TalkOutline>,
+    speakers: Vec<TalkSpeaker>,
+    outgoing: Vec<OutgoingTalk>,
+    meetings: Vec<WeekendMeeting>,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    // `Some(None)` while adding a new outgoing talk.
+    let mut editing: Signal<Option<Option<OutgoingTalk>>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let today = today_str();
+    let name_of = |id: &RecordId| {
+        users
+            .iter()
+            .find(|u| u.id.as_ref() == Some(id))
+            .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+            .unwrap_or_else(|| "—".to_string())
+    };
+
+    let incoming: Vec<(String, String, String)> = meetings
+        .iter()
+        .filter(|m| m.date >= today)
+        .map(|m| {
+            let speaker = match (&m.speaker, &m.visiting_speaker) {
+                (Some(id), _) => name_of(id),
+                (None, Some(name)) if !name.trim().is_empty() => match m.visiting_congregation.as_deref() {
+                    Some(c) if !c.is_empty() => format!("{name} ({c})"),
+                    _ => name.clone(),
+                },
+                _ => "—".to_string(),
+            };
+            let talk = match m.talk_number {
+                Some(_) => outline_label(&outlines, m.talk_number),
+                None if !m.talk_title.is_empty() => m.talk_title.clone(),
+                None => "—".to_string(),
+            };
+            (format_date(&m.date, &date_fmt), speaker, talk)
+        })
+        .collect();
+
+    // Re-creates the form when another talk is picked for editing.
+    let form_key = match editing() {
+        Some(Some(t)) => format!("{}-{}", t.date, t.congregation),
+        _ => "new".to_string(),
+    };
+
+    let mut upcoming_out: Vec<OutgoingTalk> =
+        outgoing.iter().filter(|o| o.date >= today).cloned().collect();
+    upcoming_out.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let away_options = |current: Option<&RecordId>| {
+        person_options(
+            eligible(&users, &privileges, |p| p.public_talks_away),
+            current,
+            &users,
+            &name_fmt,
+        )
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match OutgoingTalk::delete(&db, id).await {
+                Ok(_) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "grid grid-cols-1 lg:grid-cols-2 gap-5",
+
+            // ── Incoming ──────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                h2 { class: "font-semibold text-gray-900", {t!("talks-incoming")} }
+                p { class: "text-xs text-gray-500", {t!("talks-incoming-hint")} }
+                if incoming.is_empty() {
+                    p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-none-planned")} }
+                } else {
+                    ul { class: "divide-y divide-gray-100",
+                        for (date , speaker , talk) in incoming {
+                            li { class: "py-2 text-sm",
+                                div { class: "flex justify-between gap-2",
+                                    span { class: "font-medium text-gray-900", "{date}" }
+                                    span { class: "text-gray-700 truncate", "{speaker}" }
+                                }
+                                p { class: "text-xs text-gray-500 truncate", "{talk}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ── Outgoing ──────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                div { class: "flex items-center justify-between gap-2",
+                    h2 { class: "font-semibold text-gray-900", {t!("talks-outgoing")} }
+                    if editing().is_none() {
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors",
+                            onclick: move |_| editing.set(Some(None)),
+                            {t!("talks-add-outgoing")}
+                        }
+                    }
+                }
+                if let Some(draft) = editing() {
+                    OutgoingForm {
+                        key: "{form_key}",
+                        talk: draft,
+                        speaker_options: away_options(None),
+                        outlines: outlines.clone(),
+                        speakers: speakers.clone(),
+                        on_close: move |_| editing.set(None),
+                        on_saved: move |_| {
+                            editing.set(None);
+                            on_changed.call(());
+                        },
+                    }
+                }
+                if let Some(err) = error() {
+                    p { class: "text-sm text-red-600", "{err}" }
+                }
+                if upcoming_out.is_empty() {
+                    p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-none-planned")} }
+                } else {
+                    ul { class: "divide-y divide-gray-100",
+                        for talk in upcoming_out {
+                            {
+                                let to_edit = talk.clone();
+                                let id = talk.id.clone();
+                                rsx! {
+                                    li { class: "py-2 text-sm flex items-start justify-between gap-2",
+                                        div { class: "min-w-0",
+                                            p { class: "font-medium text-gray-900",
+                                                {format_date(&talk.date, &date_fmt)}
+                                                span { class: "ml-2 font-normal text-gray-700", {name_of(&talk.speaker)} }
+                                            }
+                                            p { class: "text-xs text-gray-500 truncate",
+                                                "{talk.congregation} · "
+                                                {outline_label(&outlines, talk.outline)}
+                                            }
+                                        }
+                                        div { class: "flex gap-1 shrink-0",
+                                            button {
+                                                class: "px-2 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50",
+                                                onclick: move |_| editing.set(Some(Some(to_edit.clone()))),
+                                                {t!("btn-edit")}
+                                            }
+                                            if let Some(id) = id {
+                                                button {
+                                                    class: "px-2 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50",
+                                                    onclick: move |_| on_delete(id.clone()),
+                                                    "✕"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn OutgoingForm(
+    /// `None` when adding a new talk.
+    talk: Option<OutgoingTalk>,
+    speaker_options: Vec<(String, String)>,
+    outlines: Vec<TalkOutline>,
+    speakers: Vec<TalkSpeaker>,
+    on_close: Callback<()>,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut date = use_signal(|| talk.as_ref().map(|t| t.date.clone()).unwrap_or_else(today_str));
+    let mut speaker: Signal<Option<RecordId>> = use_signal(|| talk.as_ref().map(|t| t.speaker.clone()));
+    let mut outline = use_signal(|| talk.as_ref().and_then(|t| t.outline));
+    let mut congregation =
+        use_signal(|| talk.as_ref().map(|t| t.congregation.clone()).unwrap_or_default());
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Outlines the chosen speaker has prepared, when he is in the catalog.
+    let prepared: Vec<u16> = speaker()
+        .and_then(|id| speakers.iter().find(|s| s.publisher.as_ref() == Some(&id)))
+        .map(|s| s.outlines.clone())
+        .unwrap_or_default();
+    let choices: Vec<(u16, String)> = if prepared.is_empty() {
+        outlines.iter().map(|o| (o.number, o.title.clone())).collect()
+    } else {
+        prepared
+            .iter()
+            .map(|n| (*n, outlines.iter().find(|o| o.number == *n).map(|o| o.title.clone()).unwrap_or_default()))
+            .collect()
+    };
+    let selected_outline = outline().map(|n| n.to_string()).unwrap_or_default();
+    let talk_id = talk.as_ref().and_then(|t| t.id.clone());
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let Some(speaker_id) = speaker() else {
+            error.set(Some(t!("talks-speaker-required")));
+            return;
+        };
+        if date().is_empty() || congregation().trim().is_empty() {
+            error.set(Some(t!("talks-outgoing-required")));
+            return;
+        }
+        let data = OutgoingTalkData {
+            date: date(),
+            speaker: speaker_id,
+            outline: outline(),
+            congregation: congregation().trim().to_string(),
+        };
+        let id = talk_id.clone();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = match id {
+                Some(id) => OutgoingTalk::update(&db, id, data).await.map(|_| ()),
+                None => OutgoingTalk::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-public-talks")} }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
-                    p { class: "text-4xl mb-3", "🎤" }
-                    p { class: "font-medium text-gray-600", {t!("empty-public-talks-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-public-talks-desc")} }
+        div { class: "rounded-lg border border-gray-100 bg-gray-50/50 p-3 space-y-3",
+            div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("talks-date")} }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLS,
+                        value: date(),
+                        oninput: move |e| date.set(e.value()),
+                    }
+                }
+                PersonSelect {
+                    label: t!("weekend-speaker"),
+                    options: speaker_options,
+                    selected: speaker(),
+                    on_change: move |v| speaker.set(v),
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("talks-outline")} }
+                    select {
+                        class: INPUT_CLS,
+                        onchange: move |e| outline.set(e.value().parse().ok()),
+                        option { value: "", selected: selected_outline.is_empty(), "—" }
+                        for (number , title) in choices {
+                            option {
+                                value: "{number}",
+                                selected: number.to_string() == selected_outline,
+                                "#{number} {title}"
+                            }
+                        }
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("talks-visited-congregation")} }
+                    input {
+                        r#type: "text",
+                        class: INPUT_CLS,
+                        value: congregation(),
+                        oninput: move |e| congregation.set(e.value()),
+                    }
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                    onclick: move |_| on_close.call(()),
+                    {t!("btn-cancel")}
+                }
+                button {
+                    class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: on_save,
+                    {t!("btn-save")}
+                }
+            }
+        }
+    }
+}
+
+// ── Outlines ──────────────────────────────────────────────────────────────────
+
+#[component]
+fn OutlinesView(
+    outlines: Vec<TalkOutline>,
+    meetings: Vec<WeekendMeeting>,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut paste = use_signal(String::new);
+    let mut filter = use_signal(String::new);
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let talks_given: Vec<(String, u16)> = meetings
+        .iter()
+        .filter_map(|m| Some((m.date.clone(), m.talk_number?)))
+        .collect();
+    let today = today_str();
+    let last = last_given(&talks_given, &today);
+    let parsed = parse_outlines(&paste());
+    let query = filter().trim().to_lowercase();
+    let shown: Vec<(TalkOutline, String)> = outlines
+        .iter()
+        .filter(|o| query.is_empty() || o.title.to_lowercase().contains(&query) || o.number.to_string() == query)
+        .map(|o| {
+            let given = last.get(&o.number).map(|d| format_date(d, &date_fmt)).unwrap_or_else(|| "—".to_string());
+            (o.clone(), given)
+        })
+        .collect();
+
+    let on_import = move |_| {
+        let rows: Vec<TalkOutlineData> = parse_outlines(&paste.peek())
+            .into_iter()
+            .map(|(number, title)| TalkOutlineData { number, title })
+            .collect();
+        if rows.is_empty() || *saving.peek() {
+            return;
+        }
+        saving.set(true);
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let result = TalkOutline::save_many(&db, rows).await;
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    paste.set(String::new());
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match TalkOutline::delete(&db, id).await {
+                Ok(_) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "grid grid-cols-1 lg:grid-cols-3 gap-5",
+
+            // ── Catalog ───────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3 lg:col-span-2",
+                div { class: "flex flex-wrap items-center justify-between gap-2",
+                    h2 { class: "font-semibold text-gray-900",
+                        {t!("talks-view-outlines")}
+                        span { class: "ml-2 text-sm font-normal text-gray-400", "{outlines.len()}" }
+                    }
+                    input {
+                        r#type: "search",
+                        class: "px-2 py-1.5 text-sm border border-gray-200 rounded-lg w-48",
+                        placeholder: t!("talks-search"),
+                        value: filter(),
+                        oninput: move |e| filter.set(e.value()),
+                    }
+                }
+                if outlines.is_empty() {
+                    p { class: "text-sm text-gray-400 py-8 text-center", {t!("talks-no-outlines")} }
+                } else {
+                    div { class: "overflow-x-auto",
+                        table { class: "w-full text-sm",
+                            thead {
+                                tr { class: "text-left text-xs text-gray-500 border-b border-gray-100",
+                                    th { class: "py-2 pr-3 w-14", "#" }
+                                    th { class: "py-2 pr-3", {t!("weekend-talk-title")} }
+                                    th { class: "py-2 pr-3 whitespace-nowrap", {t!("talks-last-given")} }
+                                    th { class: "py-2 w-8" }
+                                }
+                            }
+                            tbody {
+                                for (outline , given) in shown {
+                                    {
+                                        let id = outline.id.clone();
+                                        rsx! {
+                                            tr { key: "{outline.number}", class: "border-b border-gray-50",
+                                                td { class: "py-1.5 pr-3 text-gray-500", "{outline.number}" }
+                                                td { class: "py-1.5 pr-3 text-gray-900", "{outline.title}" }
+                                                td { class: "py-1.5 pr-3 text-gray-500 whitespace-nowrap", "{given}" }
+                                                td { class: "py-1.5 text-right",
+                                                    if let Some(id) = id {
+                                                        button {
+                                                            class: "text-xs text-red-500 hover:text-red-700",
+                                                            title: t!("btn-delete"),
+                                                            onclick: move |_| on_delete(id.clone()),
+                                                            "✕"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ── Bulk add ──────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3 self-start",
+                h2 { class: "font-semibold text-gray-900", {t!("talks-import-title")} }
+                p { class: "text-xs text-gray-500", {t!("talks-import-hint")} }
+                textarea {
+                    class: "w-full h-48 px-2 py-1.5 text-sm font-mono border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500",
+                    placeholder: "1. …\n2. …",
+                    value: paste(),
+                    oninput: move |e| paste.set(e.value()),
+                }
+                if let Some(err) = error() {
+                    p { class: "text-sm text-red-600", "{err}" }
+                }
+                div { class: "flex items-center justify-between gap-2",
+                    span { class: "text-xs text-gray-500",
+                        "{parsed.len()} "
+                        {t!("talks-import-count")}
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                        disabled: parsed.is_empty() || saving(),
+                        onclick: on_import,
+                        {t!("btn-save")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Speakers ──────────────────────────────────────────────────────────────────
+
+#[component]
+fn SpeakersView(
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    outlines: Vec<TalkOutline>,
+    speakers: Vec<TalkSpeaker>,
+    name_fmt: NameFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut editing: Signal<Option<TalkSpeaker>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Every brother approved to give talks, with his catalog record if any.
+    let mut local: Vec<(String, TalkSpeaker)> = eligible(&users, &privileges, |p| {
+        p.public_talks || p.public_talks_away
+    })
+    .into_iter()
+    .filter_map(|u| {
+        let id = u.id.clone()?;
+        let record = speakers
+            .iter()
+            .find(|s| s.publisher.as_ref() == Some(&id))
+            .cloned()
+            .unwrap_or(TalkSpeaker {
+                id: None,
+                publisher: Some(id),
+                name: None,
+                congregation: None,
+                phone: None,
+                email: None,
+                outlines: vec![],
+            });
+        Some((format_name(&u.first_name, &u.last_name, &name_fmt), record))
+    })
+    .collect();
+    local.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+
+    let mut visiting: Vec<TalkSpeaker> = speakers.iter().filter(|s| !s.is_local()).cloned().collect();
+    visiting.sort_by_key(|s| speaker_label(s, &users, &name_fmt).to_lowercase());
+
+    let on_delete = move |id: RecordId| {
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match TalkSpeaker::delete(&db, id).await {
+                Ok(_) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let editor = editing().map(|s| {
+        let label = speaker_label(&s, &users, &name_fmt);
+        (s, label)
+    });
+
+    rsx! {
+        if let Some((speaker, label)) = editor {
+            SpeakerForm {
+                speaker,
+                label,
+                outlines: outlines.clone(),
+                on_close: move |_| editing.set(None),
+                on_saved: move |_| {
+                    editing.set(None);
+                    on_changed.call(());
+                },
+            }
+        }
+        if let Some(err) = error() {
+            p { class: "text-sm text-red-600", "{err}" }
+        }
+        div { class: "grid grid-cols-1 lg:grid-cols-2 gap-5",
+
+            // ── Local speakers ────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                h2 { class: "font-semibold text-gray-900", {t!("talks-local-speakers")} }
+                if local.is_empty() {
+                    p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-no-local-speakers")} }
+                } else {
+                    ul { class: "divide-y divide-gray-100",
+                        for (name , record) in local {
+                            {
+                                let outlines_text = numbers_str(&record.outlines);
+                                rsx! {
+                                    li { class: "py-2 flex items-center justify-between gap-2 text-sm",
+                                        div { class: "min-w-0",
+                                            p { class: "font-medium text-gray-900", "{name}" }
+                                            p { class: "text-xs text-gray-500 truncate",
+                                                if outlines_text.is_empty() {
+                                                    {t!("talks-no-outlines-set")}
+                                                } else {
+                                                    "{outlines_text}"
+                                                }
+                                            }
+                                        }
+                                        button {
+                                            class: "px-2 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 shrink-0",
+                                            onclick: move |_| editing.set(Some(record.clone())),
+                                            {t!("btn-edit")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // ── Visiting speakers ─────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                div { class: "flex items-center justify-between gap-2",
+                    h2 { class: "font-semibold text-gray-900", {t!("talks-visiting-speakers")} }
+                    button {
+                        class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors",
+                        onclick: move |_| {
+                            editing.set(Some(TalkSpeaker {
+                                id: None,
+                                publisher: None,
+                                name: None,
+                                congregation: None,
+                                phone: None,
+                                email: None,
+                                outlines: vec![],
+                            }));
+                        },
+                        {t!("talks-add-speaker")}
+                    }
+                }
+                if visiting.is_empty() {
+                    p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-no-visiting-speakers")} }
+                } else {
+                    ul { class: "divide-y divide-gray-100",
+                        for speaker in visiting {
+                            {
+                                let label = speaker_label(&speaker, &users, &name_fmt);
+                                let contact = [speaker.phone.clone(), speaker.email.clone()]
+                                    .into_iter()
+                                    .flatten()
+                                    .collect::<Vec<_>>()
+                                    .join(" · ");
+                                let outlines_text = numbers_str(&speaker.outlines);
+                                let id = speaker.id.clone();
+                                rsx! {
+                                    li { class: "py-2 flex items-start justify-between gap-2 text-sm",
+                                        div { class: "min-w-0",
+                                            p { class: "font-medium text-gray-900", "{label}" }
+                                            if !contact.is_empty() {
+                                                p { class: "text-xs text-gray-500 truncate", "{contact}" }
+                                            }
+                                            if !outlines_text.is_empty() {
+                                                p { class: "text-xs text-gray-400 truncate", "{outlines_text}" }
+                                            }
+                                        }
+                                        div { class: "flex gap-1 shrink-0",
+                                            button {
+                                                class: "px-2 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50",
+                                                onclick: move |_| editing.set(Some(speaker.clone())),
+                                                {t!("btn-edit")}
+                                            }
+                                            if let Some(id) = id {
+                                                button {
+                                                    class: "px-2 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50",
+                                                    onclick: move |_| on_delete(id.clone()),
+                                                    "✕"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SpeakerForm(
+    speaker: TalkSpeaker,
+    /// Name shown for a local speaker, whose name is not edited here.
+    label: String,
+    outlines: Vec<TalkOutline>,
+    on_close: Callback<()>,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let is_local = speaker.is_local();
+    let mut name = use_signal(|| speaker.name.clone().unwrap_or_default());
+    let mut congregation = use_signal(|| speaker.congregation.clone().unwrap_or_default());
+    let mut phone = use_signal(|| speaker.phone.clone().unwrap_or_default());
+    let mut email = use_signal(|| speaker.email.clone().unwrap_or_default());
+    let mut numbers = use_signal(|| numbers_str(&speaker.outlines));
+    let mut saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let parsed = parse_numbers(&numbers());
+    let unknown: Vec<u16> = parsed
+        .iter()
+        .copied()
+        .filter(|n| !outlines.iter().any(|o| o.number == *n))
+        .collect();
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        if !is_local && name().trim().is_empty() {
+            error.set(Some(t!("talks-name-required")));
+            return;
+        }
+        let data = TalkSpeakerData {
+            publisher: speaker.publisher.clone(),
+            name: if is_local { None } else { optional(name()) },
+            congregation: if is_local { None } else { optional(congregation()) },
+            phone: if is_local { None } else { optional(phone()) },
+            email: if is_local { None } else { optional(email()) },
+            outlines: parse_numbers(&numbers()),
+        };
+        let id = speaker.id.clone();
+        saving.set(true);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            let result = match id {
+                Some(id) => TalkSpeaker::update(&db, &crypto, id, data).await.map(|_| ()),
+                None => TalkSpeaker::create(&db, &crypto, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-primary-200 p-4 space-y-3",
+            if is_local {
+                h2 { class: "font-semibold text-gray-900", "{label}" }
+            } else {
+                div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("weekend-speaker-name")} }
+                        input { r#type: "text", class: INPUT_CLS, value: name(), oninput: move |e| name.set(e.value()) }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("weekend-speaker-congregation")} }
+                        input { r#type: "text", class: INPUT_CLS, value: congregation(), oninput: move |e| congregation.set(e.value()) }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("talks-phone")} }
+                        input { r#type: "tel", class: INPUT_CLS, value: phone(), oninput: move |e| phone.set(e.value()) }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("talks-email")} }
+                        input { r#type: "email", class: INPUT_CLS, value: email(), oninput: move |e| email.set(e.value()) }
+                    }
+                }
+            }
+            label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                span { {t!("talks-speaker-outlines")} }
+                input {
+                    r#type: "text",
+                    class: INPUT_CLS,
+                    placeholder: "1, 15, 40",
+                    value: numbers(),
+                    oninput: move |e| numbers.set(e.value()),
+                }
+            }
+            if !unknown.is_empty() && !outlines.is_empty() {
+                p { class: "text-xs text-amber-700",
+                    {t!("talks-unknown-outlines")}
+                    {format!(" {}", numbers_str(&unknown))}
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                    onclick: move |_| on_close.call(()),
+                    {t!("btn-cancel")}
+                }
+                button {
+                    class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: on_save,
+                    {t!("btn-save")}
+                }
+            }
+        }
+    }
+}
+
+// ── History ───────────────────────────────────────────────────────────────────
+
+#[component]
+fn HistoryView(
+    users: Vec<User>,
+    outlines: Vec<TalkOutline>,
+    outgoing: Vec<OutgoingTalk>,
+    meetings: Vec<WeekendMeeting>,
+    settings: TalkSettings,
+    name_fmt: NameFormat,
+    date_fmt: DateFormat,
+    on_changed: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut months = use_signal(|| settings.repeat_months.to_string());
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let today = today_str();
+    let name_of = |id: &RecordId| {
+        users
+            .iter()
+            .find(|u| u.id.as_ref() == Some(id))
+            .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+            .unwrap_or_else(|| "—".to_string())
+    };
+    let talks_given: Vec<(String, u16)> = meetings
+        .iter()
+        .filter_map(|m| Some((m.date.clone(), m.talk_number?)))
+        .collect();
+
+    // Talks given here, newest first, each with an earlier date of the same
+    // outline when it falls inside the repeat window.
+    let given_here: Vec<(String, String, String, Option<String>)> = meetings
+        .iter()
+        .rev()
+        .filter(|m| m.date <= today && (m.talk_number.is_some() || !m.talk_title.is_empty()))
+        .map(|m| {
+            let speaker = match (&m.speaker, &m.visiting_speaker) {
+                (Some(id), _) => name_of(id),
+                (None, Some(name)) => name.clone(),
+                _ => "—".to_string(),
+            };
+            let talk = match m.talk_number {
+                Some(_) => outline_label(&outlines, m.talk_number),
+                None => m.talk_title.clone(),
+            };
+            let repeat = m
+                .talk_number
+                .and_then(|n| repeated_since(&talks_given, &m.date, n, settings.repeat_months))
+                .map(|d| format_date(d, &date_fmt));
+            (format_date(&m.date, &date_fmt), talk, speaker, repeat)
+        })
+        .collect();
+    let repeats = given_here.iter().filter(|r| r.3.is_some()).count();
+
+    let given_away: Vec<(String, String, String)> = outgoing
+        .iter()
+        .filter(|o| o.date <= today)
+        .map(|o| {
+            (
+                format_date(&o.date, &date_fmt),
+                format!("{} — {}", name_of(&o.speaker), o.congregation),
+                outline_label(&outlines, o.outline),
+            )
+        })
+        .collect();
+
+    let on_save_months = move |_| {
+        let Ok(n) = months().trim().parse::<u8>() else {
+            error.set(Some(t!("talks-months-invalid")));
+            return;
+        };
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match TalkSettings::upsert(&db, &TalkSettings { repeat_months: n }).await {
+                Ok(()) => on_changed.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "space-y-5",
+
+            // ── Repeat window ─────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex flex-wrap items-end gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("talks-repeat-months")} }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        max: "120",
+                        class: "w-24 px-2 py-1.5 text-sm border border-gray-200 rounded-lg",
+                        value: months(),
+                        oninput: move |e| months.set(e.value()),
+                    }
+                }
+                button {
+                    class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                    onclick: on_save_months,
+                    {t!("btn-save")}
+                }
+                if repeats > 0 {
+                    p { class: "text-sm text-amber-700",
+                        "⚠️ {repeats} "
+                        {t!("talks-repeats-found")}
+                    }
+                }
+                if let Some(err) = error() {
+                    p { class: "text-sm text-red-600", "{err}" }
+                }
+            }
+
+            div { class: "grid grid-cols-1 lg:grid-cols-2 gap-5",
+
+                // ── Given here ────────────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                    h2 { class: "font-semibold text-gray-900", {t!("talks-given-here")} }
+                    if given_here.is_empty() {
+                        p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-no-history")} }
+                    } else {
+                        ul { class: "divide-y divide-gray-100",
+                            for (date , talk , speaker , repeat) in given_here {
+                                li { class: "py-2 text-sm",
+                                    div { class: "flex justify-between gap-2",
+                                        span { class: "font-medium text-gray-900", "{date}" }
+                                        span { class: "text-gray-700 truncate", "{speaker}" }
+                                    }
+                                    p { class: "text-xs text-gray-500 truncate", "{talk}" }
+                                    if let Some(prev) = repeat {
+                                        p { class: "text-xs text-amber-700",
+                                            "⚠️ "
+                                            {t!("talks-repeat-warning")}
+                                            " {prev}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // ── Given elsewhere ───────────────────────────────────────
+                div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                    h2 { class: "font-semibold text-gray-900", {t!("talks-given-away")} }
+                    if given_away.is_empty() {
+                        p { class: "text-sm text-gray-400 py-4 text-center", {t!("talks-no-history")} }
+                    } else {
+                        ul { class: "divide-y divide-gray-100",
+                            for (date , who , talk) in given_away {
+                                li { class: "py-2 text-sm",
+                                    div { class: "flex justify-between gap-2",
+                                        span { class: "font-medium text-gray-900", "{date}" }
+                                        span { class: "text-gray-700 truncate", "{who}" }
+                                    }
+                                    p { class: "text-xs text-gray-500 truncate", "{talk}" }
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
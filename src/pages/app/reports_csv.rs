@@ -0,0 +1,431 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_months, current_year_month};
+use crate::format::effective_name_format;
+use crate::models::congregation::{Congregation, NameFormat};
+use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData};
+use crate::models::user::User;
+use crate::reports::csv::{self, Column, ImportRow};
+use crate::reports::download_file;
+use crate::reports::service_year::{service_year_bounds, service_year_of};
+use crate::Route;
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// `<input type="month">` value → `(year, month)`.
+fn parse_month_input(s: &str) -> Option<(i32, u8)> {
+    let (y, m) = s.split_once('-')?;
+    let month: u8 = m.parse().ok()?;
+    (1..=12).contains(&month).then_some((y.parse().ok()?, month))
+}
+
+fn month_input(ym: (i32, u8)) -> String {
+    format!("{:04}-{:02}", ym.0, ym.1)
+}
+
+/// Oldest and newest month among rows that parsed.
+fn period_span(rows: &[ImportRow]) -> Option<((i32, u8), (i32, u8))> {
+    let periods = rows.iter().filter_map(|r| r.data.as_ref().map(|d| (d.year, d.month)));
+    let min = periods.clone().min()?;
+    let max = periods.max()?;
+    Some((min, max))
+}
+
+// ── Main page ─────────────────────────────────────────────────────────────────
+
+/// Export field service reports to CSV and import them from a spreadsheet.
+#[component]
+pub fn AppReportsCsv() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    // ── Export state ──────────────────────────────────────────────────────────
+    // Default to the current service year up to the last month due.
+    let (cur_year, cur_month) = current_year_month();
+    let last_due = add_months(cur_year, cur_month, -1);
+    let sy_start = service_year_bounds(service_year_of(last_due.0, last_due.1)).0;
+    let mut export_from = use_signal(|| month_input(sy_start));
+    let mut export_to = use_signal(|| month_input(last_due));
+    let mut exporting = use_signal(|| false);
+    let mut export_msg: Signal<Option<(bool, String)>> = use_signal(|| None);
+
+    let on_export = move |_| {
+        if *exporting.peek() {
+            return;
+        }
+        let (Some(from), Some(to)) = (
+            parse_month_input(&export_from.peek()),
+            parse_month_input(&export_to.peek()),
+        ) else {
+            export_msg.set(Some((false, t!("csv-export-bad-range"))));
+            return;
+        };
+        if from > to {
+            export_msg.set(Some((false, t!("csv-export-bad-range"))));
+            return;
+        }
+        exporting.set(true);
+        export_msg.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                exporting.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            let users = users_res.peek().clone().unwrap_or_default();
+            match FieldServiceReport::by_period(&db, &crypto, from, to).await {
+                Ok(reports) => {
+                    let text = csv::export_reports(&reports, &users, &name_fmt.peek());
+                    let filename = format!("reports-{}-{}.csv", month_input(from), month_input(to));
+                    download_file(&filename, "text/csv;charset=utf-8", text);
+                    export_msg.set(Some((true, format!("{} {}", reports.len(), t!("csv-exported")))));
+                }
+                Err(e) => export_msg.set(Some((false, e.to_string()))),
+            }
+            exporting.set(false);
+        });
+    };
+
+    // ── Import state ──────────────────────────────────────────────────────────
+    // Whole file, header record first.
+    let mut file_rows: Signal<Vec<csv::Record>> = use_signal(Vec::new);
+    let mut file_name = use_signal(String::new);
+    let mut mapping: Signal<Vec<Column>> = use_signal(Vec::new);
+    let mut overwrite = use_signal(|| false);
+    let mut importing = use_signal(|| false);
+    let mut import_msg: Signal<Option<(bool, String)>> = use_signal(|| None);
+
+    // Reports already stored for the months the file covers.
+    let mut existing_res = use_resource(move || {
+        let rows = file_rows();
+        let map = mapping();
+        let users = users_res().unwrap_or_default();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let data_rows = rows.get(1..).unwrap_or_default();
+            let Some((from, to)) = period_span(&csv::validate(data_rows, &map, &users, &[])) else {
+                return vec![];
+            };
+            let crypto = crypto_signal.read().clone();
+            FieldServiceReport::by_period(&db, &crypto, from, to)
+                .await
+                .unwrap_or_default()
+        }
+    });
+
+    let users = users_res().unwrap_or_default();
+    let all_rows = file_rows();
+    let headers = all_rows.first().map(|r| r.fields.clone()).unwrap_or_default();
+    let map = mapping();
+    let mapping_check = csv::check_mapping(&map);
+    let checking = existing_res.read().is_none();
+    let preview: Vec<ImportRow> = if mapping_check.is_ok() {
+        csv::validate(
+            all_rows.get(1..).unwrap_or_default(),
+            &map,
+            &users,
+            &existing_res().unwrap_or_default(),
+        )
+    } else {
+        vec![]
+    };
+    let valid_count = preview.iter().filter(|r| r.is_valid()).count();
+    let error_count = preview.len() - valid_count;
+    let existing_count = preview.iter().filter(|r| r.is_valid() && r.existing.is_some()).count();
+    let to_import = if overwrite() { valid_count } else { valid_count - existing_count };
+
+    let on_import = {
+        let preview = preview.clone();
+        move |_| {
+            if *importing.peek() || checking {
+                return;
+            }
+            let overwrite = *overwrite.peek();
+            let batch: Vec<(Option<RecordId>, FieldServiceReportData)> = preview
+                .iter()
+                .filter(|r| r.is_valid() && (overwrite || r.existing.is_none()))
+                .filter_map(|r| Some((r.existing.clone(), r.data.clone()?)))
+                .collect();
+            if batch.is_empty() {
+                return;
+            }
+            let skipped = preview.len() - batch.len();
+            importing.set(true);
+            import_msg.set(None);
+            spawn(async move {
+                let Some(db) = db_signal.read().db.clone() else {
+                    importing.set(false);
+                    return;
+                };
+                let crypto = crypto_signal.read().clone();
+                match FieldServiceReport::save_batch(&db, &crypto, batch).await {
+                    Ok(summary) => {
+                        import_msg.set(Some((
+                            true,
+                            format!(
+                                "{} {} · {} {} · {} {}",
+                                summary.created,
+                                t!("bulk-created"),
+                                summary.updated,
+                                t!("bulk-updated"),
+                                skipped,
+                                t!("csv-skipped")
+                            ),
+                        )));
+                        existing_res.restart();
+                    }
+                    Err(e) => import_msg.set(Some((false, e.to_string()))),
+                }
+                importing.set(false);
+            });
+        }
+    };
+
+    let input_cls = "px-2 py-1.5 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500";
+    let msg_cls = |ok: bool| {
+        if ok { "text-sm text-emerald-700" } else { "text-sm text-red-600" }
+    };
+
+    rsx! {
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
+                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-reports-csv")} }
+                Link {
+                    to: Route::AppFieldServiceReports {},
+                    class: "text-sm text-primary-600 hover:underline",
+                    {t!("page-field-service-reports")}
+                }
+            }
+
+            // ── Export ────────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-3",
+                h2 { class: "font-semibold text-gray-900", {t!("csv-export-title")} }
+                p { class: "text-xs text-gray-500", {t!("csv-export-hint")} }
+                div { class: "flex flex-wrap items-end gap-3",
+                    label { class: "text-xs text-gray-600 space-y-1",
+                        span { class: "block", {t!("csv-from")} }
+                        input {
+                            r#type: "month",
+                            class: input_cls,
+                            value: export_from(),
+                            oninput: move |e| export_from.set(e.value()),
+                        }
+                    }
+                    label { class: "text-xs text-gray-600 space-y-1",
+                        span { class: "block", {t!("csv-to")} }
+                        input {
+                            r#type: "month",
+                            class: input_cls,
+                            value: export_to(),
+                            oninput: move |e| export_to.set(e.value()),
+                        }
+                    }
+                    button {
+                        class: "px-4 py-2 rounded-lg bg-primary-600 text-white text-sm font-medium hover:bg-primary-700 disabled:opacity-50",
+                        disabled: exporting(),
+                        onclick: on_export,
+                        "📤 "
+                        {t!("csv-export-btn")}
+                    }
+                }
+                if let Some((ok, msg)) = export_msg.read().clone() {
+                    p { class: msg_cls(ok), "{msg}" }
+                }
+            }
+
+            // ── Import ────────────────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-4",
+                h2 { class: "font-semibold text-gray-900", {t!("csv-import-title")} }
+                p { class: "text-xs text-gray-500", {t!("csv-import-hint")} }
+                div { class: "flex flex-wrap items-center gap-3",
+                    div { class: "relative",
+                        input {
+                            r#type: "file",
+                            accept: ".csv,text/csv",
+                            class: "absolute inset-0 opacity-0 w-full h-full cursor-pointer",
+                            onchange: move |e| {
+                                let Some(file) = e.files().into_iter().next() else { return };
+                                import_msg.set(None);
+                                spawn(async move {
+                                    match file.read_string().await {
+                                        Ok(text) => {
+                                            let rows = csv::parse(&text);
+                                            let guessed = csv::guess_mapping(
+                                                rows.first().map(|r| r.fields.as_slice()).unwrap_or_default(),
+                                            );
+                                            file_name.set(file.name());
+                                            mapping.set(guessed);
+                                            file_rows.set(rows);
+                                        }
+                                        Err(_) => import_msg.set(Some((false, t!("csv-read-error")))),
+                                    }
+                                });
+                            },
+                        }
+                        span { class: "inline-flex px-4 py-2 rounded-lg border border-gray-200 text-sm text-gray-700 hover:bg-gray-50",
+                            "📥 "
+                            {t!("csv-choose-file")}
+                        }
+                    }
+                    if !file_name().is_empty() {
+                        span { class: "text-sm text-gray-600", "{file_name} · {preview.len()} " {t!("csv-rows")} }
+                    }
+                }
+
+                if !headers.is_empty() {
+                    // Step 2: column mapping
+                    div { class: "space-y-2",
+                        h3 { class: "text-sm font-semibold text-gray-800", {t!("csv-mapping-title")} }
+                        div { class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-2",
+                            for (i , header) in headers.iter().cloned().enumerate() {
+                                label { key: "{i}", class: "flex items-center gap-2 text-sm",
+                                    span { class: "flex-1 truncate font-medium text-gray-700", title: header.clone(), "{header}" }
+                                    select {
+                                        class: input_cls,
+                                        onchange: move |e| {
+                                            let col = Column::from_key(&e.value());
+                                            if let Some(slot) = mapping.write().get_mut(i) {
+                                                *slot = col;
+                                            }
+                                        },
+                                        for col in Column::ALL {
+                                            option {
+                                                value: col.key(),
+                                                selected: map.get(i) == Some(&col),
+                                                {col.label()}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if let Err(problem) = mapping_check {
+                            p { class: "text-sm text-amber-700", "⚠️ " {problem.label()} }
+                        }
+                    }
+                }
+
+                if !preview.is_empty() {
+                    // Step 3: preview
+                    div { class: "flex flex-wrap items-center gap-3 text-sm",
+                        span { class: "text-emerald-700", "{valid_count} " {t!("csv-valid")} }
+                        if error_count > 0 {
+                            span { class: "text-red-600", "{error_count} " {t!("csv-with-errors")} }
+                        }
+                        if existing_count > 0 {
+                            span { class: "text-amber-700", "{existing_count} " {t!("csv-already-stored")} }
+                            label { class: "flex items-center gap-1.5 text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "rounded border-gray-300 text-primary-600",
+                                    checked: overwrite(),
+                                    oninput: move |e| overwrite.set(e.checked()),
+                                }
+                                {t!("csv-overwrite")}
+                            }
+                        }
+                    }
+                    div { class: "overflow-x-auto border border-gray-100 rounded-lg max-h-[28rem]",
+                        table { class: "w-full text-sm",
+                            thead { class: "bg-gray-50 text-xs text-gray-500 uppercase tracking-wider sticky top-0",
+                                tr {
+                                    th { class: "px-2 py-2 text-right", "#" }
+                                    th { class: "px-2 py-2 text-left", {t!("bulk-col-publisher")} }
+                                    th { class: "px-2 py-2 text-left", {t!("csv-col-year-month")} }
+                                    th { class: "px-2 py-2 text-right", {t!("report-form-hours")} }
+                                    th { class: "px-2 py-2 text-right", {t!("report-form-credits")} }
+                                    th { class: "px-2 py-2 text-right", {t!("report-form-bible-studies")} }
+                                    th { class: "px-2 py-2 text-left", {t!("csv-status")} }
+                                }
+                            }
+                            tbody { class: "divide-y divide-gray-100",
+                                for row in preview.iter().cloned() {
+                                    {
+                                        let num = |v: Option<u32>| v.map(|n| n.to_string()).unwrap_or_default();
+                                        let d = row.data.clone();
+                                        let period = d.as_ref().map(|d| format!("{}-{:02}", d.year, d.month)).unwrap_or_default();
+                                        let hours = num(d.as_ref().and_then(|d| d.hours));
+                                        let credits = num(d.as_ref().and_then(|d| d.credits));
+                                        let studies = num(d.as_ref().and_then(|d| d.bible_studies));
+                                        let row_cls = if !row.errors.is_empty() {
+                                            "bg-red-50"
+                                        } else if row.existing.is_some() {
+                                            "bg-amber-50"
+                                        } else {
+                                            ""
+                                        };
+                                        rsx! {
+                                            tr { key: "{row.line}", class: row_cls,
+                                                td { class: "px-2 py-1.5 text-right text-xs text-gray-400 tabular-nums", "{row.line}" }
+                                                td { class: "px-2 py-1.5 font-medium text-gray-900", "{row.name}" }
+                                                td { class: "px-2 py-1.5 tabular-nums", "{period}" }
+                                                td { class: "px-2 py-1.5 text-right tabular-nums", "{hours}" }
+                                                td { class: "px-2 py-1.5 text-right tabular-nums", "{credits}" }
+                                                td { class: "px-2 py-1.5 text-right tabular-nums", "{studies}" }
+                                                td { class: "px-2 py-1.5 text-xs",
+                                                    if !row.errors.is_empty() {
+                                                        for err in row.errors.iter() {
+                                                            p { class: "text-red-600", {err.label()} }
+                                                        }
+                                                    } else if row.existing.is_some() {
+                                                        span { class: "text-amber-700", {t!("csv-duplicate-existing")} }
+                                                    } else {
+                                                        span { class: "text-emerald-700", {t!("csv-new")} }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex flex-wrap items-center justify-end gap-3",
+                        if let Some((ok, msg)) = import_msg.read().clone() {
+                            span { class: msg_cls(ok), "{msg}" }
+                        }
+                        button {
+                            class: "px-4 py-2 rounded-lg bg-primary-600 text-white text-sm font-medium hover:bg-primary-700 disabled:opacity-50",
+                            disabled: importing() || checking || to_import == 0,
+                            onclick: on_import,
+                            {format!("{} {}", t!("csv-import-btn"), to_import)}
+                        }
+                    }
+                } else if let Some((ok, msg)) = import_msg.read().clone() {
+                    p { class: msg_cls(ok), "{msg}" }
+                }
+            }
+        }
+    }
+}
@@ -4,62 +4,14 @@ use dioxus_primitives::tabs::{TabContent, TabList, TabTrigger, Tabs};
 use surrealdb::types::RecordId;
 
 use crate::database::{use_crypto, use_db, ls_get};
+use crate::dates::{add_days, current_year_month, parse_date, today_str};
+use crate::format::format_name;
 use crate::models::congregation::{Congregation, NameFormat};
 use crate::models::territory::{
     Territory, TerritoryAddress, TerritoryAddressData, TerritoryAssignment,
-    TerritoryAssignmentData, TerritoryData, TerritoryRequest, TerritoryRequestData,
+    TerritoryAssignmentData, TerritoryData, TerritoryRequest, TerritoryRequestData, LOAN_MONTHS,
 };
 use crate::models::user::{User, UserType};
-use crate::pages::app::user::format_name;
-
-// ── Platform helpers ──────────────────────────────────────────────────────────
-
-#[cfg(target_arch = "wasm32")]
-fn today_iso() -> String {
-    let d = js_sys::Date::new_0();
-    format!(
-        "{:04}-{:02}-{:02}",
-        d.get_full_year(),
-        d.get_month() + 1,
-        d.get_date()
-    )
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn today_iso() -> String {
-    "2026-06-11".to_string()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn days_ago_iso(days: u32) -> String {
-    // Use a JS expression to compute date string
-    let js = format!(
-        "var d=new Date(Date.now()-{}*86400000);\
-         d.getFullYear()+'-'+String(d.getMonth()+1).padStart(2,'0')+'-'+String(d.getDate()).padStart(2,'0')",
-        days
-    );
-    if let Ok(val) = js_sys::eval(&js) {
-        if let Some(s) = val.as_string() {
-            return s;
-        }
-    }
-    today_iso()
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn days_ago_iso(_days: u32) -> String {
-    today_iso()
-}
-
-#[cfg(target_arch = "wasm32")]
-fn current_year() -> i32 {
-    js_sys::Date::new_0().get_full_year() as i32
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year() -> i32 {
-    2026
-}
 
 fn rid_str(id: &RecordId) -> String {
     format!(
@@ -78,12 +30,9 @@ fn parse_rid(s: &str) -> Option<RecordId> {
 }
 
 fn months_since(date: &str) -> i32 {
-    let today = today_iso();
-    let ty: i32 = today.get(0..4).and_then(|s| s.parse().ok()).unwrap_or(2026);
-    let tm: i32 = today.get(5..7).and_then(|s| s.parse().ok()).unwrap_or(1);
-    let dy: i32 = date.get(0..4).and_then(|s| s.parse().ok()).unwrap_or(ty);
-    let dm: i32 = date.get(5..7).and_then(|s| s.parse().ok()).unwrap_or(tm);
-    (ty - dy) * 12 + (tm - dm)
+    let (ty, tm) = current_year_month();
+    let (dy, dm) = parse_date(date).map(|(y, m, _)| (y, m)).unwrap_or((ty, tm));
+    (ty - dy) * 12 + (tm as i32 - dm as i32)
 }
 
 fn is_assignable(u: &User) -> bool {
@@ -159,7 +108,7 @@ fn TerritoryModal(title: String, on_close: Callback<()>, children: Element) -> E
 // ── LeafletMap ────────────────────────────────────────────────────────────────
 
 #[component]
-fn LeafletMap(
+pub(crate) fn LeafletMap(
     map_id: String,
     center_lat: f64,
     center_lng: f64,
@@ -168,6 +117,9 @@ fn LeafletMap(
     addresses_json: String,
     draw_mode: ReadOnlySignal<bool>,
     on_point: Callback<String>,
+    /// Each click replaces the previous point instead of extending a boundary.
+    #[props(default)]
+    single_point: bool,
 ) -> Element {
     let id = map_id.clone();
     let bnd = boundary_json.clone();
@@ -195,15 +147,15 @@ function initMap(){{
         attribution:'&copy; OpenStreetMap contributors',maxZoom:19
     }}).addTo(map);
     var bnd={bnd};
+    var addrs={addr};
     var pk='_theo_poly_'+mid;
     window[pk]=bnd.slice();
     if(bnd.length>2){{
         L.polygon(bnd,{{color:'#4f46e5',weight:2,fillOpacity:0.15}}).addTo(map);
         map.fitBounds(L.polygon(bnd).getBounds().pad(0.1));
-    }}else if(bnd.length===0){{
+    }}else if(bnd.length===0&&addrs.length===0){{
         map.locate({{setView:true,maxZoom:14}});
     }}
-    var addrs={addr};
     addrs.forEach(function(a){{
         var icon=L.divIcon({{className:'',html:'<div style="background:#4f46e5;width:10px;height:10px;border-radius:50%;border:2px solid white;box-shadow:0 1px 3px rgba(0,0,0,.4)"></div>',iconSize:[10,10],iconAnchor:[5,5]}});
         L.marker([a.lat,a.lng],{{icon:icon}}).bindTooltip(a.description).addTo(map);
@@ -214,6 +166,7 @@ function initMap(){{
             var lat=e.latlng.lat.toFixed(6);
             var lng=e.latlng.lng.toFixed(6);
             window[pk]=window[pk]||[];
+            if({single}){{window[pk]=[];}}
             window[pk].push([parseFloat(lat),parseFloat(lng)]);
             if(window['_theo_pl_'+mid]){{map.removeLayer(window['_theo_pl_'+mid]);window['_theo_pl_'+mid]=null;}}
             (window['_theo_mks_'+mid]||[]).forEach(function(m){{map.removeLayer(m);}});
@@ -255,6 +208,7 @@ ensureLeaflet(initMap);
                 bnd = bnd,
                 addr = addr,
                 draw = if draw { "true" } else { "false" },
+                single = if single_point { "true" } else { "false" },
             );
             let mut ev = document::eval(&js);
             loop {
@@ -359,7 +313,7 @@ pub fn AppTerritory() -> Element {
         let db_signal = db_signal.clone();
         async move {
             let db = db_signal.read().db.clone()?;
-            let cutoff = days_ago_iso(30);
+            let cutoff = add_days(&today_str(), -30);
             TerritoryRequest::expire_and_get_pending(&db, &cutoff).await.ok()
         }
     });
@@ -547,7 +501,7 @@ fn MyTerritoriesTab(
                             let terr = territory_map.get(&rid_str(&a.territory)).cloned();
                             let a_id = a.id.clone();
                             let months = months_since(&a.assigned_date);
-                            let overdue = months >= 4;
+                            let overdue = months >= LOAN_MONTHS;
                             rsx! {
                                 div { class: if overdue { "bg-red-50 border border-red-200 rounded-xl p-4 flex items-center justify-between gap-3" } else { "bg-white border border-gray-200 rounded-xl p-4 flex items-center justify-between gap-3" },
                                     div { class: "flex-1 min-w-0",
@@ -591,7 +545,7 @@ fn MyTerritoriesTab(
                             title: t!("terr-return").to_string(),
                             on_close: move |_| returning.set(None),
                             div { class: "flex flex-col gap-4",
-                                p { class: "text-sm text-gray-600", "{t!(\"terr-returned-date\")}: {today_iso()}" }
+                                p { class: "text-sm text-gray-600", "{t!(\"terr-returned-date\")}: {today_str()}" }
                                 div { class: "flex gap-2 justify-end",
                                     button {
                                         class: "px-4 py-2 text-sm rounded-lg bg-gray-100 hover:bg-gray-200 text-gray-700",
@@ -606,7 +560,7 @@ fn MyTerritoriesTab(
                                             move |_| {
                                                 let db_signal = db_signal.clone();
                                                 let aid = aid.clone();
-                                                let today = today_iso();
+                                                let today = today_str();
                                                 returning.set(None);
                                                 spawn(async move {
                                                     if let Some(db) = db_signal.read().db.clone() {
@@ -698,7 +652,7 @@ fn RequestTerritoryModal(
                                                 TerritoryRequestData {
                                                     user: uid,
                                                     notes: if n.is_empty() { None } else { Some(n) },
-                                                    requested_date: today_iso(),
+                                                    requested_date: today_str(),
                                                     status: "pending".to_string(),
                                                 },
                                             )
@@ -1353,7 +1307,7 @@ fn AssignmentsTab(
     name_fmt: Signal<NameFormat>,
 ) -> Element {
     let db_signal = use_db();
-    let mut sel_year = use_signal(current_year);
+    let mut sel_year = use_signal(|| current_year_month().0);
     let mut assign_open = use_signal(|| false);
     let mut assign_form = use_signal(AssignFormState::default);
 
@@ -1391,7 +1345,7 @@ fn AssignmentsTab(
         .read().as_ref().and_then(|r| r.as_ref()).cloned().unwrap_or_default();
 
     let (overdue, rest): (Vec<_>, Vec<_>) = assignments.iter()
-        .partition(|a| a.returned_date.is_none() && months_since(&a.assigned_date) >= 4);
+        .partition(|a| a.returned_date.is_none() && months_since(&a.assigned_date) >= LOAN_MONTHS);
 
     let assignable_users: Vec<&User> = users.iter().filter(|u| is_assignable(u)).collect();
 
@@ -1474,7 +1428,7 @@ fn AssignmentsTab(
                     class: "ml-auto px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
                     onclick: move |_| {
                         *assign_form.write() = AssignFormState {
-                            assigned_date: today_iso(),
+                            assigned_date: today_str(),
                             ..Default::default()
                         };
                         assign_open.set(true);
@@ -1514,7 +1468,7 @@ fn AssignmentsTab(
                                             move |_| {
                                                 let db_signal = db_signal.clone();
                                                 let aid = a_id.clone();
-                                                let today = today_iso();
+                                                let today = today_str();
                                                 spawn(async move {
                                                     if let (Some(db), Some(aid)) = (db_signal.read().db.clone(), aid) {
                                                         let _ = TerritoryAssignment::return_territory(&db, aid, today).await;
@@ -1759,7 +1713,7 @@ fn AssignmentsTab(
                                                                 TerritoryAssignmentData {
                                                                     territory: tid,
                                                                     user: req_user_inner,
-                                                                    assigned_date: today_iso(),
+                                                                    assigned_date: today_str(),
                                                                     returned_date: None,
                                                                 },
                                                             )
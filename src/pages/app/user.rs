@@ -3,77 +3,16 @@ use dioxus_i18n::t;
 
 use crate::components::ResponsiveModal;
 use crate::database::{use_crypto, use_db};
+use crate::dates::current_year_month;
+use crate::format::{
+    date_format_hint, effective_date_format, effective_name_format, format_name,
+};
 use crate::models::congregation::{Congregation, DateFormat, NameFormat};
 use crate::models::field_service_group::FieldServiceGroup;
 use crate::models::field_service_report::FieldServiceReport;
 use crate::models::user::{Appointment, Gender, User, UserData, UserType};
 use crate::Route;
 
-// ── Format helpers ────────────────────────────────────────────────────────────
-
-/// Returns `"FirstLast"` or `"LastFirst"`.
-pub fn effective_name_format(
-    cong: Option<&Congregation>,
-    user_prefs_name: &str,
-) -> NameFormat {
-    if !user_prefs_name.is_empty() {
-        return if user_prefs_name == "LastFirst" { NameFormat::LastFirst } else { NameFormat::FirstLast };
-    }
-    cong.map(|c| c.name_format.clone()).unwrap_or_default()
-}
-
-/// Returns `"YMD"`, `"DMY"`, or `"MDY"`.
-pub fn effective_date_format(
-    cong: Option<&Congregation>,
-    user_prefs_date: &str,
-) -> DateFormat {
-    match user_prefs_date {
-        "DMY" => return DateFormat::DMY,
-        "MDY" => return DateFormat::MDY,
-        "YMD" => return DateFormat::YMD,
-        _ => {}
-    }
-    cong.map(|c| c.date_format.clone()).unwrap_or_default()
-}
-
-pub fn format_name(first: &str, last: &str, fmt: &NameFormat) -> String {
-    match fmt {
-        NameFormat::LastFirst => format!("{last} {first}"),
-        NameFormat::FirstLast => format!("{first} {last}"),
-    }
-}
-
-/// Convert an ISO date string (`YYYY-MM-DD`) to the display format.
-pub fn format_date(iso: &str, fmt: &DateFormat) -> String {
-    if iso.len() != 10 { return iso.to_string(); }
-    let parts: Vec<&str> = iso.splitn(3, '-').collect();
-    if parts.len() != 3 { return iso.to_string(); }
-    let (y, m, d) = (parts[0], parts[1], parts[2]);
-    match fmt {
-        DateFormat::YMD => format!("{y}-{m}-{d}"),
-        DateFormat::DMY => format!("{d}/{m}/{y}"),
-        DateFormat::MDY => format!("{m}/{d}/{y}"),
-    }
-}
-
-/// Pattern hint shown in the date label.
-pub fn date_format_hint(fmt: &DateFormat) -> &'static str {
-    match fmt {
-        DateFormat::YMD => "YYYY-MM-DD",
-        DateFormat::DMY => "DD/MM/YYYY",
-        DateFormat::MDY => "MM/DD/YYYY",
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
-fn current_year_month() -> (i32, u8) {
-    let d = js_sys::Date::new_0();
-    (d.get_full_year() as i32, (d.get_month() + 1) as u8)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year_month() -> (i32, u8) { (2026, 6) }
-
 /// Returns true if this user type should show an Active/Inactive badge.
 pub fn is_publisher_type(t: &UserType) -> bool {
     !matches!(t, UserType::Student)
@@ -3,44 +3,31 @@ use dioxus_i18n::t;
 use surrealdb::types::RecordId;
 
 use crate::components::ResponsiveModal;
-use crate::database::{use_crypto, use_db};
+use crate::database::{DatabaseMode, use_crypto, use_db};
+use crate::dates::current_year_month;
+use crate::format::{
+    date_format_hint, effective_date_format, effective_name_format, format_date, format_name,
+};
 use crate::models::congregation::{Congregation, DateFormat, NameFormat};
 use crate::models::emergency_contact::{EmergencyContact, EmergencyContactData};
 use crate::models::field_service_group::FieldServiceGroup;
 use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData};
+use crate::models::month_close::MonthClose;
+use crate::models::portal::PortalAccount;
+use crate::models::report_follow_up::ReportFollowUp;
 use crate::models::user::{Appointment, Gender, User, UserData, UserType};
+use crate::reports::{month_label, print_html};
+use crate::reports::s21::{self, S21Card};
+use crate::reports::service_year::{recent_months, service_year_of};
+use crate::reports::status::{status_history, PublisherStatus};
 use crate::pages::app::user::{
-    appointment_to_key, date_format_hint, effective_date_format, effective_name_format,
-    format_date, format_name, is_publisher_type, key_to_user_type, user_form_state_from,
+    appointment_to_key, is_publisher_type, key_to_user_type, user_form_state_from,
     user_type_to_key, UserFormBody, UserFormState,
 };
 use crate::Route;
 
 // ── Date / calendar helpers ───────────────────────────────────────────────────
 
-#[cfg(target_arch = "wasm32")]
-fn current_year_month() -> (i32, u8) {
-    let date = js_sys::Date::new_0();
-    (date.get_full_year() as i32, date.get_month() as u8 + 1)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn current_year_month() -> (i32, u8) {
-    (2026, 6)
-}
-
-/// Last 12 calendar months (inclusive of current), newest → oldest.
-fn last_12_months(now_year: i32, now_month: u8) -> Vec<(i32, u8)> {
-    (0..12u32)
-        .map(|i| {
-            let total = (now_year as u32 * 12 + now_month as u32 - 1).saturating_sub(i);
-            let y = (total / 12) as i32;
-            let m = (total % 12 + 1) as u8;
-            (y, m)
-        })
-        .collect()
-}
-
 fn month_name(m: u8) -> &'static str {
     match m {
         1 => "January",
@@ -220,6 +207,12 @@ pub fn AppUserDetail(id: String) -> Element {
         })
     };
 
+    // Months whose reports are closed and can no longer be edited.
+    let closed_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return Default::default() };
+        MonthClose::closed_months(&db).await.unwrap_or_default()
+    });
+
     // Group this user belongs to (as overseer, assistant, or member)
     let group_res = {
         let rid = record_id.clone();
@@ -235,7 +228,10 @@ pub fn AppUserDetail(id: String) -> Element {
 
     // 12-month grid slots
     let (cur_year, cur_month) = current_year_month();
-    let months = last_12_months(cur_year, cur_month);
+    let months = recent_months(cur_year, cur_month, 12);
+
+    // Service year printed on the S-21 publisher record card.
+    let mut s21_year = use_signal(|| service_year_of(cur_year, cur_month));
 
     // ── Modal signals ─────────────────────────────────────────────────────────
     let mut edit_open = use_signal(|| false);
@@ -251,6 +247,7 @@ pub fn AppUserDetail(id: String) -> Element {
         use_signal(|| None);
     let mut delete_report_open = use_signal(|| false);
     let mut delete_report_id: Signal<Option<RecordId>> = use_signal(|| None);
+    let mut delete_report_error: Signal<Option<String>> = use_signal(|| None);
 
     // ── Loading / not-found guard ─────────────────────────────────────────────
     match user_res() {
@@ -328,23 +325,28 @@ pub fn AppUserDetail(id: String) -> Element {
 
     let contacts: Vec<EmergencyContact> = contacts_res().unwrap_or_default();
     let reports: Vec<FieldServiceReport> = reports_res().unwrap_or_default();
+    let closed_months = closed_res().unwrap_or_default();
 
-    // Compute active/inactive from reports for publisher-type users.
+    // Activity status for publisher-type users, derived from their reports.
     let (cy, cm) = current_year_month();
-    let (since_year, since_month) = if cm > 6 {
-        (cy, cm - 6)
-    } else {
-        (cy - 1, cm + 6)
-    };
-    let computed_active: Option<bool> = if is_publisher_type(&user.user_type) {
-        let active = reports.iter().any(|r| {
-            r.preached
-                && (r.year > since_year || (r.year == since_year && r.month >= since_month))
-        });
-        Some(active)
-    } else {
-        None
-    };
+    let status_months: Vec<(i32, u8)> = months.iter().rev().copied().collect();
+    let history: Vec<(i32, u8, PublisherStatus)> =
+        match (&user.id, is_publisher_type(&user.user_type)) {
+            (Some(id), true) => status_history(id, &reports, &status_months),
+            _ => vec![],
+        };
+    let computed_status: Option<PublisherStatus> = history.last().map(|(_, _, s)| *s);
+
+    // Service years offered for the S-21 card: every year with reports + the current one.
+    let mut s21_years: Vec<i32> = reports
+        .iter()
+        .map(|r| service_year_of(r.year, r.month))
+        .collect();
+    s21_years.push(service_year_of(cy, cm));
+    s21_years.sort_unstable_by(|a, b| b.cmp(a));
+    s21_years.dedup();
+    let s21_user = user.clone();
+    let s21_reports = reports.clone();
 
     let user_rid = record_id.clone();
 
@@ -382,15 +384,9 @@ pub fn AppUserDetail(id: String) -> Element {
                                     "{a}"
                                 }
                             }
-                            if let Some(active) = computed_active {
-                                if active {
-                                    span { class: "inline-flex px-2 py-0.5 rounded-full text-xs font-medium bg-emerald-600 text-white",
-                                        {t!("user-badge-active")}
-                                    }
-                                } else {
-                                    span { class: "inline-flex px-2 py-0.5 rounded-full text-xs font-medium bg-gray-400 text-white",
-                                        {t!("user-badge-inactive")}
-                                    }
+                            if let Some(status) = computed_status {
+                                span { class: "inline-flex px-2 py-0.5 rounded-full text-xs font-medium {status.badge_class()}",
+                                    {status.label()}
                                 }
                             }
                             if user.family_head {
@@ -511,12 +507,59 @@ pub fn AppUserDetail(id: String) -> Element {
                 }
             }
 
+            // ── Publisher portal account (online only) ────────────────────
+            if db_signal.read().mode == DatabaseMode::Online && is_publisher_type(&user.user_type) {
+                PortalAccountCard {
+                    publisher: user_rid.clone(),
+                    suggested_username: user.email.clone().unwrap_or_default(),
+                }
+            }
+
             // ── Field service reports ─────────────────────────────────────
             div { class: "bg-white rounded-xl border border-gray-200 overflow-hidden",
-                div { class: "px-5 py-4 border-b border-gray-100",
+                div { class: "flex flex-wrap items-center justify-between gap-2 px-5 py-4 border-b border-gray-100",
                     h2 { class: "text-xs font-semibold text-gray-500 uppercase tracking-wider",
                         {t!("user-detail-reports-title")}
                     }
+                    div { class: "flex items-center gap-2",
+                        select {
+                            class: "px-2 py-1.5 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500",
+                            onchange: move |e| {
+                                if let Ok(y) = e.value().parse::<i32>() {
+                                    s21_year.set(y);
+                                }
+                            },
+                            for y in s21_years.iter().copied() {
+                                option { value: "{y}", selected: y == s21_year(),
+                                    {format!("{} {}", t!("s21-service-year"), y)}
+                                }
+                            }
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 transition-colors",
+                            onclick: move |_| {
+                                let card = S21Card::build(s21_user.clone(), s21_year(), &s21_reports);
+                                print_html(s21::render(&[card], &name_fmt.read(), &date_fmt.read()));
+                            },
+                            "🖨 "
+                            {t!("s21-print-btn")}
+                        }
+                    }
+                }
+                if let Some(err) = delete_report_error() {
+                    p { class: "px-5 py-3 text-sm text-red-600 border-b border-gray-100", "{err}" }
+                }
+                if !history.is_empty() {
+                    div { class: "flex flex-wrap items-center gap-1 px-5 py-3 border-b border-gray-100",
+                        span { class: "text-xs text-gray-500 mr-2", {t!("status-history")} }
+                        for (year , month , status) in history.iter().copied() {
+                            span {
+                                class: "inline-flex px-1.5 py-0.5 rounded text-[10px] font-medium {status.badge_class()}",
+                                title: "{status.label()}",
+                                {format!("{} {:02}", month_label(month).chars().take(3).collect::<String>(), year % 100)}
+                            }
+                        }
+                    }
                 }
                 div { class: "grid grid-cols-1 sm:grid-cols-2 xl:grid-cols-3 divide-y divide-gray-100",
                     for (year , month) in months.iter().copied() {
@@ -532,6 +575,7 @@ pub fn AppUserDetail(id: String) -> Element {
                                     year,
                                     month,
                                     report: existing,
+                                    closed: closed_months.contains(&(year, month)),
                                     on_edit: move |_| {
                                         editing_report.set(Some((year, month, ex_edit.clone())));
                                         report_modal_open.set(true);
@@ -576,6 +620,8 @@ pub fn AppUserDetail(id: String) -> Element {
                         let Some(db) = db_signal.read().db.clone() else { return };
                         let _ = EmergencyContact::delete_by_publisher(&db, rid.clone()).await;
                         let _ = FieldServiceReport::delete_by_publisher(&db, rid.clone()).await;
+                        let _ = ReportFollowUp::delete_by_publisher(&db, rid.clone()).await;
+                        let _ = PortalAccount::delete_by_publisher(&db, rid.clone()).await;
                         let _ = User::delete(&db, rid).await;
                         nav.push(Route::AppUsers {});
                     });
@@ -642,7 +688,10 @@ pub fn AppUserDetail(id: String) -> Element {
                 if let Some(rid) = delete_report_id.read().clone() {
                     spawn(async move {
                         let Some(db) = db_signal.read().db.clone() else { return };
-                        let _ = FieldServiceReport::delete(&db, rid).await;
+                        match FieldServiceReport::delete(&db, rid).await {
+                            Ok(_) => delete_report_error.set(None),
+                            Err(e) => delete_report_error.set(Some(e.to_string())),
+                        }
                         reports_res.restart();
                     });
                 }
@@ -715,6 +764,8 @@ fn ReportMonthCard(
     year: i32,
     month: u8,
     report: Option<FieldServiceReport>,
+    /// The month is closed: show the lock instead of edit actions.
+    closed: bool,
     on_edit: Callback<()>,
     on_delete: Callback<()>,
 ) -> Element {
@@ -725,7 +776,14 @@ fn ReportMonthCard(
             div { class: "flex items-center justify-between gap-2 mb-2",
                 h3 { class: "text-sm font-semibold text-gray-800", "{month_name(month)} {year}" }
                 div { class: "flex gap-1.5",
-                    if has_report {
+                    if closed {
+                        span {
+                            class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-gray-100 text-gray-600",
+                            title: t!("month-closed-hint"),
+                            "🔒 "
+                            {t!("month-closed")}
+                        }
+                    } else if has_report {
                         button {
                             class: "text-xs px-2 py-0.5 border border-gray-200 rounded text-gray-600 hover:bg-gray-100 transition-colors",
                             onclick: move |_| on_edit.call(()),
@@ -786,6 +844,169 @@ fn ReportMonthCard(
     }
 }
 
+// ── PortalAccountCard ─────────────────────────────────────────────────────────
+
+/// Create, reset or disable the publisher's portal login.
+#[component]
+fn PortalAccountCard(publisher: RecordId, suggested_username: String) -> Element {
+    let db_signal = use_db();
+    let pub_for_res = publisher.clone();
+    let mut account_res = use_resource(move || {
+        let p = pub_for_res.clone();
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return None };
+            PortalAccount::by_publisher(&db, p).await.ok().flatten()
+        }
+    });
+    let mut username = use_signal(|| suggested_username.clone());
+    let mut password = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let account = account_res.read().clone().flatten();
+    let input_cls = "w-full px-3 py-2 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 overflow-hidden",
+            div { class: "flex items-center justify-between px-5 py-4 border-b border-gray-100",
+                h2 { class: "text-xs font-semibold text-gray-500 uppercase tracking-wider",
+                    {t!("portal-account-title")}
+                }
+                if let Some(a) = &account {
+                    if a.enabled {
+                        span { class: "inline-flex px-2 py-0.5 rounded-full text-xs bg-emerald-100 text-emerald-800",
+                            {t!("portal-account-enabled")}
+                        }
+                    } else {
+                        span { class: "inline-flex px-2 py-0.5 rounded-full text-xs bg-gray-100 text-gray-600",
+                            {t!("portal-account-disabled")}
+                        }
+                    }
+                }
+            }
+            div { class: "px-5 py-4 space-y-3",
+                if let Some(err) = error.read().clone() {
+                    p { class: "text-sm text-red-600", "{err}" }
+                }
+                match account {
+                    Some(a) => {
+                        let id_pw = a.id.clone();
+                        let id_toggle = a.id.clone();
+                        let enabled = a.enabled;
+                        rsx! {
+                            p { class: "text-sm text-gray-700",
+                                {t!("portal-username")}
+                                ": "
+                                span { class: "font-mono", "{a.username}" }
+                            }
+                            div { class: "flex flex-wrap gap-2",
+                                input {
+                                    class: "{input_cls} flex-1 min-w-[10rem]",
+                                    r#type: "password",
+                                    placeholder: t!("portal-account-new-password"),
+                                    value: password(),
+                                    oninput: move |e| password.set(e.value()),
+                                }
+                                button {
+                                    class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                    disabled: busy() || password().is_empty(),
+                                    onclick: move |_| {
+                                        let Some(id) = id_pw.clone() else { return };
+                                        let Some(db) = db_signal.read().db.clone() else { return };
+                                        let pw = password();
+                                        busy.set(true);
+                                        spawn(async move {
+                                            match PortalAccount::set_password(&db, id, &pw).await {
+                                                Ok(()) => {
+                                                    error.set(None);
+                                                    password.set(String::new());
+                                                }
+                                                Err(e) => error.set(Some(e.to_string())),
+                                            }
+                                            busy.set(false);
+                                        });
+                                    },
+                                    {t!("portal-account-reset-password")}
+                                }
+                                button {
+                                    class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                    disabled: busy(),
+                                    onclick: move |_| {
+                                        let Some(id) = id_toggle.clone() else { return };
+                                        let Some(db) = db_signal.read().db.clone() else { return };
+                                        busy.set(true);
+                                        spawn(async move {
+                                            if let Err(e) = PortalAccount::set_enabled(&db, id, !enabled).await {
+                                                error.set(Some(e.to_string()));
+                                            }
+                                            account_res.restart();
+                                            busy.set(false);
+                                        });
+                                    },
+                                    if enabled {
+                                        {t!("portal-account-disable")}
+                                    } else {
+                                        {t!("portal-account-enable")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        let pub_create = publisher.clone();
+                        rsx! {
+                            p { class: "text-sm text-gray-500", {t!("portal-account-none")} }
+                            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-2",
+                                input {
+                                    class: input_cls,
+                                    r#type: "text",
+                                    placeholder: t!("portal-username"),
+                                    value: username(),
+                                    oninput: move |e| username.set(e.value()),
+                                }
+                                input {
+                                    class: input_cls,
+                                    r#type: "password",
+                                    placeholder: t!("form-password"),
+                                    value: password(),
+                                    oninput: move |e| password.set(e.value()),
+                                }
+                                button {
+                                    class: "px-3 py-2 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors disabled:opacity-50",
+                                    disabled: busy(),
+                                    onclick: move |_| {
+                                        let u = username().trim().to_string();
+                                        let pw = password();
+                                        if u.is_empty() || pw.is_empty() {
+                                            error.set(Some(t!("error-fields-required")));
+                                            return;
+                                        }
+                                        let Some(db) = db_signal.read().db.clone() else { return };
+                                        let p = pub_create.clone();
+                                        busy.set(true);
+                                        spawn(async move {
+                                            match PortalAccount::create(&db, p, &u, &pw).await {
+                                                Ok(()) => {
+                                                    error.set(None);
+                                                    password.set(String::new());
+                                                    account_res.restart();
+                                                }
+                                                Err(e) => error.set(Some(e.to_string())),
+                                            }
+                                            busy.set(false);
+                                        });
+                                    },
+                                    {t!("portal-account-create")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ── Generic confirm modal ─────────────────────────────────────────────────────
 
 #[component]
@@ -1236,6 +1457,8 @@ fn ReportFormModal(
 
     let f = form.read().clone();
     let existing_id = existing.as_ref().and_then(|r| r.id.clone());
+    let existing_source = existing.as_ref().map(|r| r.source).unwrap_or_default();
+    let existing_locked = existing.as_ref().is_some_and(|r| r.locked);
     let pub_id = publisher_id.clone();
 
     let on_submit = use_callback(move |_: Event<MouseData>| {
@@ -1249,6 +1472,10 @@ fn ReportFormModal(
             bible_studies: fd.bible_studies.trim().parse().ok(),
             auxiliary_pioneer: fd.auxiliary_pioneer,
             preached: fd.preached,
+            // Saving from here counts as the secretary's review.
+            source: existing_source,
+            approved: true,
+            locked: existing_locked,
             notes: (!fd.notes.trim().is_empty()).then(|| fd.notes.trim().to_string()),
         };
         let eid = existing_id.clone();
@@ -1,21 +1,948 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{
+    add_days, add_months, add_months_iso, current_year_month, days_between, mondays_in_month,
+};
+use crate::format::{effective_date_format, effective_name_format, format_date, format_name};
+use crate::models::absence::Absence;
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::midweek_meeting::{
+    Classroom, MidweekMeeting, MidweekMeetingData, MidweekPart, PartKind, Section,
+};
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+use crate::pages::app::conflicts::{month_range, ConflictWarnings};
+use crate::reports::{month_label, print_html, s140, s89};
+use crate::schedule::midweek::{
+    apply_picks, assignments, assistant_matches, eligible, part_label, part_qualifies, people_in,
+    rotation_slots, section_label, slot_counts, slot_label, MeetingRole, PartRole,
+};
+use crate::schedule::conflicts::Source;
+use crate::schedule::rotation::{propose, Pick, Reason};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+fn parse_rid(s: &str) -> Option<RecordId> {
+    if s.is_empty() { None } else { RecordId::parse_simple(s).ok() }
+}
+
+/// `(value, label)` options for a person picker: everyone eligible, plus the
+/// current choice when it no longer qualifies so it is not lost silently.
+pub fn person_options(
+    eligible: Vec<&User>,
+    current: Option<&RecordId>,
+    users: &[User],
+    name_fmt: &NameFormat,
+) -> Vec<(String, String)> {
+    let mut opts: Vec<(String, String)> = eligible
+        .iter()
+        .filter_map(|u| {
+            let id = u.id.as_ref()?;
+            Some((rid_str(id), format_name(&u.first_name, &u.last_name, name_fmt)))
+        })
+        .collect();
+    opts.sort_by(|a, b| a.1.to_lowercase().cmp(&b.1.to_lowercase()));
+    if let Some(cur) = current {
+        let key = rid_str(cur);
+        if !opts.iter().any(|(v, _)| *v == key) {
+            let name = users
+                .iter()
+                .find(|u| u.id.as_ref() == Some(cur))
+                .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+                .unwrap_or_else(|| key.clone());
+            opts.insert(0, (key, format!("{name} ({})", t!("midweek-not-eligible"))));
+        }
+    }
+    opts
+}
+
+// ── Main page ─────────────────────────────────────────────────────────────────
 
 #[component]
 pub fn AppWeekdayMeeting() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let mut meetings_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let (Some(from), Some(to)) = (weeks.first(), weeks.last()) else { return vec![] };
+            MidweekMeeting::by_range(&db, from, to).await.unwrap_or_default()
+        }
+    });
+
+    let absences_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        Absence::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    // A year of programs before the month shown, for fair rotation.
+    let history_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let (Some(first), Some(to)) = (weeks.first(), weeks.last()) else { return vec![] };
+            let from = add_months_iso(first, -12);
+            MidweekMeeting::by_range(&db, &from, to).await.unwrap_or_default()
+        }
+    });
+
+    let mut editing: Signal<Option<MidweekMeeting>> = use_signal(|| None);
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || meetings_res.read().is_none()
+        || absences_res.read().is_none()
+        || history_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let meetings = meetings_res().unwrap_or_default();
+    let absences = absences_res().unwrap_or_default();
+    let past = history_res().unwrap_or_default();
+    let weeks = mondays_in_month(sel_year(), sel_month());
+    let nf = name_fmt();
+    let df = date_fmt();
+    let (conflicts_from, conflicts_to) = month_range(sel_year(), sel_month());
+    let name_of = |id: &Option<RecordId>| -> String {
+        id.as_ref()
+            .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+            .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+            .unwrap_or_else(|| "—".to_string())
+    };
+    // New programs fall on the same weekday as the ones already planned.
+    let meeting_offset = meetings
+        .first()
+        .and_then(|m| days_between(&m.week_start, &m.date))
+        .unwrap_or(0);
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+        editing.set(None);
+    };
+
+    let congregation_name = congregation_res
+        .read()
+        .as_ref()
+        .and_then(|c| c.as_ref())
+        .map(|c| c.name.clone())
+        .unwrap_or_default();
+    let month_title = format!("{} {}", month_label(sel_month()), sel_year());
+    let has_slips = !s89::slips(&meetings, &users, &nf, &df).is_empty();
+
+    let on_print_schedule = {
+        let meetings = meetings.clone();
+        let users = users.clone();
+        let nf = nf.clone();
+        let df = df.clone();
+        move |_| {
+            print_html(s140::render(&meetings, &users, &congregation_name, &month_title, &nf, &df))
+        }
+    };
+
+    let on_print_slips = {
+        let meetings = meetings.clone();
+        let users = users.clone();
+        let nf = nf.clone();
+        let df = df.clone();
+        move |_| print_html(s89::render(&s89::slips(&meetings, &users, &nf, &df)))
+    };
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            div { class: "flex items-center justify-between",
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-3",
                 h1 { class: "text-2xl font-bold text-gray-900", {t!("page-weekday-meeting")} }
-                button { class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
-                    {t!("btn-add-assignment")}
+                div { class: "flex flex-wrap gap-2",
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        disabled: is_loading || meetings.is_empty(),
+                        onclick: on_print_schedule,
+                        {t!("s140-print-btn")}
+                    }
+                    button {
+                        class: "px-3 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                        disabled: is_loading || !has_slips,
+                        onclick: on_print_slips,
+                        {t!("s89-print-btn")}
+                    }
+                }
+            }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(-1),
+                    "‹"
+                }
+                span { class: "font-semibold text-gray-900",
+                    "{month_label(sel_month())} {sel_year()}"
+                }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(1),
+                    "›"
+                }
+            }
+
+            ConflictWarnings {
+                key: "{conflicts_from}",
+                from: conflicts_from.clone(),
+                to: conflicts_to.clone(),
+                source: Source::Midweek,
+                name_fmt: nf.clone(),
+                date_fmt: df.clone(),
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("midweek-loading")} }
+                }
+            } else if let Some(draft) = editing() {
+                MidweekEditor {
+                    key: "{draft.week_start}",
+                    history: assignments(
+                        &past
+                            .iter()
+                            .filter(|m| m.week_start != draft.week_start)
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    ),
+                    meeting: draft,
+                    users: users.clone(),
+                    privileges: privileges.clone(),
+                    absences: absences.clone(),
+                    name_fmt: nf.clone(),
+                    on_close: move |_| editing.set(None),
+                    on_saved: move |_| {
+                        editing.set(None);
+                        meetings_res.restart();
+                    },
+                }
+            } else {
+                div { class: "space-y-3",
+                    for week in weeks.iter().cloned() {
+                        {
+                            let existing = meetings.iter().find(|m| m.week_start == week).cloned();
+                            let week_label = format_date(&week, &df);
+                            match existing {
+                                Some(m) => {
+                                    let (filled, total) = slot_counts(&m);
+                                    let open = total - filled;
+                                    let date_label = format_date(&m.date, &df);
+                                    let chairman = name_of(&m.chairman);
+                                    let reading = m.bible_reading.clone();
+                                    let to_edit = m.clone();
+                                    let week_slips = s89::slips(std::slice::from_ref(&m), &users, &nf, &df);
+                                    rsx! {
+                                        div { key: "{week}", class: "bg-white rounded-xl border border-gray-200 p-4 space-y-2",
+                                            div { class: "flex flex-wrap items-center justify-between gap-2",
+                                                div {
+                                                    p { class: "text-sm font-semibold text-gray-900",
+                                                        "{date_label}"
+                                                        if !reading.is_empty() {
+                                                            span { class: "ml-2 font-normal text-gray-500", "· {reading}" }
+                                                        }
+                                                    }
+                                                    p { class: "text-xs text-gray-500",
+                                                        {t!("midweek-chairman")}
+                                                        ": {chairman}"
+                                                    }
+                                                }
+                                                div { class: "flex items-center gap-2",
+                                                    if open > 0 {
+                                                        span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-amber-100 text-amber-800",
+                                                            "{open} "
+                                                            {t!("midweek-open-slots")}
+                                                        }
+                                                    } else {
+                                                        span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-emerald-100 text-emerald-800",
+                                                            {t!("midweek-complete")}
+                                                        }
+                                                    }
+                                                    if !week_slips.is_empty() {
+                                                        button {
+                                                            class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                                            onclick: move |_| print_html(s89::render(&week_slips)),
+                                                            {t!("s89-week-btn")}
+                                                        }
+                                                    }
+                                                    button {
+                                                        class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                                        onclick: move |_| editing.set(Some(to_edit.clone())),
+                                                        {t!("btn-edit")}
+                                                    }
+                                                }
+                                            }
+                                            ul { class: "grid grid-cols-1 sm:grid-cols-2 gap-x-4 gap-y-0.5 text-xs text-gray-600",
+                                                for part in m.parts.iter() {
+                                                    li { class: "flex justify-between gap-2",
+                                                        span { class: "truncate",
+                                                            if part.title.is_empty() {
+                                                                {part_label(part.kind)}
+                                                            } else {
+                                                                "{part.title}"
+                                                            }
+                                                        }
+                                                        span { class: "font-medium text-gray-800 shrink-0",
+                                                            {name_of(&part.assignee)}
+                                                            if part.assistant.is_some() {
+                                                                {format!(" / {}", name_of(&part.assistant))}
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                None => {
+                                    let week_start = week.clone();
+                                    rsx! {
+                                        div { key: "{week}", class: "bg-white rounded-xl border border-dashed border-gray-300 p-4 flex flex-wrap items-center justify-between gap-2",
+                                            p { class: "text-sm text-gray-500",
+                                                {t!("midweek-week-of")}
+                                                " {week_label}"
+                                            }
+                                            button {
+                                                class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors",
+                                                onclick: move |_| {
+                                                    let date = add_days(&week_start, meeting_offset);
+                                                    editing.set(Some(MidweekMeeting::template(week_start.clone(), date)));
+                                                },
+                                                {t!("midweek-create-btn")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── PersonSelect ──────────────────────────────────────────────────────────────
+
+#[component]
+pub fn PersonSelect(
+    label: String,
+    options: Vec<(String, String)>,
+    selected: Option<RecordId>,
+    /// Highlight: the person already has another place in this program.
+    #[props(default)]
+    repeated: bool,
+    on_change: Callback<Option<RecordId>>,
+) -> Element {
+    let current = selected.as_ref().map(rid_str).unwrap_or_default();
+    let cls = if repeated {
+        "w-full px-2 py-1.5 text-sm border border-amber-400 rounded-lg bg-amber-50 focus:outline-none focus:ring-2 focus:ring-primary-500"
+    } else {
+        "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg bg-white focus:outline-none focus:ring-2 focus:ring-primary-500"
+    };
+
+    rsx! {
+        label { class: "flex flex-col gap-1 text-xs text-gray-500 min-w-0",
+            span { "{label}" }
+            select {
+                class: cls,
+                onchange: move |e| on_change.call(parse_rid(&e.value())),
+                option { value: "", selected: current.is_empty(), "—" }
+                for (value , name) in options {
+                    option { value: value.clone(), selected: value == current, "{name}" }
+                }
+            }
+        }
+    }
+}
+
+// ── MidweekEditor ─────────────────────────────────────────────────────────────
+
+#[component]
+fn MidweekEditor(
+    meeting: MidweekMeeting,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    absences: Vec<Absence>,
+    /// `(person, date)` of earlier assignments, balanced by the rotation.
+    history: Vec<(RecordId, String)>,
+    name_fmt: NameFormat,
+    on_close: Callback<()>,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let mut draft = use_signal(|| meeting.clone());
+    let mut proposal: Signal<Option<Vec<Pick>>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut confirm_delete = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut new_kind = use_signal(|| PartKind::LivingAsChristians);
+
+    let d = draft();
+    let is_new = d.id.is_none();
+
+    // How often each person appears; more than once is flagged.
+    let mut load: HashMap<RecordId, usize> = HashMap::new();
+    for id in people_in(&d) {
+        *load.entry(id.clone()).or_default() += 1;
+    }
+    let repeated = |id: &Option<RecordId>| id.as_ref().is_some_and(|id| load.get(id).copied().unwrap_or(0) > 1);
+    let repeated_names: Vec<String> = load
+        .iter()
+        .filter(|(_, n)| **n > 1)
+        .filter_map(|(id, _)| users.iter().find(|u| u.id.as_ref() == Some(id)))
+        .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+        .collect();
+
+    let role_options = |role: MeetingRole, current: &Option<RecordId>| {
+        person_options(
+            eligible(&users, &privileges, |p| role.qualifies(p)),
+            current.as_ref(),
+            &users,
+            &name_fmt,
+        )
+    };
+    let user_of = |id: &Option<RecordId>| id.as_ref().and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)));
+    let name_of = |id: &Option<RecordId>| {
+        user_of(id)
+            .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+            .unwrap_or_else(|| "—".to_string())
+    };
+
+    let on_propose = {
+        let users = users.clone();
+        let privileges = privileges.clone();
+        move |_| {
+            let slots = rotation_slots(&draft.peek());
+            let picks = propose(&users, &privileges, &absences, &history, &slots);
+            proposal.set(Some(picks.into_iter().filter(|p| p.reason != Reason::Kept).collect()));
+        }
+    };
+
+    let mut set_part = move |i: usize, f: &dyn Fn(&mut MidweekPart)| {
+        if let Some(part) = draft.write().parts.get_mut(i) {
+            f(part);
+        }
+    };
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let m = draft.peek().clone();
+        if m.date.is_empty() {
+            error.set(Some(t!("midweek-date-required")));
+            return;
+        }
+        saving.set(true);
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let id = m.id.clone();
+            let data = MidweekMeetingData::from(m);
+            let result = match id {
+                Some(id) => MidweekMeeting::update(&db, id, data).await.map(|_| ()),
+                None => MidweekMeeting::create(&db, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        let Some(id) = draft.peek().id.clone() else { return };
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match MidweekMeeting::delete(&db, id).await {
+                Ok(_) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+    let small_btn = "px-2 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 disabled:opacity-30";
+    let song = |v: &str| v.trim().parse::<u16>().ok();
+    let song_str = |v: Option<u16>| v.map(|n| n.to_string()).unwrap_or_default();
+    let part_count = d.parts.len();
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-5",
+
+            // ── Week header ───────────────────────────────────────────────
+            div { class: "grid grid-cols-2 lg:grid-cols-6 gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500 col-span-2 lg:col-span-1",
+                    span { {t!("midweek-date")} }
+                    input {
+                        r#type: "date",
+                        class: input_cls,
+                        value: d.date.clone(),
+                        oninput: move |e| draft.write().date = e.value(),
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500 col-span-2",
+                    span { {t!("midweek-bible-reading")} }
+                    input {
+                        r#type: "text",
+                        class: input_cls,
+                        placeholder: t!("midweek-bible-reading-placeholder"),
+                        value: d.bible_reading.clone(),
+                        oninput: move |e| draft.write().bible_reading = e.value(),
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("midweek-songs")} }
+                    div { class: "flex gap-1",
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: song_str(d.opening_song),
+                            oninput: move |e| draft.write().opening_song = song(&e.value()),
+                        }
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: song_str(d.middle_song),
+                            oninput: move |e| draft.write().middle_song = song(&e.value()),
+                        }
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: song_str(d.closing_song),
+                            oninput: move |e| draft.write().closing_song = song(&e.value()),
+                        }
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("midweek-classrooms")} }
+                    select {
+                        class: input_cls,
+                        onchange: move |e| {
+                            let n: u8 = e.value().parse().unwrap_or(1);
+                            let mut w = draft.write();
+                            w.classrooms = n;
+                            if n < 2 {
+                                w.aux_counselor = None;
+                                for p in w.parts.iter_mut() {
+                                    p.classroom = Classroom::Main;
+                                }
+                            }
+                        },
+                        option { value: "1", selected: d.classrooms < 2, "1" }
+                        option { value: "2", selected: d.classrooms >= 2, "2" }
+                    }
+                }
+            }
+
+            // ── Fair rotation ─────────────────────────────────────────────
+            if let Some(picks) = proposal() {
+                div { class: "rounded-lg border border-primary-200 bg-primary-50 p-3 space-y-2",
+                    p { class: "text-sm font-semibold text-gray-900", {t!("rotation-proposal-title")} }
+                    p { class: "text-xs text-gray-500", {t!("rotation-proposal-hint")} }
+                    if picks.is_empty() {
+                        p { class: "text-sm text-gray-500", {t!("rotation-nothing-open")} }
+                    }
+                    ul { class: "divide-y divide-primary-100",
+                        for pick in picks.iter() {
+                            li { key: "{pick.key}", class: "py-1.5",
+                                div { class: "flex flex-wrap justify-between gap-2 text-sm",
+                                    span { class: "text-gray-600", {slot_label(&d, &pick.key)} }
+                                    span { class: "font-medium text-gray-900", {name_of(&pick.person)} }
+                                }
+                                p { class: "text-xs text-gray-500", {pick.reason.explain()} }
+                            }
+                        }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50",
+                            onclick: move |_| proposal.set(None),
+                            {t!("rotation-discard")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                            onclick: move |_| {
+                                if let Some(picks) = proposal.peek().clone() {
+                                    apply_picks(&mut draft.write(), &picks);
+                                }
+                                proposal.set(None);
+                            },
+                            {t!("rotation-accept")}
+                        }
+                    }
+                }
+            } else {
+                div { class: "flex justify-end",
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: on_propose,
+                        {t!("rotation-propose")}
+                    }
+                }
+            }
+
+            // ── Meeting roles ─────────────────────────────────────────────
+            div { class: "grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-3",
+                PersonSelect {
+                    label: t!("midweek-chairman"),
+                    options: role_options(MeetingRole::Chairman, &d.chairman),
+                    selected: d.chairman.clone(),
+                    repeated: repeated(&d.chairman),
+                    on_change: move |v| draft.write().chairman = v,
+                }
+                if d.classrooms >= 2 {
+                    PersonSelect {
+                        label: t!("midweek-aux-counselor"),
+                        options: role_options(MeetingRole::AuxCounselor, &d.aux_counselor),
+                        selected: d.aux_counselor.clone(),
+                        repeated: repeated(&d.aux_counselor),
+                        on_change: move |v| draft.write().aux_counselor = v,
+                    }
+                }
+                PersonSelect {
+                    label: t!("midweek-opening-prayer"),
+                    options: role_options(MeetingRole::Prayer, &d.opening_prayer),
+                    selected: d.opening_prayer.clone(),
+                    repeated: repeated(&d.opening_prayer),
+                    on_change: move |v| draft.write().opening_prayer = v,
+                }
+                PersonSelect {
+                    label: t!("midweek-closing-prayer"),
+                    options: role_options(MeetingRole::Prayer, &d.closing_prayer),
+                    selected: d.closing_prayer.clone(),
+                    repeated: repeated(&d.closing_prayer),
+                    on_change: move |v| draft.write().closing_prayer = v,
+                }
+            }
+
+            // ── Parts ─────────────────────────────────────────────────────
+            div { class: "space-y-2",
+                for (i , part) in d.parts.iter().cloned().enumerate() {
+                    {
+                        let new_section = i == 0 || d.parts[i - 1].kind.section() != part.kind.section();
+                        let header_cls = match part.kind.section() {
+                            Section::Treasures => "text-xs font-semibold uppercase tracking-wider text-slate-600 pt-2",
+                            Section::Ministry => "text-xs font-semibold uppercase tracking-wider text-amber-700 pt-2",
+                            Section::Living => "text-xs font-semibold uppercase tracking-wider text-red-800 pt-2",
+                        };
+                        let kind = part.kind;
+                        let assignee_opts = person_options(
+                            eligible(&users, &privileges, |p| part_qualifies(kind, PartRole::Assignee, p)),
+                            part.assignee.as_ref(),
+                            &users,
+                            &name_fmt,
+                        );
+                        let student = user_of(&part.assignee);
+                        let assistant_opts = person_options(
+                            eligible(&users, &privileges, |p| part_qualifies(kind, PartRole::Assistant, p))
+                                .into_iter()
+                                .filter(|u| assistant_matches(kind, student, u))
+                                .filter(|u| u.id != part.assignee)
+                                .collect(),
+                            part.assistant.as_ref(),
+                            &users,
+                            &name_fmt,
+                        );
+                        let assistant_label = if kind == PartKind::CongregationBibleStudy {
+                            t!("midweek-reader")
+                        } else {
+                            t!("midweek-householder")
+                        };
+                        let assignee_label = if kind.is_student_part() {
+                            t!("midweek-student")
+                        } else {
+                            t!("midweek-assigned")
+                        };
+                        rsx! {
+                            if new_section {
+                                h3 { class: header_cls, {section_label(part.kind.section())} }
+                            }
+                            div { key: "{i}", class: "rounded-lg border border-gray-100 bg-gray-50/50 p-3 space-y-2",
+                                div { class: "flex flex-wrap items-end gap-2",
+                                    label { class: "flex flex-col gap-1 text-xs text-gray-500 w-44",
+                                        span { {t!("midweek-part-kind")} }
+                                        select {
+                                            class: input_cls,
+                                            onchange: move |e| {
+                                                if let Some(k) = PartKind::from_key(&e.value()) {
+                                                    set_part(i, &|p| {
+                                                        p.kind = k;
+                                                        p.minutes = k.default_minutes();
+                                                        if !k.has_assistant() {
+                                                            p.assistant = None;
+                                                        }
+                                                    });
+                                                }
+                                            },
+                                            for k in PartKind::ALL {
+                                                option { value: k.key(), selected: k == kind, {part_label(k)} }
+                                            }
+                                        }
+                                    }
+                                    label { class: "flex flex-col gap-1 text-xs text-gray-500 flex-1 min-w-[10rem]",
+                                        span { {t!("midweek-part-title")} }
+                                        input {
+                                            r#type: "text",
+                                            class: input_cls,
+                                            value: part.title.clone(),
+                                            oninput: move |e| {
+                                                let v = e.value();
+                                                set_part(i, &|p| p.title = v.clone());
+                                            },
+                                        }
+                                    }
+                                    label { class: "flex flex-col gap-1 text-xs text-gray-500 w-20",
+                                        span { {t!("midweek-minutes")} }
+                                        input {
+                                            r#type: "number",
+                                            min: "1",
+                                            class: input_cls,
+                                            value: "{part.minutes}",
+                                            oninput: move |e| {
+                                                if let Ok(v) = e.value().trim().parse::<u8>() {
+                                                    set_part(i, &|p| p.minutes = v);
+                                                }
+                                            },
+                                        }
+                                    }
+                                    if kind.is_student_part() {
+                                        label { class: "flex flex-col gap-1 text-xs text-gray-500 w-20",
+                                            span { {t!("midweek-lesson")} }
+                                            input {
+                                                r#type: "number",
+                                                min: "1",
+                                                class: input_cls,
+                                                value: part.lesson.map(|n| n.to_string()).unwrap_or_default(),
+                                                oninput: move |e| {
+                                                    let v = e.value().trim().parse::<u16>().ok();
+                                                    set_part(i, &|p| p.lesson = v);
+                                                },
+                                            }
+                                        }
+                                        if d.classrooms >= 2 {
+                                            label { class: "flex flex-col gap-1 text-xs text-gray-500 w-32",
+                                                span { {t!("midweek-classroom")} }
+                                                select {
+                                                    class: input_cls,
+                                                    onchange: move |e| {
+                                                        let c = if e.value() == "aux" { Classroom::Auxiliary } else { Classroom::Main };
+                                                        set_part(i, &|p| p.classroom = c);
+                                                    },
+                                                    option { value: "main", selected: part.classroom == Classroom::Main, {t!("midweek-classroom-main")} }
+                                                    option { value: "aux", selected: part.classroom == Classroom::Auxiliary, {t!("midweek-classroom-aux")} }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    div { class: "flex gap-1 ml-auto",
+                                        button {
+                                            class: small_btn,
+                                            disabled: i == 0,
+                                            title: t!("midweek-move-up"),
+                                            onclick: move |_| {
+                                                if i > 0 {
+                                                    draft.write().parts.swap(i - 1, i);
+                                                }
+                                            },
+                                            "↑"
+                                        }
+                                        button {
+                                            class: small_btn,
+                                            disabled: i + 1 >= part_count,
+                                            title: t!("midweek-move-down"),
+                                            onclick: move |_| {
+                                                if i + 1 < draft.peek().parts.len() {
+                                                    draft.write().parts.swap(i, i + 1);
+                                                }
+                                            },
+                                            "↓"
+                                        }
+                                        button {
+                                            class: "px-2 py-1 text-xs border border-red-200 rounded-lg text-red-600 hover:bg-red-50",
+                                            title: t!("midweek-remove-part"),
+                                            onclick: move |_| {
+                                                draft.write().parts.remove(i);
+                                            },
+                                            "✕"
+                                        }
+                                    }
+                                }
+                                div { class: "grid grid-cols-1 sm:grid-cols-2 gap-2",
+                                    PersonSelect {
+                                        label: assignee_label,
+                                        options: assignee_opts,
+                                        selected: part.assignee.clone(),
+                                        repeated: repeated(&part.assignee),
+                                        on_change: move |v| set_part(i, &|p| p.assignee = v.clone()),
+                                    }
+                                    if kind.has_assistant() {
+                                        PersonSelect {
+                                            label: assistant_label,
+                                            options: assistant_opts,
+                                            selected: part.assistant.clone(),
+                                            repeated: repeated(&part.assistant),
+                                            on_change: move |v| set_part(i, &|p| p.assistant = v.clone()),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Add a part at the end of its section.
+                div { class: "flex flex-wrap items-center gap-2 pt-1",
+                    select {
+                        class: "px-2 py-1.5 text-sm border border-gray-200 rounded-lg bg-white",
+                        onchange: move |e| {
+                            if let Some(k) = PartKind::from_key(&e.value()) {
+                                new_kind.set(k);
+                            }
+                        },
+                        for k in PartKind::ALL {
+                            option { value: k.key(), selected: k == new_kind(), {part_label(k)} }
+                        }
+                    }
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| {
+                            let kind = new_kind();
+                            let mut w = draft.write();
+                            let at = w
+                                .parts
+                                .iter()
+                                .rposition(|p| p.kind.section() <= kind.section())
+                                .map(|i| i + 1)
+                                .unwrap_or(0);
+                            w.parts.insert(at, MidweekPart {
+                                kind,
+                                title: String::new(),
+                                minutes: kind.default_minutes(),
+                                classroom: Classroom::Main,
+                                assignee: None,
+                                assistant: None,
+                                lesson: None,
+                            });
+                        },
+                        {t!("midweek-add-part")}
+                    }
                 }
             }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
-                    p { class: "text-4xl mb-3", "📖" }
-                    p { class: "font-medium text-gray-600", {t!("empty-weekday-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-weekday-desc")} }
+
+            if !repeated_names.is_empty() {
+                p { class: "text-sm text-amber-700",
+                    "⚠️ "
+                    {t!("midweek-repeated-warning")}
+                    {format!(" {}", repeated_names.join(", "))}
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            // ── Actions ───────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-2 pt-2 border-t border-gray-100",
+                div {
+                    if !is_new {
+                        if confirm_delete() {
+                            span { class: "text-sm text-gray-600 mr-2", {t!("midweek-delete-confirm")} }
+                            button {
+                                class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                onclick: on_delete,
+                                {t!("btn-confirm")}
+                            }
+                        } else {
+                            button {
+                                class: "px-3 py-1.5 text-sm border border-red-200 rounded-lg text-red-600 hover:bg-red-50",
+                                onclick: move |_| confirm_delete.set(true),
+                                {t!("btn-delete")}
+                            }
+                        }
+                    }
+                }
+                div { class: "flex gap-2",
+                    button {
+                        class: "px-4 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| on_close.call(()),
+                        {t!("btn-cancel")}
+                    }
+                    button {
+                        class: "px-4 py-2 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                        disabled: saving(),
+                        onclick: on_save,
+                        {t!("btn-save")}
+                    }
                 }
             }
         }
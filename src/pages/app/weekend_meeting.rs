@@ -1,21 +1,850 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::database::{use_crypto, use_db};
+use crate::dates::{add_days, add_months, current_year_month, days_between, mondays_in_month};
+use crate::format::{
+    effective_date_format, effective_name_format, event_type_label, format_date, format_name,
+};
+use crate::models::absence::Absence;
+use crate::models::congregation::{Congregation, DateFormat, NameFormat};
+use crate::models::event::CongregationEvent;
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_talk::{TalkOutline, TalkSettings, TalkSpeaker};
+use crate::models::user::User;
+use crate::models::weekend_meeting::{WeekendMeeting, WeekendMeetingData};
+use crate::pages::app::conflicts::{month_range, ConflictWarnings};
+use crate::pages::app::public_talks::speaker_label;
+use crate::pages::app::weekday_meeting::{person_options, PersonSelect};
+use crate::reports::month_label;
+use crate::schedule::conflicts::Source;
+use crate::schedule::midweek::eligible;
+use crate::schedule::recurrence;
+use crate::schedule::talks::repeated_since;
+use crate::schedule::rotation::{propose, Pick, Reason};
+use crate::schedule::weekend::{
+    apply_picks, assignments, has_speaker, open_roles, people_in, rotation_slots, slot_label,
+    WeekendRole,
+};
+use crate::schedule::{cancels_meetings, events_in_week};
+
+fn rid_str(id: &RecordId) -> String {
+    format!(
+        "{}:{}",
+        id.table,
+        match &id.key {
+            surrealdb::types::RecordIdKey::String(k) => k.clone(),
+            surrealdb::types::RecordIdKey::Number(n) => n.to_string(),
+            _ => String::new(),
+        }
+    )
+}
+
+/// Weekend meetings are usually held on Sunday.
+const DEFAULT_OFFSET: i64 = 6;
+
+// ── Main page ─────────────────────────────────────────────────────────────────
 
 #[component]
 pub fn AppWeekendMeeting() -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let congregation_res = use_context::<Resource<Option<Congregation>>>();
+    let uid = db_signal.read().congregation_uid.clone().unwrap_or_default();
+
+    let mut name_fmt = use_signal(|| NameFormat::FirstLast);
+    let mut date_fmt = use_signal(|| DateFormat::YMD);
+    {
+        let uid = uid.clone();
+        use_effect(move || {
+            let uid = uid.clone();
+            let cong_snap = congregation_res.read().clone();
+            let db_opt = db_signal.read().db.clone();
+            spawn(async move {
+                let prefs = crate::pages::app::user_settings::load_prefs(&uid, db_opt).await;
+                let cong_ref = cong_snap.as_ref().and_then(|o| o.as_ref());
+                name_fmt.set(effective_name_format(
+                    cong_ref,
+                    prefs.name_format.as_deref().unwrap_or(""),
+                ));
+                date_fmt.set(effective_date_format(
+                    cong_ref,
+                    prefs.date_format.as_deref().unwrap_or(""),
+                ));
+            });
+        });
+    }
+
+    let (cur_year, cur_month) = current_year_month();
+    let mut sel_year = use_signal(|| cur_year);
+    let mut sel_month = use_signal(|| cur_month);
+
+    // ── Resources ─────────────────────────────────────────────────────────────
+    let users_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        User::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let privs_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        UserPrivileges::all(&db).await.unwrap_or_default()
+    });
+
+    let mut meetings_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let crypto = crypto_signal.read().clone();
+            let (Some(from), Some(to)) = (weeks.first(), weeks.last()) else { return vec![] };
+            WeekendMeeting::by_range(&db, &crypto, from, to).await.unwrap_or_default()
+        }
+    });
+
+    let events_res = use_resource(move || {
+        let weeks = mondays_in_month(sel_year(), sel_month());
+        async move {
+            let Some(db) = db_signal.read().db.clone() else { return vec![] };
+            let (Some(from), Some(last)) = (weeks.first(), weeks.last()) else { return vec![] };
+            let until = add_days(last, 6);
+            let rows = CongregationEvent::in_range(&db, from, &until).await.unwrap_or_default();
+            recurrence::expand(&rows, from, &until)
+        }
+    });
+
+    // Catalog data for the public talk: outlines, visiting speakers, and every
+    // talk given here so far for the repeat warning.
+    let outlines_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        TalkOutline::all(&db).await.unwrap_or_default()
+    });
+
+    let guests_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        let speakers = TalkSpeaker::all(&db, &crypto).await.unwrap_or_default();
+        speakers.into_iter().filter(|s| !s.is_local()).collect::<Vec<_>>()
+    });
+
+    let settings_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return TalkSettings::default() };
+        TalkSettings::get(&db).await.unwrap_or_default()
+    });
+
+    let mut history_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        WeekendMeeting::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let absences_res = use_resource(move || async move {
+        let Some(db) = db_signal.read().db.clone() else { return vec![] };
+        let crypto = crypto_signal.read().clone();
+        Absence::all(&db, &crypto).await.unwrap_or_default()
+    });
+
+    let mut editing: Signal<Option<WeekendMeeting>> = use_signal(|| None);
+
+    let is_loading = users_res.read().is_none()
+        || privs_res.read().is_none()
+        || meetings_res.read().is_none()
+        || events_res.read().is_none()
+        || absences_res.read().is_none()
+        || history_res.read().is_none();
+    let users = users_res().unwrap_or_default();
+    let privileges = privs_res().unwrap_or_default();
+    let meetings = meetings_res().unwrap_or_default();
+    let events = events_res().unwrap_or_default();
+    let weeks = mondays_in_month(sel_year(), sel_month());
+    let nf = name_fmt();
+    let df = date_fmt();
+    let (conflicts_from, conflicts_to) = month_range(sel_year(), sel_month());
+    let name_of = |id: &Option<RecordId>| -> String {
+        id.as_ref()
+            .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+            .map(|u| format_name(&u.first_name, &u.last_name, &nf))
+            .unwrap_or_else(|| "—".to_string())
+    };
+    let meeting_offset = meetings
+        .first()
+        .and_then(|m| days_between(&m.week_start, &m.date))
+        .unwrap_or(DEFAULT_OFFSET);
+
+    // Weeks still needing someone, skipping those without a meeting.
+    let weeks_open = weeks
+        .iter()
+        .filter(|w| !events_in_week(&events, w).iter().any(|e| cancels_meetings(&e.event_type)))
+        .filter(|w| {
+            meetings
+                .iter()
+                .find(|m| m.week_start == **w)
+                .is_none_or(|m| !open_roles(m).is_empty())
+        })
+        .count();
+
+    let mut shift_month = move |delta: i32| {
+        let (y, m) = add_months(sel_year(), sel_month(), delta);
+        sel_year.set(y);
+        sel_month.set(m);
+        editing.set(None);
+    };
+
     rsx! {
-        div { class: "space-y-6 w-full",
-            div { class: "flex items-center justify-between",
-                h1 { class: "text-2xl font-bold text-gray-900", {t!("page-weekend-meeting")} }
-                button { class: "px-4 py-2 bg-primary-600 text-white rounded-lg hover:bg-primary-700 text-sm font-medium transition-colors",
-                    {t!("btn-add-assignment")}
-                }
-            }
-            div { class: "bg-white rounded-xl border border-gray-200",
-                div { class: "px-6 py-12 text-center text-gray-400",
-                    p { class: "text-4xl mb-3", "🎤" }
-                    p { class: "font-medium text-gray-600", {t!("empty-weekend-title")} }
-                    p { class: "text-sm mt-1", {t!("empty-weekend-desc")} }
+        div { class: "space-y-5 w-full pb-10",
+
+            // ── Header ────────────────────────────────────────────────────
+            h1 { class: "text-2xl font-bold text-gray-900", {t!("page-weekend-meeting")} }
+
+            // ── Month navigation ──────────────────────────────────────────
+            div { class: "bg-white rounded-xl border border-gray-200 p-4 flex items-center justify-between gap-3",
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(-1),
+                    "‹"
+                }
+                div { class: "text-center",
+                    p { class: "font-semibold text-gray-900",
+                        "{month_label(sel_month())} {sel_year()}"
+                    }
+                    if !is_loading {
+                        if weeks_open > 0 {
+                            p { class: "text-xs text-amber-700",
+                                "{weeks_open} "
+                                {t!("weekend-weeks-open")}
+                            }
+                        } else {
+                            p { class: "text-xs text-emerald-700", {t!("weekend-all-filled")} }
+                        }
+                    }
+                }
+                button {
+                    class: "px-5 py-2.5 min-w-[56px] rounded-lg border border-gray-200 text-gray-600 hover:bg-gray-50 text-xl font-semibold",
+                    onclick: move |_| shift_month(1),
+                    "›"
+                }
+            }
+
+            ConflictWarnings {
+                key: "{conflicts_from}",
+                from: conflicts_from.clone(),
+                to: conflicts_to.clone(),
+                source: Source::Weekend,
+                name_fmt: nf.clone(),
+                date_fmt: df.clone(),
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-20 text-gray-400",
+                    span { class: "text-sm animate-pulse", {t!("weekend-loading")} }
+                }
+            } else if let Some(draft) = editing() {
+                {
+                    let others: Vec<WeekendMeeting> = history_res()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|m| m.id.is_none() || m.id != draft.id)
+                        .collect();
+                    let talks_given: Vec<(String, u16)> = others
+                        .iter()
+                        .filter_map(|m| Some((m.date.clone(), m.talk_number?)))
+                        .collect();
+                    rsx! {
+                        WeekendEditor {
+                            key: "{draft.week_start}",
+                            meeting: draft,
+                            users: users.clone(),
+                            privileges: privileges.clone(),
+                            outlines: outlines_res().unwrap_or_default(),
+                            guests: guests_res().unwrap_or_default(),
+                            talks_given,
+                            absences: absences_res().unwrap_or_default(),
+                            history: assignments(&others),
+                            repeat_months: settings_res().unwrap_or_default().repeat_months,
+                            name_fmt: nf.clone(),
+                            on_close: move |_| editing.set(None),
+                            on_saved: move |_| {
+                                editing.set(None);
+                                meetings_res.restart();
+                                history_res.restart();
+                            },
+                        }
+                    }
+                }
+            } else {
+                div { class: "space-y-3",
+                    for week in weeks.iter().cloned() {
+                        {
+                            let week_events = events_in_week(&events, &week);
+                            let cancelled = week_events.iter().any(|e| cancels_meetings(&e.event_type));
+                            let event_notes: Vec<String> = week_events
+                                .iter()
+                                .map(|e| match &e.title {
+                                    Some(title) if !title.is_empty() => title.clone(),
+                                    _ => event_type_label(&e.event_type),
+                                })
+                                .collect();
+                            let existing = meetings.iter().find(|m| m.week_start == week).cloned();
+                            let week_label = format_date(&week, &df);
+                            let card_cls = if cancelled {
+                                "bg-gray-50 rounded-xl border border-gray-200 p-4 space-y-2"
+                            } else {
+                                "bg-white rounded-xl border border-gray-200 p-4 space-y-2"
+                            };
+                            rsx! {
+                                div {
+                                    key: "{week}",
+                                    class: card_cls,
+                                    if !event_notes.is_empty() {
+                                        p { class: "text-xs font-medium text-primary-700",
+                                            {format!("📅 {}", event_notes.join(" · "))}
+                                        }
+                                    }
+                                    {
+                                        match existing {
+                                            Some(m) => {
+                                                let open = open_roles(&m);
+                                                let date_label = format_date(&m.date, &df);
+                                                let speaker = if m.speaker.is_some() {
+                                                    name_of(&m.speaker)
+                                                } else if has_speaker(&m) {
+                                                    let name = m.visiting_speaker.clone().unwrap_or_default();
+                                                    match m.visiting_congregation.as_deref() {
+                                                        Some(c) if !c.is_empty() => format!("{name} ({c})"),
+                                                        _ => name,
+                                                    }
+                                                } else {
+                                                    "—".to_string()
+                                                };
+                                                let talk = match m.talk_number {
+                                                    Some(n) if !m.talk_title.is_empty() => format!("#{n} {}", m.talk_title),
+                                                    Some(n) => format!("#{n}"),
+                                                    None => m.talk_title.clone(),
+                                                };
+                                                let open_label = open.iter().map(|r| r.label()).collect::<Vec<_>>().join(", ");
+                                                let rows = vec![
+                                                    (t!("weekend-chairman"), name_of(&m.chairman)),
+                                                    (t!("weekend-speaker"), speaker),
+                                                    (t!("weekend-wt-conductor"), name_of(&m.watchtower_conductor)),
+                                                    (t!("weekend-wt-reader"), name_of(&m.watchtower_reader)),
+                                                ];
+                                                let to_edit = m.clone();
+                                                rsx! {
+                                                    div { class: "flex flex-wrap items-start justify-between gap-2",
+                                                        div { class: "min-w-0",
+                                                            p { class: "text-sm font-semibold text-gray-900",
+                                                                "{date_label}"
+                                                                if !talk.is_empty() {
+                                                                    span { class: "ml-2 font-normal text-gray-500", "· {talk}" }
+                                                                }
+                                                            }
+                                                            if cancelled {
+                                                                p { class: "text-xs text-amber-700", {t!("weekend-cancelled-but-planned")} }
+                                                            }
+                                                        }
+                                                        div { class: "flex items-center gap-2",
+                                                            if open.is_empty() {
+                                                                span { class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-emerald-100 text-emerald-800",
+                                                                    {t!("weekend-complete")}
+                                                                }
+                                                            } else {
+                                                                span {
+                                                                    class: "inline-flex px-1.5 py-0.5 rounded-full text-xs bg-amber-100 text-amber-800",
+                                                                    title: open_label,
+                                                                    "{open.len()} "
+                                                                    {t!("weekend-open-slots")}
+                                                                }
+                                                            }
+                                                            button {
+                                                                class: "px-2.5 py-1 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50 transition-colors",
+                                                                onclick: move |_| editing.set(Some(to_edit.clone())),
+                                                                {t!("btn-edit")}
+                                                            }
+                                                        }
+                                                    }
+                                                    dl { class: "grid grid-cols-1 sm:grid-cols-2 gap-x-4 gap-y-0.5 text-xs",
+                                                        for (label , value) in rows {
+                                                            div { class: "flex justify-between gap-2",
+                                                                dt { class: "text-gray-500", "{label}" }
+                                                                dd { class: "font-medium text-gray-800 truncate", "{value}" }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            None if cancelled => rsx! {
+                                                p { class: "text-sm text-gray-500",
+                                                    {t!("weekend-week-of")}
+                                                    " {week_label} — "
+                                                    {t!("weekend-no-meeting")}
+                                                }
+                                            },
+                                            None => {
+                                                let week_start = week.clone();
+                                                rsx! {
+                                                    div { class: "flex flex-wrap items-center justify-between gap-2",
+                                                        p { class: "text-sm text-gray-500",
+                                                            {t!("weekend-week-of")}
+                                                            " {week_label}"
+                                                        }
+                                                        button {
+                                                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 transition-colors",
+                                                            onclick: move |_| {
+                                                                let date = add_days(&week_start, meeting_offset);
+                                                                editing.set(Some(WeekendMeeting::blank(week_start.clone(), date)));
+                                                            },
+                                                            {t!("weekend-create-btn")}
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── WeekendEditor ─────────────────────────────────────────────────────────────
+
+#[component]
+fn WeekendEditor(
+    meeting: WeekendMeeting,
+    users: Vec<User>,
+    privileges: Vec<UserPrivileges>,
+    outlines: Vec<TalkOutline>,
+    /// Visiting speakers of the talk catalog.
+    guests: Vec<TalkSpeaker>,
+    /// `(date, outline)` of the other programs, for the repeat warning.
+    talks_given: Vec<(String, u16)>,
+    absences: Vec<Absence>,
+    /// `(person, date)` of earlier assignments, balanced by the rotation.
+    history: Vec<(RecordId, String)>,
+    repeat_months: u8,
+    name_fmt: NameFormat,
+    on_close: Callback<()>,
+    on_saved: Callback<()>,
+) -> Element {
+    let db_signal = use_db();
+    let crypto_signal = use_crypto();
+    let mut draft = use_signal(|| meeting.clone());
+    let mut visiting = use_signal(|| meeting.speaker.is_none() && has_speaker(&meeting));
+    let mut proposal: Signal<Option<Vec<Pick>>> = use_signal(|| None);
+    let mut saving = use_signal(|| false);
+    let mut confirm_delete = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let d = draft();
+    let is_new = d.id.is_none();
+
+    let mut load: HashMap<RecordId, usize> = HashMap::new();
+    for id in people_in(&d) {
+        *load.entry(id.clone()).or_default() += 1;
+    }
+    let repeated = |id: &Option<RecordId>| id.as_ref().is_some_and(|id| load.get(id).copied().unwrap_or(0) > 1);
+    let repeated_names: Vec<String> = load
+        .iter()
+        .filter(|(_, n)| **n > 1)
+        .filter_map(|(id, _)| users.iter().find(|u| u.id.as_ref() == Some(id)))
+        .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+        .collect();
+
+    let role_options = |role: WeekendRole, current: &Option<RecordId>| {
+        person_options(
+            eligible(&users, &privileges, |p| role.qualifies(p)),
+            current.as_ref(),
+            &users,
+            &name_fmt,
+        )
+    };
+
+    let name_of = |id: &Option<RecordId>| {
+        id.as_ref()
+            .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+            .map(|u| format_name(&u.first_name, &u.last_name, &name_fmt))
+            .unwrap_or_else(|| "—".to_string())
+    };
+
+    let on_propose = {
+        let users = users.clone();
+        let privileges = privileges.clone();
+        move |_| {
+            let mut slots = rotation_slots(&draft.peek());
+            // A visiting speaker is being arranged: leave the talk out.
+            if visiting() {
+                slots.retain(|s| s.key != "speaker");
+            }
+            let picks = propose(&users, &privileges, &absences, &history, &slots);
+            proposal.set(Some(picks.into_iter().filter(|p| p.reason != Reason::Kept).collect()));
+        }
+    };
+
+    let on_save = move |_| {
+        if *saving.peek() {
+            return;
+        }
+        let mut m = draft.peek().clone();
+        if m.date.is_empty() {
+            error.set(Some(t!("weekend-date-required")));
+            return;
+        }
+        // Keep only the kind of speaker currently chosen.
+        if visiting() {
+            m.speaker = None;
+        } else {
+            m.guest_speaker = None;
+            m.visiting_speaker = None;
+            m.visiting_congregation = None;
+        }
+        saving.set(true);
+        error.set(None);
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else {
+                saving.set(false);
+                return;
+            };
+            let crypto = crypto_signal.read().clone();
+            let id = m.id.clone();
+            let data = WeekendMeetingData::from(m);
+            let result = match id {
+                Some(id) => WeekendMeeting::update(&db, &crypto, id, data).await.map(|_| ()),
+                None => WeekendMeeting::create(&db, &crypto, data).await.map(|_| ()),
+            };
+            saving.set(false);
+            match result {
+                Ok(()) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let on_delete = move |_| {
+        let Some(id) = draft.peek().id.clone() else { return };
+        spawn(async move {
+            let Some(db) = db_signal.read().db.clone() else { return };
+            match WeekendMeeting::delete(&db, id).await {
+                Ok(_) => on_saved.call(()),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let input_cls = "w-full px-2 py-1.5 text-sm border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-primary-500";
+    let number = |v: &str| v.trim().parse::<u16>().ok();
+    let number_str = |v: Option<u16>| v.map(|n| n.to_string()).unwrap_or_default();
+    let optional = |v: String| if v.trim().is_empty() { None } else { Some(v) };
+
+    let repeat_date = d
+        .talk_number
+        .and_then(|n| repeated_since(&talks_given, &d.date, n, repeat_months))
+        .map(|date| date.to_string());
+    let guest = d
+        .guest_speaker
+        .as_ref()
+        .and_then(|id| guests.iter().find(|g| g.id.as_ref() == Some(id)));
+    let guest_outlines = guest
+        .map(|g| g.outlines.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", "))
+        .unwrap_or_default();
+    let guest_key = d.guest_speaker.as_ref().map(rid_str).unwrap_or_default();
+    let mut guest_options: Vec<(String, String)> = guests
+        .iter()
+        .filter_map(|g| Some((rid_str(g.id.as_ref()?), speaker_label(g, &[], &name_fmt))))
+        .collect();
+    guest_options.sort_by(|a, b| a.1.to_lowercase().cmp(&b.1.to_lowercase()));
+
+    rsx! {
+        div { class: "bg-white rounded-xl border border-gray-200 p-4 space-y-5",
+
+            // ── Date and songs ────────────────────────────────────────────
+            div { class: "grid grid-cols-2 lg:grid-cols-4 gap-3",
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("weekend-date")} }
+                    input {
+                        r#type: "date",
+                        class: input_cls,
+                        value: d.date.clone(),
+                        oninput: move |e| draft.write().date = e.value(),
+                    }
+                }
+                label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                    span { {t!("weekend-songs")} }
+                    div { class: "flex gap-1",
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: number_str(d.opening_song),
+                            oninput: move |e| draft.write().opening_song = number(&e.value()),
+                        }
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: number_str(d.middle_song),
+                            oninput: move |e| draft.write().middle_song = number(&e.value()),
+                        }
+                        input {
+                            r#type: "number",
+                            class: input_cls,
+                            value: number_str(d.closing_song),
+                            oninput: move |e| draft.write().closing_song = number(&e.value()),
+                        }
+                    }
+                }
+            }
+
+            // ── Fair rotation ─────────────────────────────────────────────
+            if let Some(picks) = proposal() {
+                div { class: "rounded-lg border border-primary-200 bg-primary-50 p-3 space-y-2",
+                    p { class: "text-sm font-semibold text-gray-900", {t!("rotation-proposal-title")} }
+                    p { class: "text-xs text-gray-500", {t!("rotation-proposal-hint")} }
+                    if picks.is_empty() {
+                        p { class: "text-sm text-gray-500", {t!("rotation-nothing-open")} }
+                    }
+                    ul { class: "divide-y divide-primary-100",
+                        for pick in picks.iter() {
+                            li { key: "{pick.key}", class: "py-1.5",
+                                div { class: "flex flex-wrap justify-between gap-2 text-sm",
+                                    span { class: "text-gray-600", {slot_label(&pick.key)} }
+                                    span { class: "font-medium text-gray-900", {name_of(&pick.person)} }
+                                }
+                                p { class: "text-xs text-gray-500", {pick.reason.explain()} }
+                            }
+                        }
+                    }
+                    div { class: "flex justify-end gap-2",
+                        button {
+                            class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 bg-white hover:bg-gray-50",
+                            onclick: move |_| proposal.set(None),
+                            {t!("rotation-discard")}
+                        }
+                        button {
+                            class: "px-3 py-1.5 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700",
+                            onclick: move |_| {
+                                if let Some(picks) = proposal.peek().clone() {
+                                    apply_picks(&mut draft.write(), &picks);
+                                }
+                                proposal.set(None);
+                            },
+                            {t!("rotation-accept")}
+                        }
+                    }
+                }
+            } else {
+                div { class: "flex justify-end",
+                    button {
+                        class: "px-3 py-1.5 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: on_propose,
+                        {t!("rotation-propose")}
+                    }
+                }
+            }
+
+            // ── Chairman and prayers ──────────────────────────────────────
+            div { class: "grid grid-cols-1 sm:grid-cols-3 gap-3",
+                PersonSelect {
+                    label: t!("weekend-chairman"),
+                    options: role_options(WeekendRole::Chairman, &d.chairman),
+                    selected: d.chairman.clone(),
+                    repeated: repeated(&d.chairman),
+                    on_change: move |v| draft.write().chairman = v,
+                }
+                PersonSelect {
+                    label: t!("weekend-opening-prayer"),
+                    options: role_options(WeekendRole::Prayer, &d.opening_prayer),
+                    selected: d.opening_prayer.clone(),
+                    repeated: repeated(&d.opening_prayer),
+                    on_change: move |v| draft.write().opening_prayer = v,
+                }
+                PersonSelect {
+                    label: t!("weekend-closing-prayer"),
+                    options: role_options(WeekendRole::Prayer, &d.closing_prayer),
+                    selected: d.closing_prayer.clone(),
+                    repeated: repeated(&d.closing_prayer),
+                    on_change: move |v| draft.write().closing_prayer = v,
+                }
+            }
+
+            // ── Public talk ───────────────────────────────────────────────
+            div { class: "rounded-lg border border-gray-100 bg-gray-50/50 p-3 space-y-3",
+                div { class: "flex flex-wrap items-center justify-between gap-2",
+                    h3 { class: "text-xs font-semibold uppercase tracking-wider text-gray-600",
+                        {t!("weekend-public-talk")}
+                    }
+                    label { class: "flex items-center gap-2 text-sm text-gray-600",
+                        input {
+                            r#type: "checkbox",
+                            checked: visiting(),
+                            onchange: move |e| visiting.set(e.checked()),
+                        }
+                        {t!("weekend-visiting-speaker")}
+                    }
+                }
+                div { class: "grid grid-cols-1 sm:grid-cols-4 gap-3",
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                        span { {t!("weekend-talk-number")} }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            class: input_cls,
+                            value: number_str(d.talk_number),
+                            oninput: move |e| {
+                                let n = number(&e.value());
+                                let title = n.and_then(|n| outlines.iter().find(|o| o.number == n)).map(|o| o.title.clone());
+                                let mut w = draft.write();
+                                w.talk_number = n;
+                                if let Some(title) = title {
+                                    w.talk_title = title;
+                                }
+                            },
+                        }
+                    }
+                    label { class: "flex flex-col gap-1 text-xs text-gray-500 sm:col-span-3",
+                        span { {t!("weekend-talk-title")} }
+                        input {
+                            r#type: "text",
+                            class: input_cls,
+                            value: d.talk_title.clone(),
+                            oninput: move |e| draft.write().talk_title = e.value(),
+                        }
+                    }
+                }
+                if let Some(date) = repeat_date {
+                    p { class: "text-xs text-amber-700",
+                        "⚠️ "
+                        {t!("talks-repeat-warning")}
+                        " {date}"
+                    }
+                }
+                if visiting() {
+                    if !guest_options.is_empty() {
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("weekend-guest-from-catalog")} }
+                            select {
+                                class: input_cls,
+                                onchange: move |e| {
+                                    let value = e.value();
+                                    let chosen = guests.iter().find(|g| g.id.as_ref().is_some_and(|id| rid_str(id) == value)).cloned();
+                                    let mut w = draft.write();
+                                    match chosen {
+                                        Some(g) => {
+                                            w.guest_speaker = g.id;
+                                            w.visiting_speaker = g.name;
+                                            w.visiting_congregation = g.congregation;
+                                        }
+                                        None => w.guest_speaker = None,
+                                    }
+                                },
+                                option { value: "", selected: guest_key.is_empty(), "—" }
+                                for (value , label) in guest_options {
+                                    option { value: value.clone(), selected: value == guest_key, "{label}" }
+                                }
+                            }
+                            if !guest_outlines.is_empty() {
+                                span { class: "text-gray-400",
+                                    {t!("talks-speaker-outlines")}
+                                    ": {guest_outlines}"
+                                }
+                            }
+                        }
+                    }
+                    div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("weekend-speaker-name")} }
+                            input {
+                                r#type: "text",
+                                class: input_cls,
+                                value: d.visiting_speaker.clone().unwrap_or_default(),
+                                oninput: move |e| draft.write().visiting_speaker = optional(e.value()),
+                            }
+                        }
+                        label { class: "flex flex-col gap-1 text-xs text-gray-500",
+                            span { {t!("weekend-speaker-congregation")} }
+                            input {
+                                r#type: "text",
+                                class: input_cls,
+                                value: d.visiting_congregation.clone().unwrap_or_default(),
+                                oninput: move |e| draft.write().visiting_congregation = optional(e.value()),
+                            }
+                        }
+                    }
+                } else {
+                    PersonSelect {
+                        label: t!("weekend-speaker"),
+                        options: role_options(WeekendRole::Speaker, &d.speaker),
+                        selected: d.speaker.clone(),
+                        repeated: repeated(&d.speaker),
+                        on_change: move |v| draft.write().speaker = v,
+                    }
+                }
+            }
+
+            // ── Watchtower study ──────────────────────────────────────────
+            div { class: "grid grid-cols-1 sm:grid-cols-2 gap-3",
+                PersonSelect {
+                    label: t!("weekend-wt-conductor"),
+                    options: role_options(WeekendRole::WatchtowerConductor, &d.watchtower_conductor),
+                    selected: d.watchtower_conductor.clone(),
+                    repeated: repeated(&d.watchtower_conductor),
+                    on_change: move |v| draft.write().watchtower_conductor = v,
+                }
+                PersonSelect {
+                    label: t!("weekend-wt-reader"),
+                    options: role_options(WeekendRole::WatchtowerReader, &d.watchtower_reader),
+                    selected: d.watchtower_reader.clone(),
+                    repeated: repeated(&d.watchtower_reader),
+                    on_change: move |v| draft.write().watchtower_reader = v,
+                }
+            }
+
+            if !repeated_names.is_empty() {
+                p { class: "text-sm text-amber-700",
+                    "⚠️ "
+                    {t!("weekend-repeated-warning")}
+                    {format!(" {}", repeated_names.join(", "))}
+                }
+            }
+            if let Some(err) = error() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+
+            // ── Actions ───────────────────────────────────────────────────
+            div { class: "flex flex-wrap items-center justify-between gap-2 pt-2 border-t border-gray-100",
+                div {
+                    if !is_new {
+                        if confirm_delete() {
+                            span { class: "text-sm text-gray-600 mr-2", {t!("weekend-delete-confirm")} }
+                            button {
+                                class: "px-3 py-1.5 text-sm bg-red-600 text-white rounded-lg hover:bg-red-700",
+                                onclick: on_delete,
+                                {t!("btn-confirm")}
+                            }
+                        } else {
+                            button {
+                                class: "px-3 py-1.5 text-sm border border-red-200 rounded-lg text-red-600 hover:bg-red-50",
+                                onclick: move |_| confirm_delete.set(true),
+                                {t!("btn-delete")}
+                            }
+                        }
+                    }
+                }
+                div { class: "flex gap-2",
+                    button {
+                        class: "px-4 py-2 text-sm border border-gray-200 rounded-lg text-gray-700 hover:bg-gray-50",
+                        onclick: move |_| on_close.call(()),
+                        {t!("btn-cancel")}
+                    }
+                    button {
+                        class: "px-4 py-2 text-sm bg-primary-600 text-white rounded-lg hover:bg-primary-700 disabled:opacity-50",
+                        disabled: saving(),
+                        onclick: on_save,
+                        {t!("btn-save")}
+                    }
                 }
             }
         }
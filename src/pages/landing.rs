@@ -183,6 +183,11 @@ fn AccountChoice(mut step: Signal<LandingStep>) -> Element {
                     {t!("landing-create-account")}
                 }
             }
+            Link {
+                to: crate::Route::Portal {},
+                class: "block pt-2 text-center text-sm text-primary-600 hover:underline",
+                {t!("landing-portal-link")}
+            }
         }
     }
 }
@@ -1,6 +1,8 @@
 pub mod app;
 pub mod landing;
 pub mod not_found;
+pub mod portal;
 
 pub use landing::Landing;
 pub use not_found::NotFound;
+pub use portal::Portal;
@@ -0,0 +1,577 @@
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::Route;
+use crate::database::{Db, connect_portal, ls_get, ls_remove, ls_set};
+use crate::dates::current_year_month;
+use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData, ReportSource};
+use crate::models::month_close::MonthClose;
+use crate::models::portal::{self, AbsenceRequest, AbsenceRequestData, PortalAccount};
+use crate::reports::month_label;
+
+/// A signed-in portal session. Kept local to this page so the limited
+/// connection never reaches the full app behind [`crate::pages::app::AppLayout`].
+#[derive(Clone)]
+struct PortalSession {
+    db: Db,
+    account: PortalAccount,
+}
+
+fn session_db(session: Signal<Option<PortalSession>>) -> Option<Db> {
+    session.peek().as_ref().map(|s| s.db.clone())
+}
+
+// ---------------------------------------------------------------------------
+// Root
+// ---------------------------------------------------------------------------
+
+/// Self-service page for publishers with a portal account (online mode only).
+#[component]
+pub fn Portal() -> Element {
+    let session: Signal<Option<PortalSession>> = use_signal(|| None);
+
+    rsx! {
+        div { class: "min-h-screen bg-gray-50 bg-gradient-to-br from-slate-50 to-primary-50 flex justify-center p-4",
+            div { class: "w-full max-w-md space-y-4 py-6",
+                div { class: "text-center",
+                    h1 { class: "text-3xl font-bold text-gray-900", {t!("app-name")} }
+                    p { class: "text-gray-500 mt-1", {t!("portal-subtitle")} }
+                }
+                if session.read().is_some() {
+                    PortalHome { session }
+                } else {
+                    PortalLogin { session }
+                }
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Login
+// ---------------------------------------------------------------------------
+
+#[component]
+fn PortalLogin(mut session: Signal<Option<PortalSession>>) -> Element {
+    let mut congregation_code = use_signal(String::new);
+    let mut username = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut prefill_checked = use_signal(|| false);
+    use_effect(move || {
+        if *prefill_checked.peek() { return; }
+        prefill_checked.set(true);
+        spawn(async move {
+            if let Some(uid) = ls_get("theo_portal_uid").await {
+                congregation_code.set(uid);
+            }
+            if let Some(user) = ls_get("theo_portal_username").await {
+                username.set(user);
+            }
+        });
+    });
+
+    let input_cls = "w-full border border-gray-300 rounded-lg px-3 py-2 text-sm focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-2xl shadow-lg p-6 space-y-4",
+            h2 { class: "text-xl font-semibold text-gray-800", {t!("portal-login-title")} }
+            p { class: "text-gray-500 text-sm", {t!("portal-login-desc")} }
+
+            if let Some(err) = error.read().clone() {
+                div { class: "bg-red-50 border border-red-200 rounded-lg p-3 text-red-700 text-sm",
+                    "{err}"
+                }
+            }
+
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1",
+                    {t!("onboarding-congregation-code")}
+                }
+                input {
+                    class: input_cls,
+                    r#type: "text",
+                    placeholder: "xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+                    value: congregation_code.read().clone(),
+                    oninput: move |e| congregation_code.set(e.value()),
+                }
+            }
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1",
+                    {t!("portal-username")}
+                }
+                input {
+                    class: input_cls,
+                    r#type: "text",
+                    value: username.read().clone(),
+                    oninput: move |e| username.set(e.value()),
+                }
+            }
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1",
+                    {t!("form-password")}
+                }
+                input {
+                    class: input_cls,
+                    r#type: "password",
+                    value: password.read().clone(),
+                    oninput: move |e| password.set(e.value()),
+                }
+            }
+
+            button {
+                class: "w-full py-3 bg-primary-600 text-white rounded-xl font-medium hover:bg-primary-700 transition-colors disabled:opacity-50",
+                disabled: *loading.read(),
+                onclick: move |_| {
+                    if *loading.peek() {
+                        return;
+                    }
+                    let cid = congregation_code.peek().trim().to_string();
+                    let user = username.peek().trim().to_string();
+                    let pass = password.peek().clone();
+                    if cid.is_empty() || user.is_empty() || pass.is_empty() {
+                        error.set(Some(t!("error-fields-required")));
+                        return;
+                    }
+                    loading.set(true);
+                    spawn(async move {
+                        error.set(None);
+                        let result = async {
+                            let db = connect_portal(&cid, &user, &pass).await?;
+                            let account = PortalAccount::current(&db)
+                                .await?
+                                .ok_or_else(|| t!("portal-no-account"))?;
+                            Ok::<_, Box<dyn std::error::Error>>(PortalSession { db, account })
+                        }
+                            .await;
+                        match result {
+                            Ok(s) => {
+                                ls_set("theo_portal_uid", &cid);
+                                ls_set("theo_portal_username", &user);
+                                session.set(Some(s));
+                            }
+                            Err(e) => error.set(Some(e.to_string())),
+                        }
+                        loading.set(false);
+                    });
+                },
+                if *loading.read() {
+                    {t!("btn-connecting")}
+                } else {
+                    {t!("btn-login")}
+                }
+            }
+
+            Link {
+                to: Route::Landing {},
+                class: "block text-center text-sm text-gray-500 hover:underline",
+                {t!("portal-back-to-app")}
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Signed-in view
+// ---------------------------------------------------------------------------
+
+#[component]
+fn PortalHome(mut session: Signal<Option<PortalSession>>) -> Element {
+    // Portal accounts may only report the current month.
+    let (year, month) = current_year_month();
+
+    let mut reports_res = use_resource(move || async move {
+        let Some(s) = session.peek().clone() else { return vec![] };
+        portal::own_reports(&s.db, s.account.publisher).await.unwrap_or_default()
+    });
+
+    let mut requests_res = use_resource(move || async move {
+        let Some(db) = session_db(session) else { return vec![] };
+        AbsenceRequest::all(&db).await.unwrap_or_default()
+    });
+
+    // Reports of closed months are read-only whatever their own lock says.
+    let closed_res = use_resource(move || async move {
+        let Some(db) = session_db(session) else { return Default::default() };
+        MonthClose::closed_months(&db).await.unwrap_or_default()
+    });
+
+    let Some(account) = session.read().as_ref().map(|s| s.account.clone()) else {
+        return rsx! {};
+    };
+    let publisher = account.publisher.clone();
+    let username = account.username.clone();
+
+    let reports = reports_res().unwrap_or_default();
+    let closed = closed_res().unwrap_or_default();
+    let locked = closed.contains(&(year, month))
+        || reports.iter().any(|r| r.year == year && r.month == month && r.locked);
+    let existing = reports
+        .iter()
+        .find(|r| r.year == year && r.month == month)
+        .cloned();
+    let history: Vec<FieldServiceReport> = reports.into_iter().take(6).collect();
+
+    rsx! {
+        div { class: "bg-white rounded-2xl shadow-sm border border-gray-200 p-4 flex items-center justify-between",
+            span { class: "text-sm text-gray-700",
+                {t!("portal-signed-in-as")}
+                " "
+                span { class: "font-semibold", "{username}" }
+            }
+            button {
+                class: "px-3 py-1.5 text-xs border border-gray-200 rounded-lg text-gray-600 hover:bg-gray-50",
+                onclick: move |_| {
+                    ls_remove("theo_portal_username");
+                    session.set(None);
+                },
+                {t!("portal-sign-out")}
+            }
+        }
+
+        PortalReportForm {
+            key: "{year}-{month}",
+            session,
+            publisher: publisher.clone(),
+            year,
+            month,
+            existing,
+            locked,
+            on_saved: move |_| reports_res.restart(),
+        }
+
+        PortalAbsenceForm {
+            session,
+            publisher: publisher.clone(),
+            requests: requests_res().unwrap_or_default(),
+            on_changed: move |_| requests_res.restart(),
+        }
+
+        // ── Recent reports ────────────────────────────────────────────────
+        if !history.is_empty() {
+            div { class: "bg-white rounded-2xl shadow-sm border border-gray-200 p-4 space-y-2",
+                h3 { class: "text-sm font-semibold text-gray-800", {t!("portal-recent-reports")} }
+                for r in history {
+                    div { class: "flex items-center justify-between text-sm",
+                        span { class: "text-gray-700", "{month_label(r.month)} {r.year}" }
+                        ReportStateBadge { locked: r.is_locked(&closed), report: r.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ReportStateBadge(report: FieldServiceReport, locked: bool) -> Element {
+    let (cls, label) = if locked {
+        ("bg-gray-100 text-gray-600", t!("report-locked"))
+    } else if report.approved {
+        ("bg-emerald-100 text-emerald-800", t!("portal-approved"))
+    } else {
+        ("bg-amber-100 text-amber-800", t!("report-pending"))
+    };
+    rsx! {
+        span { class: "inline-flex px-2 py-0.5 rounded-full text-xs font-medium {cls}", "{label}" }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Report form
+// ---------------------------------------------------------------------------
+
+#[component]
+fn PortalReportForm(
+    session: Signal<Option<PortalSession>>,
+    publisher: RecordId,
+    year: i32,
+    month: u8,
+    existing: Option<FieldServiceReport>,
+    /// Locked by the secretary or in a closed month.
+    locked: bool,
+    on_saved: Callback<()>,
+) -> Element {
+    let opt_str = |v: Option<u32>| v.map(|n| n.to_string()).unwrap_or_default();
+    let init = existing.clone();
+    let mut preached = use_signal(|| init.as_ref().is_none_or(|r| r.preached));
+    let mut aux = use_signal(|| init.as_ref().is_some_and(|r| r.auxiliary_pioneer));
+    let mut hours = use_signal(|| opt_str(init.as_ref().and_then(|r| r.hours)));
+    let mut credits = use_signal(|| opt_str(init.as_ref().and_then(|r| r.credits)));
+    let mut studies = use_signal(|| opt_str(init.as_ref().and_then(|r| r.bible_studies)));
+    let mut saving = use_signal(|| false);
+    let mut message: Signal<Option<(bool, String)>> = use_signal(|| None);
+
+    let existing_id = existing.as_ref().and_then(|r| r.id.clone());
+    let input_cls = "w-full border border-gray-300 rounded-lg px-3 py-2 text-sm focus:outline-none focus:ring-2 focus:ring-primary-500 disabled:bg-gray-50";
+
+    rsx! {
+        div { class: "bg-white rounded-2xl shadow-sm border border-gray-200 p-4 space-y-3",
+            div { class: "flex items-center justify-between gap-2",
+                h3 { class: "text-sm font-semibold text-gray-800",
+                    {t!("report-form-title")}
+                    " — {month_label(month)} {year}"
+                }
+                if let Some(r) = existing.clone() {
+                    ReportStateBadge { report: r }
+                }
+            }
+            if locked {
+                p { class: "text-xs text-gray-500", {t!("portal-locked-hint")} }
+            }
+
+            label { class: "flex items-center gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    class: "rounded border-gray-300 text-primary-600",
+                    disabled: locked,
+                    checked: preached(),
+                    oninput: move |e| preached.set(e.checked()),
+                }
+                {t!("portal-preached")}
+            }
+
+            if preached() {
+                label { class: "flex items-center gap-2 text-sm text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        class: "rounded border-gray-300 text-primary-600",
+                        disabled: locked,
+                        checked: aux(),
+                        oninput: move |e| aux.set(e.checked()),
+                    }
+                    {t!("report-form-aux-pioneer")}
+                }
+                div { class: "grid grid-cols-3 gap-2",
+                    div {
+                        label { class: "block text-xs font-medium text-gray-500 mb-1",
+                            {t!("report-form-hours")}
+                        }
+                        input {
+                            class: input_cls,
+                            r#type: "number",
+                            min: "0",
+                            disabled: locked,
+                            value: hours(),
+                            oninput: move |e| hours.set(e.value()),
+                        }
+                    }
+                    div {
+                        label { class: "block text-xs font-medium text-gray-500 mb-1",
+                            {t!("report-form-credits")}
+                        }
+                        input {
+                            class: input_cls,
+                            r#type: "number",
+                            min: "0",
+                            disabled: locked,
+                            value: credits(),
+                            oninput: move |e| credits.set(e.value()),
+                        }
+                    }
+                    div {
+                        label { class: "block text-xs font-medium text-gray-500 mb-1",
+                            {t!("report-form-bible-studies")}
+                        }
+                        input {
+                            class: input_cls,
+                            r#type: "number",
+                            min: "0",
+                            disabled: locked,
+                            value: studies(),
+                            oninput: move |e| studies.set(e.value()),
+                        }
+                    }
+                }
+                p { class: "text-xs text-gray-400", {t!("portal-hours-hint")} }
+            }
+
+            if let Some((ok, msg)) = message.read().clone() {
+                p { class: if ok { "text-sm text-emerald-700" } else { "text-sm text-red-600" }, "{msg}" }
+            }
+
+            if !locked {
+                button {
+                    class: "w-full py-2.5 bg-primary-600 text-white rounded-xl text-sm font-medium hover:bg-primary-700 transition-colors disabled:opacity-50",
+                    disabled: saving(),
+                    onclick: move |_| {
+                        if *saving.peek() {
+                            return;
+                        }
+                        let did_preach = preached();
+                        let data = FieldServiceReportData {
+                            publisher: publisher.clone(),
+                            year,
+                            month,
+                            hours: did_preach.then(|| hours().trim().parse().ok()).flatten(),
+                            credits: did_preach.then(|| credits().trim().parse().ok()).flatten(),
+                            bible_studies: did_preach.then(|| studies().trim().parse().ok()).flatten(),
+                            auxiliary_pioneer: did_preach && aux(),
+                            preached: did_preach,
+                            source: ReportSource::Publisher,
+                            approved: false,
+                            locked: false,
+                            notes: None,
+                        };
+                        let Some(db) = session_db(session) else { return };
+                        let eid = existing_id.clone();
+                        saving.set(true);
+                        spawn(async move {
+                            match portal::submit_report(&db, eid, data).await {
+                                Ok(()) => {
+                                    message.set(Some((true, t!("portal-report-sent"))));
+                                    on_saved.call(());
+                                }
+                                Err(e) => message.set(Some((false, e.to_string()))),
+                            }
+                            saving.set(false);
+                        });
+                    },
+                    if existing_id.is_some() {
+                        {t!("portal-update-report")}
+                    } else {
+                        {t!("portal-send-report")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Absence requests
+// ---------------------------------------------------------------------------
+
+#[component]
+fn PortalAbsenceForm(
+    session: Signal<Option<PortalSession>>,
+    publisher: RecordId,
+    requests: Vec<AbsenceRequest>,
+    on_changed: Callback<()>,
+) -> Element {
+    let mut start = use_signal(String::new);
+    let mut end = use_signal(String::new);
+    let mut reason = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let input_cls = "w-full border border-gray-300 rounded-lg px-3 py-2 text-sm focus:outline-none focus:ring-2 focus:ring-primary-500";
+
+    rsx! {
+        div { class: "bg-white rounded-2xl shadow-sm border border-gray-200 p-4 space-y-3",
+            h3 { class: "text-sm font-semibold text-gray-800", {t!("portal-absence-title")} }
+            p { class: "text-xs text-gray-500", {t!("portal-absence-hint")} }
+            div { class: "grid grid-cols-2 gap-2",
+                div {
+                    label { class: "block text-xs font-medium text-gray-500 mb-1",
+                        {t!("absence-form-start-date")}
+                    }
+                    input {
+                        class: input_cls,
+                        r#type: "date",
+                        value: start(),
+                        oninput: move |e| start.set(e.value()),
+                    }
+                }
+                div {
+                    label { class: "block text-xs font-medium text-gray-500 mb-1",
+                        {t!("absence-form-end-date")}
+                    }
+                    input {
+                        class: input_cls,
+                        r#type: "date",
+                        value: end(),
+                        oninput: move |e| end.set(e.value()),
+                    }
+                }
+            }
+            input {
+                class: input_cls,
+                r#type: "text",
+                placeholder: t!("absence-form-reason-placeholder"),
+                value: reason(),
+                oninput: move |e| reason.set(e.value()),
+            }
+            if let Some(err) = error.read().clone() {
+                p { class: "text-sm text-red-600", "{err}" }
+            }
+            button {
+                class: "w-full py-2.5 border border-primary-600 text-primary-700 rounded-xl text-sm font-medium hover:bg-primary-50 transition-colors",
+                onclick: move |_| {
+                    let s = start().trim().to_string();
+                    let e = end().trim().to_string();
+                    if s.is_empty() {
+                        error.set(Some(t!("error-fields-required")));
+                        return;
+                    }
+                    if !e.is_empty() && e < s {
+                        error.set(Some(t!("absence-form-date-order-error")));
+                        return;
+                    }
+                    let r = reason().trim().to_string();
+                    let data = AbsenceRequestData {
+                        publisher: publisher.clone(),
+                        start_date: s,
+                        end_date: (!e.is_empty()).then_some(e),
+                        reason: (!r.is_empty()).then_some(r),
+                    };
+                    let Some(db) = session_db(session) else { return };
+                    spawn(async move {
+                        match AbsenceRequest::create(&db, data).await {
+                            Ok(_) => {
+                                error.set(None);
+                                start.set(String::new());
+                                end.set(String::new());
+                                reason.set(String::new());
+                                on_changed.call(());
+                            }
+                            Err(e) => error.set(Some(e.to_string())),
+                        }
+                    });
+                },
+                {t!("portal-absence-send")}
+            }
+
+            if !requests.is_empty() {
+                div { class: "divide-y divide-gray-100 border-t border-gray-100",
+                    for req in requests {
+                        {
+                            let id = req.id.clone();
+                            let range = match &req.end_date {
+                                Some(e) => format!("{} → {}", req.start_date, e),
+                                None => req.start_date.clone(),
+                            };
+                            rsx! {
+                                div { class: "flex items-center justify-between gap-2 py-2 text-sm",
+                                    div { class: "min-w-0",
+                                        p { class: "text-gray-800", "{range}" }
+                                        if let Some(r) = &req.reason {
+                                            p { class: "text-xs text-gray-500 truncate", "{r}" }
+                                        }
+                                    }
+                                    div { class: "flex items-center gap-2 shrink-0",
+                                        span { class: "inline-flex px-2 py-0.5 rounded-full text-xs bg-amber-100 text-amber-800",
+                                            {t!("report-pending")}
+                                        }
+                                        button {
+                                            class: "text-xs text-red-600 hover:underline",
+                                            onclick: move |_| {
+                                                let Some(id) = id.clone() else { return };
+                                                let Some(db) = session_db(session) else { return };
+                                                spawn(async move {
+                                                    let _ = AbsenceRequest::delete(&db, id).await;
+                                                    on_changed.call(());
+                                                });
+                                            },
+                                            {t!("portal-absence-withdraw")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Service-year statistics over [`FieldServiceReport`]s.
+//!
+//! Everything is computed from already loaded data so dashboards, printed
+//! forms and exports share the same numbers. [`ServiceYearStats::load`] is a
+//! convenience that fetches exactly the rows needed for one service year.
+
+use std::collections::{HashMap, HashSet};
+
+use surrealdb::types::RecordId;
+
+use crate::crypto::SessionCrypto;
+use crate::database::Db;
+use crate::dates::add_months;
+use crate::models::field_service_group::FieldServiceGroup;
+use crate::models::field_service_report::FieldServiceReport;
+
+use super::service_year::{service_year_bounds, service_year_months};
+
+/// Number of months (including the reported one) a publisher may go without
+/// reporting and still count as active.
+pub const ACTIVE_WINDOW_MONTHS: i32 = 6;
+
+/// Running sums over a set of reports.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Totals {
+    /// Reports submitted, including "did not preach" ones.
+    pub reports: usize,
+    /// Reports where the publisher shared in the ministry.
+    pub reporting: usize,
+    pub hours: u32,
+    pub bible_studies: u32,
+}
+
+impl Totals {
+    pub fn add(&mut self, report: &FieldServiceReport) {
+        self.reports += 1;
+        if report.preached {
+            self.reporting += 1;
+            self.hours += report.hours.unwrap_or(0);
+            self.bible_studies += report.bible_studies.unwrap_or(0);
+        }
+    }
+
+    /// Hours per report where the publisher shared in the ministry.
+    pub fn avg_hours(&self) -> f64 {
+        ratio(self.hours as f64, self.reporting)
+    }
+
+    /// Bible studies per report where the publisher shared in the ministry.
+    pub fn avg_bible_studies(&self) -> f64 {
+        ratio(self.bible_studies as f64, self.reporting)
+    }
+}
+
+fn ratio(value: f64, count: usize) -> f64 {
+    if count == 0 { 0.0 } else { value / count as f64 }
+}
+
+/// Publishers who shared in the ministry at least once in the
+/// [`ACTIVE_WINDOW_MONTHS`] months ending with `(year, month)`.
+pub fn active_publishers(reports: &[FieldServiceReport], year: i32, month: u8) -> HashSet<&RecordId> {
+    let start = add_months(year, month, 1 - ACTIVE_WINDOW_MONTHS);
+    reports
+        .iter()
+        .filter(|r| r.preached && (r.year, r.month) >= start && (r.year, r.month) <= (year, month))
+        .map(|r| &r.publisher)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthStats {
+    pub year: i32,
+    pub month: u8,
+    pub totals: Totals,
+    pub active_publishers: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupStats {
+    /// `None` collects publishers that are not assigned to any group.
+    pub group: Option<RecordId>,
+    pub name: String,
+    pub totals: Totals,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceYearStats {
+    pub service_year: i32,
+    /// Months covered so far, September first.
+    pub months: Vec<MonthStats>,
+    pub totals: Totals,
+    pub per_publisher: HashMap<RecordId, Totals>,
+    pub per_group: Vec<GroupStats>,
+}
+
+impl ServiceYearStats {
+    /// Aggregate a service year up to and including `until`.
+    ///
+    /// `reports` should reach [`ACTIVE_WINDOW_MONTHS`] months before the start
+    /// of the service year so September's active count is complete; reports
+    /// outside the service year are otherwise ignored.
+    pub fn compute(
+        service_year: i32,
+        until: (i32, u8),
+        groups: &[FieldServiceGroup],
+        reports: &[FieldServiceReport],
+    ) -> Self {
+        let months: Vec<(i32, u8)> = service_year_months(service_year)
+            .into_iter()
+            .filter(|ym| *ym <= until)
+            .collect();
+
+        let group_of: HashMap<&RecordId, usize> = groups
+            .iter()
+            .enumerate()
+            .flat_map(|(i, g)| g.members.iter().map(move |m| (m, i)))
+            .collect();
+        let mut per_group: Vec<GroupStats> = groups
+            .iter()
+            .map(|g| GroupStats {
+                group: g.id.clone(),
+                name: g.name.clone(),
+                totals: Totals::default(),
+            })
+            .collect();
+        let mut unassigned = GroupStats {
+            group: None,
+            name: String::new(),
+            totals: Totals::default(),
+        };
+
+        let mut totals = Totals::default();
+        let mut per_publisher: HashMap<RecordId, Totals> = HashMap::new();
+        let mut month_stats: Vec<MonthStats> = months
+            .iter()
+            .map(|&(year, month)| MonthStats {
+                year,
+                month,
+                totals: Totals::default(),
+                active_publishers: active_publishers(reports, year, month).len(),
+            })
+            .collect();
+
+        for r in reports {
+            let Some(ms) = month_stats
+                .iter_mut()
+                .find(|m| m.year == r.year && m.month == r.month)
+            else {
+                continue;
+            };
+            ms.totals.add(r);
+            totals.add(r);
+            per_publisher.entry(r.publisher.clone()).or_default().add(r);
+            match group_of.get(&r.publisher) {
+                Some(&i) => per_group[i].totals.add(r),
+                None => unassigned.totals.add(r),
+            }
+        }
+        if unassigned.totals.reports > 0 {
+            per_group.push(unassigned);
+        }
+
+        Self {
+            service_year,
+            months: month_stats,
+            totals,
+            per_publisher,
+            per_group,
+        }
+    }
+
+    /// Load groups and reports for `service_year` and aggregate up to `until`.
+    pub async fn load(
+        db: &Db,
+        crypto: &SessionCrypto,
+        service_year: i32,
+        until: (i32, u8),
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let (start, end) = service_year_bounds(service_year);
+        let from = add_months(start.0, start.1, 1 - ACTIVE_WINDOW_MONTHS);
+        let to = end.min(until);
+        let groups = FieldServiceGroup::all(db, crypto).await?;
+        let reports = FieldServiceReport::by_period(db, crypto, from, to).await?;
+        Ok(Self::compute(service_year, until, &groups, &reports))
+    }
+
+    /// Average of the monthly active-publisher counts.
+    pub fn avg_active_publishers(&self) -> f64 {
+        let sum: usize = self.months.iter().map(|m| m.active_publishers).sum();
+        ratio(sum as f64, self.months.len())
+    }
+}
+
+/// A figure for the current period next to the same figure a year earlier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub current: f64,
+    pub previous: f64,
+}
+
+impl Comparison {
+    pub fn delta(&self) -> f64 {
+        self.current - self.previous
+    }
+
+    /// Relative change in percent, `None` when there is nothing to compare to.
+    pub fn percent_change(&self) -> Option<f64> {
+        (self.previous != 0.0).then(|| self.delta() / self.previous * 100.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YearOverYear {
+    pub hours: Comparison,
+    pub bible_studies: Comparison,
+    pub avg_active_publishers: Comparison,
+}
+
+impl YearOverYear {
+    /// Compare two service years. Pass a `previous` computed up to the same
+    /// point of its year (one calendar year earlier) for a like-for-like view.
+    pub fn between(current: &ServiceYearStats, previous: &ServiceYearStats) -> Self {
+        Self {
+            hours: Comparison {
+                current: current.totals.hours as f64,
+                previous: previous.totals.hours as f64,
+            },
+            bible_studies: Comparison {
+                current: current.totals.bible_studies as f64,
+                previous: previous.totals.bible_studies as f64,
+            },
+            avg_active_publishers: Comparison {
+                current: current.avg_active_publishers(),
+                previous: previous.avg_active_publishers(),
+            },
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, rid, user_id};
+
+    fn report(key: &str, year: i32, month: u8, hours: u32, preached: bool) -> FieldServiceReport {
+        FieldServiceReport {
+            hours: Some(hours),
+            bible_studies: Some(1),
+            preached,
+            ..fixtures::report(key, year, month)
+        }
+    }
+
+    #[test]
+    fn aggregates_months_publishers_and_groups() {
+        let groups = vec![FieldServiceGroup {
+            id: Some(rid("field_service_group", "g1")),
+            congregation: rid("congregation", "c"),
+            name: "North".into(),
+            overseer: None,
+            assistant: None,
+            members: vec![user_id("a")],
+        }];
+        let reports = vec![
+            // Before the service year: only counts towards "active".
+            report("b", 2025, 6, 5, true),
+            report("a", 2025, 9, 10, true),
+            report("a", 2025, 10, 20, true),
+            report("b", 2025, 10, 0, false),
+            // After `until`: ignored.
+            report("a", 2025, 11, 30, true),
+        ];
+        let s = ServiceYearStats::compute(2026, (2025, 10), &groups, &reports);
+        assert_eq!(s.months.len(), 2);
+        assert_eq!(s.totals.hours, 30);
+        assert_eq!(s.totals.reports, 3);
+        assert_eq!(s.totals.reporting, 2);
+        assert_eq!(s.months[0].active_publishers, 2);
+        assert_eq!(s.per_publisher[&user_id("a")].hours, 30);
+        assert_eq!(s.per_group[0].totals.hours, 30);
+        assert_eq!(s.per_group[1].group, None);
+        assert_eq!(s.per_group[1].totals.reports, 1);
+        assert_eq!(s.totals.avg_hours(), 15.0);
+    }
+
+    #[test]
+    fn percent_change_needs_a_previous_value() {
+        let c = Comparison { current: 120.0, previous: 100.0 };
+        assert_eq!(c.percent_change(), Some(20.0));
+        let none = Comparison { current: 5.0, previous: 0.0 };
+        assert_eq!(none.percent_change(), None);
+    }
+}
@@ -0,0 +1,677 @@
+//! CSV export and import of [`FieldServiceReport`]s.
+//!
+//! Export writes one row per report with decrypted publisher names. Import is
+//! a three-step affair driven by the page: [`parse`] the file, map each
+//! column to a [`Column`] (pre-filled by [`guess_mapping`]), then [`validate`]
+//! the rows against the publishers and the reports already stored. Nothing
+//! here touches the database.
+
+use std::collections::HashMap;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::format::format_name;
+use crate::models::congregation::NameFormat;
+use crate::models::field_service_report::{FieldServiceReport, FieldServiceReportData, ReportSource};
+use crate::models::user::User;
+
+// ---------------------------------------------------------------------------
+// Reading and writing
+// ---------------------------------------------------------------------------
+
+/// Spreadsheets set to a comma-decimal locale export with `;`; pick whichever
+/// separator the first line uses more.
+fn detect_delimiter(text: &str) -> char {
+    let first = text.lines().next().unwrap_or("");
+    if first.matches(';').count() > first.matches(',').count() { ';' } else { ',' }
+}
+
+/// One row of a CSV file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// 1-based line of the file the row starts on. Blank lines and line
+    /// breaks inside quoted fields are counted.
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// Split CSV text into rows of fields. Handles quoted fields with embedded
+/// separators, quotes and line breaks; blank lines are dropped.
+pub fn parse(text: &str) -> Vec<Record> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let delim = detect_delimiter(text);
+    let mut rows = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = line;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.trim().is_empty()) {
+                    rows.push(Record { line: start, fields: std::mem::take(&mut row) });
+                } else {
+                    row.clear();
+                }
+                start = line;
+            }
+            c if c == delim => row.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    row.push(field);
+    if row.iter().any(|f| !f.trim().is_empty()) {
+        rows.push(Record { line: start, fields: row });
+    }
+    rows
+}
+
+fn escape_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r', ';']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Spreadsheets run a cell starting with one of these as a formula; some
+/// also skip a leading tab or carriage return before looking.
+const FORMULA_START: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Quote free text that would otherwise be read as a formula.
+fn guard_formula(s: String) -> String {
+    if s.starts_with(FORMULA_START) { format!("'{s}") } else { s }
+}
+
+/// Undo [`guard_formula`] on import.
+fn unguard_formula(s: &str) -> &str {
+    match s.strip_prefix('\'') {
+        Some(rest) if rest.starts_with(FORMULA_START) => rest,
+        _ => s,
+    }
+}
+
+/// Comma-separated text with CRLF line endings, as spreadsheets expect.
+pub fn write(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|r| r.iter().map(|f| escape_field(f)).collect::<Vec<_>>().join(","))
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+/// Column headers of the export; [`guess_mapping`] recognises them on import.
+pub const EXPORT_HEADERS: [&str; 10] = [
+    "publisher",
+    "first_name",
+    "last_name",
+    "year",
+    "month",
+    "preached",
+    "hours",
+    "credits",
+    "bible_studies",
+    "auxiliary_pioneer",
+];
+
+/// CSV of `reports`, oldest first, then by publisher name. Reports of
+/// publishers that no longer exist are written with empty names.
+pub fn export_reports(reports: &[FieldServiceReport], users: &[User], name_fmt: &NameFormat) -> String {
+    let by_id: HashMap<&RecordId, &User> = users
+        .iter()
+        .filter_map(|u| u.id.as_ref().map(|id| (id, u)))
+        .collect();
+    let num = |v: Option<u32>| v.map(|n| n.to_string()).unwrap_or_default();
+    let flag = |b: bool| if b { "yes" } else { "no" }.to_string();
+
+    let mut lines: Vec<(i32, u8, String, Vec<String>)> = reports
+        .iter()
+        .map(|r| {
+            let (first, last) = by_id
+                .get(&r.publisher)
+                .map(|u| (u.first_name.clone(), u.last_name.clone()))
+                .unwrap_or_default();
+            let name = format_name(&first, &last, name_fmt);
+            let row = vec![
+                guard_formula(name.clone()),
+                guard_formula(first),
+                guard_formula(last),
+                r.year.to_string(),
+                r.month.to_string(),
+                flag(r.preached),
+                num(r.hours),
+                num(r.credits),
+                num(r.bible_studies),
+                flag(r.auxiliary_pioneer),
+            ];
+            (r.year, r.month, name.to_lowercase(), row)
+        })
+        .collect();
+    lines.sort_by(|a, b| (a.0, a.1, &a.2).cmp(&(b.0, b.1, &b.2)));
+
+    let mut rows = vec![EXPORT_HEADERS.iter().map(|h| h.to_string()).collect()];
+    rows.extend(lines.into_iter().map(|l| l.3));
+    // BOM so spreadsheet apps read the names as UTF-8.
+    format!("\u{feff}{}", write(&rows))
+}
+
+// ---------------------------------------------------------------------------
+// Import: column mapping
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    Ignore,
+    /// "First Last", "Last First" or "Last, First".
+    FullName,
+    FirstName,
+    LastName,
+    Year,
+    Month,
+    /// Year and month in one cell: `2024-09`, `2024/9` or `09/2024`.
+    YearMonth,
+    Preached,
+    Hours,
+    Credits,
+    BibleStudies,
+    AuxiliaryPioneer,
+}
+
+impl Column {
+    pub const ALL: [Column; 12] = [
+        Column::Ignore,
+        Column::FullName,
+        Column::FirstName,
+        Column::LastName,
+        Column::Year,
+        Column::Month,
+        Column::YearMonth,
+        Column::Preached,
+        Column::Hours,
+        Column::Credits,
+        Column::BibleStudies,
+        Column::AuxiliaryPioneer,
+    ];
+
+    /// Stable identifier for `<select>` values.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Ignore => "ignore",
+            Self::FullName => "name",
+            Self::FirstName => "first_name",
+            Self::LastName => "last_name",
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::YearMonth => "year_month",
+            Self::Preached => "preached",
+            Self::Hours => "hours",
+            Self::Credits => "credits",
+            Self::BibleStudies => "bible_studies",
+            Self::AuxiliaryPioneer => "auxiliary_pioneer",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.key() == key)
+            .unwrap_or(Self::Ignore)
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Ignore => t!("csv-col-ignore"),
+            Self::FullName => t!("csv-col-name"),
+            Self::FirstName => t!("csv-col-first-name"),
+            Self::LastName => t!("csv-col-last-name"),
+            Self::Year => t!("csv-col-year"),
+            Self::Month => t!("csv-col-month"),
+            Self::YearMonth => t!("csv-col-year-month"),
+            Self::Preached => t!("report-preached"),
+            Self::Hours => t!("report-form-hours"),
+            Self::Credits => t!("report-form-credits"),
+            Self::BibleStudies => t!("report-form-bible-studies"),
+            Self::AuxiliaryPioneer => t!("report-form-aux-pioneer"),
+        }
+    }
+}
+
+/// Lowercase, strip accents, turn separators into single spaces.
+fn fold(s: &str) -> String {
+    let mapped: String = s
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'ä' | 'â' | 'ã' | 'Á' | 'À' | 'Ä' | 'Â' | 'Ã' => 'a',
+            'é' | 'è' | 'ë' | 'ê' | 'É' | 'È' | 'Ë' | 'Ê' => 'e',
+            'í' | 'ì' | 'ï' | 'î' | 'Í' | 'Ì' | 'Ï' | 'Î' => 'i',
+            'ó' | 'ò' | 'ö' | 'ô' | 'õ' | 'Ó' | 'Ò' | 'Ö' | 'Ô' | 'Õ' => 'o',
+            'ú' | 'ù' | 'ü' | 'û' | 'Ú' | 'Ù' | 'Ü' | 'Û' => 'u',
+            'ñ' | 'Ñ' => 'n',
+            'ç' | 'Ç' => 'c',
+            ',' | '_' | '-' | '.' => ' ',
+            other => other.to_ascii_lowercase(),
+        })
+        .collect();
+    mapped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Best guess for a header, in English or Spanish.
+pub fn guess_column(header: &str) -> Column {
+    match fold(header).as_str() {
+        "publisher" | "name" | "full name" | "publicador" | "nombre completo" => Column::FullName,
+        "first name" | "given name" | "nombre" => Column::FirstName,
+        "last name" | "surname" | "family name" | "apellido" | "apellidos" => Column::LastName,
+        "year" | "ano" => Column::Year,
+        "month" | "mes" => Column::Month,
+        "period" | "date" | "year month" | "periodo" | "fecha" => Column::YearMonth,
+        "preached" | "shared in ministry" | "predico" | "participo" => Column::Preached,
+        "hours" | "horas" => Column::Hours,
+        "credits" | "credit" | "credito" | "creditos" => Column::Credits,
+        "bible studies" | "studies" | "cursos biblicos" | "estudios biblicos" | "cursos" => {
+            Column::BibleStudies
+        }
+        "auxiliary pioneer" | "aux pioneer" | "precursor auxiliar" => Column::AuxiliaryPioneer,
+        _ => Column::Ignore,
+    }
+}
+
+pub fn guess_mapping(headers: &[String]) -> Vec<Column> {
+    headers.iter().map(|h| guess_column(h)).collect()
+}
+
+/// Why a mapping cannot be used yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingError {
+    NoName,
+    NoPeriod,
+}
+
+impl MappingError {
+    pub fn label(&self) -> String {
+        match self {
+            Self::NoName => t!("csv-map-no-name"),
+            Self::NoPeriod => t!("csv-map-no-period"),
+        }
+    }
+}
+
+pub fn check_mapping(mapping: &[Column]) -> Result<(), MappingError> {
+    let has = |c: Column| mapping.contains(&c);
+    if !has(Column::FullName) && !(has(Column::FirstName) && has(Column::LastName)) {
+        return Err(MappingError::NoName);
+    }
+    if !has(Column::YearMonth) && !(has(Column::Year) && has(Column::Month)) {
+        return Err(MappingError::NoPeriod);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Import: validation
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowError {
+    MissingName,
+    UnknownPublisher,
+    /// More than one publisher has this name.
+    AmbiguousPublisher,
+    BadPeriod,
+    BadNumber(Column),
+    BadFlag(Column),
+    /// Same publisher and month as an earlier line of the file.
+    DuplicateInFile(usize),
+}
+
+impl RowError {
+    pub fn label(&self) -> String {
+        match self {
+            Self::MissingName => t!("csv-err-missing-name"),
+            Self::UnknownPublisher => t!("csv-err-unknown-publisher"),
+            Self::AmbiguousPublisher => t!("csv-err-ambiguous-publisher"),
+            Self::BadPeriod => t!("csv-err-bad-period"),
+            Self::BadNumber(c) => format!("{}: {}", c.label(), t!("csv-err-bad-number")),
+            Self::BadFlag(c) => format!("{}: {}", c.label(), t!("csv-err-bad-flag")),
+            Self::DuplicateInFile(line) => format!("{} {line}", t!("csv-err-duplicate-line")),
+        }
+    }
+}
+
+/// One line of the file after validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    /// 1-based line number in the file, header included.
+    pub line: usize,
+    /// Name as written in the file.
+    pub name: String,
+    /// What would be stored; `None` when the row has errors.
+    pub data: Option<FieldServiceReportData>,
+    /// Report already stored for the same publisher and month.
+    pub existing: Option<RecordId>,
+    pub errors: Vec<RowError>,
+}
+
+impl ImportRow {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.data.is_some()
+    }
+}
+
+fn parse_month(s: &str) -> Option<u8> {
+    if let Ok(n) = s.parse::<u8>() {
+        return (1..=12).contains(&n).then_some(n);
+    }
+    const NAMES: [(&str, u8); 16] = [
+        ("jan", 1), ("ene", 1), ("feb", 2), ("mar", 3), ("apr", 4), ("abr", 4),
+        ("may", 5), ("jun", 6), ("jul", 7), ("aug", 8), ("ago", 8), ("sep", 9),
+        ("set", 9), ("oct", 10), ("nov", 11), ("dec", 12),
+    ];
+    let f = fold(s);
+    if f.starts_with("dic") {
+        return Some(12);
+    }
+    NAMES
+        .iter()
+        .find(|(prefix, _)| f.starts_with(prefix))
+        .map(|&(_, m)| m)
+}
+
+fn parse_year(s: &str) -> Option<i32> {
+    s.parse().ok().filter(|y| (1900..=2200).contains(y))
+}
+
+/// `2024-09`, `2024/9`, `09/2024` or `9-2024`.
+fn parse_year_month(s: &str) -> Option<(i32, u8)> {
+    let parts: Vec<&str> = s.split(['-', '/', '.', ' ']).filter(|p| !p.is_empty()).collect();
+    let [a, b] = parts[..] else { return None };
+    if a.len() == 4 {
+        Some((parse_year(a)?, parse_month(b)?))
+    } else {
+        Some((parse_year(b)?, parse_month(a)?))
+    }
+}
+
+fn parse_number(s: &str) -> Result<Option<u32>, ()> {
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(|_| ())
+}
+
+fn parse_flag(s: &str) -> Result<Option<bool>, ()> {
+    match fold(s).as_str() {
+        "" => Ok(None),
+        "yes" | "y" | "true" | "1" | "x" | "si" | "s" => Ok(Some(true)),
+        "no" | "n" | "false" | "0" => Ok(Some(false)),
+        _ => Err(()),
+    }
+}
+
+/// Every way a publisher's name may be written, folded.
+fn name_keys(first: &str, last: &str) -> [String; 2] {
+    [fold(&format!("{first} {last}")), fold(&format!("{last} {first}"))]
+}
+
+/// Check `rows` (the file without its header record) against `users` and the
+/// `existing` reports.
+///
+/// Publishers are matched by name regardless of accents, case or word order,
+/// including inactive ones so old history can be loaded. When a row matches a
+/// stored report its notes and review flags are carried over so that
+/// overwriting only replaces the figures.
+pub fn validate(
+    rows: &[Record],
+    mapping: &[Column],
+    users: &[User],
+    existing: &[FieldServiceReport],
+) -> Vec<ImportRow> {
+    let mut by_name: HashMap<String, Vec<&RecordId>> = HashMap::new();
+    for u in users {
+        let Some(id) = u.id.as_ref() else { continue };
+        for key in name_keys(&u.first_name, &u.last_name) {
+            let ids = by_name.entry(key).or_default();
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    let stored: HashMap<(&RecordId, i32, u8), &FieldServiceReport> = existing
+        .iter()
+        .map(|r| ((&r.publisher, r.year, r.month), r))
+        .collect();
+    let mut seen: HashMap<(RecordId, i32, u8), usize> = HashMap::new();
+
+    rows.iter()
+        .map(|row| {
+            let line = row.line;
+            let cell = |c: Column| -> Option<&str> {
+                let idx = mapping.iter().position(|m| *m == c)?;
+                Some(row.fields.get(idx).map(|s| unguard_formula(s.trim())).unwrap_or(""))
+            };
+            let mut errors = Vec::new();
+
+            // Publisher
+            let name = match cell(Column::FullName) {
+                Some(full) if !full.is_empty() => full.to_string(),
+                _ => format!(
+                    "{} {}",
+                    cell(Column::FirstName).unwrap_or(""),
+                    cell(Column::LastName).unwrap_or("")
+                )
+                .trim()
+                .to_string(),
+            };
+            let publisher = if name.is_empty() {
+                errors.push(RowError::MissingName);
+                None
+            } else {
+                match by_name.get(&fold(&name)).map(Vec::as_slice) {
+                    Some([id]) => Some((*id).clone()),
+                    Some([]) | None => {
+                        errors.push(RowError::UnknownPublisher);
+                        None
+                    }
+                    Some(_) => {
+                        errors.push(RowError::AmbiguousPublisher);
+                        None
+                    }
+                }
+            };
+
+            // Period
+            let period = match cell(Column::YearMonth) {
+                Some(ym) if !ym.is_empty() => parse_year_month(ym),
+                _ => cell(Column::Year)
+                    .and_then(parse_year)
+                    .zip(cell(Column::Month).and_then(parse_month)),
+            };
+            if period.is_none() {
+                errors.push(RowError::BadPeriod);
+            }
+
+            // Figures
+            let mut number = |c: Column| match cell(c).map(parse_number) {
+                Some(Err(())) => {
+                    errors.push(RowError::BadNumber(c));
+                    None
+                }
+                Some(Ok(v)) => v,
+                None => None,
+            };
+            let hours = number(Column::Hours);
+            let credits = number(Column::Credits);
+            let bible_studies = number(Column::BibleStudies);
+            let mut flag = |c: Column| match cell(c).map(parse_flag) {
+                Some(Err(())) => {
+                    errors.push(RowError::BadFlag(c));
+                    None
+                }
+                Some(Ok(v)) => v,
+                None => None,
+            };
+            let preached = flag(Column::Preached).unwrap_or(true);
+            let auxiliary_pioneer = flag(Column::AuxiliaryPioneer).unwrap_or(false);
+
+            let mut existing_id = None;
+            let data = match (publisher, period) {
+                (Some(publisher), Some((year, month))) => {
+                    let key = (publisher.clone(), year, month);
+                    if let Some(&first) = seen.get(&key) {
+                        errors.push(RowError::DuplicateInFile(first));
+                    } else {
+                        seen.insert(key, line);
+                    }
+                    let prior = stored.get(&(&publisher, year, month)).copied();
+                    existing_id = prior.and_then(|r| r.id.clone());
+                    Some(FieldServiceReportData {
+                        publisher,
+                        year,
+                        month,
+                        hours: hours.filter(|_| preached),
+                        credits: credits.filter(|_| preached),
+                        bible_studies: bible_studies.filter(|_| preached),
+                        auxiliary_pioneer: preached && auxiliary_pioneer,
+                        preached,
+                        source: prior.map(|r| r.source).unwrap_or(ReportSource::Secretary),
+                        approved: true,
+                        locked: prior.is_some_and(|r| r.locked),
+                        notes: prior.and_then(|r| r.notes.clone()),
+                    })
+                }
+                _ => None,
+            };
+
+            ImportRow {
+                line,
+                name,
+                data: data.filter(|_| errors.is_empty()),
+                existing: existing_id,
+                errors,
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, rid};
+
+    fn user(key: &str, first: &str, last: &str) -> User {
+        User {
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            ..fixtures::user(key)
+        }
+    }
+
+    #[test]
+    fn parses_quotes_and_semicolons() {
+        let rows = parse("\u{feff}name;hours\r\n\"Pérez; Ana\";10\n\n\"Say \"\"hi\"\"\";\n");
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].fields, vec!["Pérez; Ana", "10"]);
+        assert_eq!(rows[2].fields, vec!["Say \"hi\"", ""]);
+        let written = write(&[vec!["a,b".into(), "c".into()]]);
+        assert_eq!(written, "\"a,b\",c\r\n");
+        assert_eq!(parse(&written)[0].fields, vec!["a,b", "c"]);
+    }
+
+    #[test]
+    fn records_start_on_their_physical_line() {
+        let rows = parse("name,notes\r\n\r\nAna,\"two\nlines\"\r\nJosé,\r\n");
+        let lines: Vec<usize> = rows.iter().map(|r| r.line).collect();
+        assert_eq!(lines, vec![1, 3, 5]);
+        assert_eq!(rows[1].fields[1], "two\nlines");
+    }
+
+    #[test]
+    fn export_quotes_names_that_look_like_formulas() {
+        let report = fixtures::report("a", 2025, 9);
+        let users = vec![user("a", "=HYPERLINK(\"x\")", "-Ruiz")];
+        let rows = parse(&export_reports(&[report], &users, &NameFormat::FirstLast));
+        assert_eq!(rows[1].fields[1], "'=HYPERLINK(\"x\")");
+        assert_eq!(rows[1].fields[2], "'-Ruiz");
+        assert_eq!(unguard_formula(&rows[1].fields[2]), "-Ruiz");
+        assert_eq!(unguard_formula("'plain"), "'plain");
+        assert_eq!(guard_formula("\t=1+1".into()), "'\t=1+1");
+        assert_eq!(unguard_formula("'\r=1+1"), "\r=1+1");
+    }
+
+    #[test]
+    fn guesses_mapping_from_headers() {
+        let headers: Vec<String> = ["Publicador", "Mes", "Año", "Horas", "Notas"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mapping = guess_mapping(&headers);
+        assert_eq!(
+            mapping,
+            vec![Column::FullName, Column::Month, Column::Year, Column::Hours, Column::Ignore]
+        );
+        assert_eq!(check_mapping(&mapping), Ok(()));
+        assert_eq!(check_mapping(&[Column::FullName, Column::Year]), Err(MappingError::NoPeriod));
+    }
+
+    #[test]
+    fn validates_rows_and_detects_duplicates() {
+        let users = vec![user("a", "José", "Núñez"), user("b", "Ana", "Ruiz"), user("c", "Ana", "Ruiz")];
+        let stored = FieldServiceReport {
+            id: Some(rid("field_service_report", "x")),
+            notes: Some("kept".into()),
+            ..fixtures::report("a", 2019, 9)
+        };
+        let mapping = [Column::FullName, Column::YearMonth, Column::Hours];
+        let rows = parse(
+            "name;period;hours\n\
+             nunez, jose;2019-09;12\n\
+             \n\
+             Jose Nunez;09/2019;\n\
+             Ana Ruiz;2019-10;\n\
+             Nobody;2019-13;x\n",
+        );
+        let out = validate(&rows[1..], &mapping, &users, &[stored]);
+
+        assert!(out[0].is_valid());
+        assert_eq!(out[0].existing, Some(rid("field_service_report", "x")));
+        let data = out[0].data.as_ref().unwrap();
+        assert_eq!(data.hours, Some(12));
+        assert_eq!(data.notes.as_deref(), Some("kept"));
+
+        // The blank line still counts.
+        assert_eq!(out[1].line, 4);
+        assert_eq!(out[1].errors, vec![RowError::DuplicateInFile(2)]);
+        assert_eq!(out[2].errors, vec![RowError::AmbiguousPublisher]);
+        assert_eq!(
+            out[3].errors,
+            vec![RowError::UnknownPublisher, RowError::BadPeriod, RowError::BadNumber(Column::Hours)]
+        );
+    }
+}
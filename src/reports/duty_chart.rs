@@ -0,0 +1,48 @@
+//! Monthly chart of attendants, microphones and the AV team.
+//!
+//! [`table`] lays the month's [`DutyRota`]s out as rows of plain text — one
+//! per meeting, one column per duty — which is printed by
+//! [`super::table_document`] or exported through [`super::csv::write`].
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::format::format_date;
+use crate::models::congregation::DateFormat;
+use crate::models::duty_rota::{DutyRole, DutyRota, MeetingKind};
+use crate::schedule::duties::label;
+
+pub fn meeting_label(kind: MeetingKind) -> String {
+    match kind {
+        MeetingKind::Midweek => t!("duty-midweek"),
+        MeetingKind::Weekend => t!("duty-weekend"),
+    }
+}
+
+/// Header row followed by one row per rota. People of a role are joined
+/// with `", "`; open places are left out.
+pub fn table(
+    rotas: &[DutyRota],
+    roles: &[DutyRole],
+    name_of: &dyn Fn(&RecordId) -> String,
+    date_fmt: &DateFormat,
+) -> Vec<Vec<String>> {
+    let mut header = vec![t!("duty-date"), t!("duty-meeting")];
+    header.extend(roles.iter().map(|r| label(*r)));
+    let mut rows = vec![header];
+    for rota in rotas {
+        let mut row = vec![format_date(&rota.date, date_fmt), meeting_label(rota.meeting)];
+        for role in roles {
+            let names: Vec<String> = rota
+                .duties
+                .iter()
+                .filter(|d| d.role == *role)
+                .filter_map(|d| d.person.as_ref())
+                .map(name_of)
+                .collect();
+            row.push(names.join(", "));
+        }
+        rows.push(row);
+    }
+    rows
+}
@@ -0,0 +1,726 @@
+//! iCalendar (RFC 5545) export of congregation events and assignments, and
+//! import of event dates sent by the circuit.
+//!
+//! Every exported entry is an all-day event. UIDs are built from record ids — or, for
+//! meetings, from the date and the meeting — plus the congregation uid, so a
+//! calendar importing the file again updates its entries instead of adding
+//! them twice. Entries of a personal file carry the person in their UID and
+//! never collide with the congregation file.
+//!
+//! Import [`parse`]s a file into [`ImportedEvent`]s, typed by keyword rules
+//! in [`classify`]; the page previews them and flags any [`duplicate_of`]
+//! an event already stored. Nothing here touches the database.
+
+use dioxus_i18n::t;
+use surrealdb::types::{RecordId, RecordIdKey};
+
+use crate::dates::{add_days, add_months_iso, civil_from_days, days_from_civil, local_date};
+use crate::format::{event_display_title, format_name};
+use crate::models::congregation::NameFormat;
+use crate::models::duty_rota::{DutyRota, MeetingKind};
+use crate::models::event::{CongregationEvent, CongregationEventData, EventType};
+use crate::models::field_service_meeting::FieldServiceMeeting;
+use crate::models::midweek_meeting::{MidweekMeeting, PartKind};
+use crate::models::territory::{LOAN_MONTHS, Territory, TerritoryAssignment};
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+use crate::schedule::duties::label as duty_label;
+use crate::schedule::weekend::WeekendRole;
+
+use super::duty_chart::meeting_label;
+use super::s140::part_title;
+
+/// One all-day calendar entry. `end` is the last day, inclusive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarItem {
+    pub uid: String,
+    pub start: String, // "YYYY-MM-DD"
+    pub end: String,   // "YYYY-MM-DD"
+    pub summary: String,
+    pub description: String,
+    pub location: String,
+}
+
+// ---------------------------------------------------------------------------
+// Writing
+// ---------------------------------------------------------------------------
+
+/// Escape a TEXT value: backslashes, separators and line breaks.
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Fold a content line to 75 octets, continuation lines starting with a
+/// space. Multi-byte characters are never split.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + line.len() / 70 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+fn compact(iso: &str) -> String {
+    iso.replace('-', "")
+}
+
+/// Current UTC time as an iCalendar timestamp, `"YYYYMMDDTHHMMSSZ"`.
+pub fn now_stamp() -> String {
+    #[cfg(target_arch = "wasm32")]
+    let secs = (js_sys::Date::now() / 1000.0) as i64;
+    #[cfg(not(target_arch = "wasm32"))]
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+    let rem = secs.rem_euclid(86_400);
+    format!(
+        "{y:04}{m:02}{d:02}T{:02}{:02}{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// A complete `VCALENDAR` named `name`, lines ending in CRLF.
+pub fn write(name: &str, stamp: &str, items: &[CalendarItem]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//theo-manager//Congregation calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+    for item in items {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", item.uid));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("DTSTART;VALUE=DATE:{}", compact(&item.start)));
+        // DTEND is exclusive: the day after the last one.
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            compact(&add_days(&item.end, 1))
+        ));
+        lines.push(format!("SUMMARY:{}", escape_text(&item.summary)));
+        if !item.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&item.description)));
+        }
+        if !item.location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_text(&item.location)));
+        }
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+    let mut out: String = lines
+        .iter()
+        .map(|l| fold(l))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    out.push_str("\r\n");
+    out
+}
+
+// ---------------------------------------------------------------------------
+// Building entries
+// ---------------------------------------------------------------------------
+
+fn key(id: &RecordId) -> String {
+    match &id.key {
+        RecordIdKey::String(k) => k.clone(),
+        RecordIdKey::Number(n) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// `<record table>-<record key>[-<person key>]@<congregation uid>`.
+fn record_uid(id: &RecordId, person: Option<&RecordId>, congregation_uid: &str) -> String {
+    match person {
+        Some(p) => format!("{}-{}-{}@{congregation_uid}", id.table, key(id), key(p)),
+        None => format!("{}-{}@{congregation_uid}", id.table, key(id)),
+    }
+}
+
+fn name_of(id: &RecordId, users: &[User], name_fmt: &NameFormat) -> String {
+    users
+        .iter()
+        .find(|u| u.id.as_ref() == Some(id))
+        .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+        .unwrap_or_default()
+}
+
+/// Congregation events concern everybody; personal files get them too.
+/// Occurrences of a series share its record, so their UID adds the date.
+pub fn event_items(
+    events: &[CongregationEvent],
+    person: Option<&RecordId>,
+    congregation_uid: &str,
+) -> Vec<CalendarItem> {
+    events
+        .iter()
+        .filter_map(|e| {
+            let uid = record_uid(e.id.as_ref()?, person, congregation_uid);
+            Some(CalendarItem {
+                uid: match e.recurrence {
+                    Some(_) => uid.replacen('@', &format!("-{}@", compact(&e.start_date)), 1),
+                    None => uid,
+                },
+                start: e.start_date.clone(),
+                end: e.end_date.clone(),
+                summary: event_display_title(e),
+                description: e.description.clone().unwrap_or_default(),
+                location: String::new(),
+            })
+        })
+        .collect()
+}
+
+/// Field service meetings, all of them or those `person` conducts.
+pub fn field_service_items(
+    meetings: &[FieldServiceMeeting],
+    users: &[User],
+    name_fmt: &NameFormat,
+    person: Option<&RecordId>,
+    congregation_uid: &str,
+) -> Vec<CalendarItem> {
+    meetings
+        .iter()
+        .filter(|m| person.is_none_or(|p| p == &m.assignee))
+        .filter_map(|m| {
+            let summary = match person {
+                Some(_) => t!("ics-field-service"),
+                None => format!(
+                    "{} · {}",
+                    t!("ics-field-service"),
+                    name_of(&m.assignee, users, name_fmt)
+                ),
+            };
+            Some(CalendarItem {
+                uid: record_uid(m.id.as_ref()?, person, congregation_uid),
+                start: m.date.clone(),
+                end: m.date.clone(),
+                summary,
+                description: m.notes.clone().unwrap_or_default(),
+                location: m.location.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Territories still out, on the day they are due back.
+pub fn territory_items(
+    assignments: &[TerritoryAssignment],
+    territories: &[Territory],
+    users: &[User],
+    name_fmt: &NameFormat,
+    person: Option<&RecordId>,
+    congregation_uid: &str,
+) -> Vec<CalendarItem> {
+    assignments
+        .iter()
+        .filter(|a| a.returned_date.is_none() && person.is_none_or(|p| p == &a.user))
+        .filter_map(|a| {
+            let territory = territories
+                .iter()
+                .find(|t| t.id.as_ref() == Some(&a.territory))?;
+            let due = add_months_iso(&a.assigned_date, LOAN_MONTHS);
+            let mut summary = format!(
+                "{} #{} {}",
+                t!("ics-territory-due"),
+                territory.number,
+                territory.name
+            );
+            if person.is_none() {
+                summary.push_str(&format!(" · {}", name_of(&a.user, users, name_fmt)));
+            }
+            Some(CalendarItem {
+                uid: record_uid(a.id.as_ref()?, person, congregation_uid),
+                start: due.clone(),
+                end: due,
+                summary,
+                description: format!("{}: {}", t!("terr-assigned-date"), a.assigned_date),
+                location: String::new(),
+            })
+        })
+        .collect()
+}
+
+/// Everyone serving at one meeting: program parts and support duties.
+struct MeetingRoles {
+    date: String,
+    kind: MeetingKind,
+    roles: Vec<(String, RecordId)>,
+}
+
+fn midweek_roles(meeting: &MidweekMeeting) -> Vec<(String, RecordId)> {
+    let mut roles: Vec<(String, Option<&RecordId>)> = vec![
+        (t!("midweek-chairman"), meeting.chairman.as_ref()),
+        (t!("midweek-aux-counselor"), meeting.aux_counselor.as_ref()),
+        (
+            t!("midweek-opening-prayer"),
+            meeting.opening_prayer.as_ref(),
+        ),
+    ];
+    for part in &meeting.parts {
+        let title = part_title(part);
+        roles.push((title.clone(), part.assignee.as_ref()));
+        if part.kind.has_assistant() {
+            let helper = if part.kind == PartKind::CongregationBibleStudy {
+                t!("midweek-reader")
+            } else {
+                t!("midweek-householder")
+            };
+            roles.push((format!("{title} ({helper})"), part.assistant.as_ref()));
+        }
+    }
+    roles.push((
+        t!("midweek-closing-prayer"),
+        meeting.closing_prayer.as_ref(),
+    ));
+    roles
+        .into_iter()
+        .filter_map(|(role, id)| Some((role, id?.clone())))
+        .collect()
+}
+
+fn weekend_roles(meeting: &WeekendMeeting) -> Vec<(String, RecordId)> {
+    [
+        (WeekendRole::Chairman, &meeting.chairman),
+        (WeekendRole::Prayer, &meeting.opening_prayer),
+        (WeekendRole::Speaker, &meeting.speaker),
+        (
+            WeekendRole::WatchtowerConductor,
+            &meeting.watchtower_conductor,
+        ),
+        (WeekendRole::WatchtowerReader, &meeting.watchtower_reader),
+        (WeekendRole::Prayer, &meeting.closing_prayer),
+    ]
+    .into_iter()
+    .filter_map(|(role, id)| Some((role.label(), id.clone()?)))
+    .collect()
+}
+
+fn meeting_roles(
+    midweek: &[MidweekMeeting],
+    weekend: &[WeekendMeeting],
+    rotas: &[DutyRota],
+) -> Vec<MeetingRoles> {
+    let mut meetings: Vec<MeetingRoles> = midweek
+        .iter()
+        .map(|m| MeetingRoles {
+            date: m.date.clone(),
+            kind: MeetingKind::Midweek,
+            roles: midweek_roles(m),
+        })
+        .chain(weekend.iter().map(|m| MeetingRoles {
+            date: m.date.clone(),
+            kind: MeetingKind::Weekend,
+            roles: weekend_roles(m),
+        }))
+        .collect();
+    for rota in rotas {
+        let duties = rota
+            .duties
+            .iter()
+            .filter_map(|d| Some((duty_label(d.role), d.person.clone()?)));
+        match meetings
+            .iter_mut()
+            .find(|m| m.date == rota.date && m.kind == rota.meeting)
+        {
+            Some(meeting) => meeting.roles.extend(duties),
+            None => meetings.push(MeetingRoles {
+                date: rota.date.clone(),
+                kind: rota.meeting,
+                roles: duties.collect(),
+            }),
+        }
+    }
+    meetings.sort_by(|a, b| a.date.cmp(&b.date));
+    meetings
+}
+
+/// One entry per meeting. The congregation file lists everyone serving;
+/// a personal file only has the meetings where `person` serves, titled
+/// with their roles.
+pub fn meeting_items(
+    midweek: &[MidweekMeeting],
+    weekend: &[WeekendMeeting],
+    rotas: &[DutyRota],
+    users: &[User],
+    name_fmt: &NameFormat,
+    person: Option<&RecordId>,
+    congregation_uid: &str,
+) -> Vec<CalendarItem> {
+    let kind_key = |kind: MeetingKind| match kind {
+        MeetingKind::Midweek => "midweek",
+        MeetingKind::Weekend => "weekend",
+    };
+    meeting_roles(midweek, weekend, rotas)
+        .into_iter()
+        .filter(|m| !m.roles.is_empty())
+        .filter_map(|m| {
+            let base = format!("meeting-{}-{}", compact(&m.date), kind_key(m.kind));
+            let (uid, summary, description) = match person {
+                Some(p) => {
+                    let mine: Vec<String> = m
+                        .roles
+                        .iter()
+                        .filter(|(_, id)| id == p)
+                        .map(|(role, _)| role.clone())
+                        .collect();
+                    if mine.is_empty() {
+                        return None;
+                    }
+                    (
+                        format!("{base}-{}@{congregation_uid}", key(p)),
+                        format!("{}: {}", meeting_label(m.kind), mine.join(", ")),
+                        String::new(),
+                    )
+                }
+                None => (
+                    format!("{base}@{congregation_uid}"),
+                    meeting_label(m.kind),
+                    m.roles
+                        .iter()
+                        .map(|(role, id)| format!("{role}: {}", name_of(id, users, name_fmt)))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+            };
+            Some(CalendarItem {
+                uid,
+                start: m.date.clone(),
+                end: m.date,
+                summary,
+                description,
+                location: String::new(),
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Importing
+// ---------------------------------------------------------------------------
+
+/// An event read from a calendar file, ready to become a
+/// [`CongregationEvent`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedEvent {
+    pub start: String, // "YYYY-MM-DD"
+    pub end: String,   // "YYYY-MM-DD", inclusive
+    pub summary: String,
+    pub description: String,
+    pub event_type: EventType,
+}
+
+impl ImportedEvent {
+    /// Known event types are titled by their type; only "other" events keep
+    /// the summary as title.
+    pub fn into_data(self) -> CongregationEventData {
+        CongregationEventData {
+            start_date: self.start,
+            end_date: self.end,
+            title: (self.event_type == EventType::Other)
+                .then_some(self.summary)
+                .filter(|s| !s.is_empty()),
+            description: Some(self.description).filter(|s| !s.is_empty()),
+            event_type: self.event_type,
+            recurrence: None,
+        }
+    }
+}
+
+/// Phrases, lowercase, that give a summary its event type, checked in order.
+/// Bare words like "assembly" are left out on purpose: they also turn up in
+/// "Assembly Hall cleaning" and similar entries that cancel no meeting.
+const KEYWORDS: &[(EventType, &[&str])] = &[
+    (
+        EventType::RegionalConvention,
+        &[
+            "regional convention",
+            "special convention",
+            "international convention",
+            "asamblea regional",
+            "asamblea especial",
+            "asamblea internacional",
+        ],
+    ),
+    (
+        EventType::CircuitAssembly,
+        &[
+            "circuit assembly",
+            "asamblea de circuito",
+            "asamblea del circuito",
+            "asamblea circuital",
+        ],
+    ),
+    (
+        EventType::CircuitOverseerVisit,
+        &[
+            "circuit overseer",
+            "overseer visit",
+            "co visit",
+            "superintendente de circuito",
+            "visita del superintendente",
+        ],
+    ),
+    (
+        EventType::Memorial,
+        &["memorial", "conmemoración", "conmemoracion"],
+    ),
+];
+
+/// Event type for a summary; [`EventType::Other`] when no keyword matches.
+pub fn classify(summary: &str) -> EventType {
+    let summary = summary.to_lowercase();
+    KEYWORDS
+        .iter()
+        .find(|(_, words)| words.iter().any(|w| summary.contains(w)))
+        .map(|(kind, _)| kind.clone())
+        .unwrap_or(EventType::Other)
+}
+
+fn unescape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// `"20261018"` or `"20261018T090000"` → `"2026-10-18"`. A UTC time such
+/// as `"20261018T230000Z"` is turned into local time first, so it may fall
+/// on the next or previous day.
+fn iso_date(value: &str) -> Option<String> {
+    let number = |range: std::ops::Range<usize>| -> Option<u32> {
+        value
+            .get(range)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))?
+            .parse()
+            .ok()
+    };
+    let (y, m, d) = (number(0..4)?, number(4..6)?, number(6..8)?);
+    if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return None;
+    }
+    if value.len() == 16 && value.ends_with(['Z', 'z']) && value.get(8..9) == Some("T") {
+        let secs = number(9..11)? * 3600 + number(11..13)? * 60 + number(13..15)?;
+        let days = days_from_civil(y as i32, m as u8, d as u8);
+        return Some(local_date(days * 86_400 + secs as i64));
+    }
+    Some(format!("{y:04}-{m:02}-{d:02}"))
+}
+
+/// Properties of a `VEVENT` collected while reading it.
+#[derive(Default)]
+struct Draft {
+    start: Option<String>,
+    end: Option<String>,
+    end_exclusive: bool,
+    summary: String,
+    description: String,
+    location: String,
+}
+
+/// Every `VEVENT` of `text` with a readable start date, by start date.
+/// All-day end dates are exclusive in iCalendar and are turned into the
+/// last day; timed events end on the day their end time falls.
+pub fn parse(text: &str) -> Vec<ImportedEvent> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    // Unfold: a line starting with a space or tab continues the previous one.
+    let mut lines: Vec<String> = vec![];
+    for line in text.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match line.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    let mut events = vec![];
+    let mut current: Option<Draft> = None;
+    for line in lines {
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut params = head.split(';');
+        let name = params.next().unwrap_or("").to_ascii_uppercase();
+        let date_only = params.any(|p| p.eq_ignore_ascii_case("VALUE=DATE"));
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(Draft::default());
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                let Some(draft) = current.take() else {
+                    continue;
+                };
+                let Some(start) = draft.start else { continue };
+                let end = match draft.end {
+                    Some(end) if draft.end_exclusive => add_days(&end, -1),
+                    Some(end) => end,
+                    None => start.clone(),
+                };
+                let end = if end < start { start.clone() } else { end };
+                let summary = draft.summary;
+                let description = [draft.description, draft.location]
+                    .into_iter()
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                events.push(ImportedEvent {
+                    event_type: classify(&summary),
+                    start,
+                    end,
+                    summary,
+                    description,
+                });
+            }
+            ("DTSTART", Some(draft)) => draft.start = iso_date(value),
+            ("DTEND", Some(draft)) => {
+                draft.end = iso_date(value);
+                draft.end_exclusive = date_only || value.len() == 8;
+            }
+            ("SUMMARY", Some(draft)) => draft.summary = unescape_text(value).trim().to_string(),
+            ("DESCRIPTION", Some(draft)) => {
+                draft.description = unescape_text(value).trim().to_string()
+            }
+            ("LOCATION", Some(draft)) => draft.location = unescape_text(value).trim().to_string(),
+            _ => {}
+        }
+    }
+    events.sort_by(|a, b| a.start.cmp(&b.start));
+    events
+}
+
+/// A stored event the import would repeat: one with exactly the same dates,
+/// or of the same type with overlapping dates.
+pub fn duplicate_of<'a>(
+    event: &ImportedEvent,
+    existing: &'a [CongregationEvent],
+) -> Option<&'a CongregationEvent> {
+    existing.iter().find(|e| {
+        let same_dates = e.start_date == event.start && e.end_date == event.end;
+        let overlaps = e.start_date <= event.end && event.start <= e.end_date;
+        same_dates || (overlaps && e.event_type == event.event_type)
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_escaped() {
+        assert_eq!(escape_text("a;b,c\\d\r\ne"), "a\\;b\\,c\\\\d\\ne");
+    }
+
+    #[test]
+    fn long_lines_fold_on_char_boundaries() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold(&line);
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= 75);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn parse_reads_folded_all_day_and_timed_events() {
+        let text = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20270213\r\n\
+                    DTEND;VALUE=DATE:20270215\r\nSUMMARY:Circuit assembly with the bra\r\n \
+                    nch representative\r\nLOCATION:Assembly Hall\\, North\r\nEND:VEVENT\r\n\
+                    BEGIN:VEVENT\r\nDTSTART:20270322T190000Z\r\nDTEND:20270322T210000Z\r\n\
+                    SUMMARY:Memorial\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let events = parse(text);
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            (events[0].start.as_str(), events[0].end.as_str()),
+            ("2027-02-13", "2027-02-14")
+        );
+        assert_eq!(
+            events[0].summary,
+            "Circuit assembly with the branch representative"
+        );
+        assert_eq!(events[0].description, "Assembly Hall, North");
+        assert_eq!(events[0].event_type, EventType::CircuitAssembly);
+        assert_eq!(
+            (events[1].start.as_str(), events[1].end.as_str()),
+            ("2027-03-22", "2027-03-22")
+        );
+        assert_eq!(events[1].event_type, EventType::Memorial);
+    }
+
+    #[test]
+    fn keyword_rules() {
+        assert_eq!(
+            classify("Asamblea regional 2027"),
+            EventType::RegionalConvention
+        );
+        assert_eq!(classify("Asamblea de circuito"), EventType::CircuitAssembly);
+        assert_eq!(
+            classify("Visita del superintendente de circuito"),
+            EventType::CircuitOverseerVisit
+        );
+        assert_eq!(classify("Hall cleaning day"), EventType::Other);
+        assert_eq!(classify("Assembly Hall cleaning"), EventType::Other);
+        assert_eq!(classify("Limpieza del Salón de Asambleas"), EventType::Other);
+    }
+
+    #[test]
+    fn utc_times_are_read_in_local_time() {
+        // Native builds read dates in UTC, like `today_str`.
+        assert_eq!(iso_date("20270322T233000Z").as_deref(), Some("2027-03-22"));
+        assert_eq!(iso_date("20270322T233000").as_deref(), Some("2027-03-22"));
+        assert_eq!(iso_date("20271340"), None);
+        assert_eq!(iso_date("2027-03-22"), None);
+    }
+
+    #[test]
+    fn all_day_end_is_exclusive() {
+        let item = CalendarItem {
+            uid: "x@y".into(),
+            start: "2026-12-31".into(),
+            end: "2026-12-31".into(),
+            summary: "S".into(),
+            description: String::new(),
+            location: String::new(),
+        };
+        let ics = write("Cal", "20261018T120000Z", &[item]);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20261231\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20270101\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Missing monthly reports, grouped by field service group.
+//!
+//! Reports are due on the [`DUE_DAY`] of the following month. The tracker
+//! crosses active publishers with group membership and the month's
+//! [`FieldServiceReport`]s, and builds reminder messages for whoever is still
+//! missing.
+
+use std::collections::HashSet;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::dates::add_months;
+use crate::models::field_service_group::FieldServiceGroup;
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::user::{User, UserType};
+
+use super::month_label;
+
+/// Day of the following month by which a report is expected.
+pub const DUE_DAY: u8 = 6;
+
+/// ISO date on which reports for `(year, month)` are due.
+pub fn due_date(year: i32, month: u8) -> String {
+    let (y, m) = add_months(year, month, 1);
+    format!("{y:04}-{m:02}-{DUE_DAY:02}")
+}
+
+/// Whether reports for `(year, month)` are overdue on `today` (ISO date).
+pub fn is_overdue(year: i32, month: u8, today: &str) -> bool {
+    today > due_date(year, month).as_str()
+}
+
+/// Publishers of one group who have not reported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingGroup {
+    /// `None` collects active publishers that are not in any group.
+    pub group: Option<FieldServiceGroup>,
+    pub missing: Vec<User>,
+    /// Active publishers in the group, reported or not.
+    pub expected: usize,
+}
+
+/// Active, non-student publishers without a report for the month, per group.
+///
+/// Groups keep their given order; the unassigned bucket comes last and only
+/// when it has members. Groups where everyone reported are kept so the view
+/// can show them as complete.
+pub fn missing_by_group(
+    users: &[User],
+    groups: &[FieldServiceGroup],
+    reports: &[FieldServiceReport],
+) -> Vec<MissingGroup> {
+    let reported: HashSet<&RecordId> = reports.iter().map(|r| &r.publisher).collect();
+    let expected: Vec<&User> = users
+        .iter()
+        .filter(|u| u.active && !matches!(u.user_type, UserType::Student))
+        .collect();
+    let is_missing = |u: &&User| u.id.as_ref().is_some_and(|id| !reported.contains(id));
+
+    let mut out: Vec<MissingGroup> = groups
+        .iter()
+        .map(|g| {
+            let members: Vec<&User> = expected
+                .iter()
+                .copied()
+                .filter(|u| u.id.as_ref().is_some_and(|id| g.members.contains(id)))
+                .collect();
+            MissingGroup {
+                group: Some(g.clone()),
+                expected: members.len(),
+                missing: members.into_iter().filter(is_missing).cloned().collect(),
+            }
+        })
+        .collect();
+
+    let unassigned: Vec<&User> = expected
+        .iter()
+        .copied()
+        .filter(|u| {
+            u.id.as_ref()
+                .is_none_or(|id| !groups.iter().any(|g| g.members.contains(id)))
+        })
+        .collect();
+    if !unassigned.is_empty() {
+        out.push(MissingGroup {
+            group: None,
+            expected: unassigned.len(),
+            missing: unassigned.into_iter().filter(is_missing).cloned().collect(),
+        });
+    }
+    out
+}
+
+/// Friendly reminder text for one publisher.
+pub fn reminder_message(first_name: &str, year: i32, month: u8) -> String {
+    format!(
+        "{} {},\n\n{} {} {}.\n\n{}",
+        t!("missing-reminder-hello"),
+        first_name,
+        t!("missing-reminder-body"),
+        month_label(month),
+        year,
+        t!("missing-reminder-thanks")
+    )
+}
+
+/// Percent-encode a string for use in a `mailto:`, `sms:` or web link.
+pub fn url_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+/// Phone number reduced to digits (and a leading `+`) for `sms:` / WhatsApp links.
+pub fn dialable(phone: &str) -> String {
+    let trimmed = phone.trim();
+    let digits: String = trimmed.chars().filter(|c| c.is_ascii_digit()).collect();
+    if trimmed.starts_with('+') { format!("+{digits}") } else { digits }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, rid, user_id};
+
+    fn user(key: &str, user_type: UserType, active: bool) -> User {
+        User { user_type, active, ..fixtures::user(key) }
+    }
+
+    fn report(key: &str) -> FieldServiceReport {
+        FieldServiceReport { preached: false, ..fixtures::report(key, 2026, 5) }
+    }
+
+    #[test]
+    fn lists_missing_publishers_per_group() {
+        let users = vec![
+            user("a", UserType::Publisher, true),
+            user("b", UserType::BaptizedPublisher, true),
+            user("c", UserType::Publisher, true),
+            user("student", UserType::Student, true),
+            user("gone", UserType::Publisher, false),
+        ];
+        let groups = vec![FieldServiceGroup {
+            id: None,
+            congregation: rid("congregation", "x"),
+            name: "North".into(),
+            overseer: None,
+            assistant: None,
+            members: vec![user_id("a"), user_id("b"), user_id("gone")],
+        }];
+        // "Did not preach" still counts as a submitted report.
+        let out = missing_by_group(&users, &groups, &[report("a")]);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].expected, 2);
+        assert_eq!(out[0].missing.len(), 1);
+        assert_eq!(out[0].missing[0].first_name, "b");
+        assert!(out[1].group.is_none());
+        assert_eq!(out[1].missing[0].first_name, "c");
+    }
+
+    #[test]
+    fn due_on_the_sixth_of_next_month() {
+        assert_eq!(due_date(2025, 12), "2026-01-06");
+        assert!(!is_overdue(2026, 5, "2026-06-06"));
+        assert!(is_overdue(2026, 5, "2026-06-07"));
+    }
+
+    #[test]
+    fn encodes_links() {
+        assert_eq!(url_encode("a b&c"), "a%20b%26c");
+        assert_eq!(dialable("+34 600 12-34"), "+346001234");
+    }
+}
@@ -0,0 +1,162 @@
+//! Printable documents and exports built from model data.
+//!
+//! Every print generator returns a self-contained HTML document. [`print_html`]
+//! hands it to the browser print dialog, where it can be printed or saved as
+//! PDF — no PDF library is bundled into the WASM binary. Data exports go
+//! through [`download_file`].
+
+pub mod analytics;
+pub mod csv;
+pub mod duty_chart;
+pub mod ics;
+pub mod missing;
+pub mod pioneer;
+pub mod s1;
+pub mod s140;
+pub mod s21;
+pub mod s88;
+pub mod s89;
+pub mod service_year;
+pub mod status;
+
+use dioxus::prelude::*;
+use dioxus_i18n::t;
+
+use crate::i18n::active_language;
+
+/// Escape text for safe interpolation into HTML element content or attributes.
+pub fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Wrap a rendered body into a complete, print-ready HTML document, tagged
+/// with the language of the active locale.
+pub fn html_document(title: &str, style: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html lang=\"{}\"><head><meta charset=\"utf-8\"><title>{}</title>\
+         <style>{BASE_STYLE}{style}</style></head><body>{body}</body></html>",
+        escape_html(&active_language()),
+        escape_html(title)
+    )
+}
+
+/// Shared print stylesheet: A4 pages, neutral typography.
+const BASE_STYLE: &str = "\
+@page { size: A4; margin: 12mm; }\
+* { box-sizing: border-box; }\
+body { font-family: Arial, Helvetica, sans-serif; font-size: 10pt; color: #000; margin: 0; }\
+table { border-collapse: collapse; width: 100%; }\
+th, td { border: 1px solid #000; padding: 3px 5px; }\
+.page-break { break-after: page; page-break-after: always; }\
+.page-break:last-child { break-after: auto; page-break-after: auto; }\
+";
+
+const TABLE_STYLE: &str = "\
+@page { size: A4 landscape; }\
+h1 { font-size: 14pt; margin: 0 0 2mm; }\
+h2 { font-size: 11pt; font-weight: normal; margin: 0 0 6mm; }\
+th { background: #f2f2f2; text-align: left; }\
+td { height: 9mm; }\
+";
+
+/// A titled chart of plain-text rows; the first row is the header.
+pub fn table_document(title: &str, subtitle: &str, rows: &[Vec<String>]) -> String {
+    let mut html = format!(
+        "<h1>{}</h1><h2>{}</h2><table>",
+        escape_html(title),
+        escape_html(subtitle)
+    );
+    for (i, row) in rows.iter().enumerate() {
+        let cell = if i == 0 { "th" } else { "td" };
+        if i == 0 {
+            html.push_str("<thead>");
+        }
+        html.push_str("<tr>");
+        for field in row {
+            html.push_str(&format!("<{cell}>{}</{cell}>", escape_html(field)));
+        }
+        html.push_str("</tr>");
+        if i == 0 {
+            html.push_str("</thead><tbody>");
+        }
+    }
+    html.push_str("</tbody></table>");
+    html_document(title, TABLE_STYLE, &html)
+}
+
+/// Open the browser print dialog for `html` without leaving the app.
+///
+/// The document is written into a hidden iframe that removes itself once the
+/// dialog closes.
+pub fn print_html(html: String) {
+    let eval = document::eval(
+        "
+        let html = await dioxus.recv();
+        const frame = document.createElement('iframe');
+        frame.style.position = 'fixed';
+        frame.style.right = '0';
+        frame.style.bottom = '0';
+        frame.style.width = '0';
+        frame.style.height = '0';
+        frame.style.border = '0';
+        document.body.appendChild(frame);
+        const doc = frame.contentDocument;
+        doc.open();
+        doc.write(html);
+        doc.close();
+        setTimeout(() => {
+            frame.contentWindow.focus();
+            frame.contentWindow.print();
+            setTimeout(() => frame.remove(), 1000);
+        }, 250);
+        ",
+    );
+    let _ = eval.send(html);
+}
+
+/// Offer `content` to the user as a downloaded file.
+pub fn download_file(filename: &str, mime: &str, content: String) {
+    let eval = document::eval(&format!(
+        "
+        let data = await dioxus.recv();
+        const blob = new Blob([data], {{ type: {mime:?} }});
+        const url = URL.createObjectURL(blob);
+        const a = document.createElement('a');
+        a.href = url;
+        a.download = {filename:?};
+        a.click();
+        URL.revokeObjectURL(url);
+        "
+    ));
+    let _ = eval.send(content);
+}
+
+/// Localised full month name (`1` → "January").
+pub fn month_label(month: u8) -> String {
+    match month {
+        1 => t!("month-1"),
+        2 => t!("month-2"),
+        3 => t!("month-3"),
+        4 => t!("month-4"),
+        5 => t!("month-5"),
+        6 => t!("month-6"),
+        7 => t!("month-7"),
+        8 => t!("month-8"),
+        9 => t!("month-9"),
+        10 => t!("month-10"),
+        11 => t!("month-11"),
+        12 => t!("month-12"),
+        _ => String::new(),
+    }
+}
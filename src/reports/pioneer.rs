@@ -0,0 +1,305 @@
+//! Pioneer hour goals and credit tracking.
+//!
+//! Each pioneer's reports for a service year are compared with the monthly
+//! requirement of their [`PioneerKind`]. Credit hours help reach a month's
+//! requirement but never count beyond the allowed limit: a month's counted
+//! credit is the reported credit capped at whatever the field service hours
+//! left short of the requirement, or of the congregation's
+//! [`PioneerSettings::credit_limit`] when that is higher.
+//! Pace is measured from the start of the service year because the date a
+//! pioneer was appointed is not recorded.
+
+use std::collections::BTreeSet;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::pioneer::PioneerSettings;
+use crate::models::user::{User, UserType};
+
+use super::service_year::service_year_months;
+
+/// Monthly requirement of an auxiliary pioneer.
+pub const AUXILIARY_MONTHLY_HOURS: u32 = 30;
+
+/// How many months' worth of hours a yearly pioneer may fall behind pace
+/// before an alert is raised.
+pub const ALERT_MONTHS_BEHIND: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PioneerKind {
+    Regular,
+    /// Special pioneers and missionaries in the field ministry.
+    Special,
+    ContinuousAuxiliary,
+    /// Auxiliary pioneer for individual months, taken from the reports.
+    Auxiliary,
+}
+
+impl PioneerKind {
+    /// The kind a publisher's type implies for every month, if any.
+    pub fn of(user_type: &UserType) -> Option<Self> {
+        match user_type {
+            UserType::RegularPioneer => Some(Self::Regular),
+            UserType::SpecialPioneer | UserType::Missionary => Some(Self::Special),
+            UserType::ContinuousAuxiliaryPioneer => Some(Self::ContinuousAuxiliary),
+            _ => None,
+        }
+    }
+
+    pub fn monthly_hours(&self) -> u32 {
+        match self {
+            Self::Regular => 50,
+            Self::Special => 100,
+            Self::ContinuousAuxiliary | Self::Auxiliary => AUXILIARY_MONTHLY_HOURS,
+        }
+    }
+
+    /// Whether the goal runs over the whole service year.
+    pub fn is_yearly(&self) -> bool {
+        !matches!(self, Self::Auxiliary)
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Regular => t!("user-type-regular-pioneer"),
+            Self::Special => t!("user-type-special-pioneer"),
+            Self::ContinuousAuxiliary => t!("user-type-cont-aux-pioneer"),
+            Self::Auxiliary => t!("report-form-aux-pioneer"),
+        }
+    }
+}
+
+/// One month measured against the requirement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PioneerMonth {
+    pub year: i32,
+    pub month: u8,
+    pub goal: u32,
+    pub hours: u32,
+    /// Credit as reported.
+    pub credits: u32,
+    /// Credit that counts toward the goal.
+    pub credits_counted: u32,
+}
+
+impl PioneerMonth {
+    fn new(
+        year: i32,
+        month: u8,
+        goal: u32,
+        settings: &PioneerSettings,
+        report: Option<&FieldServiceReport>,
+    ) -> Self {
+        let (hours, credits) = report
+            .filter(|r| r.preached)
+            .map(|r| (r.hours.unwrap_or(0), r.credits.unwrap_or(0)))
+            .unwrap_or((0, 0));
+        let limit = settings.credit_limit.map_or(goal, |l| l.max(goal));
+        Self {
+            year,
+            month,
+            goal,
+            hours,
+            credits,
+            credits_counted: credits.min(limit.saturating_sub(hours)),
+        }
+    }
+
+    /// Hours that count toward the goal.
+    pub fn counted(&self) -> u32 {
+        self.hours + self.credits_counted
+    }
+
+    pub fn met(&self) -> bool {
+        self.counted() >= self.goal
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PioneerProgress {
+    pub publisher: RecordId,
+    pub kind: PioneerKind,
+    /// Months with a requirement up to the evaluated month, oldest first.
+    pub months: Vec<PioneerMonth>,
+    /// Requirement for the whole service year (auxiliary: the months served).
+    pub annual_goal: u32,
+}
+
+impl PioneerProgress {
+    /// Hours plus counted credit so far.
+    pub fn total(&self) -> u32 {
+        self.months.iter().map(PioneerMonth::counted).sum()
+    }
+
+    pub fn credits_reported(&self) -> u32 {
+        self.months.iter().map(|m| m.credits).sum()
+    }
+
+    pub fn credits_counted(&self) -> u32 {
+        self.months.iter().map(|m| m.credits_counted).sum()
+    }
+
+    /// What the requirement asks for up to now.
+    pub fn expected_to_date(&self) -> u32 {
+        self.months.iter().map(|m| m.goal).sum()
+    }
+
+    /// Year-end total if the average so far continues.
+    pub fn projected(&self) -> f64 {
+        if !self.kind.is_yearly() || self.months.is_empty() {
+            return self.total() as f64;
+        }
+        self.total() as f64 / self.months.len() as f64 * 12.0
+    }
+
+    /// How many months' requirement the pioneer is behind pace; 0 when on or
+    /// ahead of pace.
+    pub fn months_behind(&self) -> f64 {
+        let short = self.expected_to_date().saturating_sub(self.total());
+        short as f64 / self.kind.monthly_hours() as f64
+    }
+
+    /// Significantly short: behind by [`ALERT_MONTHS_BEHIND`] for yearly goals,
+    /// any missed month for auxiliary pioneers.
+    pub fn needs_attention(&self) -> bool {
+        if self.kind.is_yearly() {
+            self.months_behind() >= ALERT_MONTHS_BEHIND
+        } else {
+            self.months.iter().any(|m| !m.met())
+        }
+    }
+}
+
+/// Progress of every pioneer in `service_year`, up to and including `until`.
+///
+/// Publishers whose type carries a yearly goal are measured over every month;
+/// other publishers appear when they auxiliary pioneered at least once, and
+/// only those months count. Soft-deleted publishers are skipped.
+pub fn pioneer_progress(
+    users: &[User],
+    reports: &[FieldServiceReport],
+    settings: &PioneerSettings,
+    service_year: i32,
+    until: (i32, u8),
+) -> Vec<PioneerProgress> {
+    let months: Vec<(i32, u8)> = service_year_months(service_year)
+        .into_iter()
+        .filter(|ym| *ym <= until)
+        .collect();
+    let in_year: BTreeSet<(i32, u8)> = months.iter().copied().collect();
+
+    users
+        .iter()
+        .filter(|u| u.active)
+        .filter_map(|u| {
+            let id = u.id.as_ref()?;
+            let own: Vec<&FieldServiceReport> = reports
+                .iter()
+                .filter(|r| &r.publisher == id && in_year.contains(&(r.year, r.month)))
+                .collect();
+            let report_for = |y: i32, m: u8| own.iter().copied().find(|r| r.year == y && r.month == m);
+
+            if let Some(kind) = PioneerKind::of(&u.user_type) {
+                let goal = kind.monthly_hours();
+                return Some(PioneerProgress {
+                    publisher: id.clone(),
+                    kind,
+                    months: months
+                        .iter()
+                        .map(|&(y, m)| PioneerMonth::new(y, m, goal, settings, report_for(y, m)))
+                        .collect(),
+                    annual_goal: goal * 12,
+                });
+            }
+
+            let aux: Vec<PioneerMonth> = months
+                .iter()
+                .filter_map(|&(y, m)| {
+                    let r = report_for(y, m).filter(|r| r.auxiliary_pioneer)?;
+                    Some(PioneerMonth::new(y, m, AUXILIARY_MONTHLY_HOURS, settings, Some(r)))
+                })
+                .collect();
+            (!aux.is_empty()).then(|| PioneerProgress {
+                publisher: id.clone(),
+                kind: PioneerKind::Auxiliary,
+                annual_goal: aux.iter().map(|m| m.goal).sum(),
+                months: aux,
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn user(key: &str, user_type: UserType) -> User {
+        User { user_type, ..fixtures::user(key) }
+    }
+
+    fn report(key: &str, year: i32, month: u8, hours: u32, credits: u32, aux: bool) -> FieldServiceReport {
+        FieldServiceReport {
+            hours: Some(hours),
+            credits: Some(credits),
+            auxiliary_pioneer: aux,
+            ..fixtures::report(key, year, month)
+        }
+    }
+
+    #[test]
+    fn credit_only_fills_the_monthly_gap() {
+        let settings = PioneerSettings::default();
+        let m = PioneerMonth::new(2025, 9, 50, &settings, Some(&report("p", 2025, 9, 40, 20, false)));
+        assert_eq!(m.credits_counted, 10);
+        assert_eq!(m.counted(), 50);
+        assert!(m.met());
+        let over = PioneerMonth::new(2025, 10, 50, &settings, Some(&report("p", 2025, 10, 60, 5, false)));
+        assert_eq!(over.credits_counted, 0);
+    }
+
+    #[test]
+    fn credit_limit_raises_the_cap_but_not_below_the_goal() {
+        let settings = PioneerSettings { credit_limit: Some(55) };
+        let m = PioneerMonth::new(2025, 9, 50, &settings, Some(&report("p", 2025, 9, 40, 20, false)));
+        assert_eq!(m.credits_counted, 15);
+        assert_eq!(m.counted(), 55);
+        let low = PioneerSettings { credit_limit: Some(20) };
+        let m = PioneerMonth::new(2025, 9, 50, &low, Some(&report("p", 2025, 9, 40, 20, false)));
+        assert_eq!(m.credits_counted, 10);
+    }
+
+    #[test]
+    fn measures_pace_and_raises_alerts() {
+        let users = vec![user("rp", UserType::RegularPioneer), user("pub", UserType::Publisher)];
+        let reports = vec![
+            report("rp", 2025, 9, 50, 0, false),
+            report("rp", 2025, 10, 30, 0, false),
+            report("rp", 2025, 11, 20, 0, false),
+            report("pub", 2025, 10, 25, 0, true),
+            report("pub", 2025, 11, 12, 0, false),
+        ];
+        let out = pioneer_progress(&users, &reports, &PioneerSettings::default(), 2026, (2025, 11));
+        assert_eq!(out.len(), 2);
+
+        let rp = &out[0];
+        assert_eq!(rp.kind, PioneerKind::Regular);
+        assert_eq!(rp.total(), 100);
+        assert_eq!(rp.expected_to_date(), 150);
+        assert_eq!(rp.months_behind(), 1.0);
+        assert_eq!(rp.projected(), 400.0);
+        assert!(!rp.needs_attention());
+
+        let aux = &out[1];
+        assert_eq!(aux.kind, PioneerKind::Auxiliary);
+        assert_eq!(aux.months.len(), 1);
+        assert_eq!(aux.annual_goal, 30);
+        assert!(aux.needs_attention());
+    }
+}
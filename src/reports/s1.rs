@@ -0,0 +1,261 @@
+//! S-1 monthly congregation totals.
+//!
+//! Splits a month's [`FieldServiceReport`]s into the three categories the
+//! secretary sends to the branch — publishers, auxiliary pioneers and regular
+//! pioneers — and counts the active publishers of the congregation.
+//!
+//! Special pioneers and field missionaries report directly to the branch, so
+//! their reports are listed separately and left out of the three categories.
+
+use std::collections::HashMap;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::crypto::SessionCrypto;
+use crate::database::Db;
+use crate::dates::add_months;
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::user::{User, UserType};
+
+use super::analytics::{active_publishers, ACTIVE_WINDOW_MONTHS};
+use super::status::StatusCounts;
+use super::{escape_html, html_document, month_label};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum S1Category {
+    Publishers,
+    AuxiliaryPioneers,
+    RegularPioneers,
+    /// Special pioneers and field missionaries — not part of the S-1 totals.
+    ReportedToBranch,
+}
+
+impl S1Category {
+    /// Category a report falls into, based on the publisher's [`UserType`]
+    /// and the report's `auxiliary_pioneer` flag.
+    pub fn of(user_type: &UserType, report: &FieldServiceReport) -> Self {
+        match user_type {
+            UserType::RegularPioneer => Self::RegularPioneers,
+            UserType::SpecialPioneer | UserType::Missionary => Self::ReportedToBranch,
+            UserType::ContinuousAuxiliaryPioneer => Self::AuxiliaryPioneers,
+            _ if report.auxiliary_pioneer => Self::AuxiliaryPioneers,
+            _ => Self::Publishers,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Publishers => t!("s1-cat-publishers"),
+            Self::AuxiliaryPioneers => t!("s1-cat-aux-pioneers"),
+            Self::RegularPioneers => t!("s1-cat-regular-pioneers"),
+            Self::ReportedToBranch => t!("s1-cat-branch"),
+        }
+    }
+}
+
+/// Figures for one category plus the reports that make them up.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct S1CategoryTotals {
+    /// Reports where the publisher shared in the ministry.
+    pub reporting: usize,
+    pub hours: u32,
+    pub bible_studies: u32,
+    /// Contributing reports (drill-down), already decrypted.
+    pub reports: Vec<FieldServiceReport>,
+}
+
+impl S1CategoryTotals {
+    fn add(&mut self, report: &FieldServiceReport) {
+        self.reporting += 1;
+        self.hours += report.hours.unwrap_or(0);
+        self.bible_studies += report.bible_studies.unwrap_or(0);
+        self.reports.push(report.clone());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct S1Summary {
+    pub year: i32,
+    pub month: u8,
+    pub active_publishers: usize,
+    pub publishers: S1CategoryTotals,
+    pub auxiliary_pioneers: S1CategoryTotals,
+    pub regular_pioneers: S1CategoryTotals,
+    pub reported_to_branch: S1CategoryTotals,
+    /// Publishers per activity status (not part of the printed S-1).
+    pub statuses: StatusCounts,
+}
+
+impl S1Summary {
+    /// Aggregate the month from already loaded data.
+    ///
+    /// `reports` may span several months: those outside `(year, month)` are
+    /// only used to decide who is active. Reports marked as "did not preach"
+    /// are never counted as reporting.
+    pub fn compute(year: i32, month: u8, users: &[User], reports: &[FieldServiceReport]) -> Self {
+        let types: HashMap<&RecordId, &UserType> = users
+            .iter()
+            .filter_map(|u| u.id.as_ref().map(|id| (id, &u.user_type)))
+            .collect();
+
+        let mut summary = Self {
+            year,
+            month,
+            active_publishers: active_publishers(reports, year, month).len(),
+            statuses: StatusCounts::compute(users, reports, year, month),
+            ..Default::default()
+        };
+        for r in reports
+            .iter()
+            .filter(|r| r.year == year && r.month == month && r.preached)
+        {
+            let user_type = types.get(&r.publisher).copied().cloned().unwrap_or_default();
+            summary.category_mut(S1Category::of(&user_type, r)).add(r);
+        }
+        summary
+    }
+
+    /// Load everything needed for `(year, month)` and aggregate it.
+    ///
+    /// Two activity windows of history are fetched so returning publishers
+    /// can be told apart from new ones.
+    pub async fn load(
+        db: &Db,
+        crypto: &SessionCrypto,
+        year: i32,
+        month: u8,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let users = User::all(db, crypto).await?;
+        let from = add_months(year, month, -2 * ACTIVE_WINDOW_MONTHS);
+        let reports = FieldServiceReport::by_period(db, crypto, from, (year, month)).await?;
+        Ok(Self::compute(year, month, &users, &reports))
+    }
+
+    pub fn category(&self, cat: S1Category) -> &S1CategoryTotals {
+        match cat {
+            S1Category::Publishers => &self.publishers,
+            S1Category::AuxiliaryPioneers => &self.auxiliary_pioneers,
+            S1Category::RegularPioneers => &self.regular_pioneers,
+            S1Category::ReportedToBranch => &self.reported_to_branch,
+        }
+    }
+
+    fn category_mut(&mut self, cat: S1Category) -> &mut S1CategoryTotals {
+        match cat {
+            S1Category::Publishers => &mut self.publishers,
+            S1Category::AuxiliaryPioneers => &mut self.auxiliary_pioneers,
+            S1Category::RegularPioneers => &mut self.regular_pioneers,
+            S1Category::ReportedToBranch => &mut self.reported_to_branch,
+        }
+    }
+}
+
+const STYLE: &str = "\
+h1 { font-size: 14pt; margin: 0 0 2mm; }\
+h2 { font-size: 11pt; font-weight: normal; margin: 0 0 6mm; }\
+.active { font-size: 12pt; margin: 0 0 5mm; }\
+th { background: #f2f2f2; text-align: left; }\
+td.num { text-align: right; width: 30mm; }\
+";
+
+/// Printable one-page summary of the month.
+pub fn render(summary: &S1Summary, congregation_name: &str) -> String {
+    let mut html = String::new();
+    html.push_str(&format!(
+        "<h1>{}</h1><h2>{} — {} {}</h2>",
+        escape_html(&t!("s1-title")),
+        escape_html(congregation_name),
+        escape_html(&month_label(summary.month)),
+        summary.year
+    ));
+    html.push_str(&format!(
+        "<p class=\"active\">{}: <b>{}</b></p>",
+        escape_html(&t!("s1-active-publishers")),
+        summary.active_publishers
+    ));
+    html.push_str(&format!(
+        "<table><thead><tr><th></th><th>{}</th><th>{}</th><th>{}</th></tr></thead><tbody>",
+        escape_html(&t!("s1-col-reports")),
+        escape_html(&t!("report-form-hours")),
+        escape_html(&t!("report-form-bible-studies")),
+    ));
+    for cat in [
+        S1Category::Publishers,
+        S1Category::AuxiliaryPioneers,
+        S1Category::RegularPioneers,
+    ] {
+        let c = summary.category(cat);
+        // Publishers do not report hours on the S-1.
+        let hours = if cat == S1Category::Publishers {
+            "—".to_string()
+        } else {
+            c.hours.to_string()
+        };
+        html.push_str(&format!(
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>",
+            escape_html(&cat.label()),
+            c.reporting,
+            hours,
+            c.bible_studies
+        ));
+    }
+    html.push_str("</tbody></table>");
+    html_document(&t!("s1-title"), STYLE, &html)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn user(key: &str, user_type: UserType) -> User {
+        User { user_type, ..fixtures::user(key) }
+    }
+
+    fn report(key: &str, year: i32, month: u8, hours: u32, aux: bool, preached: bool) -> FieldServiceReport {
+        FieldServiceReport {
+            hours: Some(hours),
+            bible_studies: Some(1),
+            auxiliary_pioneer: aux,
+            preached,
+            ..fixtures::report(key, year, month)
+        }
+    }
+
+    #[test]
+    fn splits_reports_by_category_and_counts_active() {
+        let users = vec![
+            user("pub", UserType::BaptizedPublisher),
+            user("aux", UserType::Publisher),
+            user("rp", UserType::RegularPioneer),
+            user("sp", UserType::SpecialPioneer),
+            user("old", UserType::Publisher),
+            user("idle", UserType::Publisher),
+        ];
+        let reports = vec![
+            report("pub", 2026, 3, 0, false, true),
+            report("aux", 2026, 3, 30, true, true),
+            report("rp", 2026, 3, 50, false, true),
+            report("sp", 2026, 3, 100, false, true),
+            // Reported earlier in the window only: active, not reporting.
+            report("old", 2025, 10, 0, false, true),
+            // "Did not preach" counts for nothing.
+            report("idle", 2026, 3, 0, false, false),
+        ];
+        let s = S1Summary::compute(2026, 3, &users, &reports);
+        assert_eq!(s.active_publishers, 5);
+        assert_eq!(s.publishers.reporting, 1);
+        assert_eq!(s.auxiliary_pioneers.reporting, 1);
+        assert_eq!(s.auxiliary_pioneers.hours, 30);
+        assert_eq!(s.regular_pioneers.hours, 50);
+        assert_eq!(s.regular_pioneers.bible_studies, 1);
+        assert_eq!(s.reported_to_branch.reporting, 1);
+        assert_eq!(s.statuses.irregular, 5);
+        assert_eq!(s.statuses.inactive, 1);
+    }
+}
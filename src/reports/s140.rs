@@ -0,0 +1,300 @@
+//! S-140 Our Christian Life and Ministry meeting schedule.
+//!
+//! One printed program per month, two weeks to a page, laid out from the
+//! month's [`MidweekMeeting`]s in section order. When student parts are
+//! repeated in the auxiliary classroom, both classrooms get a column.
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::format::{format_date, format_name};
+use crate::models::congregation::{DateFormat, NameFormat};
+use crate::models::midweek_meeting::{Classroom, MidweekMeeting, MidweekPart, PartKind, Section};
+use crate::models::user::User;
+use crate::schedule::midweek::{part_label, section_label};
+
+use super::{escape_html, html_document};
+
+/// Printed number of every part, in part order. Main-hall parts count up
+/// from 1; a part repeated in the auxiliary classroom shares the number of
+/// its main-hall twin.
+pub fn part_numbers(meeting: &MidweekMeeting) -> Vec<usize> {
+    let mut numbers = vec![0; meeting.parts.len()];
+    let mut next = 1;
+    for (i, part) in meeting.parts.iter().enumerate() {
+        if part.classroom == Classroom::Main {
+            numbers[i] = next;
+            next += 1;
+        }
+    }
+    let mut twinned: Vec<usize> = vec![];
+    for (i, part) in meeting.parts.iter().enumerate() {
+        if part.classroom != Classroom::Auxiliary {
+            continue;
+        }
+        let twin = (0..meeting.parts.len()).find(|&j| {
+            let p = &meeting.parts[j];
+            p.classroom == Classroom::Main && p.kind == part.kind && !twinned.contains(&j)
+        });
+        numbers[i] = match twin {
+            Some(j) => {
+                twinned.push(j);
+                numbers[j]
+            }
+            None => {
+                next += 1;
+                next - 1
+            }
+        };
+    }
+    numbers
+}
+
+/// Theme of a part as printed: the workbook title, or the kind of part.
+pub fn part_title(part: &MidweekPart) -> String {
+    if part.title.is_empty() {
+        part_label(part.kind)
+    } else {
+        part.title.clone()
+    }
+}
+
+fn person(id: &Option<RecordId>, users: &[User], name_fmt: &NameFormat) -> String {
+    id.as_ref()
+        .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+        .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+        .unwrap_or_default()
+}
+
+/// Assignee and, for parts with a householder or reader, the assistant.
+fn people(part: &MidweekPart, users: &[User], name_fmt: &NameFormat) -> String {
+    let main = person(&part.assignee, users, name_fmt);
+    match person(&part.assistant, users, name_fmt) {
+        helper if helper.is_empty() || !part.kind.has_assistant() => main,
+        helper => format!("{main} / {helper}"),
+    }
+}
+
+const STYLE: &str = "\
+h1 { font-size: 13pt; margin: 0 0 1mm; text-transform: uppercase; }\
+h2 { font-size: 10pt; font-weight: normal; margin: 0 0 4mm; }\
+.week { margin-bottom: 7mm; break-inside: avoid; }\
+.week td { border: none; border-bottom: 1px solid #ddd; }\
+.week th { border: none; text-align: left; font-size: 8pt; color: #555; }\
+.head td { border-bottom: 2px solid #000; font-weight: bold; font-size: 11pt; }\
+.role { color: #555; font-size: 8pt; }\
+.name { width: 45mm; }\
+.section td { color: #fff; font-weight: bold; font-size: 9pt; text-transform: uppercase; }\
+.treasures td { background: #575a5d; }\
+.ministry td { background: #be8900; }\
+.living td { background: #7e0024; }\
+.song td { color: #555; }\
+";
+
+fn section_class(section: Section) -> &'static str {
+    match section {
+        Section::Treasures => "treasures",
+        Section::Ministry => "ministry",
+        Section::Living => "living",
+    }
+}
+
+fn song_label(song: Option<u16>) -> String {
+    match song {
+        Some(n) => format!("{} {n}", t!("s140-song")),
+        None => t!("s140-song"),
+    }
+}
+
+fn render_week(
+    meeting: &MidweekMeeting,
+    users: &[User],
+    name_fmt: &NameFormat,
+    date_fmt: &DateFormat,
+) -> String {
+    let two_rooms = meeting.classrooms > 1;
+    let span = if two_rooms { 3 } else { 2 };
+    let name = |id: &Option<RecordId>| escape_html(&person(id, users, name_fmt));
+    let role_row = |label: String, value: String| {
+        format!(
+            "<tr><td colspan=\"{}\"><span class=\"role\">{}:</span></td><td class=\"name\">{}</td></tr>",
+            span - 1,
+            escape_html(&label),
+            value
+        )
+    };
+
+    let mut html = String::from("<table class=\"week\">");
+    html.push_str(&format!(
+        "<tr class=\"head\"><td colspan=\"{span}\">{} | {}</td></tr>",
+        escape_html(&format_date(&meeting.date, date_fmt)),
+        escape_html(&meeting.bible_reading)
+    ));
+    html.push_str(&role_row(t!("midweek-chairman"), name(&meeting.chairman)));
+    if two_rooms {
+        html.push_str(&role_row(
+            t!("midweek-aux-counselor"),
+            name(&meeting.aux_counselor),
+        ));
+    }
+    html.push_str(&format!(
+        "<tr class=\"song\"><td colspan=\"{}\">{}</td><td class=\"name\">{}</td></tr>",
+        span - 1,
+        escape_html(&format!(
+            "{} · {}",
+            song_label(meeting.opening_song),
+            t!("midweek-opening-prayer")
+        )),
+        name(&meeting.opening_prayer)
+    ));
+
+    if two_rooms {
+        html.push_str(&format!(
+            "<tr><th></th><th>{}</th><th>{}</th></tr>",
+            escape_html(&t!("midweek-classroom-aux")),
+            escape_html(&t!("midweek-classroom-main"))
+        ));
+    }
+
+    let numbers = part_numbers(meeting);
+    let mut section: Option<Section> = None;
+    for (i, part) in meeting.parts.iter().enumerate() {
+        // Auxiliary parts are printed on the row of their main-hall twin.
+        let number = numbers[i];
+        let twin_of = |classroom: Classroom| {
+            meeting
+                .parts
+                .iter()
+                .zip(&numbers)
+                .find(|(p, n)| p.classroom == classroom && **n == number)
+                .map(|(p, _)| p)
+        };
+        if part.classroom == Classroom::Auxiliary && twin_of(Classroom::Main).is_some() {
+            continue;
+        }
+        if section != Some(part.kind.section()) {
+            section = Some(part.kind.section());
+            html.push_str(&format!(
+                "<tr class=\"section {}\"><td colspan=\"{span}\">{}</td></tr>",
+                section_class(part.kind.section()),
+                escape_html(&section_label(part.kind.section()))
+            ));
+            if part.kind.section() == Section::Living {
+                html.push_str(&format!(
+                    "<tr class=\"song\"><td colspan=\"{span}\">{}</td></tr>",
+                    escape_html(&song_label(meeting.middle_song))
+                ));
+            }
+        }
+        let title = format!(
+            "{number}. {} ({} {})",
+            part_title(part),
+            part.minutes,
+            t!("midweek-minutes")
+        );
+        // Cells are escaped here: the study lists conductor and reader on
+        // two lines.
+        let main = if part.classroom == Classroom::Auxiliary {
+            String::new()
+        } else if part.kind == PartKind::CongregationBibleStudy {
+            format!(
+                "{}: {}<br>{}: {}",
+                escape_html(&t!("s140-conductor")),
+                name(&part.assignee),
+                escape_html(&t!("midweek-reader")),
+                name(&part.assistant)
+            )
+        } else {
+            escape_html(&people(part, users, name_fmt))
+        };
+        let aux_cell = if two_rooms {
+            let aux = twin_of(Classroom::Auxiliary)
+                .map(|p| people(p, users, name_fmt))
+                .unwrap_or_default();
+            format!("<td class=\"name\">{}</td>", escape_html(&aux))
+        } else {
+            String::new()
+        };
+        html.push_str(&format!(
+            "<tr><td>{}</td>{aux_cell}<td class=\"name\">{main}</td></tr>",
+            escape_html(&title)
+        ));
+    }
+
+    html.push_str(&format!(
+        "<tr class=\"song\"><td colspan=\"{}\">{}</td><td class=\"name\">{}</td></tr>",
+        span - 1,
+        escape_html(&format!(
+            "{} · {}",
+            song_label(meeting.closing_song),
+            t!("midweek-closing-prayer")
+        )),
+        name(&meeting.closing_prayer)
+    ));
+    html.push_str("</table>");
+    html
+}
+
+/// The month's schedule, two weeks to a page.
+pub fn render(
+    meetings: &[MidweekMeeting],
+    users: &[User],
+    congregation_name: &str,
+    subtitle: &str,
+    name_fmt: &NameFormat,
+    date_fmt: &DateFormat,
+) -> String {
+    let mut html = String::new();
+    for (page, weeks) in meetings.chunks(2).enumerate() {
+        html.push_str("<div class=\"page-break\">");
+        if page == 0 {
+            html.push_str(&format!(
+                "<h1>{}</h1><h2>{} — {}</h2>",
+                escape_html(&t!("s140-title")),
+                escape_html(congregation_name),
+                escape_html(subtitle)
+            ));
+        }
+        for meeting in weeks {
+            html.push_str(&render_week(meeting, users, name_fmt, date_fmt));
+        }
+        html.push_str("</div>");
+    }
+    html_document(&t!("s140-title"), STYLE, &html)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(kind: PartKind, classroom: Classroom) -> MidweekPart {
+        MidweekPart {
+            kind,
+            title: String::new(),
+            minutes: kind.default_minutes(),
+            classroom,
+            assignee: None,
+            assistant: None,
+            lesson: None,
+        }
+    }
+
+    #[test]
+    fn auxiliary_parts_share_their_twin_number() {
+        let mut meeting = MidweekMeeting::template("2026-10-19".into(), "2026-10-21".into());
+        meeting.classrooms = 2;
+        meeting
+            .parts
+            .insert(3, part(PartKind::BibleReading, Classroom::Auxiliary));
+        meeting
+            .parts
+            .push(part(PartKind::StudentTalk, Classroom::Auxiliary));
+        // Treasures, Gems, Reading, Reading (aux), Starting, Following,
+        // Disciples, Living, Study, Talk (aux, no twin).
+        assert_eq!(part_numbers(&meeting), vec![1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,207 @@
+//! S-21 Congregation's Publisher Record card.
+//!
+//! One card covers a single publisher and service year: the header comes from
+//! [`User`], the twelve monthly rows (September → August) from
+//! [`FieldServiceReport`].
+
+use dioxus_i18n::t;
+
+use crate::format::{format_date, format_name};
+use crate::models::congregation::{DateFormat, NameFormat};
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::user::{Appointment, Gender, User, UserType};
+
+use super::service_year::service_year_months;
+use super::{escape_html, html_document, month_label};
+
+/// One month line of the card. `report` is `None` when nothing was submitted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct S21Row {
+    pub year: i32,
+    pub month: u8,
+    pub report: Option<FieldServiceReport>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct S21Card {
+    pub user: User,
+    pub service_year: i32,
+    pub rows: Vec<S21Row>,
+}
+
+impl S21Card {
+    /// Build a card from any set of reports; only the publisher's own reports
+    /// inside the service year are picked up.
+    pub fn build(user: User, service_year: i32, reports: &[FieldServiceReport]) -> Self {
+        let rows = service_year_months(service_year)
+            .into_iter()
+            .map(|(year, month)| S21Row {
+                year,
+                month,
+                report: reports
+                    .iter()
+                    .find(|r| {
+                        user.id.as_ref() == Some(&r.publisher) && r.year == year && r.month == month
+                    })
+                    .cloned(),
+            })
+            .collect();
+        Self { user, service_year, rows }
+    }
+
+    /// Sum of reported hours over the service year.
+    pub fn total_hours(&self) -> u32 {
+        self.rows
+            .iter()
+            .filter_map(|r| r.report.as_ref().and_then(|r| r.hours))
+            .sum()
+    }
+
+    /// Whether any month of the card has a report.
+    pub fn has_reports(&self) -> bool {
+        self.rows.iter().any(|r| r.report.is_some())
+    }
+}
+
+const STYLE: &str = "\
+.card { padding: 4mm 0; }\
+h1 { font-size: 13pt; text-align: center; margin: 0 0 4mm; text-transform: uppercase; }\
+.field { margin: 1.5mm 0; }\
+.field b { display: inline-block; min-width: 38mm; }\
+.boxes { display: flex; flex-wrap: wrap; gap: 2mm 6mm; margin: 2mm 0 4mm; }\
+th { font-size: 8pt; font-weight: bold; background: #f2f2f2; }\
+td.num, td.mark { text-align: center; width: 18mm; }\
+td.month { width: 28mm; }\
+tr.total td { font-weight: bold; }\
+";
+
+/// Render one or more cards into a printable document, one card per page.
+pub fn render(cards: &[S21Card], name_fmt: &NameFormat, date_fmt: &DateFormat) -> String {
+    let body: String = cards
+        .iter()
+        .map(|c| render_card(c, name_fmt, date_fmt))
+        .collect();
+    html_document(&t!("s21-title"), STYLE, &body)
+}
+
+fn checkbox(checked: bool, label: &str) -> String {
+    format!(
+        "<span>{} {}</span>",
+        if checked { "☑" } else { "☐" },
+        escape_html(label)
+    )
+}
+
+fn render_card(card: &S21Card, name_fmt: &NameFormat, date_fmt: &DateFormat) -> String {
+    let u = &card.user;
+    let name = format_name(&u.first_name, &u.last_name, name_fmt);
+    let birth = u
+        .birthday
+        .as_deref()
+        .map(|d| format_date(d, date_fmt))
+        .unwrap_or_default();
+    let baptism = u
+        .baptism_date
+        .as_deref()
+        .map(|d| format_date(d, date_fmt))
+        .unwrap_or_default();
+    let is_cont_aux = matches!(u.user_type, UserType::ContinuousAuxiliaryPioneer);
+
+    let mut html = String::new();
+    html.push_str("<div class=\"card page-break\">");
+    html.push_str(&format!("<h1>{}</h1>", escape_html(&t!("s21-title"))));
+    for (label, value) in [
+        (t!("s21-name"), name),
+        (t!("s21-birth-date"), birth),
+        (t!("s21-baptism-date"), baptism),
+    ] {
+        html.push_str(&format!(
+            "<div class=\"field\"><b>{}:</b> {}</div>",
+            escape_html(&label),
+            escape_html(&value)
+        ));
+    }
+
+    // Anointed / other sheep is not tracked, so both boxes are left for the
+    // secretary to tick by hand.
+    html.push_str("<div class=\"boxes\">");
+    html.push_str(&checkbox(u.gender == Gender::Male, &t!("s21-male")));
+    html.push_str(&checkbox(u.gender == Gender::Female, &t!("s21-female")));
+    html.push_str(&checkbox(false, &t!("s21-other-sheep")));
+    html.push_str(&checkbox(false, &t!("s21-anointed")));
+    html.push_str("</div><div class=\"boxes\">");
+    html.push_str(&checkbox(
+        matches!(u.appointment, Some(Appointment::Elder)),
+        &t!("s21-elder"),
+    ));
+    html.push_str(&checkbox(
+        matches!(u.appointment, Some(Appointment::MinisterialServant)),
+        &t!("s21-ms"),
+    ));
+    html.push_str(&checkbox(
+        matches!(u.user_type, UserType::RegularPioneer),
+        &t!("s21-regular-pioneer"),
+    ));
+    html.push_str(&checkbox(
+        matches!(u.user_type, UserType::SpecialPioneer),
+        &t!("s21-special-pioneer"),
+    ));
+    html.push_str(&checkbox(
+        matches!(u.user_type, UserType::Missionary),
+        &t!("s21-field-missionary"),
+    ));
+    html.push_str("</div>");
+
+    html.push_str("<table><thead><tr>");
+    for head in [
+        format!("{} {}", t!("s21-service-year"), card.service_year),
+        t!("s21-col-shared"),
+        t!("s21-col-studies"),
+        t!("s21-col-aux"),
+        t!("s21-col-hours"),
+        t!("s21-col-remarks"),
+    ] {
+        html.push_str(&format!("<th>{}</th>", escape_html(&head)));
+    }
+    html.push_str("</tr></thead><tbody>");
+
+    for row in &card.rows {
+        let (shared, studies, aux, hours, remarks) = match &row.report {
+            Some(r) => {
+                let mut remarks: Vec<String> = vec![];
+                if let Some(c) = r.credits.filter(|c| *c > 0) {
+                    remarks.push(format!("{}: {c}", t!("report-form-credits")));
+                }
+                if let Some(n) = r.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+                    remarks.push(n.trim().to_string());
+                }
+                (
+                    r.preached,
+                    r.bible_studies.map(|v| v.to_string()).unwrap_or_default(),
+                    r.preached && (r.auxiliary_pioneer || is_cont_aux),
+                    r.hours.map(|v| v.to_string()).unwrap_or_default(),
+                    remarks.join(" · "),
+                )
+            }
+            None => (false, String::new(), false, String::new(), String::new()),
+        };
+        html.push_str(&format!(
+            "<tr><td class=\"month\">{}</td><td class=\"mark\">{}</td><td class=\"num\">{}</td>\
+             <td class=\"mark\">{}</td><td class=\"num\">{}</td><td>{}</td></tr>",
+            escape_html(&month_label(row.month)),
+            if shared { "☑" } else { "☐" },
+            studies,
+            if aux { "☑" } else { "☐" },
+            hours,
+            escape_html(&remarks),
+        ));
+    }
+
+    html.push_str(&format!(
+        "<tr class=\"total\"><td>{}</td><td></td><td></td><td></td><td class=\"num\">{}</td><td></td></tr>",
+        escape_html(&t!("s21-total")),
+        card.total_hours()
+    ));
+    html.push_str("</tbody></table></div>");
+    html
+}
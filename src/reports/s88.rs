@@ -0,0 +1,230 @@
+//! S-88 Record of Meeting Attendance.
+//!
+//! For each meeting the form lists, month by month (September → August), how
+//! many meetings were held, the total attendance and the average per meeting,
+//! two service years side by side. Figures come from [`MeetingAttendance`]
+//! entries: in-person and online counts are added up, and meetings without an
+//! entry are not counted.
+
+use dioxus_i18n::t;
+
+use crate::models::duty_rota::MeetingKind;
+use crate::models::meeting_attendance::MeetingAttendance;
+
+use super::duty_chart::meeting_label;
+use super::service_year::service_year_months;
+use super::{escape_html, html_document, month_label};
+
+/// One month of one meeting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonthAttendance {
+    pub year: i32,
+    pub month: u8,
+    pub meetings: usize,
+    pub in_person: u32,
+    pub online: u32,
+}
+
+impl MonthAttendance {
+    pub fn total(&self) -> u32 {
+        self.in_person + self.online
+    }
+
+    /// Attendance per meeting held, `0.0` for a month without meetings.
+    pub fn average(&self) -> f64 {
+        if self.meetings == 0 {
+            0.0
+        } else {
+            self.total() as f64 / self.meetings as f64
+        }
+    }
+}
+
+/// `(year, month)` of an ISO date.
+fn year_month(date: &str) -> Option<(i32, u8)> {
+    Some((date.get(0..4)?.parse().ok()?, date.get(5..7)?.parse().ok()?))
+}
+
+/// Sum the entries of `kind` held in the given calendar month.
+pub fn month_attendance(
+    entries: &[MeetingAttendance],
+    kind: MeetingKind,
+    year: i32,
+    month: u8,
+) -> MonthAttendance {
+    let mut m = MonthAttendance {
+        year,
+        month,
+        meetings: 0,
+        in_person: 0,
+        online: 0,
+    };
+    for e in entries {
+        if e.meeting != kind || e.total() == 0 || year_month(&e.date) != Some((year, month)) {
+            continue;
+        }
+        m.meetings += 1;
+        m.in_person += e.in_person;
+        m.online += e.online;
+    }
+    m
+}
+
+/// One meeting over one service year: a column group of the form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct S88Year {
+    pub service_year: i32,
+    pub meeting: MeetingKind,
+    /// September first.
+    pub months: Vec<MonthAttendance>,
+}
+
+impl S88Year {
+    /// Build the year from any set of entries; only those of `meeting` inside
+    /// the service year are picked up.
+    pub fn compute(service_year: i32, meeting: MeetingKind, entries: &[MeetingAttendance]) -> Self {
+        let months = service_year_months(service_year)
+            .into_iter()
+            .map(|(year, month)| month_attendance(entries, meeting, year, month))
+            .collect();
+        Self {
+            service_year,
+            meeting,
+            months,
+        }
+    }
+
+    /// "Average attendance each month": the mean of the monthly averages,
+    /// over the months that had meetings.
+    pub fn average(&self) -> f64 {
+        let held: Vec<f64> = self
+            .months
+            .iter()
+            .filter(|m| m.meetings > 0)
+            .map(|m| m.average())
+            .collect();
+        if held.is_empty() {
+            0.0
+        } else {
+            held.iter().sum::<f64>() / held.len() as f64
+        }
+    }
+}
+
+const STYLE: &str = "\
+h1 { font-size: 13pt; text-align: center; margin: 0 0 4mm; text-transform: uppercase; }\
+h2 { font-size: 11pt; margin: 6mm 0 2mm; }\
+th { font-size: 8pt; font-weight: bold; background: #f2f2f2; }\
+td.num { text-align: center; width: 24mm; }\
+tr.total td { font-weight: bold; }\
+";
+
+/// Render the form for `service_year` next to the year before it. `entries`
+/// should cover both service years.
+pub fn render(service_year: i32, entries: &[MeetingAttendance]) -> String {
+    let mut html = format!("<h1>{}</h1>", escape_html(&t!("s88-title")));
+    for meeting in [MeetingKind::Midweek, MeetingKind::Weekend] {
+        let years = [
+            S88Year::compute(service_year - 1, meeting, entries),
+            S88Year::compute(service_year, meeting, entries),
+        ];
+        html.push_str(&format!(
+            "<h2>{}</h2><table><thead><tr>",
+            escape_html(&meeting_label(meeting))
+        ));
+        html.push_str("<th></th>");
+        for y in &years {
+            html.push_str(&format!(
+                "<th colspan=\"3\">{} {}</th>",
+                escape_html(&t!("s88-service-year")),
+                y.service_year
+            ));
+        }
+        html.push_str(&format!(
+            "</tr><tr><th>{}</th>",
+            escape_html(&t!("s88-month"))
+        ));
+        for _ in &years {
+            for label in [t!("s88-meetings"), t!("s88-total"), t!("s88-average")] {
+                html.push_str(&format!("<th>{}</th>", escape_html(&label)));
+            }
+        }
+        html.push_str("</tr></thead><tbody>");
+        for i in 0..12 {
+            html.push_str(&format!(
+                "<tr><td>{}</td>",
+                escape_html(&month_label(years[1].months[i].month))
+            ));
+            for y in &years {
+                let m = &y.months[i];
+                if m.meetings == 0 {
+                    html.push_str(
+                        "<td class=\"num\"></td><td class=\"num\"></td><td class=\"num\"></td>",
+                    );
+                } else {
+                    html.push_str(&format!(
+                        "<td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{:.0}</td>",
+                        m.meetings,
+                        m.total(),
+                        m.average()
+                    ));
+                }
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str(&format!(
+            "<tr class=\"total\"><td colspan=\"3\">{}</td>",
+            escape_html(&t!("s88-year-average"))
+        ));
+        html.push_str(&format!("<td class=\"num\">{:.0}</td>", years[0].average()));
+        html.push_str(&format!(
+            "<td colspan=\"2\"></td><td class=\"num\">{:.0}</td></tr></tbody></table>",
+            years[1].average()
+        ));
+    }
+    html_document(&t!("s88-title"), STYLE, &html)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(date: &str, meeting: MeetingKind, in_person: u32, online: u32) -> MeetingAttendance {
+        MeetingAttendance {
+            id: None,
+            date: date.into(),
+            meeting,
+            in_person,
+            online,
+        }
+    }
+
+    #[test]
+    fn months_add_in_person_and_online() {
+        let entries = vec![
+            entry("2025-09-03", MeetingKind::Midweek, 60, 10),
+            entry("2025-09-10", MeetingKind::Midweek, 50, 20),
+            // Cleared entry: no meeting held.
+            entry("2025-09-17", MeetingKind::Midweek, 0, 0),
+            entry("2025-09-07", MeetingKind::Weekend, 90, 5),
+            entry("2025-11-05", MeetingKind::Midweek, 80, 0),
+            // Previous service year.
+            entry("2025-08-27", MeetingKind::Midweek, 40, 0),
+        ];
+        let year = S88Year::compute(2026, MeetingKind::Midweek, &entries);
+        let sep = year.months[0];
+        assert_eq!((sep.meetings, sep.in_person, sep.online), (2, 110, 30));
+        assert_eq!(sep.average(), 70.0);
+        assert_eq!(year.months[1].meetings, 0);
+        // October had no meetings and is left out of the yearly average.
+        assert_eq!(year.average(), 75.0);
+        assert_eq!(
+            S88Year::compute(2025, MeetingKind::Midweek, &entries).average(),
+            40.0
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! S-89 Our Christian Life and Ministry meeting assignment slips.
+//!
+//! Every assigned student part of a [`MidweekMeeting`] gets one slip naming
+//! the student, the householder, the part and the study point to work on.
+//! Slips are printed four to a page, ready to be cut apart.
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::format::{format_date, format_name};
+use crate::models::congregation::{DateFormat, NameFormat};
+use crate::models::midweek_meeting::{Classroom, MidweekMeeting};
+use crate::models::user::User;
+
+use super::s140::{part_numbers, part_title};
+use super::{escape_html, html_document};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct S89Slip {
+    pub student: String,
+    pub assistant: String,
+    pub date: String,
+    /// Part number as printed on the S-140, followed by its theme.
+    pub part: String,
+    pub lesson: Option<u16>,
+    pub classroom: Classroom,
+}
+
+/// Slips for the assigned student parts of `meetings`, in program order.
+pub fn slips(
+    meetings: &[MidweekMeeting],
+    users: &[User],
+    name_fmt: &NameFormat,
+    date_fmt: &DateFormat,
+) -> Vec<S89Slip> {
+    let name = |id: &Option<RecordId>| {
+        id.as_ref()
+            .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+            .map(|u| format_name(&u.first_name, &u.last_name, name_fmt))
+            .unwrap_or_default()
+    };
+    let mut out = vec![];
+    for meeting in meetings {
+        let numbers = part_numbers(meeting);
+        for (part, number) in meeting.parts.iter().zip(numbers) {
+            if !part.kind.is_student_part() || part.assignee.is_none() {
+                continue;
+            }
+            out.push(S89Slip {
+                student: name(&part.assignee),
+                assistant: if part.kind.has_assistant() {
+                    name(&part.assistant)
+                } else {
+                    String::new()
+                },
+                date: format_date(&meeting.date, date_fmt),
+                part: format!("{number}. {}", part_title(part)),
+                lesson: part.lesson,
+                classroom: part.classroom,
+            });
+        }
+    }
+    out
+}
+
+const STYLE: &str = "\
+.sheet { display: grid; grid-template-columns: 1fr 1fr; gap: 0; }\
+.slip { border: 1px dashed #999; padding: 6mm; min-height: 132mm; break-inside: avoid; }\
+.slip h1 { font-size: 10pt; text-align: center; text-transform: uppercase; margin: 0 0 5mm; }\
+.field { margin: 0 0 3mm; }\
+.field b { display: inline-block; min-width: 28mm; }\
+.field span { display: inline-block; min-width: 45mm; border-bottom: 1px solid #000; }\
+.room { margin: 4mm 0; }\
+.room div { margin: 1mm 0 1mm 4mm; }\
+.note { font-size: 8pt; color: #333; margin-top: 5mm; }\
+";
+
+fn field(label: &str, value: &str) -> String {
+    format!(
+        "<div class=\"field\"><b>{}:</b> <span>{}</span></div>",
+        escape_html(label),
+        escape_html(value)
+    )
+}
+
+fn checkbox(checked: bool, label: &str) -> String {
+    format!(
+        "<div>{} {}</div>",
+        if checked { "☑" } else { "☐" },
+        escape_html(label)
+    )
+}
+
+fn render_slip(slip: &S89Slip) -> String {
+    let mut html = String::from("<div class=\"slip\">");
+    html.push_str(&format!("<h1>{}</h1>", escape_html(&t!("s89-title"))));
+    html.push_str(&field(&t!("s89-name"), &slip.student));
+    html.push_str(&field(&t!("s89-assistant"), &slip.assistant));
+    html.push_str(&field(&t!("s89-date"), &slip.date));
+    html.push_str(&field(&t!("s89-part"), &slip.part));
+    html.push_str(&field(
+        &t!("midweek-lesson"),
+        &slip.lesson.map(|l| l.to_string()).unwrap_or_default(),
+    ));
+    html.push_str(&format!(
+        "<div class=\"room\"><b>{}</b>",
+        escape_html(&t!("s89-given-in"))
+    ));
+    html.push_str(&checkbox(
+        slip.classroom == Classroom::Main,
+        &t!("midweek-classroom-main"),
+    ));
+    html.push_str(&checkbox(
+        slip.classroom == Classroom::Auxiliary,
+        &t!("midweek-classroom-aux"),
+    ));
+    html.push_str("</div>");
+    html.push_str(&format!(
+        "<p class=\"note\">{}</p>",
+        escape_html(&t!("s89-note"))
+    ));
+    html.push_str("</div>");
+    html
+}
+
+/// Slips four to a page.
+pub fn render(slips: &[S89Slip]) -> String {
+    let body: String = slips
+        .chunks(4)
+        .map(|page| {
+            let cards: String = page.iter().map(render_slip).collect();
+            format!("<div class=\"sheet page-break\">{cards}</div>")
+        })
+        .collect();
+    html_document(&t!("s89-title"), STYLE, &body)
+}
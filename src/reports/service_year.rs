@@ -0,0 +1,72 @@
+//! Service-year calendar helpers.
+//!
+//! The service year runs from September to August and is named after the
+//! calendar year in which it ends: September 2025 – August 2026 is the
+//! 2026 service year.
+
+use crate::dates::add_months;
+
+/// First calendar month of a service year.
+pub const START_MONTH: u8 = 9;
+
+/// Service year that contains the given calendar month.
+pub fn service_year_of(year: i32, month: u8) -> i32 {
+    if month >= START_MONTH { year + 1 } else { year }
+}
+
+/// The twelve `(year, month)` pairs of a service year, September first.
+pub fn service_year_months(service_year: i32) -> Vec<(i32, u8)> {
+    (0..12u8)
+        .map(|i| {
+            let month = (START_MONTH - 1 + i) % 12 + 1;
+            let year = if month >= START_MONTH { service_year - 1 } else { service_year };
+            (year, month)
+        })
+        .collect()
+}
+
+/// First and last `(year, month)` of a service year (inclusive).
+pub fn service_year_bounds(service_year: i32) -> ((i32, u8), (i32, u8)) {
+    ((service_year - 1, START_MONTH), (service_year, START_MONTH - 1))
+}
+
+/// The `count` months ending with `(year, month)`, newest first.
+pub fn recent_months(year: i32, month: u8, count: u32) -> Vec<(i32, u8)> {
+    (0..count as i32).map(|i| add_months(year, month, -i)).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn september_starts_next_service_year() {
+        assert_eq!(service_year_of(2025, 8), 2025);
+        assert_eq!(service_year_of(2025, 9), 2026);
+        assert_eq!(service_year_of(2026, 1), 2026);
+    }
+
+    #[test]
+    fn months_run_september_to_august() {
+        let months = service_year_months(2026);
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], (2025, 9));
+        assert_eq!(months[3], (2025, 12));
+        assert_eq!(months[4], (2026, 1));
+        assert_eq!(months[11], (2026, 8));
+        assert_eq!(service_year_bounds(2026), ((2025, 9), (2026, 8)));
+    }
+
+    #[test]
+    fn recent_months_are_newest_first() {
+        let months = recent_months(2026, 2, 12);
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], (2026, 2));
+        assert_eq!(months[2], (2025, 12));
+        assert_eq!(months[11], (2025, 3));
+    }
+}
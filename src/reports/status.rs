@@ -0,0 +1,178 @@
+//! Publisher activity status, derived month by month from reports.
+//!
+//! Statuses are never stored: they follow from the [`FieldServiceReport`]s in
+//! the [`ACTIVE_WINDOW_MONTHS`] months ending with the month in question, so
+//! editing an old report automatically corrects the history. A report marked
+//! "did not preach" counts as a missed month.
+
+use std::collections::HashSet;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::dates::add_months;
+use crate::models::field_service_report::FieldServiceReport;
+use crate::models::user::{User, UserType};
+
+use super::analytics::ACTIVE_WINDOW_MONTHS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublisherStatus {
+    /// Shared in the ministry every month of the window.
+    Active,
+    /// Shared at least once in the window but missed one or more months.
+    Irregular,
+    /// Nothing reported in the whole window.
+    Inactive,
+    /// Shared this month after a full inactive window, having reported at
+    /// some point before it.
+    Reactivated,
+}
+
+impl PublisherStatus {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Active => t!("status-active"),
+            Self::Irregular => t!("status-irregular"),
+            Self::Inactive => t!("status-inactive"),
+            Self::Reactivated => t!("status-reactivated"),
+        }
+    }
+
+    /// Tailwind classes for a solid badge.
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            Self::Active => "bg-emerald-600 text-white",
+            Self::Irregular => "bg-amber-500 text-white",
+            Self::Inactive => "bg-gray-400 text-white",
+            Self::Reactivated => "bg-sky-600 text-white",
+        }
+    }
+}
+
+/// Months (as `year * 12 + month`) in which the publisher shared in the ministry.
+fn preached_months(publisher: &RecordId, reports: &[FieldServiceReport]) -> HashSet<i32> {
+    reports
+        .iter()
+        .filter(|r| r.preached && &r.publisher == publisher)
+        .map(|r| r.year * 12 + r.month as i32)
+        .collect()
+}
+
+fn status_from(preached: &HashSet<i32>, year: i32, month: u8) -> PublisherStatus {
+    let idx = |(y, m): (i32, u8)| y * 12 + m as i32;
+    let now = idx((year, month));
+    let window_start = idx(add_months(year, month, 1 - ACTIVE_WINDOW_MONTHS));
+    let in_window = (window_start..=now).filter(|i| preached.contains(i)).count();
+    if in_window == 0 {
+        return PublisherStatus::Inactive;
+    }
+    let prev_window = (window_start - 1)..now;
+    let returned = preached.contains(&now)
+        && !prev_window.clone().any(|i| preached.contains(&i))
+        && preached.iter().any(|&i| i < prev_window.start);
+    if returned {
+        PublisherStatus::Reactivated
+    } else if in_window == ACTIVE_WINDOW_MONTHS as usize {
+        PublisherStatus::Active
+    } else {
+        PublisherStatus::Irregular
+    }
+}
+
+/// Status of one publisher at `(year, month)`.
+///
+/// `reports` may contain anyone's reports; for [`PublisherStatus::Reactivated`]
+/// to be detected they must reach further back than twice the window.
+pub fn status_at(
+    publisher: &RecordId,
+    reports: &[FieldServiceReport],
+    year: i32,
+    month: u8,
+) -> PublisherStatus {
+    status_from(&preached_months(publisher, reports), year, month)
+}
+
+/// Status for each of `months`, in the order given.
+pub fn status_history(
+    publisher: &RecordId,
+    reports: &[FieldServiceReport],
+    months: &[(i32, u8)],
+) -> Vec<(i32, u8, PublisherStatus)> {
+    let preached = preached_months(publisher, reports);
+    months
+        .iter()
+        .map(|&(y, m)| (y, m, status_from(&preached, y, m)))
+        .collect()
+}
+
+/// How many publishers fall into each status in one month.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StatusCounts {
+    pub active: usize,
+    pub irregular: usize,
+    pub inactive: usize,
+    pub reactivated: usize,
+}
+
+impl StatusCounts {
+    /// Count enrolled (not soft-deleted), non-student publishers.
+    pub fn compute(users: &[User], reports: &[FieldServiceReport], year: i32, month: u8) -> Self {
+        let mut counts = Self::default();
+        for id in users
+            .iter()
+            .filter(|u| u.active && !matches!(u.user_type, UserType::Student))
+            .filter_map(|u| u.id.as_ref())
+        {
+            match status_at(id, reports, year, month) {
+                PublisherStatus::Active => counts.active += 1,
+                PublisherStatus::Irregular => counts.irregular += 1,
+                PublisherStatus::Inactive => counts.inactive += 1,
+                PublisherStatus::Reactivated => counts.reactivated += 1,
+            }
+        }
+        counts
+    }
+
+    pub fn get(&self, status: PublisherStatus) -> usize {
+        match status {
+            PublisherStatus::Active => self.active,
+            PublisherStatus::Irregular => self.irregular,
+            PublisherStatus::Inactive => self.inactive,
+            PublisherStatus::Reactivated => self.reactivated,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{report, user_id};
+
+    fn reports(months: &[(i32, u8)]) -> Vec<FieldServiceReport> {
+        months.iter().map(|&(year, month)| report("p", year, month)).collect()
+    }
+
+    #[test]
+    fn classifies_by_six_month_window() {
+        let every_month: Vec<(i32, u8)> = (1..=6).map(|m| (2026, m)).collect();
+        assert_eq!(status_at(&user_id("p"), &reports(&every_month), 2026, 6), PublisherStatus::Active);
+        let gap = reports(&[(2026, 1), (2026, 3)]);
+        assert_eq!(status_at(&user_id("p"), &gap, 2026, 6), PublisherStatus::Irregular);
+        assert_eq!(status_at(&user_id("p"), &gap, 2026, 9), PublisherStatus::Inactive);
+        assert_eq!(status_at(&user_id("p"), &[], 2026, 6), PublisherStatus::Inactive);
+    }
+
+    #[test]
+    fn returning_after_inactive_window_is_reactivated() {
+        let r = reports(&[(2025, 1), (2025, 12)]);
+        assert_eq!(status_at(&user_id("p"), &r, 2025, 12), PublisherStatus::Reactivated);
+        // A first-ever report is not a reactivation.
+        let first = reports(&[(2025, 12)]);
+        assert_eq!(status_at(&user_id("p"), &first, 2025, 12), PublisherStatus::Irregular);
+    }
+}
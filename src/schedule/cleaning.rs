@@ -0,0 +1,141 @@
+//! Kingdom Hall cleaning, rotated among the field service groups.
+//!
+//! Each week one group cleans every [`CleaningArea`]; a deep clean adds the
+//! areas' deep tasks to the weekly ones. [`rotation`] hands out the weeks of
+//! a range in group order, carrying on from whichever group cleaned last.
+
+use surrealdb::types::RecordId;
+
+use crate::models::cleaning::{CleaningArea, CleaningKind, CleaningWeek};
+
+/// `(week_start, group, kind)` for every week of `weeks`, cycling through
+/// `groups` starting with the one after `after` (or the first group when
+/// `after` is not among them). Every `deep_every`-th week of the range is a
+/// deep clean; `0` means never.
+pub fn rotation(
+    weeks: &[String],
+    groups: &[RecordId],
+    after: Option<&RecordId>,
+    deep_every: u8,
+) -> Vec<(String, RecordId, CleaningKind)> {
+    if groups.is_empty() {
+        return vec![];
+    }
+    let start = after
+        .and_then(|a| groups.iter().position(|g| g == a))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    weeks
+        .iter()
+        .enumerate()
+        .map(|(i, week)| {
+            let group = groups[(start + i) % groups.len()].clone();
+            let kind = if deep_every > 0 && (i + 1) % deep_every as usize == 0 {
+                CleaningKind::Deep
+            } else {
+                CleaningKind::Weekly
+            };
+            (week.clone(), group, kind)
+        })
+        .collect()
+}
+
+/// The checklist of `area` for a cleaning of `kind`.
+pub fn tasks(area: &CleaningArea, kind: CleaningKind) -> Vec<String> {
+    let mut tasks = area.weekly.clone();
+    if kind == CleaningKind::Deep {
+        tasks.extend(area.deep.iter().cloned());
+    }
+    tasks
+}
+
+pub fn is_checked(week: &CleaningWeek, area: &RecordId, task: &str) -> bool {
+    week.done.iter().any(|d| &d.area == area && d.task == task)
+}
+
+/// `(done, total)` tasks of the week over every area. Ticks of tasks since
+/// removed from a checklist are not counted.
+pub fn progress(week: &CleaningWeek, areas: &[CleaningArea]) -> (usize, usize) {
+    let mut done = 0;
+    let mut total = 0;
+    for area in areas {
+        let Some(id) = area.id.as_ref() else { continue };
+        for task in tasks(area, week.kind) {
+            total += 1;
+            if is_checked(week, id, &task) {
+                done += 1;
+            }
+        }
+    }
+    (done, total)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::rid;
+    use crate::models::cleaning::CheckedTask;
+
+    #[test]
+    fn rotation_continues_after_last_group() {
+        let weeks: Vec<String> = ["2026-10-05", "2026-10-12", "2026-10-19", "2026-10-26"]
+            .map(String::from)
+            .to_vec();
+        let groups = vec![rid("g", "a"), rid("g", "b"), rid("g", "c")];
+        let plan = rotation(&weeks, &groups, Some(&rid("g", "b")), 2);
+        let order: Vec<RecordId> = plan.iter().map(|(_, g, _)| g.clone()).collect();
+        assert_eq!(
+            order,
+            vec![rid("g", "c"), rid("g", "a"), rid("g", "b"), rid("g", "c")]
+        );
+        let kinds: Vec<CleaningKind> = plan.iter().map(|(_, _, k)| *k).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                CleaningKind::Weekly,
+                CleaningKind::Deep,
+                CleaningKind::Weekly,
+                CleaningKind::Deep
+            ]
+        );
+        assert_eq!(
+            rotation(&weeks, &groups, Some(&rid("g", "x")), 0)[0].1,
+            rid("g", "a")
+        );
+        assert!(rotation(&weeks, &[], None, 0).is_empty());
+    }
+
+    #[test]
+    fn progress_counts_current_tasks() {
+        let area = CleaningArea {
+            id: Some(rid("cleaning_area", "hall")),
+            name: "Hall".into(),
+            weekly: vec!["Vacuum".into(), "Dust".into()],
+            deep: vec!["Windows".into()],
+        };
+        let mut week = CleaningWeek {
+            id: None,
+            week_start: "2026-10-12".into(),
+            kind: CleaningKind::Weekly,
+            group: None,
+            done: vec![
+                CheckedTask {
+                    area: rid("cleaning_area", "hall"),
+                    task: "Dust".into(),
+                },
+                CheckedTask {
+                    area: rid("cleaning_area", "hall"),
+                    task: "Windows".into(),
+                },
+            ],
+        };
+        let areas = [area];
+        assert_eq!(progress(&week, &areas), (1, 2));
+        week.kind = CleaningKind::Deep;
+        assert_eq!(progress(&week, &areas), (2, 3));
+    }
+}
@@ -0,0 +1,211 @@
+//! Circuit overseer visit week.
+//!
+//! A visit runs from Tuesday to Sunday: the midweek meeting moves to
+//! Tuesday, the overseer joins field service from Wednesday and meets the
+//! pioneers and the elders on Saturday. [`template`] lays that week out for
+//! the congregation to adjust; meal hosts come from the publishers with the
+//! hospitality privilege, spread out with the fair rotation.
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Pick, Requirement, Slot};
+use crate::dates::{add_days, week_start};
+use crate::models::co_visit::{
+    Arrangement, ArrangementKind, ChecklistItem, CoVisit, Meal, MealHost, Preparation,
+};
+
+/// Tuesday to Sunday of the week `start` falls in.
+pub fn visit_days(start: &str) -> Vec<String> {
+    let monday = week_start(start);
+    (1..=6).map(|d| add_days(&monday, d)).collect()
+}
+
+/// The usual arrangements, meals and checklist of a visit starting on `start`.
+pub fn template(event: RecordId, start: &str) -> CoVisit {
+    let days = visit_days(start);
+    let arrangement = |day: usize, kind: ArrangementKind| Arrangement {
+        kind,
+        date: days[day].clone(),
+        time: String::new(),
+        place: String::new(),
+        person: None,
+        notes: String::new(),
+    };
+    let mut arrangements = vec![arrangement(0, ArrangementKind::MidweekMeeting)];
+    for day in 1..=3 {
+        arrangements.push(arrangement(day, ArrangementKind::FieldService));
+        arrangements.push(arrangement(day, ArrangementKind::ShepherdingCall));
+    }
+    arrangements.push(arrangement(4, ArrangementKind::FieldService));
+    arrangements.push(arrangement(4, ArrangementKind::PioneerMeeting));
+    arrangements.push(arrangement(4, ArrangementKind::EldersMeeting));
+    arrangements.push(arrangement(5, ArrangementKind::WeekendMeeting));
+
+    // Dinner after the Tuesday meeting, lunch after the Sunday one.
+    let meals = days
+        .iter()
+        .enumerate()
+        .flat_map(|(i, date)| {
+            let meals: &[Meal] = match i {
+                0 => &[Meal::Dinner],
+                5 => &[Meal::Lunch],
+                _ => &[Meal::Lunch, Meal::Dinner],
+            };
+            meals.iter().map(move |&meal| MealHost {
+                date: date.clone(),
+                meal,
+                host: None,
+            })
+        })
+        .collect();
+
+    CoVisit {
+        id: None,
+        event,
+        arrangements,
+        meals,
+        checklist: Preparation::ALL
+            .into_iter()
+            .map(|task| ChecklistItem { task, done: false })
+            .collect(),
+        notes: String::new(),
+    }
+}
+
+/// Rotation slots for the meals, keyed by meal index. A household hosts
+/// at most one meal a day.
+pub fn host_slots(visit: &CoVisit) -> Vec<Slot<'static>> {
+    visit
+        .meals
+        .iter()
+        .enumerate()
+        .map(|(i, meal)| Slot {
+            key: i.to_string(),
+            meeting: meal.date.clone(),
+            date: meal.date.clone(),
+            qualifies: Box::new(|p| p.hospitality),
+            requirement: Requirement::Anyone,
+            same_gender_as: None,
+            fixed: meal.host.clone(),
+        })
+        .collect()
+}
+
+/// Write accepted picks back into the meals. Empty picks leave the meal as is.
+pub fn apply_picks(visit: &mut CoVisit, picks: &[Pick]) {
+    for pick in picks {
+        let Some(host) = pick.person.clone() else {
+            continue;
+        };
+        let Ok(i) = pick.key.parse::<usize>() else {
+            continue;
+        };
+        if let Some(meal) = visit.meals.get_mut(i) {
+            meal.host = Some(host);
+        }
+    }
+}
+
+/// `(host, date)` of every meal of earlier visits, so the same households
+/// are not asked every time.
+pub fn hosts(visits: &[CoVisit]) -> Vec<(RecordId, String)> {
+    visits
+        .iter()
+        .flat_map(|v| {
+            v.meals
+                .iter()
+                .filter_map(|m| Some((m.host.clone()?, m.date.clone())))
+        })
+        .collect()
+}
+
+pub fn kind_label(kind: ArrangementKind) -> String {
+    match kind {
+        ArrangementKind::MidweekMeeting => t!("co-midweek-meeting"),
+        ArrangementKind::WeekendMeeting => t!("co-weekend-meeting"),
+        ArrangementKind::FieldService => t!("co-field-service"),
+        ArrangementKind::PioneerMeeting => t!("co-pioneer-meeting"),
+        ArrangementKind::EldersMeeting => t!("co-elders-meeting"),
+        ArrangementKind::ShepherdingCall => t!("co-shepherding-call"),
+        ArrangementKind::Other => t!("co-other"),
+    }
+}
+
+pub fn meal_label(meal: Meal) -> String {
+    match meal {
+        Meal::Lunch => t!("co-lunch"),
+        Meal::Dinner => t!("co-dinner"),
+    }
+}
+
+pub fn task_label(task: Preparation) -> String {
+    match task {
+        Preparation::PublisherRecords => t!("co-task-publisher-records"),
+        Preparation::FieldServiceReports => t!("co-task-reports"),
+        Preparation::MissingReports => t!("co-task-missing-reports"),
+        Preparation::PioneerList => t!("co-task-pioneers"),
+        Preparation::TerritoryCoverage => t!("co-task-territory"),
+        Preparation::MeetingSchedules => t!("co-task-schedules"),
+        Preparation::Accounts => t!("co-task-accounts"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::rid;
+    use crate::schedule::rotation::Reason;
+
+    #[test]
+    fn template_covers_tuesday_to_sunday() {
+        // Entered on the Monday, Tuesday or Sunday: same week.
+        let visit = template(rid("congregation_event", "v"), "2026-10-20");
+        assert_eq!(visit_days("2026-10-19"), visit_days("2026-10-25"));
+        assert_eq!(visit.arrangements[0].date, "2026-10-20");
+        assert_eq!(visit.arrangements[0].kind, ArrangementKind::MidweekMeeting);
+        let last = visit.arrangements.last().unwrap();
+        assert_eq!(
+            (last.kind, last.date.as_str()),
+            (ArrangementKind::WeekendMeeting, "2026-10-25")
+        );
+        assert_eq!(visit.meals.len(), 10);
+        assert_eq!(visit.checklist.len(), Preparation::ALL.len());
+    }
+
+    #[test]
+    fn picks_fill_open_meals_only() {
+        let mut visit = template(rid("congregation_event", "v"), "2026-10-20");
+        visit.meals[0].host = Some(rid("user", "a"));
+        let slots = host_slots(&visit);
+        assert_eq!(slots[0].fixed, Some(rid("user", "a")));
+        apply_picks(
+            &mut visit,
+            &[
+                Pick {
+                    key: "1".into(),
+                    person: Some(rid("user", "b")),
+                    reason: Reason::Kept,
+                },
+                Pick {
+                    key: "2".into(),
+                    person: None,
+                    reason: Reason::Kept,
+                },
+            ],
+        );
+        assert_eq!(visit.meals[1].host, Some(rid("user", "b")));
+        assert_eq!(visit.meals[2].host, None);
+        assert_eq!(
+            hosts(&[visit]),
+            vec![
+                (rid("user", "a"), "2026-10-20".to_string()),
+                (rid("user", "b"), "2026-10-21".to_string())
+            ]
+        );
+    }
+}
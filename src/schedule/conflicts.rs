@@ -0,0 +1,447 @@
+//! Conflicts between date-bound assignments across the whole app.
+//!
+//! Every module that puts a person on a date — meeting programs, duty
+//! rotas, field service meetings, public witnessing shifts and the circuit
+//! overseer's week — is turned into [`Assignment`]s, mostly through the
+//! same rotation slots the proposals use. [`check`] then flags anyone who
+//! is away, booked during an assembly or convention, holding too many
+//! places on one day, or not allowed to take the place at all. Issues are
+//! only put into words by [`Issue::label`], so checking needs no UI.
+
+use std::collections::HashMap;
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Requirement, Slot, is_absent};
+use super::{cancels_meetings, duties, midweek, weekend};
+use crate::models::absence::Absence;
+use crate::models::co_visit::{ArrangementKind, CoVisit};
+use crate::models::duty_rota::{DutyRole, DutyRota};
+use crate::models::event::CongregationEvent;
+use crate::models::field_service_meeting::FieldServiceMeeting;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_witnessing::ShiftOccurrence;
+use crate::models::user::User;
+use crate::models::weekend_meeting::WeekendMeeting;
+
+/// Module an assignment comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Source {
+    Midweek,
+    Weekend,
+    Duty,
+    FieldService,
+    Witnessing,
+    CoVisit,
+}
+
+impl Source {
+    /// Held at the Kingdom Hall around meeting time, where one person
+    /// cannot be in two places.
+    pub fn at_meeting_time(&self) -> bool {
+        matches!(
+            self,
+            Self::Midweek | Self::Weekend | Self::Duty | Self::FieldService
+        )
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Midweek => t!("nav-weekday-meeting"),
+            Self::Weekend => t!("nav-weekend-meeting"),
+            Self::Duty => t!("conflict-source-duty"),
+            Self::FieldService => t!("nav-field-service-meetings"),
+            Self::Witnessing => t!("nav-public-preaching"),
+            Self::CoVisit => t!("co-title"),
+        }
+    }
+}
+
+/// One person on one date.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    pub person: RecordId,
+    pub date: String,
+    pub source: Source,
+    /// Place as shown on its page: "Chairman", "Attendant", ….
+    pub label: String,
+    pub requirement: Requirement,
+    /// Whether the person's privileges cover the place.
+    pub qualified: bool,
+    /// Who the person must share a gender with (the student of a
+    /// householder).
+    pub partner: Option<RecordId>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Issue {
+    /// An absence covers the date.
+    Absent,
+    /// An assembly or convention replaces the meetings that day.
+    DuringEvent(CongregationEvent),
+    /// Clashing places from other modules, or more than
+    /// [`MAX_PLACES_PER_DAY`] in one, on the same date: `(module, place)`.
+    Overbooked(Vec<(Source, String)>),
+    NotAllowed(Requirement),
+    NoPrivilege,
+    GenderMismatch,
+    Inactive,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub assignment: Assignment,
+    pub issue: Issue,
+}
+
+/// Places one person may hold in a single module on one day, e.g. a part
+/// and a prayer.
+pub const MAX_PLACES_PER_DAY: usize = 2;
+
+/// Assignments for the filled `slots`, labelled by slot key.
+pub fn from_slots(
+    source: Source,
+    slots: &[Slot],
+    label: impl Fn(&str) -> String,
+    privileges: &[UserPrivileges],
+) -> Vec<Assignment> {
+    slots
+        .iter()
+        .filter_map(|slot| {
+            let person = slot.fixed.clone()?;
+            let qualified = privileges
+                .iter()
+                .any(|p| p.publisher == person && (slot.qualifies)(p));
+            let partner = slot
+                .same_gender_as
+                .as_ref()
+                .and_then(|k| slots.iter().find(|s| &s.key == k))
+                .and_then(|s| s.fixed.clone());
+            Some(Assignment {
+                person,
+                date: slot.date.clone(),
+                source,
+                label: label(&slot.key),
+                requirement: slot.requirement,
+                qualified,
+                partner,
+            })
+        })
+        .collect()
+}
+
+pub fn midweek_assignments(
+    meetings: &[MidweekMeeting],
+    privileges: &[UserPrivileges],
+) -> Vec<Assignment> {
+    meetings
+        .iter()
+        .flat_map(|m| {
+            from_slots(
+                Source::Midweek,
+                &midweek::rotation_slots(m),
+                |key| midweek::slot_label(m, key),
+                privileges,
+            )
+        })
+        .collect()
+}
+
+pub fn weekend_assignments(
+    meetings: &[WeekendMeeting],
+    privileges: &[UserPrivileges],
+) -> Vec<Assignment> {
+    meetings
+        .iter()
+        .flat_map(|m| {
+            from_slots(
+                Source::Weekend,
+                &weekend::rotation_slots(m),
+                weekend::slot_label,
+                privileges,
+            )
+        })
+        .collect()
+}
+
+pub fn duty_assignments(rotas: &[DutyRota], privileges: &[UserPrivileges]) -> Vec<Assignment> {
+    let label = |key: &str| {
+        key.split_once('-')
+            .and_then(|(r, d)| {
+                let rota = rotas.get(r.parse::<usize>().ok()?)?;
+                rota.duties.get(d.parse::<usize>().ok()?)
+            })
+            .map(|duty| duties::label(duty.role))
+            .unwrap_or_default()
+    };
+    from_slots(
+        Source::Duty,
+        &duties::rotation_slots(rotas, &DutyRole::ALL, &[]),
+        label,
+        privileges,
+    )
+}
+
+pub fn field_service_assignments(
+    meetings: &[FieldServiceMeeting],
+    privileges: &[UserPrivileges],
+) -> Vec<Assignment> {
+    let slots: Vec<Slot> = meetings
+        .iter()
+        .enumerate()
+        .map(|(i, m)| Slot {
+            key: i.to_string(),
+            meeting: m.date.clone(),
+            date: m.date.clone(),
+            qualifies: Box::new(|p| p.field_service_meeting),
+            requirement: Requirement::Anyone,
+            same_gender_as: None,
+            fixed: Some(m.assignee.clone()),
+        })
+        .collect();
+    let label = |key: &str| {
+        key.parse::<usize>()
+            .ok()
+            .and_then(|i| meetings.get(i))
+            .map(|m| format!("{} · {}", t!("conflict-conductor"), m.location))
+            .unwrap_or_default()
+    };
+    from_slots(Source::FieldService, &slots, label, privileges)
+}
+
+pub fn witnessing_assignments(
+    occurrences: &[ShiftOccurrence],
+    privileges: &[UserPrivileges],
+) -> Vec<Assignment> {
+    let slots: Vec<Slot> = occurrences
+        .iter()
+        .flat_map(|o| {
+            o.publishers.iter().map(|person| Slot {
+                key: String::new(),
+                meeting: o.date.clone(),
+                date: o.date.clone(),
+                qualifies: Box::new(|p| p.public_witnessing),
+                requirement: Requirement::Anyone,
+                same_gender_as: None,
+                fixed: Some(person.clone()),
+            })
+        })
+        .collect();
+    from_slots(
+        Source::Witnessing,
+        &slots,
+        |_| t!("conflict-shift"),
+        privileges,
+    )
+}
+
+/// Brothers named in the overseer's week. Meeting entries are left out:
+/// the meeting programs already list who takes part.
+pub fn co_visit_assignments(visits: &[CoVisit]) -> Vec<Assignment> {
+    visits
+        .iter()
+        .flat_map(|v| v.arrangements.iter())
+        .filter(|a| {
+            !matches!(
+                a.kind,
+                ArrangementKind::MidweekMeeting | ArrangementKind::WeekendMeeting
+            )
+        })
+        .filter_map(|a| {
+            Some(Assignment {
+                person: a.person.clone()?,
+                date: a.date.clone(),
+                source: Source::CoVisit,
+                label: super::co_visit::kind_label(a.kind),
+                requirement: Requirement::Brother,
+                qualified: true,
+                partner: None,
+            })
+        })
+        .collect()
+}
+
+/// Every problem with `assignments`, by date and module.
+pub fn check(
+    assignments: &[Assignment],
+    users: &[User],
+    absences: &[Absence],
+    events: &[CongregationEvent],
+) -> Vec<Conflict> {
+    let user = |id: &RecordId| users.iter().find(|u| u.id.as_ref() == Some(id));
+    let mut per_day: HashMap<(&RecordId, &str), Vec<&Assignment>> = HashMap::new();
+    for a in assignments {
+        per_day
+            .entry((&a.person, a.date.as_str()))
+            .or_default()
+            .push(a);
+    }
+
+    let mut out = vec![];
+    for a in assignments {
+        let mut flag = |issue: Issue| {
+            out.push(Conflict {
+                assignment: a.clone(),
+                issue,
+            })
+        };
+        if is_absent(absences, &a.person, &a.date) {
+            flag(Issue::Absent);
+        }
+        if a.source.at_meeting_time()
+            && let Some(event) = events.iter().find(|e| {
+                cancels_meetings(&e.event_type)
+                    && e.start_date.as_str() <= a.date.as_str()
+                    && e.end_date.as_str() >= a.date.as_str()
+            })
+        {
+            flag(Issue::DuringEvent(event.clone()));
+        }
+
+        // Meeting places clash with each other; shifts and visit calls
+        // have their own hours and only clash within their module.
+        let clashing: Vec<&Assignment> = per_day[&(&a.person, a.date.as_str())]
+            .iter()
+            .copied()
+            .filter(|o| {
+                o.source == a.source || (o.source.at_meeting_time() && a.source.at_meeting_time())
+            })
+            .collect();
+        let in_source = clashing.iter().filter(|o| o.source == a.source).count();
+        if in_source > MAX_PLACES_PER_DAY || in_source < clashing.len() {
+            let others = clashing
+                .iter()
+                .filter(|o| !std::ptr::eq(**o, a))
+                .map(|o| (o.source, o.label.clone()))
+                .collect();
+            flag(Issue::Overbooked(others));
+        }
+
+        match user(&a.person) {
+            Some(u) if !u.active => flag(Issue::Inactive),
+            Some(u) if !a.requirement.allows(u) => flag(Issue::NotAllowed(a.requirement)),
+            _ => {}
+        }
+        if !a.qualified {
+            flag(Issue::NoPrivilege);
+        }
+        let gender = |id: &RecordId| user(id).map(|u| u.gender.clone());
+        if let Some(partner) = &a.partner
+            && let (Some(g), Some(pg)) = (gender(&a.person), gender(partner))
+            && g != pg
+        {
+            flag(Issue::GenderMismatch);
+        }
+    }
+    out.sort_by(|a, b| {
+        (&a.assignment.date, a.assignment.source).cmp(&(&b.assignment.date, b.assignment.source))
+    });
+    out
+}
+
+impl Issue {
+    /// What is wrong, in the active language. For [`Issue::DuringEvent`] the
+    /// page adds the event's title.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Absent => t!("conflict-absent"),
+            Self::DuringEvent(_) => t!("conflict-during-event"),
+            Self::Overbooked(others) => {
+                let others: Vec<String> = others
+                    .iter()
+                    .map(|(source, place)| format!("{} · {place}", source.label()))
+                    .collect();
+                format!("{} {}", t!("conflict-overbooked"), others.join(", "))
+            }
+            Self::NotAllowed(requirement) => match requirement {
+                Requirement::Anyone => String::new(),
+                Requirement::Brother => t!("conflict-brothers-only"),
+                Requirement::Appointed => t!("conflict-appointed-only"),
+                Requirement::Elder => t!("conflict-elders-only"),
+            },
+            Self::NoPrivilege => t!("conflict-no-privilege"),
+            Self::GenderMismatch => t!("conflict-gender-mismatch"),
+            Self::Inactive => t!("conflict-inactive"),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, user_id};
+    use crate::models::user::Gender;
+
+    fn user(key: &str, gender: Gender) -> User {
+        User { gender, ..fixtures::user(key) }
+    }
+
+    fn assignment(key: &str, date: &str, source: Source, requirement: Requirement) -> Assignment {
+        Assignment {
+            person: user_id(key),
+            date: date.to_string(),
+            source,
+            label: String::new(),
+            requirement,
+            qualified: true,
+            partner: None,
+        }
+    }
+
+    #[test]
+    fn flags_absence_clashes_and_eligibility() {
+        let users = vec![user("a", Gender::Male), user("b", Gender::Female)];
+        let absences = vec![Absence {
+            id: None,
+            user: user_id("a"),
+            start_date: "2026-10-28".into(),
+            end_date: Some("2026-10-30".into()),
+            reason: None,
+        }];
+        let assignments = vec![
+            assignment("a", "2026-10-25", Source::FieldService, Requirement::Anyone),
+            assignment("a", "2026-10-25", Source::Duty, Requirement::Brother),
+            // A shift keeps its own hours.
+            assignment("b", "2026-10-25", Source::Witnessing, Requirement::Anyone),
+            assignment("b", "2026-10-25", Source::Duty, Requirement::Brother),
+            assignment("a", "2026-10-29", Source::Midweek, Requirement::Brother),
+        ];
+        let conflicts = check(&assignments, &users, &absences, &[]);
+        let issues: Vec<(&str, Source, &Issue)> = conflicts
+            .iter()
+            .map(|c| (c.assignment.date.as_str(), c.assignment.source, &c.issue))
+            .collect();
+        assert_eq!(issues.len(), 4);
+        assert_eq!(
+            issues[0],
+            (
+                "2026-10-25",
+                Source::Duty,
+                &Issue::Overbooked(vec![(Source::FieldService, String::new())])
+            )
+        );
+        assert_eq!(
+            issues[1],
+            (
+                "2026-10-25",
+                Source::Duty,
+                &Issue::NotAllowed(Requirement::Brother)
+            )
+        );
+        assert_eq!(
+            issues[2],
+            (
+                "2026-10-25",
+                Source::FieldService,
+                &Issue::Overbooked(vec![(Source::Duty, String::new())])
+            )
+        );
+        assert_eq!(issues[3], ("2026-10-29", Source::Midweek, &Issue::Absent));
+    }
+}
@@ -0,0 +1,213 @@
+//! Support duties at meetings: attendants, microphones and the AV team.
+//!
+//! A [`DutyRota`] holds the places of one meeting. How many places each role
+//! has comes from [`DutySettings`]; [`fit`] brings a rota to those counts
+//! without dropping anyone already assigned. Rotas are filled by the fair
+//! rotation in [`super::rotation`].
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Pick, Requirement, Slot};
+use super::{cancels_meetings, events_in_week};
+use crate::dates::add_days;
+use crate::models::duty_rota::{Duty, DutyRole, DutyRota, DutySettings, MeetingKind};
+use crate::models::event::CongregationEvent;
+use crate::models::midweek_meeting::MidweekMeeting;
+use crate::models::privilege::UserPrivileges;
+use crate::models::weekend_meeting::WeekendMeeting;
+
+pub fn qualifies(role: DutyRole, p: &UserPrivileges) -> bool {
+    match role {
+        DutyRole::Attendant => p.attendant,
+        DutyRole::ZoomAttendant => p.zoom_attendant,
+        DutyRole::Stage => p.stage,
+        DutyRole::Audio => p.audio,
+        DutyRole::Video => p.video,
+        DutyRole::Microphones => p.microphones,
+    }
+}
+
+/// Hall duties go to brothers; the Zoom host can be anyone trained for it.
+pub fn requirement(role: DutyRole) -> Requirement {
+    match role {
+        DutyRole::ZoomAttendant => Requirement::Anyone,
+        _ => Requirement::Brother,
+    }
+}
+
+pub fn label(role: DutyRole) -> String {
+    match role {
+        DutyRole::Attendant => t!("priv-attendant"),
+        DutyRole::ZoomAttendant => t!("priv-zoom-attendant"),
+        DutyRole::Stage => t!("priv-stage"),
+        DutyRole::Audio => t!("priv-audio"),
+        DutyRole::Video => t!("priv-video"),
+        DutyRole::Microphones => t!("priv-microphones"),
+    }
+}
+
+/// Both meetings of every week, on the date of their program when one
+/// exists and on the usual weekday otherwise. Weeks of a circuit assembly
+/// or convention have no meetings.
+pub fn meeting_dates(
+    weeks: &[String],
+    midweek: &[MidweekMeeting],
+    weekend: &[WeekendMeeting],
+    events: &[CongregationEvent],
+    settings: &DutySettings,
+) -> Vec<(String, MeetingKind)> {
+    let mut dates = vec![];
+    for week in weeks {
+        if events_in_week(events, week).iter().any(|e| cancels_meetings(&e.event_type)) {
+            continue;
+        }
+        let midweek_date = midweek
+            .iter()
+            .find(|m| &m.week_start == week)
+            .map(|m| m.date.clone())
+            .unwrap_or_else(|| add_days(week, settings.midweek_offset as i64));
+        let weekend_date = weekend
+            .iter()
+            .find(|m| &m.week_start == week)
+            .map(|m| m.date.clone())
+            .unwrap_or_else(|| add_days(week, settings.weekend_offset as i64));
+        dates.push((midweek_date, MeetingKind::Midweek));
+        dates.push((weekend_date, MeetingKind::Weekend));
+    }
+    dates
+}
+
+/// Give each role of `roles` as many places as the settings ask for. People
+/// already assigned are never dropped, so a role may keep more places than
+/// configured. Duties end up grouped in [`DutyRole::ALL`] order.
+pub fn fit(rota: &mut DutyRota, settings: &DutySettings, roles: &[DutyRole]) {
+    let mut duties = vec![];
+    for role in DutyRole::ALL {
+        let mut places: Vec<Duty> = rota.duties.iter().filter(|d| d.role == role).cloned().collect();
+        if roles.contains(&role) {
+            places.sort_by_key(|d| d.person.is_none());
+            let filled = places.iter().filter(|d| d.person.is_some()).count();
+            let target = (settings.count(role) as usize).max(filled);
+            places.resize(target, Duty { role, person: None });
+        }
+        duties.extend(places);
+    }
+    rota.duties = duties;
+}
+
+/// Rotation slots for the places of `roles` in every rota, keyed
+/// `"<rota>-<duty>"`. Places of other roles, and `also_serving` — people
+/// with a part in the meeting program, as `(date, person)` — count as busy.
+pub fn rotation_slots(
+    rotas: &[DutyRota],
+    roles: &[DutyRole],
+    also_serving: &[(String, RecordId)],
+) -> Vec<Slot<'static>> {
+    let mut slots = vec![];
+    for (r, rota) in rotas.iter().enumerate() {
+        for (d, duty) in rota.duties.iter().enumerate() {
+            if !roles.contains(&duty.role) && duty.person.is_none() {
+                continue;
+            }
+            let role = duty.role;
+            slots.push(Slot {
+                key: format!("{r}-{d}"),
+                meeting: rota.date.clone(),
+                date: rota.date.clone(),
+                qualifies: Box::new(move |p| qualifies(role, p)),
+                requirement: requirement(role),
+                same_gender_as: None,
+                fixed: duty.person.clone(),
+            });
+        }
+    }
+    for (i, (date, person)) in also_serving.iter().enumerate() {
+        slots.push(Slot {
+            key: format!("program-{i}"),
+            meeting: date.clone(),
+            date: date.clone(),
+            qualifies: Box::new(|_| false),
+            requirement: Requirement::Anyone,
+            same_gender_as: None,
+            fixed: Some(person.clone()),
+        });
+    }
+    slots
+}
+
+/// Write accepted picks back into the rotas. Empty picks leave the place as is.
+pub fn apply_picks(rotas: &mut [DutyRota], picks: &[Pick]) {
+    for pick in picks {
+        let Some(person) = pick.person.clone() else { continue };
+        let Some((r, d)) = pick.key.split_once('-') else { continue };
+        let (Ok(r), Ok(d)) = (r.parse::<usize>(), d.parse::<usize>()) else { continue };
+        if let Some(duty) = rotas.get_mut(r).and_then(|rota| rota.duties.get_mut(d)) {
+            duty.person = Some(person);
+        }
+    }
+}
+
+/// `(person, date)` for every filled place of `roles`: the history fair
+/// rotation balances on.
+pub fn assignments(rotas: &[DutyRota], roles: &[DutyRole]) -> Vec<(RecordId, String)> {
+    rotas
+        .iter()
+        .flat_map(|r| {
+            r.duties
+                .iter()
+                .filter(|d| roles.contains(&d.role))
+                .filter_map(|d| Some((d.person.clone()?, r.date.clone())))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::user_id;
+    use crate::schedule::rotation::Reason;
+
+    #[test]
+    fn fit_keeps_assigned_people() {
+        let settings = DutySettings { attendants: 1, microphones: 2, ..DutySettings::default() };
+        let mut rota = DutyRota::blank("2026-10-14".into(), MeetingKind::Midweek);
+        rota.duties = vec![
+            Duty { role: DutyRole::Microphones, person: None },
+            Duty { role: DutyRole::Attendant, person: Some(user_id("a")) },
+            Duty { role: DutyRole::Attendant, person: Some(user_id("b")) },
+            Duty { role: DutyRole::Audio, person: Some(user_id("c")) },
+        ];
+        fit(&mut rota, &settings, &[DutyRole::Attendant, DutyRole::Microphones]);
+        let roles: Vec<DutyRole> = rota.duties.iter().map(|d| d.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                DutyRole::Attendant,
+                DutyRole::Attendant,
+                DutyRole::Audio,
+                DutyRole::Microphones,
+                DutyRole::Microphones,
+            ]
+        );
+
+        let mut rotas = vec![rota];
+        let slots = rotation_slots(&rotas, &[DutyRole::Microphones], &[]);
+        // Two open microphones plus the three filled places counting as busy.
+        assert_eq!(slots.iter().filter(|s| s.fixed.is_none()).count(), 2);
+        assert_eq!(slots.len(), 5);
+
+        let picks = vec![Pick {
+            key: "0-4".into(),
+            person: Some(user_id("d")),
+            reason: Reason::Kept,
+        }];
+        apply_picks(&mut rotas, &picks);
+        assert_eq!(rotas[0].duties[4].person, Some(user_id("d")));
+        assert_eq!(assignments(&rotas, &[DutyRole::Microphones]), vec![(user_id("d"), "2026-10-14".to_string())]);
+    }
+}
@@ -0,0 +1,125 @@
+//! Kingdom Hall upkeep: when preventive tasks fall due and what is overdue.
+
+use crate::dates::{add_days, add_months_iso};
+use crate::models::maintenance::{IntervalUnit, PreventiveTask, WorkOrder};
+
+/// Date the task is next due, or `None` if it was never carried out and is
+/// due right away. An interval of zero counts as one.
+pub fn next_due(task: &PreventiveTask) -> Option<String> {
+    let last = task.last_done.as_deref()?;
+    let n = task.every.max(1) as i64;
+    Some(match task.unit {
+        IntervalUnit::Days => add_days(last, n),
+        IntervalUnit::Weeks => add_days(last, 7 * n),
+        IntervalUnit::Months => add_months_iso(last, n as i32),
+        IntervalUnit::Years => add_months_iso(last, 12 * n as i32),
+    })
+}
+
+pub fn task_is_overdue(task: &PreventiveTask, today: &str) -> bool {
+    next_due(task).is_none_or(|due| due.as_str() < today)
+}
+
+/// Open or in progress with a due date already past.
+pub fn order_is_overdue(order: &WorkOrder, today: &str) -> bool {
+    order.status.is_pending() && order.due.as_deref().is_some_and(|due| due < today)
+}
+
+/// `(title, due)` of every overdue task and work order, oldest first; tasks
+/// never carried out come first with no date.
+pub fn overdue(
+    tasks: &[PreventiveTask],
+    orders: &[WorkOrder],
+    today: &str,
+) -> Vec<(String, Option<String>)> {
+    let mut items: Vec<(String, Option<String>)> = tasks
+        .iter()
+        .filter(|t| task_is_overdue(t, today))
+        .map(|t| (t.title.clone(), next_due(t)))
+        .chain(
+            orders
+                .iter()
+                .filter(|o| order_is_overdue(o, today))
+                .map(|o| (o.title.clone(), o.due.clone())),
+        )
+        .collect();
+    items.sort_by(|a, b| a.1.cmp(&b.1));
+    items
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::maintenance::WorkOrderStatus;
+
+    fn task(
+        title: &str,
+        last_done: Option<&str>,
+        every: u16,
+        unit: IntervalUnit,
+    ) -> PreventiveTask {
+        PreventiveTask {
+            id: None,
+            title: title.into(),
+            asset: None,
+            every,
+            unit,
+            last_done: last_done.map(String::from),
+            assignee: None,
+            notes: String::new(),
+        }
+    }
+
+    fn order(title: &str, status: WorkOrderStatus, due: Option<&str>) -> WorkOrder {
+        WorkOrder {
+            id: None,
+            title: title.into(),
+            description: String::new(),
+            asset: None,
+            status,
+            assignee: None,
+            reported: "2026-09-01".into(),
+            due: due.map(String::from),
+            completed: None,
+            cost_notes: String::new(),
+        }
+    }
+
+    #[test]
+    fn next_due_follows_the_interval() {
+        let t = task("Extinguishers", Some("2025-10-18"), 1, IntervalUnit::Years);
+        assert_eq!(next_due(&t).as_deref(), Some("2026-10-18"));
+        let t = task("Filters", Some("2026-01-31"), 1, IntervalUnit::Months);
+        assert_eq!(next_due(&t).as_deref(), Some("2026-02-28"));
+        let t = task("Gutters", Some("2026-10-01"), 2, IntervalUnit::Weeks);
+        assert_eq!(next_due(&t).as_deref(), Some("2026-10-15"));
+        assert_eq!(next_due(&task("New", None, 1, IntervalUnit::Days)), None);
+    }
+
+    #[test]
+    fn overdue_lists_tasks_and_orders() {
+        let tasks = vec![
+            task("Extinguishers", Some("2025-10-18"), 1, IntervalUnit::Years),
+            task("Filters", Some("2026-08-01"), 1, IntervalUnit::Months),
+            task("Never", None, 1, IntervalUnit::Years),
+        ];
+        let orders = vec![
+            order("Leak", WorkOrderStatus::InProgress, Some("2026-10-10")),
+            order("Lamp", WorkOrderStatus::Done, Some("2026-10-01")),
+            order("Paint", WorkOrderStatus::Open, None),
+        ];
+        let items = overdue(&tasks, &orders, "2026-10-18");
+        assert_eq!(
+            items,
+            vec![
+                ("Never".to_string(), None),
+                ("Filters".to_string(), Some("2026-09-01".to_string())),
+                ("Leak".to_string(), Some("2026-10-10".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Who may take which part of the midweek meeting.
+//!
+//! Eligibility comes from [`UserPrivileges`]: each part kind and meeting
+//! role maps to one flag. Householders of student parts must also be of the
+//! same gender as the student. Inactive publishers are never offered.
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Pick, Requirement, Slot};
+use crate::models::midweek_meeting::{MidweekMeeting, PartKind, Section};
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::User;
+
+/// Roles of the meeting outside the part list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeetingRole {
+    Chairman,
+    /// Counselor of the auxiliary classroom.
+    AuxCounselor,
+    Prayer,
+}
+
+impl MeetingRole {
+    pub fn qualifies(&self, p: &UserPrivileges) -> bool {
+        match self {
+            Self::Chairman => p.weekday_chairman,
+            Self::AuxCounselor => p.aux_chairman,
+            Self::Prayer => p.weekday_pray,
+        }
+    }
+
+    pub fn requirement(&self) -> Requirement {
+        match self {
+            Self::Chairman => Requirement::Appointed,
+            Self::AuxCounselor => Requirement::Elder,
+            Self::Prayer => Requirement::Brother,
+        }
+    }
+}
+
+/// The two people a part can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartRole {
+    Assignee,
+    /// Householder, or reader of the congregation Bible study.
+    Assistant,
+}
+
+pub fn part_qualifies(kind: PartKind, role: PartRole, p: &UserPrivileges) -> bool {
+    match (kind, role) {
+        (PartKind::Treasures, PartRole::Assignee) => p.treasures,
+        (PartKind::SpiritualGems, PartRole::Assignee) => p.spiritual_gems,
+        (PartKind::BibleReading, PartRole::Assignee) => p.bible_reading,
+        (PartKind::StartingConversation, PartRole::Assignee) => p.starting_conversation,
+        (PartKind::FollowingUp, PartRole::Assignee) => p.following_up,
+        (PartKind::MakingDisciples, PartRole::Assignee) => p.making_disciples,
+        (PartKind::StudentTalk, PartRole::Assignee) => p.student_talk,
+        (PartKind::FieldMinistryDiscussion, PartRole::Assignee) => p.field_ministry_discussion,
+        (PartKind::LivingAsChristians, PartRole::Assignee) => p.living_as_christians,
+        (PartKind::CongregationBibleStudy, PartRole::Assignee) => p.congregation_bible_study,
+        (PartKind::CongregationBibleStudy, PartRole::Assistant) => {
+            p.congregation_bible_study_reader
+        }
+        (kind, PartRole::Assistant) => kind.has_assistant() && p.assistant,
+    }
+}
+
+/// Who may be proposed for a part on top of the privilege flag: talks and
+/// readings go to brothers, teaching parts to appointed men.
+pub fn part_requirement(kind: PartKind, role: PartRole) -> Requirement {
+    match (kind, role) {
+        (PartKind::Treasures | PartKind::SpiritualGems, PartRole::Assignee)
+        | (PartKind::FieldMinistryDiscussion | PartKind::LivingAsChristians, PartRole::Assignee)
+        | (PartKind::CongregationBibleStudy, PartRole::Assignee) => Requirement::Appointed,
+        (PartKind::BibleReading | PartKind::StudentTalk, PartRole::Assignee)
+        | (PartKind::CongregationBibleStudy, PartRole::Assistant) => Requirement::Brother,
+        _ => Requirement::Anyone,
+    }
+}
+
+/// Householders in student parts are of the same gender as the student.
+pub fn assistant_matches(kind: PartKind, student: Option<&User>, helper: &User) -> bool {
+    !kind.is_student_part() || student.is_none_or(|s| s.gender == helper.gender)
+}
+
+/// Active publishers whose privileges pass `test`, in the order of `users`.
+pub fn eligible<'a>(
+    users: &'a [User],
+    privileges: &[UserPrivileges],
+    test: impl Fn(&UserPrivileges) -> bool,
+) -> Vec<&'a User> {
+    users
+        .iter()
+        .filter(|u| u.active)
+        .filter(|u| {
+            privileges
+                .iter()
+                .any(|p| Some(&p.publisher) == u.id.as_ref() && test(p))
+        })
+        .collect()
+}
+
+/// Everyone with a place in the program, for showing a person's load.
+pub fn people_in(meeting: &MidweekMeeting) -> Vec<&RecordId> {
+    [
+        &meeting.chairman,
+        &meeting.aux_counselor,
+        &meeting.opening_prayer,
+        &meeting.closing_prayer,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(meeting.parts.iter().flat_map(|p| p.assignee.iter().chain(p.assistant.iter())))
+    .collect()
+}
+
+/// `(filled, total)` slots of a program: chairman, prayers, auxiliary
+/// counselor when two classrooms meet, and every part's people.
+pub fn slot_counts(meeting: &MidweekMeeting) -> (usize, usize) {
+    let mut slots = vec![&meeting.chairman, &meeting.opening_prayer, &meeting.closing_prayer];
+    if meeting.classrooms > 1 {
+        slots.push(&meeting.aux_counselor);
+    }
+    for part in &meeting.parts {
+        slots.push(&part.assignee);
+        if part.kind.has_assistant() {
+            slots.push(&part.assistant);
+        }
+    }
+    (slots.iter().filter(|s| s.is_some()).count(), slots.len())
+}
+
+/// `(person, meeting date)` for every place in the programs: the history
+/// fair rotation balances on.
+pub fn assignments(meetings: &[MidweekMeeting]) -> Vec<(RecordId, String)> {
+    meetings
+        .iter()
+        .flat_map(|m| people_in(m).into_iter().map(|id| (id.clone(), m.date.clone())))
+        .collect()
+}
+
+/// One rotation slot per place the program needs, in the order of
+/// [`slot_counts`]. Places already filled are passed as fixed.
+pub fn rotation_slots(meeting: &MidweekMeeting) -> Vec<Slot<'static>> {
+    let slot = |key: String,
+                fixed: &Option<RecordId>,
+                requirement: Requirement,
+                qualifies: Box<dyn Fn(&UserPrivileges) -> bool>| Slot {
+        key,
+        meeting: meeting.week_start.clone(),
+        date: meeting.date.clone(),
+        qualifies,
+        requirement,
+        same_gender_as: None,
+        fixed: fixed.clone(),
+    };
+    let role = |key: &str, role: MeetingRole, fixed: &Option<RecordId>| {
+        slot(key.to_string(), fixed, role.requirement(), Box::new(move |p| role.qualifies(p)))
+    };
+
+    let mut slots = vec![role("chairman", MeetingRole::Chairman, &meeting.chairman)];
+    if meeting.classrooms > 1 {
+        slots.push(role("aux-counselor", MeetingRole::AuxCounselor, &meeting.aux_counselor));
+    }
+    slots.push(role("opening-prayer", MeetingRole::Prayer, &meeting.opening_prayer));
+    slots.push(role("closing-prayer", MeetingRole::Prayer, &meeting.closing_prayer));
+    for (i, part) in meeting.parts.iter().enumerate() {
+        let kind = part.kind;
+        let assignee_key = format!("part-{i}-assignee");
+        slots.push(slot(
+            assignee_key.clone(),
+            &part.assignee,
+            part_requirement(kind, PartRole::Assignee),
+            Box::new(move |p| part_qualifies(kind, PartRole::Assignee, p)),
+        ));
+        if kind.has_assistant() {
+            slots.push(Slot {
+                same_gender_as: kind.is_student_part().then_some(assignee_key),
+                ..slot(
+                    format!("part-{i}-assistant"),
+                    &part.assistant,
+                    part_requirement(kind, PartRole::Assistant),
+                    Box::new(move |p| part_qualifies(kind, PartRole::Assistant, p)),
+                )
+            });
+        }
+    }
+    slots
+}
+
+/// Write accepted picks back into the program. Empty picks leave the place as is.
+pub fn apply_picks(meeting: &mut MidweekMeeting, picks: &[Pick]) {
+    for pick in picks {
+        let Some(person) = pick.person.clone() else { continue };
+        let place = match pick.key.as_str() {
+            "chairman" => &mut meeting.chairman,
+            "aux-counselor" => &mut meeting.aux_counselor,
+            "opening-prayer" => &mut meeting.opening_prayer,
+            "closing-prayer" => &mut meeting.closing_prayer,
+            key => {
+                let mut it = key.splitn(3, '-').skip(1);
+                let index = it.next().and_then(|i| i.parse::<usize>().ok());
+                let Some(part) = index.and_then(|i| meeting.parts.get_mut(i)) else { continue };
+                match it.next() {
+                    Some("assignee") => &mut part.assignee,
+                    Some("assistant") => &mut part.assistant,
+                    _ => continue,
+                }
+            }
+        };
+        *place = Some(person);
+    }
+}
+
+/// Label of a rotation slot key, as shown in the proposal.
+pub fn slot_label(meeting: &MidweekMeeting, key: &str) -> String {
+    match key {
+        "chairman" => t!("midweek-chairman"),
+        "aux-counselor" => t!("midweek-aux-counselor"),
+        "opening-prayer" => t!("midweek-opening-prayer"),
+        "closing-prayer" => t!("midweek-closing-prayer"),
+        key => {
+            let mut it = key.splitn(3, '-').skip(1);
+            let index = it.next().and_then(|i| i.parse::<usize>().ok());
+            let Some(part) = index.and_then(|i| meeting.parts.get(i)) else {
+                return key.to_string();
+            };
+            let title = if part.title.is_empty() { part_label(part.kind) } else { part.title.clone() };
+            let role = match (it.next(), part.kind) {
+                (Some("assistant"), PartKind::CongregationBibleStudy) => t!("midweek-reader"),
+                (Some("assistant"), _) => t!("midweek-householder"),
+                (_, kind) if kind.is_student_part() => t!("midweek-student"),
+                _ => t!("midweek-assigned"),
+            };
+            format!("{title} · {role}")
+        }
+    }
+}
+
+pub fn part_label(kind: PartKind) -> String {
+    match kind {
+        PartKind::Treasures => t!("priv-treasures"),
+        PartKind::SpiritualGems => t!("priv-spiritual-gems"),
+        PartKind::BibleReading => t!("priv-bible-reading"),
+        PartKind::StartingConversation => t!("priv-starting-conversation"),
+        PartKind::FollowingUp => t!("priv-following-up"),
+        PartKind::MakingDisciples => t!("priv-making-disciples"),
+        PartKind::StudentTalk => t!("priv-student-talk"),
+        PartKind::FieldMinistryDiscussion => t!("priv-field-ministry-discussion"),
+        PartKind::LivingAsChristians => t!("priv-living-as-christians"),
+        PartKind::CongregationBibleStudy => t!("priv-congregation-bible-study"),
+    }
+}
+
+pub fn section_label(section: Section) -> String {
+    match section {
+        Section::Treasures => t!("priv-sub-treasures"),
+        Section::Ministry => t!("priv-sub-field-ministry"),
+        Section::Living => t!("priv-sub-christians"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, privileges, user_id};
+    use crate::models::user::Gender;
+    use crate::schedule::rotation::Reason;
+
+    fn user(key: &str, gender: Gender, active: bool) -> User {
+        User { gender, active, ..fixtures::user(key) }
+    }
+
+    #[test]
+    fn offers_only_qualified_active_publishers() {
+        let users = vec![
+            user("a", Gender::Male, true),
+            user("b", Gender::Male, false),
+            user("c", Gender::Female, true),
+        ];
+        let privs = vec![
+            UserPrivileges { congregation_bible_study_reader: true, ..privileges("a") },
+            UserPrivileges { congregation_bible_study_reader: true, ..privileges("b") },
+            UserPrivileges { assistant: true, ..privileges("c") },
+        ];
+        let readers = eligible(&users, &privs, |p| {
+            part_qualifies(PartKind::CongregationBibleStudy, PartRole::Assistant, p)
+        });
+        assert_eq!(readers.len(), 1);
+        assert_eq!(readers[0].first_name, "a");
+
+        // The assistant flag does not make anyone a Bible reading student.
+        let readers = eligible(&users, &privs, |p| {
+            part_qualifies(PartKind::BibleReading, PartRole::Assistant, p)
+        });
+        assert!(readers.is_empty());
+    }
+
+    #[test]
+    fn householder_matches_student_gender() {
+        let brother = user("a", Gender::Male, true);
+        let sister = user("c", Gender::Female, true);
+        assert!(!assistant_matches(PartKind::FollowingUp, Some(&sister), &brother));
+        assert!(assistant_matches(PartKind::FollowingUp, Some(&sister), &sister));
+        assert!(assistant_matches(PartKind::CongregationBibleStudy, Some(&sister), &brother));
+    }
+
+    #[test]
+    fn rotation_slots_cover_every_place() {
+        let mut m = MidweekMeeting::template("2026-10-12".into(), "2026-10-14".into());
+        m.chairman = Some(user_id("a"));
+        let slots = rotation_slots(&m);
+        assert_eq!(slots.len(), slot_counts(&m).1);
+        assert!(slots[0].fixed.is_some());
+        let householder = slots.iter().find(|s| s.key == "part-3-assistant").unwrap();
+        assert_eq!(householder.same_gender_as.as_deref(), Some("part-3-assignee"));
+
+        let picks = vec![Pick {
+            key: "part-3-assistant".into(),
+            person: Some(user_id("c")),
+            reason: Reason::Kept,
+        }];
+        apply_picks(&mut m, &picks);
+        assert!(m.parts[3].assistant.is_some());
+        assert_eq!(slot_counts(&m).0, 2);
+    }
+}
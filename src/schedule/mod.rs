@@ -0,0 +1,83 @@
+//! Meeting schedules, duty planning and Kingdom Hall upkeep.
+//!
+//! Everything here is pure logic over model data: the rules that decide who
+//! may take a part, on top of the calendar arithmetic in [`crate::dates`].
+//! Pages load the data and persist results.
+
+pub mod cleaning;
+pub mod co_visit;
+pub mod conflicts;
+pub mod duties;
+pub mod maintenance;
+pub mod midweek;
+pub mod recurrence;
+pub mod rotation;
+pub mod talks;
+pub mod weekend;
+pub mod witnessing;
+
+use dioxus_i18n::t;
+
+use crate::dates::add_days;
+use crate::models::event::{CongregationEvent, EventType};
+
+/// Localised weekday name for a day `offset` days after Monday.
+pub fn weekday_label(offset: u8) -> String {
+    match offset {
+        0 => t!("weekday-1"),
+        1 => t!("weekday-2"),
+        2 => t!("weekday-3"),
+        3 => t!("weekday-4"),
+        4 => t!("weekday-5"),
+        5 => t!("weekday-6"),
+        6 => t!("weekday-7"),
+        _ => String::new(),
+    }
+}
+
+/// Events overlapping the week that starts on Monday `week_start`.
+pub fn events_in_week<'a>(
+    events: &'a [CongregationEvent],
+    week_start: &str,
+) -> Vec<&'a CongregationEvent> {
+    let week_end = add_days(week_start, 6);
+    events
+        .iter()
+        .filter(|e| e.start_date.as_str() <= week_end.as_str() && e.end_date.as_str() >= week_start)
+        .collect()
+}
+
+/// Assemblies and conventions replace the congregation's meetings that week.
+pub fn cancels_meetings(event_type: &EventType) -> bool {
+    matches!(event_type, EventType::CircuitAssembly | EventType::RegionalConvention)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_events_overlapping_a_week() {
+        let event = |start: &str, end: &str| CongregationEvent {
+            id: None,
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            event_type: EventType::CircuitAssembly,
+            title: None,
+            description: None,
+            recurrence: None,
+        };
+        let events = vec![
+            event("2026-10-10", "2026-10-11"),
+            event("2026-10-18", "2026-10-18"),
+            event("2026-10-19", "2026-10-21"),
+        ];
+        let found = events_in_week(&events, "2026-10-12");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].start_date, "2026-10-18");
+    }
+}
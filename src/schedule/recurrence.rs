@@ -0,0 +1,232 @@
+//! Repeating congregation events.
+//!
+//! A series is stored once, as a [`CongregationEvent`] with a
+//! [`Recurrence`]; its dates are its first occurrence. Occurrences are
+//! worked out whenever events are read, so past ones stay in the history and
+//! future ones need no typing. The model only loads stored rows; pages pass
+//! them through [`expand`] or [`history`].
+
+use dioxus_i18n::t;
+
+use super::weekday_label;
+use crate::dates::{
+    add_days, add_months_iso, days_between, days_from_civil, iso_date, parse_date, weekday,
+};
+use crate::models::event::{CongregationEvent, Frequency, Recurrence};
+
+/// Most occurrences worked out for one series, against runaway loops.
+const MAX_OCCURRENCES: i32 = 5_000;
+
+/// Week of the month `iso` falls in: 1–4, or 5 from the 29th on.
+pub fn week_of_month(iso: &str) -> u8 {
+    parse_date(iso)
+        .map(|(_, _, d)| (d - 1) / 7 + 1)
+        .unwrap_or(1)
+}
+
+/// The `nth` (1–4, 5 = last) `weekday` (0 = Monday) of a month.
+pub fn nth_weekday(year: i32, month: u8, weekday: u8, nth: u8) -> String {
+    let (ny, nm) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let month_len = (days_from_civil(ny, nm, 1) - days_from_civil(year, month, 1)) as u8;
+    // 1970-01-01 was a Thursday.
+    let first_wd = ((days_from_civil(year, month, 1) + 3).rem_euclid(7)) as u8;
+    let first = 1 + (weekday + 7 - first_wd) % 7;
+    let mut day = first + 7 * (nth.clamp(1, 5) - 1);
+    while day > month_len {
+        day -= 7;
+    }
+    iso_date(year, month, day)
+}
+
+/// Start of occurrence `k` of a series starting on `start`.
+fn nth_start(start: &str, rule: &Recurrence, k: i32) -> String {
+    match rule.frequency {
+        Frequency::Weekly => add_days(start, 7 * k as i64),
+        Frequency::MonthlyWeekday => {
+            let month = add_months_iso(start, k);
+            match (parse_date(&month), weekday(start)) {
+                (Some((y, m, _)), Some(wd)) => nth_weekday(y, m, wd, rule.nth),
+                _ => month,
+            }
+        }
+        Frequency::Yearly => add_months_iso(start, 12 * k),
+    }
+}
+
+/// `(start, end)` of every occurrence of `event` overlapping `from..=to`.
+/// An event without a recurrence is its own single occurrence.
+pub fn occurrences(event: &CongregationEvent, from: &str, to: &str) -> Vec<(String, String)> {
+    let overlaps = |start: &str, end: &str| start <= to && end >= from;
+    let Some(rule) = &event.recurrence else {
+        return if overlaps(&event.start_date, &event.end_date) {
+            vec![(event.start_date.clone(), event.end_date.clone())]
+        } else {
+            vec![]
+        };
+    };
+    let length = days_between(&event.start_date, &event.end_date)
+        .unwrap_or(0)
+        .max(0);
+    let mut out = vec![];
+    for k in 0..MAX_OCCURRENCES {
+        let start = nth_start(&event.start_date, rule, k);
+        if start.as_str() > to || rule.until.as_ref().is_some_and(|u| &start > u) {
+            break;
+        }
+        // A monthly rule can land before the first date in its first month.
+        if start < event.start_date {
+            continue;
+        }
+        let end = add_days(&start, length);
+        if overlaps(&start, &end) {
+            out.push((start, end));
+        }
+    }
+    out
+}
+
+/// Every occurrence of `events` overlapping `from..=to`, each a copy of
+/// its series with the occurrence dates, by start date.
+pub fn expand(events: &[CongregationEvent], from: &str, to: &str) -> Vec<CongregationEvent> {
+    let mut out: Vec<CongregationEvent> = events
+        .iter()
+        .flat_map(|e| {
+            occurrences(e, from, to)
+                .into_iter()
+                .map(move |(start, end)| CongregationEvent {
+                    start_date: start,
+                    end_date: end,
+                    ..e.clone()
+                })
+        })
+        .collect();
+    out.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+    out
+}
+
+/// Occurrences of `events` that ended before `today`, most recent first.
+pub fn history(events: &[CongregationEvent], today: &str) -> Vec<CongregationEvent> {
+    let mut past: Vec<CongregationEvent> = expand(events, "0000-01-01", today)
+        .into_iter()
+        .filter(|e| e.end_date.as_str() < today)
+        .collect();
+    past.reverse();
+    past
+}
+
+/// First occurrence that has not ended by `today`.
+pub fn next_occurrence(event: &CongregationEvent, today: &str) -> Option<(String, String)> {
+    let horizon = match &event.recurrence {
+        Some(_) => add_months_iso(today, 13),
+        None => event.end_date.clone(),
+    };
+    occurrences(event, today, &horizon).into_iter().next()
+}
+
+/// "Every week", "Monthly: second Tuesday", "Every year".
+pub fn label(rule: &Recurrence, start: &str) -> String {
+    match rule.frequency {
+        Frequency::Weekly => t!("event-repeat-weekly"),
+        Frequency::MonthlyWeekday => format!(
+            "{}: {} {}",
+            t!("event-repeat-monthly"),
+            nth_label(rule.nth),
+            weekday(start).map(weekday_label).unwrap_or_default()
+        ),
+        Frequency::Yearly => t!("event-repeat-yearly"),
+    }
+}
+
+pub fn nth_label(nth: u8) -> String {
+    match nth {
+        1 => t!("event-nth-1"),
+        2 => t!("event-nth-2"),
+        3 => t!("event-nth-3"),
+        4 => t!("event-nth-4"),
+        _ => t!("event-nth-last"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+
+    fn series(start: &str, end: &str, frequency: Frequency, nth: u8) -> CongregationEvent {
+        CongregationEvent {
+            id: None,
+            start_date: start.to_string(),
+            end_date: end.to_string(),
+            event_type: EventType::Other,
+            title: None,
+            description: None,
+            recurrence: Some(Recurrence {
+                frequency,
+                nth,
+                until: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn finds_nth_and_last_weekdays() {
+        // October 2026 starts on a Thursday.
+        assert_eq!(nth_weekday(2026, 10, 1, 2), "2026-10-13");
+        assert_eq!(nth_weekday(2026, 10, 3, 1), "2026-10-01");
+        assert_eq!(nth_weekday(2026, 10, 5, 5), "2026-10-31");
+        assert_eq!(nth_weekday(2026, 2, 0, 5), "2026-02-23");
+        assert_eq!(week_of_month("2026-10-29"), 5);
+    }
+
+    #[test]
+    fn expands_each_frequency() {
+        let weekly = series("2026-10-06", "2026-10-06", Frequency::Weekly, 0);
+        assert_eq!(
+            occurrences(&weekly, "2026-10-10", "2026-10-27"),
+            vec![
+                ("2026-10-13".to_string(), "2026-10-13".to_string()),
+                ("2026-10-20".to_string(), "2026-10-20".to_string()),
+                ("2026-10-27".to_string(), "2026-10-27".to_string()),
+            ]
+        );
+
+        // Second Tuesday, two days long.
+        let monthly = series("2026-10-13", "2026-10-14", Frequency::MonthlyWeekday, 2);
+        let starts: Vec<String> = occurrences(&monthly, "2026-10-01", "2027-01-31")
+            .into_iter()
+            .map(|(s, _)| s)
+            .collect();
+        assert_eq!(
+            starts,
+            vec!["2026-10-13", "2026-11-10", "2026-12-08", "2027-01-12"]
+        );
+
+        let mut yearly = series("2024-02-29", "2024-02-29", Frequency::Yearly, 0);
+        yearly.recurrence.as_mut().unwrap().until = Some("2026-12-31".into());
+        let starts: Vec<String> = occurrences(&yearly, "2020-01-01", "2030-01-01")
+            .into_iter()
+            .map(|(s, _)| s)
+            .collect();
+        assert_eq!(starts, vec!["2024-02-29", "2025-02-28", "2026-02-28"]);
+    }
+
+    #[test]
+    fn history_lists_past_occurrences_newest_first() {
+        let weekly = series("2026-10-06", "2026-10-06", Frequency::Weekly, 0);
+        let mut ongoing = series("2026-10-19", "2026-10-22", Frequency::Weekly, 0);
+        ongoing.recurrence = None;
+        let starts: Vec<String> = history(&[weekly, ongoing], "2026-10-21")
+            .into_iter()
+            .map(|e| e.start_date)
+            .collect();
+        assert_eq!(starts, vec!["2026-10-20", "2026-10-13", "2026-10-06"]);
+    }
+}
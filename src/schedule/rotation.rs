@@ -0,0 +1,394 @@
+//! Fair rotation: proposes who fills each open slot of a schedule.
+//!
+//! For every slot the engine takes the active publishers whose privileges
+//! pass the slot's test and who meet its gender and appointment
+//! requirement, drops anyone away on the date (an [`Absence`] covering it)
+//! or already busy at the same meeting, and picks whoever has waited
+//! longest since their last assignment. Each pick carries a [`Reason`] so
+//! the proposal can be reviewed before it is accepted.
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use crate::models::absence::Absence;
+use crate::models::privilege::UserPrivileges;
+use crate::models::user::{Appointment, Gender, User};
+
+/// Constraint from the publisher record, checked on top of the privilege.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    Anyone,
+    Brother,
+    /// Elder or ministerial servant.
+    Appointed,
+    Elder,
+}
+
+impl Requirement {
+    pub fn allows(&self, user: &User) -> bool {
+        match self {
+            Self::Anyone => true,
+            Self::Brother => user.gender == Gender::Male,
+            Self::Appointed => user.gender == Gender::Male && user.appointment.is_some(),
+            Self::Elder => {
+                user.gender == Gender::Male && user.appointment == Some(Appointment::Elder)
+            }
+        }
+    }
+}
+
+/// One place to fill.
+pub struct Slot<'a> {
+    /// Caller's identifier, used to apply the pick.
+    pub key: String,
+    /// Meeting or event the slot belongs to; a person gets one slot per meeting.
+    pub meeting: String,
+    /// `"YYYY-MM-DD"`, checked against absences and used as the new last date.
+    pub date: String,
+    pub qualifies: Box<dyn Fn(&UserPrivileges) -> bool + 'a>,
+    pub requirement: Requirement,
+    /// Key of another slot whose person this one must share a gender with
+    /// (the householder of a student part).
+    pub same_gender_as: Option<String>,
+    /// Already assigned: kept as is, but the person counts as busy.
+    pub fixed: Option<RecordId>,
+}
+
+/// Why a slot got its person, or why it stayed empty.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    /// The slot was filled before the proposal.
+    Kept,
+    Picked {
+        /// Their previous assignment, `None` if they never had one.
+        last: Option<String>,
+        /// Eligible people considered for the slot.
+        candidates: usize,
+        absent: usize,
+        busy: usize,
+    },
+    NoCandidate {
+        eligible: usize,
+        absent: usize,
+        busy: usize,
+    },
+}
+
+impl Reason {
+    pub fn explain(&self) -> String {
+        match self {
+            Self::Kept => t!("rotation-kept"),
+            Self::Picked {
+                last,
+                candidates,
+                absent,
+                busy,
+            } => {
+                let since = match last {
+                    Some(date) => format!("{} {date}", t!("rotation-last-assigned")),
+                    None => t!("rotation-never-assigned"),
+                };
+                format!(
+                    "{since} · {candidates} {} · {absent} {} · {busy} {}",
+                    t!("rotation-candidates"),
+                    t!("rotation-absent"),
+                    t!("rotation-busy"),
+                )
+            }
+            Self::NoCandidate {
+                eligible,
+                absent,
+                busy,
+            } => format!(
+                "{} · {eligible} {} · {absent} {} · {busy} {}",
+                t!("rotation-no-candidate"),
+                t!("rotation-candidates"),
+                t!("rotation-absent"),
+                t!("rotation-busy"),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pick {
+    pub key: String,
+    pub person: Option<RecordId>,
+    pub reason: Reason,
+}
+
+/// Whether an absence covers `date`. Open-ended absences run indefinitely.
+pub fn is_absent(absences: &[Absence], person: &RecordId, date: &str) -> bool {
+    absences.iter().any(|a| {
+        &a.user == person
+            && a.start_date.as_str() <= date
+            && a.end_date.as_deref().is_none_or(|end| end >= date)
+    })
+}
+
+/// Latest assignment date per person from `(person, date)` pairs.
+pub fn last_dates(history: &[(RecordId, String)]) -> HashMap<RecordId, String> {
+    let mut last: HashMap<RecordId, String> = HashMap::new();
+    for (person, date) in history {
+        let entry = last.entry(person.clone()).or_default();
+        if date > entry {
+            *entry = date.clone();
+        }
+    }
+    last
+}
+
+/// Propose a person for every slot, in the order of `slots`.
+///
+/// Slots with the fewest eligible people are filled first so scarce
+/// privileges are not used up by slots anyone could take. Householder
+/// slots wait for the slot they depend on. Ties on the last date go to the
+/// person with fewer assignments in `history`, then to the order of `users`.
+pub fn propose(
+    users: &[User],
+    privileges: &[UserPrivileges],
+    absences: &[Absence],
+    history: &[(RecordId, String)],
+    slots: &[Slot],
+) -> Vec<Pick> {
+    let mut last = last_dates(history);
+    let mut count: HashMap<RecordId, usize> = HashMap::new();
+    for (person, _) in history {
+        *count.entry(person.clone()).or_default() += 1;
+    }
+
+    let eligible_for = |slot: &Slot| -> Vec<&User> {
+        users
+            .iter()
+            .filter(|u| u.active && slot.requirement.allows(u))
+            .filter(|u| {
+                privileges
+                    .iter()
+                    .any(|p| Some(&p.publisher) == u.id.as_ref() && (slot.qualifies)(p))
+            })
+            .collect()
+    };
+
+    let mut busy: HashSet<(String, RecordId)> = slots
+        .iter()
+        .filter_map(|s| Some((s.meeting.clone(), s.fixed.clone()?)))
+        .collect();
+    let mut chosen: HashMap<String, RecordId> = slots
+        .iter()
+        .filter_map(|s| Some((s.key.clone(), s.fixed.clone()?)))
+        .collect();
+
+    let mut order: Vec<(usize, usize)> = slots
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.fixed.is_none())
+        .map(|(i, s)| (i, eligible_for(s).len()))
+        .collect();
+    order.sort_by_key(|&(i, n)| (slots[i].same_gender_as.is_some(), n));
+
+    let mut picks: HashMap<usize, Pick> = HashMap::new();
+    for (i, _) in order {
+        let slot = &slots[i];
+        let partner_gender = slot
+            .same_gender_as
+            .as_ref()
+            .and_then(|k| chosen.get(k))
+            .and_then(|id| users.iter().find(|u| u.id.as_ref() == Some(id)))
+            .map(|u| u.gender.clone());
+        let eligible: Vec<&User> = eligible_for(slot)
+            .into_iter()
+            .filter(|u| partner_gender.as_ref().is_none_or(|g| &u.gender == g))
+            .collect();
+
+        let (mut absent, mut taken) = (0, 0);
+        let mut best: Option<&RecordId> = None;
+        for user in &eligible {
+            let Some(id) = user.id.as_ref() else { continue };
+            if is_absent(absences, id, &slot.date) {
+                absent += 1;
+                continue;
+            }
+            if busy.contains(&(slot.meeting.clone(), id.clone())) {
+                taken += 1;
+                continue;
+            }
+            let waited = |id: &RecordId| {
+                let last = last.get(id).map(String::as_str).unwrap_or_default();
+                (last, count.get(id).copied().unwrap_or(0))
+            };
+            if best.is_none_or(|b| waited(id) < waited(b)) {
+                best = Some(id);
+            }
+        }
+
+        let pick = match best {
+            Some(id) => {
+                let reason = Reason::Picked {
+                    last: last.get(id).cloned(),
+                    candidates: eligible.len(),
+                    absent,
+                    busy: taken,
+                };
+                busy.insert((slot.meeting.clone(), id.clone()));
+                chosen.insert(slot.key.clone(), id.clone());
+                last.entry(id.clone())
+                    .and_modify(|d| {
+                        if slot.date > *d {
+                            *d = slot.date.clone()
+                        }
+                    })
+                    .or_insert_with(|| slot.date.clone());
+                *count.entry(id.clone()).or_default() += 1;
+                Pick {
+                    key: slot.key.clone(),
+                    person: Some(id.clone()),
+                    reason,
+                }
+            }
+            None => Pick {
+                key: slot.key.clone(),
+                person: None,
+                reason: Reason::NoCandidate {
+                    eligible: eligible.len(),
+                    absent,
+                    busy: taken,
+                },
+            },
+        };
+        picks.insert(i, pick);
+    }
+
+    slots
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            picks.remove(&i).unwrap_or_else(|| Pick {
+                key: s.key.clone(),
+                person: s.fixed.clone(),
+                reason: Reason::Kept,
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, privileges, user_id};
+
+    fn user(key: &str, gender: Gender, appointment: Option<Appointment>) -> User {
+        User { gender, appointment, ..fixtures::user(key) }
+    }
+
+    fn can_pray(key: &str) -> UserPrivileges {
+        UserPrivileges { weekday_pray: true, ..privileges(key) }
+    }
+
+    fn prayer(key: &str, meeting: &str, date: &str) -> Slot<'static> {
+        Slot {
+            key: key.to_string(),
+            meeting: meeting.to_string(),
+            date: date.to_string(),
+            qualifies: Box::new(|p| p.weekday_pray),
+            requirement: Requirement::Brother,
+            same_gender_as: None,
+            fixed: None,
+        }
+    }
+
+    #[test]
+    fn rotates_by_last_assignment_without_double_booking() {
+        let users = vec![
+            user("a", Gender::Male, None),
+            user("b", Gender::Male, Some(Appointment::Elder)),
+            user("c", Gender::Male, None),
+            user("s", Gender::Female, None),
+        ];
+        let privs = vec![can_pray("a"), can_pray("b"), can_pray("c"), can_pray("s")];
+        let absences = vec![Absence {
+            id: None,
+            user: user_id("c"),
+            start_date: "2026-10-01".into(),
+            end_date: Some("2026-10-31".into()),
+            reason: None,
+        }];
+        let history = vec![
+            (user_id("a"), "2026-09-01".into()),
+            (user_id("b"), "2026-08-01".into()),
+        ];
+        let slots = vec![
+            prayer("open", "w1", "2026-10-14"),
+            prayer("close", "w1", "2026-10-14"),
+            prayer("open", "w2", "2026-11-04"),
+        ];
+        let picks = propose(&users, &privs, &absences, &history, &slots);
+
+        // b waited longest, then a; c is away and s is not a brother.
+        assert_eq!(picks[0].person, Some(user_id("b")));
+        assert_eq!(picks[1].person, Some(user_id("a")));
+        assert!(matches!(
+            picks[1].reason,
+            Reason::Picked {
+                candidates: 3,
+                absent: 1,
+                busy: 1,
+                ..
+            }
+        ));
+        // c is back and never assigned.
+        assert_eq!(picks[2].person, Some(user_id("c")));
+    }
+
+    #[test]
+    fn an_earlier_slot_does_not_hide_a_later_assignment() {
+        let users = vec![
+            user("a", Gender::Male, None),
+            user("c", Gender::Male, None),
+            user("d", Gender::Male, None),
+        ];
+        let privs = vec![can_pray("a"), can_pray("c"), can_pray("d")];
+        let absences = vec![Absence {
+            id: None,
+            user: user_id("d"),
+            start_date: "2026-10-14".into(),
+            end_date: Some("2026-10-14".into()),
+            reason: None,
+        }];
+        // a is already down for the 20th when the 14th is planned.
+        let history = vec![
+            (user_id("a"), "2026-10-20".into()),
+            (user_id("c"), "2026-11-01".into()),
+            (user_id("d"), "2026-10-17".into()),
+        ];
+        let slots = vec![prayer("open", "w1", "2026-10-14"), prayer("open", "w2", "2026-10-21")];
+        let picks = propose(&users, &privs, &absences, &history, &slots);
+        assert_eq!(picks[0].person, Some(user_id("a")));
+        assert_eq!(picks[1].person, Some(user_id("d")));
+    }
+
+    #[test]
+    fn keeps_fixed_slots_and_reports_empty_ones() {
+        let users = vec![user("a", Gender::Male, None)];
+        let privs = vec![can_pray("a")];
+        let mut fixed = prayer("open", "w1", "2026-10-14");
+        fixed.fixed = Some(user_id("a"));
+        let slots = vec![fixed, prayer("close", "w1", "2026-10-14")];
+        let picks = propose(&users, &privs, &[], &[], &slots);
+        assert_eq!(picks[0].reason, Reason::Kept);
+        assert_eq!(picks[1].person, None);
+        assert_eq!(
+            picks[1].reason,
+            Reason::NoCandidate {
+                eligible: 1,
+                absent: 0,
+                busy: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Public talk catalog parsing and outline repeat checks.
+
+use std::collections::HashMap;
+
+use crate::dates::add_months_iso;
+
+/// Outlines pasted one per line as `"<number> <title>"`. The number may be
+/// followed by `.`, `;`, `,`, `-` or a tab. Lines without a number are skipped.
+pub fn parse_outlines(text: &str) -> Vec<(u16, String)> {
+    text.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let digits = line.find(|c: char| !c.is_ascii_digit()).unwrap_or(line.len());
+            let number: u16 = line[..digits].parse().ok()?;
+            let title = line[digits..]
+                .trim_start_matches(|c: char| c.is_whitespace() || ".;,-".contains(c))
+                .trim()
+                .trim_matches('"')
+                .to_string();
+            (number > 0).then_some((number, title))
+        })
+        .collect()
+}
+
+/// Outline numbers typed as a list, e.g. `"1, 15 40"`: sorted, no repeats.
+pub fn parse_numbers(text: &str) -> Vec<u16> {
+    let mut numbers: Vec<u16> = text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse().ok())
+        .filter(|n| *n > 0)
+        .collect();
+    numbers.sort_unstable();
+    numbers.dedup();
+    numbers
+}
+
+/// Latest date each outline was given on or before `until`.
+pub fn last_given<'a>(talks: &'a [(String, u16)], until: &str) -> HashMap<u16, &'a str> {
+    let mut last: HashMap<u16, &str> = HashMap::new();
+    for (date, outline) in talks.iter().filter(|(d, _)| d.as_str() <= until) {
+        let entry = last.entry(*outline).or_insert(date.as_str());
+        if date.as_str() > *entry {
+            *entry = date.as_str();
+        }
+    }
+    last
+}
+
+/// Date the same outline was given before `date`, if that was less than
+/// `months` months earlier. `talks` are `(date, outline)` pairs in any order.
+pub fn repeated_since<'a>(
+    talks: &'a [(String, u16)],
+    date: &str,
+    outline: u16,
+    months: u8,
+) -> Option<&'a str> {
+    let window_start = add_months_iso(date, -(months as i32));
+    talks
+        .iter()
+        .filter(|(d, o)| *o == outline && d.as_str() < date && d.as_str() > window_start.as_str())
+        .map(|(d, _)| d.as_str())
+        .max()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pasted_outlines() {
+        let text = "1. Do You Know God Well?\n2 - Will You Survive?\n\nHeading\n\t3;\"Title\"";
+        assert_eq!(
+            parse_outlines(text),
+            vec![
+                (1, "Do You Know God Well?".to_string()),
+                (2, "Will You Survive?".to_string()),
+                (3, "Title".to_string()),
+            ]
+        );
+        assert_eq!(parse_numbers("40, 1 15;1"), vec![1, 15, 40]);
+    }
+
+    #[test]
+    fn finds_repeats_within_window() {
+        let talks = vec![
+            ("2025-11-02".to_string(), 15),
+            ("2026-03-01".to_string(), 15),
+            ("2026-06-07".to_string(), 40),
+        ];
+        assert_eq!(repeated_since(&talks, "2026-10-18", 15, 12), Some("2026-03-01"));
+        assert_eq!(repeated_since(&talks, "2026-10-18", 15, 6), None);
+        assert_eq!(repeated_since(&talks, "2026-03-01", 15, 12), Some("2025-11-02"));
+        assert_eq!(last_given(&talks, "2026-02-01").get(&15), Some(&"2025-11-02"));
+    }
+}
@@ -0,0 +1,180 @@
+//! Who may take which role at the weekend meeting.
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Pick, Requirement, Slot};
+use crate::models::privilege::UserPrivileges;
+use crate::models::weekend_meeting::WeekendMeeting;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekendRole {
+    Chairman,
+    Prayer,
+    /// Public talk given by a brother of this congregation.
+    Speaker,
+    WatchtowerConductor,
+    WatchtowerReader,
+}
+
+impl WeekendRole {
+    pub fn qualifies(&self, p: &UserPrivileges) -> bool {
+        match self {
+            Self::Chairman => p.weekend_chairman,
+            Self::Prayer => p.weekend_pray,
+            Self::Speaker => p.public_talks,
+            Self::WatchtowerConductor => p.watchtower_conductor,
+            Self::WatchtowerReader => p.watchtower_reader,
+        }
+    }
+
+    pub fn requirement(&self) -> Requirement {
+        match self {
+            Self::Chairman | Self::Speaker => Requirement::Appointed,
+            Self::WatchtowerConductor => Requirement::Elder,
+            Self::Prayer | Self::WatchtowerReader => Requirement::Brother,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Chairman => t!("priv-weekend-chairman"),
+            Self::Prayer => t!("priv-weekend-pray"),
+            Self::Speaker => t!("priv-public-talks"),
+            Self::WatchtowerConductor => t!("priv-watchtower-conductor"),
+            Self::WatchtowerReader => t!("priv-watchtower-reader"),
+        }
+    }
+}
+
+/// A visiting speaker counts once a name is entered.
+pub fn has_speaker(meeting: &WeekendMeeting) -> bool {
+    meeting.speaker.is_some()
+        || meeting.visiting_speaker.as_deref().is_some_and(|s| !s.trim().is_empty())
+}
+
+/// Local brothers with a place in the program, for showing double bookings.
+pub fn people_in(meeting: &WeekendMeeting) -> Vec<&RecordId> {
+    [
+        &meeting.chairman,
+        &meeting.opening_prayer,
+        &meeting.closing_prayer,
+        &meeting.speaker,
+        &meeting.watchtower_conductor,
+        &meeting.watchtower_reader,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// `(person, meeting date)` for every place in the programs: the history
+/// fair rotation balances on.
+pub fn assignments(meetings: &[WeekendMeeting]) -> Vec<(RecordId, String)> {
+    meetings
+        .iter()
+        .flat_map(|m| people_in(m).into_iter().map(|id| (id.clone(), m.date.clone())))
+        .collect()
+}
+
+/// The places of a program as rotation slots. The speaker is only proposed
+/// when no visiting speaker is set.
+pub fn rotation_slots(meeting: &WeekendMeeting) -> Vec<Slot<'static>> {
+    let places = [
+        ("chairman", WeekendRole::Chairman, &meeting.chairman),
+        ("opening-prayer", WeekendRole::Prayer, &meeting.opening_prayer),
+        ("closing-prayer", WeekendRole::Prayer, &meeting.closing_prayer),
+        ("speaker", WeekendRole::Speaker, &meeting.speaker),
+        ("watchtower-conductor", WeekendRole::WatchtowerConductor, &meeting.watchtower_conductor),
+        ("watchtower-reader", WeekendRole::WatchtowerReader, &meeting.watchtower_reader),
+    ];
+    places
+        .into_iter()
+        .filter(|(_, role, fixed)| *role != WeekendRole::Speaker || fixed.is_some() || !has_speaker(meeting))
+        .map(|(key, role, fixed)| Slot {
+            key: key.to_string(),
+            meeting: meeting.week_start.clone(),
+            date: meeting.date.clone(),
+            qualifies: Box::new(move |p| role.qualifies(p)),
+            requirement: role.requirement(),
+            same_gender_as: None,
+            fixed: fixed.clone(),
+        })
+        .collect()
+}
+
+/// Write accepted picks back into the program. Empty picks leave the place as is.
+pub fn apply_picks(meeting: &mut WeekendMeeting, picks: &[Pick]) {
+    for pick in picks {
+        let Some(person) = pick.person.clone() else { continue };
+        let place = match pick.key.as_str() {
+            "chairman" => &mut meeting.chairman,
+            "opening-prayer" => &mut meeting.opening_prayer,
+            "closing-prayer" => &mut meeting.closing_prayer,
+            "speaker" => &mut meeting.speaker,
+            "watchtower-conductor" => &mut meeting.watchtower_conductor,
+            "watchtower-reader" => &mut meeting.watchtower_reader,
+            _ => continue,
+        };
+        *place = Some(person);
+    }
+}
+
+/// Label of a rotation slot key, as shown in the proposal.
+pub fn slot_label(key: &str) -> String {
+    match key {
+        "chairman" => t!("weekend-chairman"),
+        "opening-prayer" => t!("weekend-opening-prayer"),
+        "closing-prayer" => t!("weekend-closing-prayer"),
+        "speaker" => t!("weekend-speaker"),
+        "watchtower-conductor" => t!("weekend-wt-conductor"),
+        "watchtower-reader" => t!("weekend-wt-reader"),
+        key => key.to_string(),
+    }
+}
+
+/// Roles of a program nobody has been assigned to yet.
+pub fn open_roles(meeting: &WeekendMeeting) -> Vec<WeekendRole> {
+    let mut open = vec![];
+    if meeting.chairman.is_none() {
+        open.push(WeekendRole::Chairman);
+    }
+    if meeting.opening_prayer.is_none() || meeting.closing_prayer.is_none() {
+        open.push(WeekendRole::Prayer);
+    }
+    if !has_speaker(meeting) {
+        open.push(WeekendRole::Speaker);
+    }
+    if meeting.watchtower_conductor.is_none() {
+        open.push(WeekendRole::WatchtowerConductor);
+    }
+    if meeting.watchtower_reader.is_none() {
+        open.push(WeekendRole::WatchtowerReader);
+    }
+    open
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::user_id;
+
+    #[test]
+    fn lists_open_roles() {
+        let mut m = WeekendMeeting::blank("2026-10-12".into(), "2026-10-18".into());
+        assert_eq!(open_roles(&m).len(), 5);
+
+        m.visiting_speaker = Some("  ".into());
+        assert!(open_roles(&m).contains(&WeekendRole::Speaker));
+        m.visiting_speaker = Some("Visiting brother".into());
+        m.opening_prayer = Some(user_id("a"));
+        let open = open_roles(&m);
+        assert!(!open.contains(&WeekendRole::Speaker));
+        // Still waiting for the closing prayer.
+        assert!(open.contains(&WeekendRole::Prayer));
+    }
+}
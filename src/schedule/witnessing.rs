@@ -0,0 +1,307 @@
+//! Public witnessing: weekly cart shifts, sign-up rules and pairing.
+//!
+//! A [`WitnessingShift`] repeats every week; each of its dates is a
+//! [`ShiftOccurrence`] holding who signed up. Nobody should stand alone, so
+//! a shift wants at least two publishers, and a brother and a sister only
+//! make a pair when they live in the same household. [`pairing_slots`] turns
+//! under-staffed dates into slots for the fair rotation in
+//! [`super::rotation`].
+
+use dioxus_i18n::t;
+use surrealdb::types::RecordId;
+
+use super::rotation::{Pick, Requirement, Slot, is_absent};
+use crate::dates::add_days;
+use crate::models::absence::Absence;
+use crate::models::privilege::UserPrivileges;
+use crate::models::public_witnessing::{ShiftOccurrence, WitnessingShift};
+use crate::models::user::User;
+
+/// Fewest publishers that may take a shift.
+pub const MIN_PER_SHIFT: usize = 2;
+
+/// Every active shift of the week starting `week`, as `(date, shift)` in
+/// date and time order.
+pub fn week_dates<'a>(
+    week: &str,
+    shifts: &'a [WitnessingShift],
+) -> Vec<(String, &'a WitnessingShift)> {
+    let mut dates: Vec<(String, &WitnessingShift)> = shifts
+        .iter()
+        .filter(|s| s.active)
+        .map(|s| (add_days(week, s.weekday as i64), s))
+        .collect();
+    dates.sort_by(|a, b| (&a.0, &a.1.start).cmp(&(&b.0, &b.1.start)));
+    dates
+}
+
+/// Shifts on the same weekday whose hours overlap.
+pub fn overlaps(a: &WitnessingShift, b: &WitnessingShift) -> bool {
+    a.weekday == b.weekday && a.start < b.end && b.start < a.end
+}
+
+/// Same household, judged by the address on record.
+pub fn same_household(a: &User, b: &User) -> bool {
+    let norm = |u: &User| {
+        u.address
+            .as_deref()
+            .map(|s| {
+                s.split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            })
+            .unwrap_or_default()
+    };
+    let addr = norm(a);
+    !addr.is_empty() && addr == norm(b)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftIssue {
+    Unstaffed,
+    /// Only one publisher signed up.
+    NeedsPartner,
+    /// A brother and a sister of different households on their own.
+    MixedPair,
+}
+
+impl ShiftIssue {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Unstaffed => t!("pw-issue-unstaffed"),
+            Self::NeedsPartner => t!("pw-issue-needs-partner"),
+            Self::MixedPair => t!("pw-issue-mixed-pair"),
+        }
+    }
+}
+
+/// What is wrong with the people of a shift, if anything.
+pub fn issue(people: &[&User]) -> Option<ShiftIssue> {
+    match people {
+        [] => Some(ShiftIssue::Unstaffed),
+        [_] => Some(ShiftIssue::NeedsPartner),
+        [a, b] if a.gender != b.gender && !same_household(a, b) => Some(ShiftIssue::MixedPair),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignupError {
+    NotQualified,
+    AlreadySignedUp,
+    Full,
+    Absent,
+    /// Signed up for another shift at the same time.
+    Overlapping,
+}
+
+impl SignupError {
+    pub fn label(&self) -> String {
+        match self {
+            Self::NotQualified => t!("pw-err-not-qualified"),
+            Self::AlreadySignedUp => t!("pw-err-already"),
+            Self::Full => t!("pw-err-full"),
+            Self::Absent => t!("pw-err-absent"),
+            Self::Overlapping => t!("pw-err-overlapping"),
+        }
+    }
+}
+
+/// Whether `person` may join `shift` on `date`, given every occurrence
+/// stored around that date.
+pub fn check_signup(
+    person: &RecordId,
+    shift: &WitnessingShift,
+    date: &str,
+    occurrences: &[ShiftOccurrence],
+    shifts: &[WitnessingShift],
+    privileges: &[UserPrivileges],
+    absences: &[Absence],
+) -> Result<(), SignupError> {
+    if !privileges
+        .iter()
+        .any(|p| &p.publisher == person && p.public_witnessing)
+    {
+        return Err(SignupError::NotQualified);
+    }
+    let signed = occurrences
+        .iter()
+        .find(|o| Some(&o.shift) == shift.id.as_ref() && o.date == date)
+        .map(|o| o.publishers.as_slice())
+        .unwrap_or_default();
+    if signed.contains(person) {
+        return Err(SignupError::AlreadySignedUp);
+    }
+    if signed.len() >= shift.capacity as usize {
+        return Err(SignupError::Full);
+    }
+    if is_absent(absences, person, date) {
+        return Err(SignupError::Absent);
+    }
+    let clash = occurrences
+        .iter()
+        .filter(|o| {
+            o.date == date && Some(&o.shift) != shift.id.as_ref() && o.publishers.contains(person)
+        })
+        .filter_map(|o| shifts.iter().find(|s| s.id.as_ref() == Some(&o.shift)))
+        .any(|other| overlaps(shift, other));
+    if clash {
+        return Err(SignupError::Overlapping);
+    }
+    Ok(())
+}
+
+/// Rotation slots that bring every occurrence up to [`MIN_PER_SHIFT`],
+/// keyed `"<occurrence>-<n>"`. New partners share the gender of whoever is
+/// already on the shift. People already signed up count as busy for their
+/// whole day, so nobody is proposed twice on one date.
+pub fn pairing_slots<'a>(
+    occurrences: &[ShiftOccurrence],
+    shifts: &'a [WitnessingShift],
+) -> Vec<Slot<'a>> {
+    let mut slots = vec![];
+    for (i, occ) in occurrences.iter().enumerate() {
+        for (j, person) in occ.publishers.iter().enumerate() {
+            slots.push(Slot {
+                key: format!("{i}-fixed-{j}"),
+                meeting: occ.date.clone(),
+                date: occ.date.clone(),
+                qualifies: Box::new(|_| false),
+                requirement: Requirement::Anyone,
+                same_gender_as: None,
+                fixed: Some(person.clone()),
+            });
+        }
+        let Some(shift) = shifts.iter().find(|s| s.id.as_ref() == Some(&occ.shift)) else {
+            continue;
+        };
+        let wanted = MIN_PER_SHIFT.min(shift.capacity as usize);
+        let mut anchor = (!occ.publishers.is_empty()).then(|| format!("{i}-fixed-0"));
+        for n in occ.publishers.len()..wanted {
+            let key = format!("{i}-{n}");
+            slots.push(Slot {
+                key: key.clone(),
+                meeting: occ.date.clone(),
+                date: occ.date.clone(),
+                qualifies: Box::new(|p| p.public_witnessing),
+                requirement: Requirement::Anyone,
+                same_gender_as: anchor.clone(),
+                fixed: None,
+            });
+            anchor.get_or_insert(key);
+        }
+    }
+    slots
+}
+
+/// Add accepted picks to their occurrences.
+pub fn apply_picks(occurrences: &mut [ShiftOccurrence], picks: &[Pick]) {
+    for pick in picks {
+        let Some(person) = pick.person.clone() else {
+            continue;
+        };
+        let Some((i, n)) = pick.key.split_once('-') else {
+            continue;
+        };
+        if n.starts_with("fixed") {
+            continue;
+        }
+        let Ok(i) = i.parse::<usize>() else { continue };
+        if let Some(occ) = occurrences.get_mut(i)
+            && !occ.publishers.contains(&person)
+        {
+            occ.publishers.push(person);
+        }
+    }
+}
+
+/// `(person, date)` of every sign-up, the history the rotation balances on.
+pub fn assignments(occurrences: &[ShiftOccurrence]) -> Vec<(RecordId, String)> {
+    occurrences
+        .iter()
+        .flat_map(|o| o.publishers.iter().map(|p| (p.clone(), o.date.clone())))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{privileges, rid};
+
+    fn shift(key: &str, weekday: u8, start: &str, end: &str, capacity: u8) -> WitnessingShift {
+        WitnessingShift {
+            id: Some(rid("witnessing_shift", key)),
+            location: rid("witnessing_location", "square"),
+            weekday,
+            start: start.into(),
+            end: end.into(),
+            capacity,
+            active: true,
+        }
+    }
+
+    fn privileged(key: &str) -> UserPrivileges {
+        UserPrivileges { public_witnessing: true, ..privileges(key) }
+    }
+
+    #[test]
+    fn signup_rules() {
+        let morning = shift("morning", 5, "09:00", "11:00", 2);
+        let late = shift("late", 5, "10:00", "12:00", 3);
+        let shifts = vec![morning.clone(), late.clone()];
+        let mut occ =
+            ShiftOccurrence::blank(rid("witnessing_shift", "morning"), "2026-10-24".into());
+        occ.publishers = vec![rid("user", "a"), rid("user", "b")];
+        let occurrences = vec![occ];
+        let privileges = vec![privileged("a"), privileged("b"), privileged("c")];
+        let check = |who: &str, s: &WitnessingShift| {
+            check_signup(
+                &rid("user", who),
+                s,
+                "2026-10-24",
+                &occurrences,
+                &shifts,
+                &privileges,
+                &[],
+            )
+        };
+        assert_eq!(check("a", &morning), Err(SignupError::AlreadySignedUp));
+        assert_eq!(check("c", &morning), Err(SignupError::Full));
+        assert_eq!(check("d", &late), Err(SignupError::NotQualified));
+        assert_eq!(check("a", &late), Err(SignupError::Overlapping));
+        assert_eq!(check("c", &late), Ok(()));
+    }
+
+    #[test]
+    fn pairing_fills_up_to_two() {
+        let shifts = vec![
+            shift("morning", 5, "09:00", "11:00", 4),
+            shift("solo", 6, "09:00", "11:00", 1),
+        ];
+        let mut one =
+            ShiftOccurrence::blank(rid("witnessing_shift", "morning"), "2026-10-24".into());
+        one.publishers = vec![rid("user", "a")];
+        let empty = ShiftOccurrence::blank(rid("witnessing_shift", "morning"), "2026-10-31".into());
+        let solo = ShiftOccurrence::blank(rid("witnessing_shift", "solo"), "2026-10-25".into());
+        let slots = pairing_slots(&[one, empty, solo], &shifts);
+        let open: Vec<(&str, Option<&str>)> = slots
+            .iter()
+            .filter(|s| s.fixed.is_none())
+            .map(|s| (s.key.as_str(), s.same_gender_as.as_deref()))
+            .collect();
+        assert_eq!(
+            open,
+            vec![
+                ("0-1", Some("0-fixed-0")),
+                ("1-0", None),
+                ("1-1", Some("1-0")),
+                ("2-0", None)
+            ]
+        );
+    }
+}